@@ -0,0 +1,211 @@
+//! The manifest of known correct answers to the actual problem inputs.
+//!
+//! Rather than each day's tests containing the answers to its actual input, these are
+//! kept together in the [`ANSWERS_FILE`] manifest, which is loaded once when first needed.
+//! The manifest is used by the `actual` test generated by the
+//! [`solution_tests`](crate::solution_tests) macro when no `actual_answers` are given,
+//! and by the `--verify` option of the main program.
+//!
+//! The manifest is a TOML file with a table for each year, in which each day has an
+//! array of the answers for each part.
+//! Numeric answers are integers and text answers are strings.
+//! Answers for different sets of inputs, for example those of another account, can be
+//! kept in profile tables and selected with the [`PROFILE_VAR`] environment variable.
+//! ```toml
+//! [2015]
+//! 1 = [280, 1797]
+//! 11 = ["hxbxxyzz", "hxcaabcc"]
+//!
+//! [profile.work.2015]
+//! 1 = [74, 1795]
+//! ```
+
+use crate::{
+    error::{AocError, AocResult},
+    solution::{Answer, Year},
+};
+use std::{fs, sync::OnceLock};
+
+/// The path of the answer manifest, relative to the working directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The environment variable that selects the profile of answers to use, if set.
+pub const PROFILE_VAR: &str = "AOC_ANSWER_PROFILE";
+
+/// The known correct answers to the actual problem inputs.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::answer_db::AnswerDb;
+/// use aoc::solution::Year;
+///
+/// let db = AnswerDb::parse(
+///     "[2015]
+/// 1 = [280, 1797]
+/// 11 = [\"hxbxxyzz\", \"hxcaabcc\"]
+///
+/// [profile.work.2015]
+/// 1 = [74, -3]",
+/// )
+/// .unwrap();
+/// let year = Year::new(2015).unwrap();
+///
+/// assert_eq!(
+///     db.answers(None, year, 1),
+///     Ok(vec![Answer::Unsigned(280), Answer::Unsigned(1797)]),
+/// );
+/// assert_eq!(
+///     db.answers(Some("work"), year, 1),
+///     Ok(vec![Answer::Unsigned(74), Answer::Signed(-3)]),
+/// );
+/// assert!(db.answers(None, year, 2).is_err());
+///
+/// // Answers are compared by value regardless of their signedness
+/// let work = [Some(Answer::Signed(74)), Some(Answer::Signed(-3))];
+/// assert!(db.verify(Some("work"), year, 1, &work).is_ok());
+/// let passwords = [Some("hxbxxyzz".into()), Some("hxcaabcc".into())];
+/// assert!(db.verify(None, year, 11, &passwords).is_ok());
+///
+/// // Every part must be answered correctly
+/// assert!(db.verify(None, year, 11, &[None, Some("hxcaabcc".into())]).is_err());
+/// assert!(db.verify(None, year, 1, &[Some(Answer::Unsigned(280))]).is_err());
+/// let text = [Some(Answer::Unsigned(280)), Some("1797".into())];
+/// assert!(db.verify(None, year, 1, &text).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnswerDb {
+    /// The parsed manifest.
+    table: toml::Table,
+}
+impl AnswerDb {
+    /// Parses a manifest from its TOML text.
+    pub fn parse(s: &str) -> AocResult<Self> {
+        Ok(Self {
+            table: s.parse().map_err(|e: toml::de::Error| {
+                AocError::InvalidInput(format!("Invalid answer manifest: {}", e.message()).into())
+            })?,
+        })
+    }
+
+    /// Returns the manifest loaded from the [`ANSWERS_FILE`], which is only read the
+    /// first time this is called.
+    pub fn load() -> AocResult<&'static Self> {
+        /// The loaded manifest.
+        static DB: OnceLock<AocResult<AnswerDb>> = OnceLock::new();
+
+        DB.get_or_init(|| {
+            let text = fs::read_to_string(ANSWERS_FILE).map_err(|e| {
+                AocError::Other(format!("Could not read {ANSWERS_FILE}: {e}").into())
+            })?;
+            Self::parse(&text)
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+    }
+
+    /// Returns the known answers to every part of a `day`'s problem for some `year`,
+    /// which are from a `profile` if one is given.
+    pub fn answers(&self, profile: Option<&str>, year: Year, day: u8) -> AocResult<Vec<Answer>> {
+        let missing = || {
+            AocError::Other(
+                match profile {
+                    Some(p) => format!("No known answers for year {year} day {day} in profile {p}"),
+                    None => format!("No known answers for year {year} day {day}"),
+                }
+                .into(),
+            )
+        };
+
+        let mut table = &self.table;
+        if let Some(profile) = profile {
+            table = table
+                .get("profile")
+                .and_then(|t| t.get(profile))
+                .and_then(|t| t.as_table())
+                .ok_or_else(missing)?;
+        }
+        let answers = table
+            .get(&year.to_string())
+            .and_then(|t| t.get(&day.to_string()))
+            .and_then(|a| a.as_array())
+            .ok_or_else(missing)?;
+
+        answers
+            .iter()
+            .map(|value| match value {
+                toml::Value::Integer(n) => Ok(match u64::try_from(*n) {
+                    Ok(n) => Answer::Unsigned(n),
+                    Err(_) => Answer::Signed(*n),
+                }),
+                toml::Value::String(s) => Ok(Answer::String(s.clone().into())),
+                _ => Err(AocError::InvalidInput(
+                    format!("Invalid answer {value} for year {year} day {day}").into(),
+                )),
+            })
+            .collect()
+    }
+
+    /// Verifies some `answers` to each part of a `day`'s problem for some `year` against
+    /// the known answers, which are from a `profile` if one is given.
+    ///
+    /// Every known part must have an answer that matches the known answer, see
+    /// [`Answer::matches`], and there must be no answers to parts that are not known.
+    /// Returns an [`AocError::Process`] describing the first mismatch, if any.
+    pub fn verify(
+        &self,
+        profile: Option<&str>,
+        year: Year,
+        day: u8,
+        answers: &[Option<Answer>],
+    ) -> AocResult<()> {
+        let known = self.answers(profile, year, day)?;
+
+        if answers.len() != known.len() {
+            return Err(AocError::Process(
+                format!(
+                    "There are {} answers but {} known answers",
+                    answers.len(),
+                    known.len()
+                )
+                .into(),
+            ));
+        }
+
+        for (part, (answer, k)) in answers.iter().zip(known.iter()).enumerate() {
+            match answer {
+                Some(answer) if answer.matches(k) => {}
+                Some(answer) => {
+                    return Err(AocError::Process(
+                        format!(
+                            "The answer {answer} to part {} is not the known answer {k}",
+                            part + 1
+                        )
+                        .into(),
+                    ))
+                }
+                None => {
+                    return Err(AocError::Process(
+                        format!("There is no answer to part {}", part + 1).into(),
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the profile selected by the [`PROFILE_VAR`] environment variable, if any.
+pub fn active_profile() -> Option<String> {
+    std::env::var(PROFILE_VAR)
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+}
+
+/// Verifies some `answers` to each part of a `day`'s problem for some `year` against
+/// the [`ANSWERS_FILE`] manifest using the [`active_profile`], see [`AnswerDb::verify`].
+pub fn verify_answers(year: Year, day: u8, answers: &[Option<Answer>]) -> AocResult<()> {
+    AnswerDb::load()?.verify(active_profile().as_deref(), year, day, answers)
+}
@@ -0,0 +1,266 @@
+//! Support for dense two-state cellular automata on grids.
+//!
+//! The [`Evolver`](crate::evolver::Evolver) trait recomputes the neighbor counts of every
+//! cell each generation.
+//! For automata in which only a small fraction of cells change in each generation, the
+//! [`NeighborCounts`] structure is much faster because it maintains the live neighbor
+//! counts incrementally and only reconsiders cells that could possibly change.
+//! Automata with any rule for each cell, such as ones that depend on the lines of sight
+//! from each cell, can be run with [`generations`] until they become stable with
+//! [`stabilize`].
+
+use crate::{
+    collections::HashSet,
+    error::{AocError, AocResult},
+    grid::{Grid, GridPoint, GridSpace},
+};
+use std::hash::Hash;
+
+/// A grid of two-state cells that maintains the number of live neighbors of
+/// every cell as cells are toggled.
+///
+/// The cell values can be any type that can be converted to and from [`bool`],
+/// such as [`bool`] itself or [`StdBool`](crate::grid::StdBool).
+/// Neighbors may or may not include the diagonal cells, and cells outside the
+/// grid are always considered to be dead.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::automaton::NeighborCounts;
+/// use aoc::grid::StdBool;
+/// use std::str::FromStr;
+///
+/// // Conway's Game of Life rule
+/// let rule = |live: bool, count: u8| count == 3 || (live && count == 2);
+///
+/// // The Blinker oscillator
+/// let vertical = Grid::<StdBool>::from_str(
+///     ".....
+/// ..#..
+/// ..#..
+/// ..#..
+/// .....",
+/// )
+/// .unwrap();
+/// let horizontal = Grid::<StdBool>::from_str(
+///     ".....
+/// .....
+/// .###.
+/// .....
+/// .....",
+/// )
+/// .unwrap();
+///
+/// let mut cells = NeighborCounts::new(vertical.clone(), true);
+/// assert_eq!(cells.count(&GridPoint::new(1, 2)), 3);
+/// assert_eq!(cells.live_cells(), 3);
+///
+/// assert_eq!(cells.step(rule), 4);
+/// assert_eq!(cells.cells(), &horizontal);
+/// assert_eq!(cells.step(rule), 4);
+/// assert_eq!(cells.cells(), &vertical);
+///
+/// // Cells can also be changed manually
+/// cells.set(&GridPoint::new(0, 0), true.into());
+/// assert_eq!(cells.count(&GridPoint::new(1, 1)), 3);
+/// ```
+#[derive(Clone)]
+pub struct NeighborCounts<T, U = GridSpace> {
+    /// The current cell values.
+    cells: Grid<T, U>,
+    /// The number of live neighbors of each cell.
+    counts: Grid<u8, U>,
+    /// Whether the diagonal cells are neighbors.
+    include_diagonals: bool,
+    /// Cells that either changed or had a neighbor change since the last step,
+    /// which are the only cells that can change in the next step.
+    active: HashSet<GridPoint<U>>,
+}
+impl<T: From<bool> + Into<bool> + Clone, U> NeighborCounts<T, U> {
+    /// Creates the structure from an initial grid of `cells`, counting all the
+    /// live neighbors.
+    pub fn new(cells: Grid<T, U>, include_diagonals: bool) -> Self {
+        let mut counts = Grid::default(*cells.size());
+        for point in cells.all_points().filter(|p| cells.get(p).clone().into()) {
+            for neighbor in cells.neighbor_points(&point, include_diagonals, false) {
+                *counts.element_at(&neighbor) += 1;
+            }
+        }
+
+        Self {
+            active: cells.all_points().collect(),
+            cells,
+            counts,
+            include_diagonals,
+        }
+    }
+
+    /// Returns the current cell values.
+    pub fn cells(&self) -> &Grid<T, U> {
+        &self.cells
+    }
+
+    /// Consumes the structure, returning the current cell values.
+    pub fn into_cells(self) -> Grid<T, U> {
+        self.cells
+    }
+
+    /// Returns whether the cell at a `point` is live.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is out of the bounds of the grid.
+    pub fn is_live(&self, point: &GridPoint<U>) -> bool {
+        self.cells.get(point).clone().into()
+    }
+
+    /// Returns the number of live neighbors of the cell at a `point`.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is out of the bounds of the grid.
+    pub fn count(&self, point: &GridPoint<U>) -> u8 {
+        *self.counts.get(point)
+    }
+
+    /// Returns the total number of live cells.
+    pub fn live_cells(&self) -> usize {
+        self.cells
+            .all_values()
+            .filter(|v| (*v).clone().into())
+            .count()
+    }
+
+    /// Sets the cell at a `point` to a `value`, updating the neighbor counts
+    /// if its state changed.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is out of the bounds of the grid.
+    pub fn set(&mut self, point: &GridPoint<U>, value: T) {
+        let live: bool = value.clone().into();
+        let changed = live != self.is_live(point);
+        self.cells.set(point, value);
+
+        if changed {
+            self.active.insert(*point);
+            for neighbor in self
+                .cells
+                .neighbor_points(point, self.include_diagonals, false)
+            {
+                let count = self.counts.element_at(&neighbor);
+                if live {
+                    *count += 1;
+                } else {
+                    *count -= 1;
+                }
+                self.active.insert(neighbor);
+            }
+        }
+    }
+
+    /// Advances all the cells by one generation, returning the number of cells
+    /// that changed.
+    ///
+    /// The `rule` determines whether a cell will be live in the next generation
+    /// given whether it is currently live and its number of live neighbors.
+    /// Since only cells whose neighborhood changed in the previous generation are
+    /// reconsidered, the `rule` must depend on nothing else.
+    pub fn step(&mut self, rule: impl Fn(bool, u8) -> bool) -> usize {
+        let changes: Vec<_> = std::mem::take(&mut self.active)
+            .into_iter()
+            .filter_map(|point| {
+                let live = self.is_live(&point);
+                let next = rule(live, self.count(&point));
+                (next != live).then_some((point, next))
+            })
+            .collect();
+
+        for (point, live) in changes.iter() {
+            self.set(point, (*live).into());
+        }
+        changes.len()
+    }
+}
+
+/// Returns an [`Iterator`] over the generations of a `grid`, starting with the grid
+/// itself, in which each cell of the next generation is the result of a `rule` given
+/// the current generation and the point of the cell.
+///
+/// Since all the cells are recomputed for every generation, the `rule` can depend on
+/// anything, such as the first cells that are visible in each direction, see
+/// [`Grid::first_visible`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::automaton::generations;
+///
+/// // Each cell becomes the largest of itself and its orthogonal neighbors
+/// let grid = Grid::<u8>::from_data(vec![vec![0, 0, 0], vec![0, 0, 5]]).unwrap();
+/// let rule = |grid: &Grid<u8>, point: &GridPoint| {
+///     grid.neighbor_points(point, false, true)
+///         .map(|p| *grid.get(&p))
+///         .max()
+///         .unwrap()
+/// };
+///
+/// let mut iter = generations(grid.clone(), rule);
+/// assert_eq!(iter.next(), Some(grid));
+/// assert_eq!(
+///     iter.next(),
+///     Some(Grid::from_data(vec![vec![0, 0, 5], vec![0, 5, 5]]).unwrap())
+/// );
+/// ```
+pub fn generations<T: Clone, U>(
+    grid: Grid<T, U>,
+    rule: impl Fn(&Grid<T, U>, &GridPoint<U>) -> T,
+) -> impl Iterator<Item = Grid<T, U>> {
+    std::iter::successors(Some(grid), move |grid| {
+        let mut next = grid.clone();
+        for point in grid.all_points() {
+            next.set(&point, rule(grid, &point));
+        }
+        Some(next)
+    })
+}
+
+/// Returns the first state of a sequence of `states`, such as [`generations`], that is
+/// the same as the state after it, along with the number of states before it.
+///
+/// Returns [`AocError::Process`] if a state repeats after some other state, so that the
+/// states cycle without ever becoming stable, or [`AocError::NoSolution`] if the states
+/// end before becoming stable.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::automaton::stabilize;
+///
+/// assert_eq!(stabilize([3, 2, 1, 1, 0]), Ok((1, 2)));
+/// assert_matches!(stabilize([3, 2, 1, 2, 1]), Err(AocError::Process(_)));
+/// assert_eq!(stabilize([3, 2, 1]), Err(AocError::NoSolution));
+/// ```
+pub fn stabilize<S: Clone + Eq + Hash>(
+    states: impl IntoIterator<Item = S>,
+) -> AocResult<(S, usize)> {
+    let mut seen = HashSet::default();
+    let mut last: Option<S> = None;
+    for (idx, state) in states.into_iter().enumerate() {
+        if let Some(last) = last {
+            if state == last {
+                return Ok((state, idx - 1));
+            }
+        }
+        if !seen.insert(state.clone()) {
+            return Err(AocError::Process(
+                "The states repeat without becoming stable".into(),
+            ));
+        }
+        last = Some(state);
+    }
+    Err(AocError::NoSolution)
+}
@@ -0,0 +1,115 @@
+//! Caching of preprocessed solution data across runs.
+//!
+//! Some solutions perform most of their work in the preprocessor, for example
+//! the 2020 day 20 problem, which places all of the tiles.
+//! The [`cached`] function can be used in such preprocessors so that the work is only done
+//! once for a particular input, with the output being serialized to the [`CACHE_DIR`] directory
+//! and reloaded on subsequent runs.
+//!
+//! Serialization only occurs when the `cache` feature is enabled, in which case the preprocessed
+//! data type must implement the `serde` serialization traits.
+//! Otherwise [`cached`] simply runs the preprocessing every time.
+
+use crate::{error::AocResult, solution::SolverInput};
+use std::any::Any;
+
+/// The directory, relative to the working directory, in which cached data is stored.
+pub const CACHE_DIR: &str = "cache";
+
+/// Marker trait for preprocessed data that can be cached.
+///
+/// This should only be implemented for data that depends solely on the problem input
+/// and the [`Cacheable::VERSION`], since these are used to identify the cached data.
+#[cfg(feature = "cache")]
+pub trait Cacheable: serde::Serialize + serde::de::DeserializeOwned + Any {
+    /// The version of the preprocessing that produces this data, which is part of the key
+    /// of the cached data.
+    ///
+    /// This must be incremented whenever the preprocessing changes, so that data cached by
+    /// previous versions is not used.
+    const VERSION: u32;
+}
+
+/// Marker trait for preprocessed data that can be cached.
+///
+/// This should only be implemented for data that depends solely on the problem input
+/// and the [`Cacheable::VERSION`], since these are used to identify the cached data.
+#[cfg(not(feature = "cache"))]
+pub trait Cacheable: Any {
+    /// The version of the preprocessing that produces this data, which is part of the key
+    /// of the cached data.
+    ///
+    /// This must be incremented whenever the preprocessing changes, so that data cached by
+    /// previous versions is not used.
+    const VERSION: u32;
+}
+
+/// Runs a `preprocess` function on the `input` or loads its output from the cache
+/// if available, returning the data as a [`SolverInput`].
+///
+/// The cached data is keyed by a hash of the `input`, the name and [`Cacheable::VERSION`]
+/// of the data type, and the version of this crate, so that different problems do not
+/// share cached data and data cached by older code is not used.
+/// If the cached data cannot be read or written for any reason, the preprocessing is
+/// performed as if there were no cache.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::cache::{cached, Cacheable};
+///
+/// #[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
+/// struct Total(u64);
+/// impl Cacheable for Total {
+///     const VERSION: u32 = 1;
+/// }
+///
+/// let data = cached("1\n2\n3", |input| Ok(Total(u64::gather(input.lines())?.into_iter().sum())))
+///     .unwrap();
+/// assert_eq!(data.expect_data::<Total>().unwrap().0, 6);
+/// ```
+pub fn cached<'a, T: Cacheable>(
+    input: &str,
+    preprocess: impl FnOnce(&str) -> AocResult<T>,
+) -> AocResult<SolverInput<'a>> {
+    #[cfg(feature = "cache")]
+    let data = load_or_preprocess(input, preprocess)?;
+    #[cfg(not(feature = "cache"))]
+    let data = preprocess(input)?;
+
+    Ok(Box::new(data).into())
+}
+
+/// This is an internal function of [`cached`] that loads the data from the cache if
+/// possible, otherwise runs the `preprocess` function and stores its output in the cache.
+#[cfg(feature = "cache")]
+fn load_or_preprocess<T: Cacheable>(
+    input: &str,
+    preprocess: impl FnOnce(&str) -> AocResult<T>,
+) -> AocResult<T> {
+    use std::{fs, path::Path};
+
+    let key = md5::compute(format!(
+        "{}\n{}\n{}\n{input}",
+        env!("CARGO_PKG_VERSION"),
+        std::any::type_name::<T>(),
+        T::VERSION,
+    ));
+    let path = Path::new(CACHE_DIR).join(format!("{key:x}.json"));
+
+    // Use the cached data if it exists and is valid
+    if let Some(data) = fs::read_to_string(&path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+    {
+        return Ok(data);
+    }
+
+    let data = preprocess(input)?;
+    if let Ok(s) = serde_json::to_string(&data) {
+        // Failing to write the cache is not an error
+        let _ = fs::create_dir_all(CACHE_DIR).and_then(|_| fs::write(&path, s));
+    }
+    Ok(data)
+}
@@ -0,0 +1,50 @@
+//! Hash collections whose hashing algorithm can be swapped for speed.
+//!
+//! Hashing often dominates the run time of memoized searches and sets of grid points.
+//! By default these are just the standard library collections, but with the `fast-hash`
+//! feature they instead use the FxHash algorithm from the `rustc-hash` crate, which
+//! is much faster for small keys, but is not resistant to denial of service attacks.
+//!
+//! Since the hashing algorithm is a type parameter, the collections are created with
+//! [`Default::default`] or [`FromIterator`] rather than `new` or `From`, which only
+//! exist for the standard hashing algorithm.
+
+/// The builder of the hashers used by the collections, which is the standard one.
+#[cfg(not(feature = "fast-hash"))]
+pub type HashState = std::collections::hash_map::RandomState;
+
+/// The builder of the hashers used by the collections, which is FxHash.
+#[cfg(feature = "fast-hash")]
+pub type HashState = std::hash::BuildHasherDefault<rustc_hash::FxHasher>;
+
+/// A [`std::collections::HashMap`] using the [`HashState`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::collections::HashMap;
+///
+/// let mut map = HashMap::default();
+/// map.insert(GridPoint::<GridSpace>::new(1, 2), 'a');
+/// assert_eq!(map[&GridPoint::new(1, 2)], 'a');
+///
+/// let map: HashMap<_, _> = [(1, "one"), (2, "two")].into_iter().collect();
+/// assert_eq!(map.get(&2), Some(&"two"));
+/// ```
+pub type HashMap<K, V> = std::collections::HashMap<K, V, HashState>;
+
+/// A [`std::collections::HashSet`] using the [`HashState`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::collections::HashSet;
+///
+/// let mut set = HashSet::default();
+/// assert!(set.insert("text"));
+/// assert!(!set.insert("text"));
+/// assert_eq!(HashSet::from_iter([1, 2, 2, 3]).len(), 3);
+/// ```
+pub type HashSet<T> = std::collections::HashSet<T, HashState>;
@@ -0,0 +1,162 @@
+//! Counting of items and evolution of item counts under substitution rules.
+//!
+//! The [`Counter`] is a simple multiset with large counts, and the [`RuleEvolver`]
+//! evolves such counts under rules that replace each item with other items.
+//! This is useful for problems such as polymer pair insertion, in which the items
+//! themselves grow exponentially but only the number of each kind of item matters.
+
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+    ops::RangeInclusive,
+};
+
+use crate::iter::IteratorExt;
+
+/// A multiset that counts the number of occurrences of items.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::counter::Counter;
+///
+/// let mut counter: Counter<char> = "hello".chars().collect();
+/// assert_eq!(counter.get(&'l'), 2);
+/// assert_eq!(counter.get(&'z'), 0);
+///
+/// counter.add('z', 5);
+/// assert_eq!(counter.total(), 10);
+/// assert_eq!(counter.range(), Some(1..=5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Hash + Eq> {
+    /// Map of items to their counts.
+    counts: HashMap<K, u128>,
+}
+impl<K: Hash + Eq> Default for Counter<K> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+impl<K: Hash + Eq> Counter<K> {
+    /// Creates a new counter in which all items have zero counts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the count of an `item`.
+    pub fn get(&self, item: &K) -> u128 {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Adds some `count` to the count of an `item`.
+    pub fn add(&mut self, item: K, count: u128) {
+        *self.counts.entry(item).or_insert(0) += count;
+    }
+
+    /// Returns the total count of all items.
+    pub fn total(&self) -> u128 {
+        self.counts.values().sum()
+    }
+
+    /// Returns an [`Iterator`] over all the items that have been counted
+    /// and their counts, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, u128> {
+        self.counts.iter()
+    }
+
+    /// Returns the range from the least to the greatest item count, or `None`
+    /// if nothing has been counted.
+    pub fn range(&self) -> Option<RangeInclusive<u128>> {
+        self.counts.values().copied().range()
+    }
+
+    /// Returns a new counter by mapping every item to another item, combining the
+    /// counts of items that map to the same item.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::counter::Counter;
+    ///
+    /// let counter: Counter<u8> = [1, 2, 3, 4, 5].into_iter().collect();
+    /// let parities = counter.map_items(|n| n % 2);
+    ///
+    /// assert_eq!(parities.get(&0), 2);
+    /// assert_eq!(parities.get(&1), 3);
+    /// ```
+    pub fn map_items<J: Hash + Eq>(&self, f: impl Fn(&K) -> J) -> Counter<J> {
+        let mut counter = Counter::new();
+        for (item, count) in self.counts.iter() {
+            counter.add(f(item), *count);
+        }
+        counter
+    }
+}
+impl<K: Hash + Eq> FromIterator<K> for Counter<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut counter = Self::new();
+        for item in iter {
+            counter.add(item, 1);
+        }
+        counter
+    }
+}
+
+/// Evolves the item counts of a [`Counter`] in generations, in which each item is
+/// replaced by the items given by a rule map.
+///
+/// Items for which there is no rule are left unchanged.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::counter::{Counter, RuleEvolver};
+///
+/// // Lanternfish that spawn every seven days
+/// let evolver = RuleEvolver::new((0..=8).map(|timer| {
+///     (timer, if timer == 0 { vec![6, 8] } else { vec![timer - 1] })
+/// }));
+/// let fish: Counter<u8> = [3, 4, 3, 1, 2].into_iter().collect();
+///
+/// assert_eq!(evolver.evolve(&fish, 18).total(), 26);
+/// assert_eq!(evolver.evolve(&fish, 256).total(), 26984457539);
+/// ```
+pub struct RuleEvolver<K> {
+    /// Map of each item to the items that replace it in the next generation.
+    rules: HashMap<K, Vec<K>>,
+}
+impl<K: Hash + Eq + Clone> RuleEvolver<K> {
+    /// Creates a new evolver from the replacement `rules`.
+    pub fn new(rules: impl IntoIterator<Item = (K, Vec<K>)>) -> Self {
+        Self {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    /// Returns the counts after a single generation starting with some `counter`.
+    pub fn step(&self, counter: &Counter<K>) -> Counter<K> {
+        let mut next = Counter::new();
+        for (item, count) in counter.iter() {
+            match self.rules.get(item) {
+                Some(replacements) => {
+                    for replacement in replacements {
+                        next.add(replacement.clone(), *count);
+                    }
+                }
+                None => next.add(item.clone(), *count),
+            }
+        }
+        next
+    }
+
+    /// Returns the counts after some number of `generations` starting with some `counter`.
+    pub fn evolve(&self, counter: &Counter<K>, generations: usize) -> Counter<K> {
+        (0..generations).fold(counter.clone(), |counter, _| self.step(&counter))
+    }
+}
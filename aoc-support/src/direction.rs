@@ -0,0 +1,245 @@
+//! Cardinal directions and quarter turns in 2D.
+//!
+//! Many problems involve walking around a grid or plane while turning left or right.
+//! The [`Direction4`] can be turned by a [`Turn`] using multiplication, and the same
+//! turns can be applied to vectors using [`RotateQuarter::rotate_quarter`] and to
+//! entire grids using [`Grid::rotated`].
+//!
+//! These all use the [`GridSpace`](crate::grid::GridSpace) convention in which increasing
+//! `y` is down, so that turning right is clockwise when viewed on a screen.
+
+use crate::grid::{Grid, GridPoint, GridSize};
+use euclid::Vector2D;
+use num::Signed;
+use std::ops::Mul;
+
+/// A turn by some number of quarter turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    /// A quarter turn to the left, that is counter-clockwise.
+    Left,
+    /// A quarter turn to the right, that is clockwise.
+    Right,
+    /// A half turn.
+    Around,
+}
+impl Turn {
+    /// Returns the number of clockwise quarter turns in the turn, which is between zero and three.
+    pub fn quarters(&self) -> u8 {
+        match self {
+            Turn::Left => 3,
+            Turn::Right => 1,
+            Turn::Around => 2,
+        }
+    }
+}
+
+/// One of the four cardinal directions, which can be turned by a [`Turn`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::{Direction4, Turn};
+/// use euclid::default::Vector2D;
+///
+/// assert_eq!(Direction4::Up * Turn::Right, Direction4::Right);
+/// assert_eq!(Direction4::Up * Turn::Left, Direction4::Left);
+/// assert_eq!(Direction4::Left * Turn::Around, Direction4::Right);
+/// assert_eq!(Direction4::Down.as_vector::<i32, _>(), Vector2D::new(0, 1));
+/// assert_eq!(Direction4::try_from('<'), Ok(Direction4::Left));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    /// Up, that is towards decreasing `y`.
+    Up,
+    /// Right, that is towards increasing `x`.
+    Right,
+    /// Down, that is towards increasing `y`.
+    Down,
+    /// Left, that is towards decreasing `x`.
+    Left,
+}
+impl Direction4 {
+    /// Returns all of the directions in clockwise order starting with up.
+    pub fn all() -> [Self; 4] {
+        [Self::Up, Self::Right, Self::Down, Self::Left]
+    }
+
+    /// Returns the unit vector in this direction.
+    pub fn as_vector<T: Signed, U>(&self) -> Vector2D<T, U> {
+        match self {
+            Direction4::Up => Vector2D::new(T::zero(), -T::one()),
+            Direction4::Right => Vector2D::new(T::one(), T::zero()),
+            Direction4::Down => Vector2D::new(T::zero(), T::one()),
+            Direction4::Left => Vector2D::new(-T::one(), T::zero()),
+        }
+    }
+
+    /// Returns the index of this direction in [`Direction4::all`].
+    fn index(&self) -> u8 {
+        match self {
+            Direction4::Up => 0,
+            Direction4::Right => 1,
+            Direction4::Down => 2,
+            Direction4::Left => 3,
+        }
+    }
+}
+impl Mul<Turn> for Direction4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Turn) -> Self::Output {
+        Self::all()[usize::from((self.index() + rhs.quarters()) % 4)]
+    }
+}
+impl TryFrom<char> for Direction4 {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | 'N' | '^' => Ok(Self::Up),
+            'R' | 'E' | '>' => Ok(Self::Right),
+            'D' | 'S' | 'v' => Ok(Self::Down),
+            'L' | 'W' | '<' => Ok(Self::Left),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Extension trait to rotate 2D vectors by quarter turns.
+pub trait RotateQuarter {
+    /// Returns the vector rotated by some number of clockwise quarter turns, where
+    /// a negative number of turns is counter-clockwise.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::direction::{Direction4, RotateQuarter, Turn};
+    /// use euclid::default::Vector2D;
+    ///
+    /// let vector = Vector2D::new(3, -1);
+    /// assert_eq!(vector.rotate_quarter(1), Vector2D::new(1, 3));
+    /// assert_eq!(vector.rotate_quarter(-1), Vector2D::new(-1, -3));
+    /// assert_eq!(vector.rotate_quarter(6), Vector2D::new(-3, 1));
+    /// assert_eq!(vector.rotate_quarter(4), vector);
+    ///
+    /// // This is consistent with turning directions.
+    /// assert_eq!(
+    ///     Direction4::Up.as_vector::<i32, _>().rotate_quarter(Turn::Right.quarters().into()),
+    ///     Direction4::Right.as_vector(),
+    /// );
+    /// ```
+    fn rotate_quarter(&self, turns: i32) -> Self;
+}
+impl<T: Signed + Copy, U> RotateQuarter for Vector2D<T, U> {
+    fn rotate_quarter(&self, turns: i32) -> Self {
+        match turns.rem_euclid(4) {
+            0 => *self,
+            1 => Vector2D::new(-self.y, self.x),
+            2 => Vector2D::new(-self.x, -self.y),
+            _ => Vector2D::new(self.y, -self.x),
+        }
+    }
+}
+
+impl<T: Clone, U> Grid<T, U> {
+    /// Returns a copy of this grid turned by a `turn`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::direction::Turn;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.rotated(Turn::Right),
+    ///     Grid::from_data(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     grid.rotated(Turn::Left),
+    ///     Grid::from_data(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     grid.rotated(Turn::Around),
+    ///     Grid::from_data(vec![vec![6, 5, 4], vec![3, 2, 1]]).unwrap(),
+    /// );
+    /// ```
+    pub fn rotated(&self, turn: Turn) -> Self {
+        let size = *self.size();
+        let new_size = match turn {
+            Turn::Around => size,
+            _ => GridSize::new(size.height, size.width),
+        };
+
+        // Map each point in the new grid back to its point in this grid
+        let source = |x: usize, y: usize| match turn {
+            Turn::Left => GridPoint::new(size.width - 1 - y, x),
+            Turn::Right => GridPoint::new(y, size.height - 1 - x),
+            Turn::Around => GridPoint::new(size.width - 1 - x, size.height - 1 - y),
+        };
+
+        Grid::from_data(
+            (0..new_size.height)
+                .map(|y| {
+                    (0..new_size.width)
+                        .map(|x| self.get(&source(x, y)).clone())
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+}
+
+impl<T, U> Grid<T, U> {
+    /// Returns an [`Iterator`] over the values seen when looking out in each direction
+    /// from a `point`, not including the value at the `point` itself.
+    ///
+    /// The directions are in the order of [`Direction4::all`], and the values seen in
+    /// each direction are in order of increasing distance up to the edge of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::direction::Direction4;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.sightlines(&GridPoint::new(1, 1)).collect::<Vec<_>>(),
+    ///     vec![
+    ///         (Direction4::Up, vec![&2]),
+    ///         (Direction4::Right, vec![&6]),
+    ///         (Direction4::Down, vec![&8]),
+    ///         (Direction4::Left, vec![&4]),
+    ///     ],
+    /// );
+    /// assert_eq!(
+    ///     grid.sightlines(&GridPoint::new(0, 2)).collect::<Vec<_>>(),
+    ///     vec![
+    ///         (Direction4::Up, vec![&4, &1]),
+    ///         (Direction4::Right, vec![&8, &9]),
+    ///         (Direction4::Down, vec![]),
+    ///         (Direction4::Left, vec![]),
+    ///     ],
+    /// );
+    /// ```
+    pub fn sightlines<'a>(
+        &'a self,
+        point: &GridPoint<U>,
+    ) -> impl Iterator<Item = (Direction4, Vec<&'a T>)> + 'a {
+        let start = point.to_isize();
+        Direction4::all().into_iter().map(move |direction| {
+            let step = direction.as_vector::<isize, U>();
+            let values = std::iter::successors(Some(start + step), move |p| Some(*p + step))
+                .map_while(|p| self.get_any(&p))
+                .collect();
+            (direction, values)
+        })
+    }
+}
@@ -0,0 +1,361 @@
+//! For cell arrays that can evolve in discrete steps.
+//!
+//! The [`Evolver`] trait can be implemented for cell arrays that can
+//! evolve.
+//! The value of some state far too many steps ahead to simulate can be found from a
+//! repeating sequence of states with [`extrapolate`].
+use crate::{
+    error::{AocError, AocResult},
+    iter::IndexedStates,
+};
+use num::{NumCast, PrimInt};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData, rc::Rc};
+
+/// Can be implemented for something that evolves in discrete steps and has
+/// addressable cells.
+///
+/// For example, a [Conway's Game of Life](https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life) simulation.
+/// Each cell has a value of type `T`. An basic example of this using the Toad oscillator is provided below.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use aoc::prelude::*;
+/// use aoc::grid::StdBool;
+/// use std::str::FromStr;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq)]
+/// struct ConwayCells {
+///     grid: Grid<StdBool>,
+/// }
+/// impl From<Grid<StdBool>> for ConwayCells {
+///     fn from(value: Grid<StdBool>) -> Self {
+///         Self { grid: value }
+///     }
+/// }
+/// impl Evolver<bool> for ConwayCells {
+///     type Point = GridPoint;
+///
+///     fn next_default(other: &Self) -> Self {
+///         Self {
+///             grid: Grid::default(*other.grid.size()),
+///         }
+///     }
+///
+///     fn set_element(&mut self, point: &Self::Point, value: bool) {
+///         self.grid.set(point, value.into())
+///     }
+///
+///     fn next_cell(&self, point: &Self::Point) -> bool {
+///         let live_neighbors: usize = self
+///             .grid
+///             .neighbor_points(point, true, false)
+///             .filter_count(|p| **self.grid.get(p));
+///         if **self.grid.get(point) {
+///             // The current cell is live
+///             live_neighbors == 2 || live_neighbors == 3
+///         } else {
+///             // The current cell is dead
+///             live_neighbors == 3
+///         }
+///     }
+///
+///     fn next_iter(&self) -> impl Iterator<Item = Self::Point> {
+///         self.grid.size().all_points()
+///     }
+/// }
+///
+/// // The first and initial frame of the Toad oscillator
+/// let step_a: ConwayCells = Grid::from_str(
+///     "....
+/// .###
+/// ####.
+/// ....",
+/// )
+/// .unwrap()
+/// .into();
+/// // The second frame of the Toad oscillator
+/// let step_b: ConwayCells = Grid::from_str(
+///     "..#.
+/// ##..#
+/// ##..#
+/// .#..",
+/// )
+/// .unwrap()
+/// .into();
+///
+/// // The two frames of the Toad oscillator alternate forever.
+/// let mut evolutions = step_a.evolutions();
+/// for _ in 0..10 {
+///     assert_eq!(evolutions.next().unwrap().as_ref(), &step_b);
+///     assert_eq!(evolutions.next().unwrap().as_ref(), &step_a);
+/// }
+///
+/// // Any step can also be accessed directly.
+/// let mut states = step_a.indexed_states(16);
+/// assert_eq!(states.get(101), step_b);
+/// assert_eq!(states.get(50), step_a);
+/// ```
+pub trait Evolver<T> {
+    /// Type that is used to address a single cell.
+    type Point;
+
+    /// Creates a new cell array in the default state based on the current
+    /// cell array.
+    ///
+    /// This is used to create the initial cell array for the next step before
+    /// actually setting each cell to the new value using [`Evolver::next_cell`].
+    fn next_default(other: &Self) -> Self;
+
+    /// Sets the cell value at the specified address.
+    fn set_element(&mut self, point: &Self::Point, value: T);
+
+    /// Returns the value of the addressed cell for the next step.
+    fn next_cell(&self, point: &Self::Point) -> T;
+
+    /// Returns an iterator over the cell addresses to be set in the
+    /// next step using the [`Evolver::next_cell`] method.
+    fn next_iter(&self) -> impl Iterator<Item = Self::Point>;
+
+    /// Returns the next step in the evolution of the cell array.
+    fn evolve(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut next = Self::next_default(self);
+        for point in self.next_iter() {
+            next.set_element(&point, self.next_cell(&point));
+        }
+        next
+    }
+
+    /// Returns the steps in the evolution of the cell array with random access by step,
+    /// where the current array is step zero and every `interval`th step is cached,
+    /// see [`IndexedStates`].
+    ///
+    /// # Panics
+    /// This will panic if the `interval` is zero.
+    fn indexed_states(&self, interval: usize) -> IndexedStates<Self, fn(&Self) -> Self>
+    where
+        Self: Sized + Clone,
+    {
+        IndexedStates::new(self.clone(), interval, Self::evolve as fn(&Self) -> Self)
+    }
+
+    /// Evolves the cell array until it is `done`, returning the number of steps taken
+    /// and the final cell array, where the current array is step zero.
+    ///
+    /// Returns an [`AocError::Process`] if the array is not done after `max_steps`
+    /// steps, or if the array repeats a previous state without being done, since
+    /// the evolution would then loop forever.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct Counter(u8);
+    /// impl Evolver<u8> for Counter {
+    ///     type Point = ();
+    ///
+    ///     fn next_default(_other: &Self) -> Self {
+    ///         Self(0)
+    ///     }
+    ///
+    ///     fn set_element(&mut self, _point: &Self::Point, value: u8) {
+    ///         self.0 = value;
+    ///     }
+    ///
+    ///     fn next_cell(&self, _point: &Self::Point) -> u8 {
+    ///         (self.0 + 3) % 10
+    ///     }
+    ///
+    ///     fn next_iter(&self) -> impl Iterator<Item = Self::Point> {
+    ///         std::iter::once(())
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Counter(0).evolve_detecting_loop(100, |c| c.0 == 4),
+    ///     Ok((8, Counter(4)))
+    /// );
+    /// assert_eq!(
+    ///     Counter(4).evolve_detecting_loop(100, |c| c.0 == 4),
+    ///     Ok((0, Counter(4)))
+    /// );
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// # #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    /// # struct Counter(u8);
+    /// # impl Evolver<u8> for Counter {
+    /// #     type Point = ();
+    /// #     fn next_default(_other: &Self) -> Self {
+    /// #         Self(0)
+    /// #     }
+    /// #     fn set_element(&mut self, _point: &Self::Point, value: u8) {
+    /// #         self.0 = value;
+    /// #     }
+    /// #     fn next_cell(&self, _point: &Self::Point) -> u8 {
+    /// #         (self.0 + 3) % 10
+    /// #     }
+    /// #     fn next_iter(&self) -> impl Iterator<Item = Self::Point> {
+    /// #         std::iter::once(())
+    /// #     }
+    /// # }
+    /// // The counter loops back to zero after ten steps
+    /// assert_eq!(
+    ///     Counter(0).evolve_detecting_loop(100, |c| c.0 > 9),
+    ///     Err(AocError::Process(
+    ///         "The state at step 10 repeats that of step 0 without finishing".into()
+    ///     ))
+    /// );
+    /// assert!(Counter(0).evolve_detecting_loop(5, |c| c.0 == 4).is_err());
+    /// ```
+    fn evolve_detecting_loop(
+        &self,
+        max_steps: usize,
+        done: impl Fn(&Self) -> bool,
+    ) -> AocResult<(usize, Self)>
+    where
+        Self: Sized + Clone + Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+        let mut current = self.clone();
+        for step in 0..=max_steps {
+            if done(&current) {
+                return Ok((step, current));
+            }
+            if let Some(previous) = seen.insert(current.clone(), step) {
+                return Err(AocError::Process(
+                    format!(
+                        "The state at step {step} repeats that of step {previous} without finishing"
+                    )
+                    .into(),
+                ));
+            }
+            current = current.evolve();
+        }
+
+        Err(AocError::Process(
+            format!("The evolution did not finish within {max_steps} steps").into(),
+        ))
+    }
+
+    /// Returns an [`Iterator`] over the steps in the evolution of the cell array.
+    ///
+    /// The first element will be the next evolution, not the current array.
+    fn evolutions(&self) -> EvolverIter<Self, T>
+    where
+        Self: Sized + Clone,
+    {
+        EvolverIter {
+            current: Rc::new(self.clone()),
+            _phant: PhantomData {},
+        }
+    }
+}
+
+/// [`Iterator`] to evolve an [`Evolver`] cell array.
+///
+/// `E` is the type that implements the [`Evolver`] trait, while `T` is
+/// type of the cell values.
+pub struct EvolverIter<E, T> {
+    /// The current cell array.
+    current: Rc<E>,
+    /// Phantom data for the cell value type `T`.
+    _phant: PhantomData<T>,
+}
+impl<E, T> Iterator for EvolverIter<E, T>
+where
+    E: Evolver<T>,
+{
+    type Item = Rc<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current = Rc::new(self.current.evolve());
+        Some(self.current.clone())
+    }
+}
+
+/// Returns the value of the state at some `target_step` of a sequence of `states`, where
+/// the first state is step zero, without necessarily having to reach that step.
+///
+/// The states are assumed to eventually repeat in a cycle, which is detected when the
+/// `fingerprint` of a state is the same as that of an earlier state.
+/// The `value` of a state is then assumed to change by the same amount over each repetition
+/// of the cycle, so that the value at the target step is extrapolated from the first cycle.
+/// If the value can decrease over a cycle, then it should be of a signed type.
+///
+/// Returns an [`AocError::Process`] if the states end before both the target step and a
+/// repeated fingerprint, or if the number of cycles cannot be represented by the value type.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::evolver::extrapolate;
+///
+/// // A simplified tower of falling rocks, like those of 2022 day 17, in which the rocks are
+/// // stacked directly on top of each other so that the rock shapes repeat every five rocks.
+/// const ROCK_HEIGHTS: [u64; 5] = [1, 3, 3, 4, 2];
+/// let towers = || {
+///     (0..).scan(0, |height, rocks: usize| {
+///         let tower = (rocks, *height);
+///         *height += ROCK_HEIGHTS[rocks % ROCK_HEIGHTS.len()];
+///         Some(tower)
+///     })
+/// };
+/// let tower_height = |rocks| {
+///     extrapolate(
+///         towers(),
+///         rocks,
+///         |(rocks, _)| rocks % ROCK_HEIGHTS.len(),
+///         |(_, height)| *height,
+///     )
+/// };
+///
+/// assert_eq!(tower_height(3), Ok(7));
+/// assert_eq!(tower_height(2022), Ok(5256));
+/// assert_eq!(tower_height(1000000000000), Ok(2600000000000));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::evolver::extrapolate;
+///
+/// // The states end without ever repeating
+/// assert!(extrapolate(0..10, 100, |s| *s, |s| *s).is_err());
+/// ```
+pub fn extrapolate<S, F: Hash + Eq, V: PrimInt>(
+    states: impl IntoIterator<Item = S>,
+    target_step: usize,
+    mut fingerprint: impl FnMut(&S) -> F,
+    mut value: impl FnMut(&S) -> V,
+) -> AocResult<V> {
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+    for (step, state) in states.into_iter().enumerate() {
+        values.push(value(&state));
+        if step == target_step {
+            return Ok(values[step]);
+        }
+
+        if let Some(start) = seen.insert(fingerprint(&state), step) {
+            let cycle_len = step - start;
+            let num_cycles =
+                <V as NumCast>::from((target_step - start) / cycle_len).ok_or_else(|| {
+                    AocError::Process("The number of cycles is too large for the value".into())
+                })?;
+            return Ok(values[start + (target_step - start) % cycle_len]
+                + (values[step] - values[start]) * num_cycles);
+        }
+    }
+
+    Err(AocError::Process(
+        format!("The states ended before step {target_step} without repeating").into(),
+    ))
+}
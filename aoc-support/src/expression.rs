@@ -0,0 +1,513 @@
+//! Parsing and evaluation of infix arithmetic expressions with custom grammars.
+//!
+//! A [`Grammar`] defines the unary and binary operators and the functions that can
+//! appear in expressions, along with their precedence and how they are evaluated.
+//! Expressions are first split into [`Token`]s, which are then converted into reverse
+//! Polish notation (RPN) using the
+//! [shunting-yard algorithm](https://en.wikipedia.org/wiki/Shunting_yard_algorithm).
+//! The RPN tokens can be evaluated by the grammar, or processed directly by solutions
+//! that need to manipulate expressions symbolically, for example to solve for an unknown.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # use aoc::prelude::*;
+//! use aoc::expression::{Associativity, Grammar, Token};
+//!
+//! let grammar = Grammar::<i64>::default()
+//!     .binary("+", 1, Associativity::Left, |a, b| a + b)
+//!     .binary("-", 1, Associativity::Left, |a, b| a - b)
+//!     .binary("*", 2, Associativity::Left, |a, b| a * b);
+//! let rpn = grammar
+//!     .to_rpn(&grammar.tokenize("2 * (x + 3) - 4").unwrap())
+//!     .unwrap();
+//!
+//! // Evaluate the expression as a linear function `a * x + b` of the unknown
+//! let mut stack: Vec<(i64, i64)> = Vec::new();
+//! for token in rpn {
+//!     match token {
+//!         Token::Number(n) => stack.push((0, n)),
+//!         Token::Variable(_) => stack.push((1, 0)),
+//!         Token::Binary(op) => {
+//!             let (b, a) = (stack.pop().unwrap(), stack.pop().unwrap());
+//!             stack.push(match op {
+//!                 "+" => (a.0 + b.0, a.1 + b.1),
+//!                 "-" => (a.0 - b.0, a.1 - b.1),
+//!                 _ => (a.0 * b.1 + b.0 * a.1, a.1 * b.1),
+//!             });
+//!         }
+//!         _ => unreachable!(),
+//!     }
+//! }
+//! let (a, b) = stack.pop().unwrap();
+//!
+//! // Solve for the unknown that makes the expression equal to ten
+//! assert_eq!((10 - b) / a, 4);
+//! ```
+
+use crate::error::{AocError, AocResult};
+use std::{collections::HashMap, str::FromStr};
+
+/// Whether a sequence of binary operators with the same precedence is evaluated from
+/// the left or from the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// Evaluated from the left, for example `a - b - c` is `(a - b) - c`.
+    Left,
+    /// Evaluated from the right, for example `a ^ b ^ c` is `a ^ (b ^ c)`.
+    Right,
+}
+
+/// A token of an expression, which is either from an infix expression as returned by
+/// [`Grammar::tokenize`], or from an RPN expression as returned by [`Grammar::to_rpn`].
+///
+/// Operators and functions are identified by the symbols and names with which they
+/// were added to the [`Grammar`].
+/// RPN expressions do not contain parentheses or commas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<N> {
+    /// A number literal.
+    Number(N),
+    /// A named variable, whose value is provided when evaluating.
+    Variable(String),
+    /// A prefix unary operator.
+    Unary(&'static str),
+    /// A binary operator.
+    Binary(&'static str),
+    /// A function, whose arguments are in parentheses separated by commas.
+    Function(&'static str),
+    /// An opening parenthesis.
+    LeftParen,
+    /// A closing parenthesis.
+    RightParen,
+    /// A comma that separates function arguments.
+    Comma,
+}
+
+/// A binary operator of a [`Grammar`].
+#[derive(Clone)]
+struct BinaryOperator<N> {
+    /// The precedence, where higher precedence operators are evaluated first.
+    precedence: u8,
+    /// The associativity with operators of the same precedence.
+    associativity: Associativity,
+    /// The function that evaluates the operator.
+    apply: fn(N, N) -> N,
+}
+
+/// A prefix unary operator of a [`Grammar`].
+#[derive(Clone)]
+struct UnaryOperator<N> {
+    /// The precedence, where higher precedence operators are evaluated first.
+    precedence: u8,
+    /// The function that evaluates the operator.
+    apply: fn(N) -> N,
+}
+
+/// A function of a [`Grammar`].
+#[derive(Clone)]
+struct Function<N> {
+    /// The number of arguments.
+    arity: usize,
+    /// The function that evaluates the function from its arguments.
+    apply: fn(&[N]) -> N,
+}
+
+/// An item on the operator stack of the shunting-yard algorithm.
+enum StackItem {
+    /// A prefix unary operator.
+    Unary(&'static str),
+    /// A binary operator.
+    Binary(&'static str),
+    /// An opening parenthesis, which may be the start of the arguments of a function.
+    Paren {
+        /// The function whose arguments are in the parentheses, if any.
+        function: Option<&'static str>,
+        /// The number of arguments so far.
+        args: usize,
+    },
+}
+
+/// Defines the operators and functions of expressions, which numbers of type `N` can be
+/// parsed from and evaluated with.
+///
+/// Numbers are parsed from runs of digits, so negative numbers require a unary minus
+/// operator.
+/// Identifiers consisting of letters, digits and underscores that are not functions are
+/// variables.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::expression::{Associativity, Grammar, Token};
+///
+/// let grammar = Grammar::<i64>::default()
+///     .binary("+", 1, Associativity::Left, |a, b| a + b)
+///     .binary("-", 1, Associativity::Left, |a, b| a - b)
+///     .binary("*", 2, Associativity::Left, |a, b| a * b)
+///     .binary("**", 3, Associativity::Right, |a, b| a.pow(b.try_into().unwrap()))
+///     .unary("-", 4, |a| -a)
+///     .function("max", 2, |args| args[0].max(args[1]))
+///     .function("abs", 1, |args| args[0].abs());
+///
+/// assert_eq!(grammar.evaluate("1 + 2 * 3 - 4"), Ok(3));
+/// assert_eq!(grammar.evaluate("2 ** 3 ** 2"), Ok(512));
+/// assert_eq!(grammar.evaluate("-2 - -3"), Ok(1));
+/// assert_eq!(grammar.evaluate("max(abs(-7), 2 * 3) + 1"), Ok(8));
+///
+/// assert_eq!(
+///     grammar.to_rpn(&grammar.tokenize("-(1 + 2) * max(3, 4)").unwrap()),
+///     Ok(vec![
+///         Token::Number(1),
+///         Token::Number(2),
+///         Token::Binary("+"),
+///         Token::Unary("-"),
+///         Token::Number(3),
+///         Token::Number(4),
+///         Token::Function("max"),
+///         Token::Binary("*"),
+///     ]),
+/// );
+///
+/// let rpn = grammar.to_rpn(&grammar.tokenize("x * x - y").unwrap()).unwrap();
+/// let variables = |name: &str| match name {
+///     "x" => Some(5),
+///     "y" => Some(3),
+///     _ => None,
+/// };
+/// assert_eq!(grammar.evaluate_rpn(&rpn, variables), Ok(22));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::expression::{Associativity, Grammar};
+///
+/// let grammar = Grammar::<u64>::default()
+///     .binary("+", 1, Associativity::Left, |a, b| a + b)
+///     .function("min", 2, |args| args[0].min(args[1]));
+///
+/// assert!(grammar.evaluate("1 + ").is_err());
+/// assert!(grammar.evaluate("(1 + 2").is_err());
+/// assert!(grammar.evaluate("1 + 2)").is_err());
+/// assert!(grammar.evaluate("1 2").is_err());
+/// assert!(grammar.evaluate("1 & 2").is_err());
+/// assert!(grammar.evaluate("min(1)").is_err());
+/// assert!(grammar.evaluate("x + 1").is_err());
+/// ```
+#[derive(Clone)]
+pub struct Grammar<N> {
+    /// The binary operators keyed on their symbols.
+    binary: HashMap<&'static str, BinaryOperator<N>>,
+    /// The prefix unary operators keyed on their symbols.
+    unary: HashMap<&'static str, UnaryOperator<N>>,
+    /// The functions keyed on their names.
+    functions: HashMap<&'static str, Function<N>>,
+}
+impl<N> Default for Grammar<N> {
+    fn default() -> Self {
+        Self {
+            binary: HashMap::new(),
+            unary: HashMap::new(),
+            functions: HashMap::new(),
+        }
+    }
+}
+impl<N: FromStr + Clone> Grammar<N> {
+    /// Adds a binary operator with some `symbol`, which is evaluated by `apply`.
+    ///
+    /// Operators with a higher `precedence` are evaluated first.
+    pub fn binary(
+        mut self,
+        symbol: &'static str,
+        precedence: u8,
+        associativity: Associativity,
+        apply: fn(N, N) -> N,
+    ) -> Self {
+        self.binary.insert(
+            symbol,
+            BinaryOperator {
+                precedence,
+                associativity,
+                apply,
+            },
+        );
+        self
+    }
+
+    /// Adds a prefix unary operator with some `symbol`, which is evaluated by `apply`.
+    ///
+    /// The same symbol can be both a unary and a binary operator, for example `-`.
+    /// The operator is applied to everything to its right up to the first binary operator
+    /// whose `precedence` is not higher.
+    pub fn unary(mut self, symbol: &'static str, precedence: u8, apply: fn(N) -> N) -> Self {
+        self.unary
+            .insert(symbol, UnaryOperator { precedence, apply });
+        self
+    }
+
+    /// Adds a function with some `name` that takes some number of arguments, which is
+    /// evaluated by `apply` with the arguments in order.
+    pub fn function(mut self, name: &'static str, arity: usize, apply: fn(&[N]) -> N) -> Self {
+        self.functions.insert(name, Function { arity, apply });
+        self
+    }
+
+    /// Splits an infix expression into its tokens.
+    ///
+    /// Whether an operator symbol is unary or binary is determined by whether it follows
+    /// an operand.
+    /// Returns [`AocError::InvalidInput`] if the expression contains an unknown symbol or
+    /// a number that cannot be parsed.
+    pub fn tokenize(&self, s: &str) -> AocResult<Vec<Token<N>>> {
+        let invalid = |reason: String| {
+            AocError::InvalidInput(format!("Invalid expression '{s}': {reason}").into())
+        };
+
+        let mut tokens = Vec::new();
+        let mut rest = s.trim_start();
+        while let Some(c) = rest.chars().next() {
+            let follows_operand = matches!(
+                tokens.last(),
+                Some(Token::Number(_) | Token::Variable(_) | Token::RightParen)
+            );
+            let len = if c.is_ascii_digit() {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                tokens.push(Token::Number(rest[..len].parse().map_err(|_| {
+                    invalid(format!("'{}' is not a valid number", &rest[..len]))
+                })?));
+                len
+            } else if c.is_alphabetic() || c == '_' {
+                let len = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let name = &rest[..len];
+                tokens.push(match self.functions.get_key_value(name) {
+                    Some((name, _)) => Token::Function(*name),
+                    None => Token::Variable(name.to_string()),
+                });
+                len
+            } else if let Some((token, len)) = match c {
+                '(' => Some((Token::LeftParen, 1)),
+                ')' => Some((Token::RightParen, 1)),
+                ',' => Some((Token::Comma, 1)),
+                _ => None,
+            } {
+                tokens.push(token);
+                len
+            } else {
+                // Match the longest operator symbol of the expected kind
+                let symbol = if follows_operand {
+                    self.binary
+                        .keys()
+                        .copied()
+                        .filter(|k| rest.starts_with(k))
+                        .max_by_key(|k| k.len())
+                } else {
+                    self.unary
+                        .keys()
+                        .copied()
+                        .filter(|k| rest.starts_with(k))
+                        .max_by_key(|k| k.len())
+                }
+                .ok_or_else(|| invalid(format!("unexpected '{c}'")))?;
+                tokens.push(if follows_operand {
+                    Token::Binary(symbol)
+                } else {
+                    Token::Unary(symbol)
+                });
+                symbol.len()
+            };
+            rest = rest[len..].trim_start();
+        }
+
+        Ok(tokens)
+    }
+
+    /// Converts the tokens of an infix expression into the tokens of the equivalent
+    /// RPN expression using the shunting-yard algorithm.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the expression is malformed, for example
+    /// with mismatched parentheses, missing operands, or the wrong number of arguments
+    /// to a function.
+    pub fn to_rpn(&self, tokens: &[Token<N>]) -> AocResult<Vec<Token<N>>> {
+        let invalid =
+            |reason: &str| AocError::InvalidInput(format!("Malformed expression: {reason}").into());
+
+        let mut output = Vec::new();
+        let mut stack: Vec<StackItem> = Vec::new();
+        let mut expect_operand = true;
+        let mut iter = tokens.iter();
+
+        // Pops operators to the output until an opening parenthesis, which is left
+        // on the stack
+        let pop_to_paren = |stack: &mut Vec<StackItem>, output: &mut Vec<Token<N>>| loop {
+            match stack.last() {
+                Some(StackItem::Paren { .. }) => return Ok(()),
+                Some(StackItem::Unary(s)) => output.push(Token::Unary(*s)),
+                Some(StackItem::Binary(s)) => output.push(Token::Binary(*s)),
+                None => return Err(invalid("unmatched closing parenthesis or comma")),
+            }
+            stack.pop();
+        };
+
+        while let Some(token) = iter.next() {
+            match (expect_operand, token) {
+                (true, Token::Number(_) | Token::Variable(_)) => {
+                    output.push(token.clone());
+                    expect_operand = false;
+                }
+                (true, Token::Unary(s)) => {
+                    if !self.unary.contains_key(s) {
+                        return Err(invalid("unknown unary operator"));
+                    }
+                    stack.push(StackItem::Unary(*s));
+                }
+                (true, Token::LeftParen) => stack.push(StackItem::Paren {
+                    function: None,
+                    args: 1,
+                }),
+                (true, Token::Function(f)) => {
+                    if !self.functions.contains_key(f) {
+                        return Err(invalid("unknown function"));
+                    }
+                    if !matches!(iter.next(), Some(Token::LeftParen)) {
+                        return Err(invalid("function without arguments"));
+                    }
+                    stack.push(StackItem::Paren {
+                        function: Some(*f),
+                        args: 1,
+                    });
+                }
+                (false, Token::Binary(s)) => {
+                    let op = self
+                        .binary
+                        .get(s)
+                        .ok_or_else(|| invalid("unknown binary operator"))?;
+
+                    // Pop operators that must be evaluated before this one
+                    while let Some(item) = stack.last() {
+                        let (precedence, symbol, unary) = match item {
+                            StackItem::Unary(t) => (self.unary[t].precedence, *t, true),
+                            StackItem::Binary(t) => (self.binary[t].precedence, *t, false),
+                            StackItem::Paren { .. } => break,
+                        };
+                        if precedence > op.precedence
+                            || (precedence == op.precedence
+                                && (unary || op.associativity == Associativity::Left))
+                        {
+                            output.push(if unary {
+                                Token::Unary(symbol)
+                            } else {
+                                Token::Binary(symbol)
+                            });
+                            stack.pop();
+                        } else {
+                            break;
+                        }
+                    }
+                    stack.push(StackItem::Binary(*s));
+                    expect_operand = true;
+                }
+                (false, Token::Comma) => {
+                    pop_to_paren(&mut stack, &mut output)?;
+                    match stack.last_mut() {
+                        Some(StackItem::Paren {
+                            function: Some(_),
+                            args,
+                        }) => *args += 1,
+                        _ => return Err(invalid("comma outside of function arguments")),
+                    }
+                    expect_operand = true;
+                }
+                (false, Token::RightParen) => {
+                    pop_to_paren(&mut stack, &mut output)?;
+                    if let Some(StackItem::Paren {
+                        function: Some(f),
+                        args,
+                    }) = stack.pop()
+                    {
+                        if args != self.functions[f].arity {
+                            return Err(invalid("wrong number of function arguments"));
+                        }
+                        output.push(Token::Function(f));
+                    }
+                }
+                (true, _) => return Err(invalid("expected an operand")),
+                (false, _) => return Err(invalid("expected an operator")),
+            }
+        }
+
+        if expect_operand {
+            return Err(invalid("expected an operand"));
+        }
+        while let Some(item) = stack.pop() {
+            output.push(match item {
+                StackItem::Unary(s) => Token::Unary(s),
+                StackItem::Binary(s) => Token::Binary(s),
+                StackItem::Paren { .. } => return Err(invalid("unmatched opening parenthesis")),
+            });
+        }
+
+        Ok(output)
+    }
+
+    /// Evaluates the tokens of an RPN expression, with the values of any variables
+    /// provided by the `variables` function.
+    ///
+    /// Returns [`AocError::Process`] if a variable has no value, and
+    /// [`AocError::InvalidInput`] if the RPN expression is malformed.
+    pub fn evaluate_rpn(
+        &self,
+        rpn: &[Token<N>],
+        variables: impl Fn(&str) -> Option<N>,
+    ) -> AocResult<N> {
+        let invalid = || AocError::InvalidInput("Malformed RPN expression".into());
+
+        let mut stack = Vec::new();
+        for token in rpn {
+            match token {
+                Token::Number(n) => stack.push(n.clone()),
+                Token::Variable(name) => stack.push(variables(name).ok_or_else(|| {
+                    AocError::Process(format!("The variable '{name}' has no value").into())
+                })?),
+                Token::Unary(s) => {
+                    let a = stack.pop().ok_or_else(invalid)?;
+                    stack.push((self.unary.get(s).ok_or_else(invalid)?.apply)(a));
+                }
+                Token::Binary(s) => {
+                    let b = stack.pop().ok_or_else(invalid)?;
+                    let a = stack.pop().ok_or_else(invalid)?;
+                    stack.push((self.binary.get(s).ok_or_else(invalid)?.apply)(a, b));
+                }
+                Token::Function(f) => {
+                    let function = self.functions.get(f).ok_or_else(invalid)?;
+                    let start = stack
+                        .len()
+                        .checked_sub(function.arity)
+                        .ok_or_else(invalid)?;
+                    let result = (function.apply)(&stack[start..]);
+                    stack.truncate(start);
+                    stack.push(result);
+                }
+                Token::LeftParen | Token::RightParen | Token::Comma => return Err(invalid()),
+            }
+        }
+
+        match (stack.pop(), stack.is_empty()) {
+            (Some(result), true) => Ok(result),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Parses and evaluates an infix expression, which cannot contain variables.
+    ///
+    /// Returns an [`AocError`] if the expression is invalid, see [`Grammar::tokenize`],
+    /// [`Grammar::to_rpn`] and [`Grammar::evaluate_rpn`].
+    pub fn evaluate(&self, s: &str) -> AocResult<N> {
+        self.evaluate_rpn(&self.to_rpn(&self.tokenize(s)?)?, |_| None)
+    }
+}
@@ -0,0 +1,289 @@
+//! Data structures for queries over ranges of a sequence that changes.
+//!
+//! Some problems need the sums of prefixes or ranges of a sequence of values that are
+//! updated in between queries, for example for order statistics such as the rank of a
+//! value among those seen so far.
+//! Recomputing the sums after every update is too slow for long sequences, so these
+//! structures take time proportional to the logarithm of the length of the sequence for
+//! both updates and queries.
+//! A [`Fenwick`] tree supports sums, while a [`SegmentTree`] supports any associative
+//! operation such as the minimum or maximum.
+
+use std::ops::{Add, Range, Sub};
+
+/// A Fenwick tree, or binary indexed tree, of values, which supports adding to
+/// values and the sums of prefixes and ranges of the values.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::fenwick::Fenwick;
+///
+/// let mut fenwick = Fenwick::from_values([3, 1, 4, 1, 5]);
+/// assert_eq!(fenwick.prefix_sum(3), 8);
+/// assert_eq!(fenwick.range_sum(1..4), 6);
+///
+/// fenwick.add(2, 10);
+/// assert_eq!(fenwick.get(2), 14);
+/// assert_eq!(fenwick.range_sum(1..4), 16);
+/// assert_eq!(fenwick.prefix_sum(5), 24);
+/// ```
+///
+/// For order statistics, the values can count how many times each value was seen:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::fenwick::Fenwick;
+///
+/// let mut counts = Fenwick::new(10);
+/// for value in [7, 2, 9, 2, 5] {
+///     counts.add(value, 1);
+/// }
+///
+/// // The number of values less than 6
+/// assert_eq!(counts.prefix_sum(6), 3);
+/// // The value with a rank of three, counting from zero
+/// assert_eq!(counts.max_prefix_within(3), 7);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fenwick<T> {
+    /// The tree, in which the element at each one-based index is the sum of the values
+    /// in the range ending at that index with a length of its lowest set bit.
+    tree: Vec<T>,
+}
+impl<T: Copy + Default + Add<Output = T>> Fenwick<T> {
+    /// Creates a new tree of some length in which all the values are zero, that is
+    /// their [`Default`].
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![T::default(); len + 1],
+        }
+    }
+
+    /// Creates a new tree of some `values`.
+    ///
+    /// This takes time proportional to the number of values.
+    pub fn from_values(values: impl IntoIterator<Item = T>) -> Self {
+        let mut tree = vec![T::default()];
+        tree.extend(values);
+        for idx in 1..tree.len() {
+            let parent = idx + lowest_bit(idx);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[idx];
+            }
+        }
+        Self { tree }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Returns whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a `delta` to the value at an index.
+    ///
+    /// # Panics
+    /// This will panic if the index is out of bounds.
+    pub fn add(&mut self, idx: usize, delta: T) {
+        assert!(idx < self.len(), "index {idx} is out of bounds");
+        let mut idx = idx + 1;
+        while idx < self.tree.len() {
+            self.tree[idx] = self.tree[idx] + delta;
+            idx += lowest_bit(idx);
+        }
+    }
+
+    /// Returns the sum of the values before the `end` index.
+    ///
+    /// # Panics
+    /// This will panic if the `end` is greater than the number of values.
+    pub fn prefix_sum(&self, end: usize) -> T {
+        assert!(end <= self.len(), "end {end} is out of bounds");
+        let mut sum = T::default();
+        let mut idx = end;
+        while idx > 0 {
+            sum = sum + self.tree[idx];
+            idx -= lowest_bit(idx);
+        }
+        sum
+    }
+
+    /// Returns the largest `end` for which the [`Fenwick::prefix_sum`] is no more
+    /// than the `target`, provided that no values are negative.
+    ///
+    /// When the values are counts of values, this is the value at a rank of the
+    /// `target`, counting from zero.
+    pub fn max_prefix_within(&self, target: T) -> usize
+    where
+        T: PartialOrd + Sub<Output = T>,
+    {
+        let mut end = 0;
+        let mut remaining = target;
+        let mut step = if self.is_empty() {
+            0
+        } else {
+            1 << self.len().ilog2()
+        };
+        while step > 0 {
+            let next = end + step;
+            if next < self.tree.len() && self.tree[next] <= remaining {
+                end = next;
+                remaining = remaining - self.tree[next];
+            }
+            step >>= 1;
+        }
+        end
+    }
+}
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> Fenwick<T> {
+    /// Returns the sum of the values in a `range` of indices.
+    ///
+    /// # Panics
+    /// This will panic if the `range` is out of bounds or decreasing.
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        assert!(range.start <= range.end, "range {range:?} is decreasing");
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+
+    /// Returns the value at an index.
+    ///
+    /// # Panics
+    /// This will panic if the index is out of bounds.
+    pub fn get(&self, idx: usize) -> T {
+        self.range_sum(idx..idx + 1)
+    }
+}
+
+/// Returns the value of the lowest set bit of a non-zero index.
+fn lowest_bit(idx: usize) -> usize {
+    idx & idx.wrapping_neg()
+}
+
+/// A segment tree of values, which supports setting values and combining the values in
+/// ranges with an associative operation.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::fenwick::SegmentTree;
+///
+/// let mut sums = SegmentTree::from_values([3, 1, 4, 1, 5], 0, |a, b| a + b);
+/// assert_eq!(sums.query(1..4), 6);
+/// sums.set(2, 10);
+/// assert_eq!(sums.query(1..4), 12);
+/// assert_eq!(sums.query(0..5), 20);
+/// assert_eq!(sums.query(3..3), 0);
+///
+/// let mut minima = SegmentTree::from_values([3, 1, 4, 1, 5], u32::MAX, u32::min);
+/// assert_eq!(minima.query(2..5), 1);
+/// minima.set(3, 9);
+/// assert_eq!(minima.query(2..5), 4);
+/// assert_eq!(*minima.get(3), 9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentTree<T> {
+    /// The number of values.
+    len: usize,
+    /// The tree, in which the values are the leaves from index `len` onwards, and every
+    /// other element at an index is the combination of its children at twice the index
+    /// and the next index.
+    tree: Vec<T>,
+    /// The identity of the operation.
+    identity: T,
+    /// The associative operation.
+    combine: fn(T, T) -> T,
+}
+impl<T: Clone> SegmentTree<T> {
+    /// Creates a new tree of some `values`, which are combined with an associative
+    /// `combine` operation, for which `identity` is the identity.
+    ///
+    /// This takes time proportional to the number of values.
+    pub fn from_values(
+        values: impl IntoIterator<Item = T>,
+        identity: T,
+        combine: fn(T, T) -> T,
+    ) -> Self {
+        let values: Vec<_> = values.into_iter().collect();
+        let len = values.len();
+        let mut tree = vec![identity.clone(); len];
+        tree.extend(values);
+        for idx in (1..len).rev() {
+            tree[idx] = combine(tree[2 * idx].clone(), tree[2 * idx + 1].clone());
+        }
+        Self {
+            len,
+            tree,
+            identity,
+            combine,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value at an index.
+    ///
+    /// # Panics
+    /// This will panic if the index is out of bounds.
+    pub fn get(&self, idx: usize) -> &T {
+        assert!(idx < self.len, "index {idx} is out of bounds");
+        &self.tree[self.len + idx]
+    }
+
+    /// Sets the `value` at an index.
+    ///
+    /// # Panics
+    /// This will panic if the index is out of bounds.
+    pub fn set(&mut self, idx: usize, value: T) {
+        assert!(idx < self.len, "index {idx} is out of bounds");
+        let mut idx = self.len + idx;
+        self.tree[idx] = value;
+        while idx > 1 {
+            idx /= 2;
+            self.tree[idx] =
+                (self.combine)(self.tree[2 * idx].clone(), self.tree[2 * idx + 1].clone());
+        }
+    }
+
+    /// Returns the combination of the values in a `range` of indices, in order, which
+    /// is the identity for an empty range.
+    ///
+    /// # Panics
+    /// This will panic if the `range` is out of bounds or decreasing.
+    pub fn query(&self, range: Range<usize>) -> T {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range {range:?} is out of bounds"
+        );
+
+        // Combine from both ends inwards, keeping the order for non-commutative operations
+        let (mut left, mut right) = (self.identity.clone(), self.identity.clone());
+        let (mut start, mut end) = (range.start + self.len, range.end + self.len);
+        while start < end {
+            if start % 2 == 1 {
+                left = (self.combine)(left, self.tree[start].clone());
+                start += 1;
+            }
+            if end % 2 == 1 {
+                end -= 1;
+                right = (self.combine)(self.tree[end].clone(), right);
+            }
+            start /= 2;
+            end /= 2;
+        }
+        (self.combine)(left, right)
+    }
+}
@@ -0,0 +1,859 @@
+//! Geometric utilities for 3D point clouds, and for rectangles and diamonds on a plane.
+//!
+//! Some problems involve several observers (e.g. scanners) that each measure
+//! points relative to themselves, but which have unknown orientations and locations.
+//! The [`register_point_clouds`] function finds the [`Transform`] that aligns one cloud
+//! with another, and the [`assemble_point_clouds`] function uses this to bring many clouds
+//! into the frame of the first.
+//!
+//! Other problems involve rectangles that are claimed on a plane, for which the
+//! [`RectClaim`] can be parsed and the [`count_multi_covered`] function counts the
+//! area covered by more than one claim.
+//! Similarly, the [`DiamondCoverage`] determines the coverage of a plane by [`Diamond`]s,
+//! which are the regions within some Manhattan distance of a point.
+//!
+//! Problems about folding transparent paper reflect sets of points across lines with
+//! each [`Fold`], after which the points often draw letters that [`fold_letters`] reads.
+//!
+//! Whether points are inside polygons with integer vertices, such as loops on a grid, is
+//! determined exactly by [`contains_point`], or by [`polygon_containment`] for every
+//! point in a region at once.
+
+use crate::{
+    collections::{HashMap, HashSet},
+    error::AocResult,
+    grid::{AnyGridPoint, Grid, GridBox, GridPoint, GridSize, StdBool},
+    ocr::read_letters,
+    parse::{NomParseResult, Parsable},
+};
+use euclid::default::{Point3D, Vector3D};
+use itertools::{iproduct, Itertools};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{one_of, space0, space1},
+    combinator::map,
+    sequence::{delimited, preceded, separated_pair, tuple},
+};
+use num::Integer;
+use std::{collections::VecDeque, ops::RangeInclusive};
+
+/// A point in a point cloud.
+pub type CloudPoint = Point3D<i64>;
+
+/// A vector relating points in a point cloud.
+pub type CloudVector = Vector3D<i64>;
+
+/// One of the 24 rotations that keep the coordinate axes aligned with the axes, represented
+/// as an integer matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+impl Rotation {
+    /// Returns the rotation that leaves everything unchanged.
+    pub fn identity() -> Self {
+        Self([[1, 0, 0], [0, 1, 0], [0, 0, 1]])
+    }
+
+    /// Returns an [`Iterator`] over all 24 rotations, starting with the identity.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::geometry::{CloudVector, Rotation};
+    /// use std::collections::HashSet;
+    ///
+    /// assert_eq!(Rotation::all().count(), 24);
+    /// assert_eq!(Rotation::all().next(), Some(Rotation::identity()));
+    ///
+    /// let vector = CloudVector::new(1, 2, 3);
+    /// let images: HashSet<_> = Rotation::all().map(|r| r.rotate(vector)).collect();
+    /// assert_eq!(images.len(), 24);
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        // These are the signed permutation matrices with a determinant of one
+        (0..3)
+            .permutations(3)
+            .cartesian_product(iproduct!([1, -1], [1, -1], [1, -1]))
+            .map(|(perm, signs)| {
+                let signs = [signs.0, signs.1, signs.2];
+                let mut matrix = [[0; 3]; 3];
+                for (row, col) in perm.into_iter().enumerate() {
+                    matrix[row][col] = signs[row];
+                }
+                Self(matrix)
+            })
+            .filter(|r| r.determinant() == 1)
+    }
+
+    /// Returns the determinant of the matrix.
+    fn determinant(&self) -> i64 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Returns a `vector` rotated by this rotation.
+    pub fn rotate(&self, vector: CloudVector) -> CloudVector {
+        let v = [vector.x, vector.y, vector.z];
+        let row = |r: &[i64; 3]| r.iter().zip(v.iter()).map(|(a, b)| a * b).sum::<i64>();
+        CloudVector::new(row(&self.0[0]), row(&self.0[1]), row(&self.0[2]))
+    }
+
+    /// Returns the rotation that is the `other` rotation followed by this one.
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        for (i, j) in iproduct!(0..3, 0..3) {
+            matrix[i][j] = (0..3).map(|k| self.0[i][k] * other.0[k][j]).sum();
+        }
+        Self(matrix)
+    }
+}
+
+/// A transformation between the frames of two point clouds, which is a rotation
+/// followed by a translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transform {
+    /// The rotation, which is applied first.
+    pub rotation: Rotation,
+    /// The translation, which is applied after the rotation.
+    ///
+    /// This is also the location of the origin of the source frame in the destination frame.
+    pub translation: CloudVector,
+}
+impl Transform {
+    /// Returns the transform that leaves everything unchanged.
+    pub fn identity() -> Self {
+        Self {
+            rotation: Rotation::identity(),
+            translation: CloudVector::zero(),
+        }
+    }
+
+    /// Returns a `point` transformed to the destination frame.
+    pub fn apply(&self, point: CloudPoint) -> CloudPoint {
+        (self.rotation.rotate(point.to_vector()) + self.translation).to_point()
+    }
+
+    /// Returns the transform that is the `other` transform followed by this one.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.compose(&other.rotation),
+            translation: self.rotation.rotate(other.translation) + self.translation,
+        }
+    }
+}
+
+/// Returns the squared distances between every pair of points in a point cloud,
+/// along with how many times each distance occurs.
+///
+/// These do not depend on the frame, so serve as a fingerprint of the cloud.
+fn distance_fingerprint(points: &[CloudPoint]) -> HashMap<i64, usize> {
+    let mut counts = HashMap::default();
+    for (a, b) in points.iter().tuple_combinations() {
+        *counts.entry((*a - *b).square_length()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the [`Transform`] from the frame of point cloud `b` to that of point
+/// cloud `a` if at least `min_overlap` of the points coincide after the transformation.
+///
+/// The pairwise distances between the points of the clouds are first compared, which
+/// quickly rules out most clouds that cannot overlap sufficiently.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{register_point_clouds, CloudPoint, CloudVector, Rotation, Transform};
+///
+/// let b = [
+///     CloudPoint::new(0, 2, 5),
+///     CloudPoint::new(4, 1, -3),
+///     CloudPoint::new(-6, 3, 2),
+///     CloudPoint::new(7, -8, 9),
+///     CloudPoint::new(-1, -4, 11),
+/// ];
+/// let transform = Transform {
+///     rotation: Rotation::all().nth(7).unwrap(),
+///     translation: CloudVector::new(100, -20, 35),
+/// };
+///
+/// // Cloud a overlaps with the first four points of b
+/// let mut a: Vec<_> = b[..4].iter().map(|p| transform.apply(*p)).collect();
+/// a.push(CloudPoint::new(50, 50, 50));
+///
+/// assert_eq!(register_point_clouds(&a, &b, 4), Some(transform));
+/// assert_eq!(register_point_clouds(&a, &b, 5), None);
+/// ```
+pub fn register_point_clouds(
+    a: &[CloudPoint],
+    b: &[CloudPoint],
+    min_overlap: usize,
+) -> Option<Transform> {
+    // Any overlapping points must share all of their pairwise distances
+    let (a_distances, b_distances) = (distance_fingerprint(a), distance_fingerprint(b));
+    let shared_distances: usize = a_distances
+        .iter()
+        .map(|(d, n)| (*n).min(b_distances.get(d).copied().unwrap_or(0)))
+        .sum();
+    if shared_distances < min_overlap * min_overlap.saturating_sub(1) / 2 {
+        return None;
+    }
+
+    // Every pair of points votes for the translation that would make them coincide
+    Rotation::all().find_map(|rotation| {
+        let mut votes = HashMap::default();
+        for (pa, pb) in iproduct!(a.iter(), b.iter()) {
+            *votes
+                .entry(*pa - rotation.rotate(pb.to_vector()))
+                .or_insert(0) += 1;
+        }
+
+        votes
+            .into_iter()
+            .find(|(_, n)| *n >= min_overlap)
+            .map(|(t, _)| Transform {
+                rotation,
+                translation: t.to_vector(),
+            })
+    })
+}
+
+/// Returns the [`Transform`]s that bring each of some point `clouds` into the frame of the
+/// first cloud, or `None` if some cloud cannot be registered.
+///
+/// Clouds are registered using [`register_point_clouds`] against clouds that have
+/// already been placed, so that every cloud must be connected to the first through a
+/// chain of clouds that each overlap by at least `min_overlap` points.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{assemble_point_clouds, CloudPoint, CloudVector, Rotation, Transform};
+///
+/// let points = [
+///     CloudPoint::new(0, 2, 5),
+///     CloudPoint::new(4, 1, -3),
+///     CloudPoint::new(-6, 3, 2),
+///     CloudPoint::new(7, -8, 9),
+///     CloudPoint::new(-1, -4, 11),
+///     CloudPoint::new(12, 0, -7),
+/// ];
+/// let transform = Transform {
+///     rotation: Rotation::all().nth(13).unwrap(),
+///     translation: CloudVector::new(-5, 8, 2),
+/// };
+///
+/// // The second cloud only overlaps the first and the third
+/// let clouds = vec![
+///     points[..3].to_vec(),
+///     points.iter().map(|p| transform.apply(*p)).collect(),
+///     points[3..].to_vec(),
+/// ];
+/// let transforms = assemble_point_clouds(&clouds, 3).unwrap();
+///
+/// assert_eq!(transforms[0], Transform::identity());
+/// assert_eq!(transforms[2], Transform::identity());
+/// assert_eq!(transforms[1].apply(clouds[1][0]), points[0]);
+/// assert_eq!(assemble_point_clouds(&clouds, 4), None);
+/// ```
+pub fn assemble_point_clouds(
+    clouds: &[Vec<CloudPoint>],
+    min_overlap: usize,
+) -> Option<Vec<Transform>> {
+    let mut transforms: Vec<Option<Transform>> = vec![None; clouds.len()];
+    let mut queue = VecDeque::new();
+    if !clouds.is_empty() {
+        transforms[0] = Some(Transform::identity());
+        queue.push_back(0);
+    }
+
+    // Register unplaced clouds against each placed cloud in turn
+    while let Some(i) = queue.pop_front() {
+        let placed = transforms[i].unwrap();
+        for j in 0..clouds.len() {
+            if transforms[j].is_some() {
+                continue;
+            }
+            if let Some(t) = register_point_clouds(&clouds[i], &clouds[j], min_overlap) {
+                transforms[j] = Some(placed.compose(&t));
+                queue.push_back(j);
+            }
+        }
+    }
+
+    transforms.into_iter().collect()
+}
+
+/// A rectangular claim on a plane, which can be parsed from text such as
+/// `#123 @ 3,2: 5x4`.
+///
+/// This example claim has ID 123, has its upper left corner at (3, 2) and is
+/// five units wide and four units tall.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::RectClaim;
+///
+/// let claim = RectClaim::from_str("#123 @ 3,2: 5x4").unwrap();
+/// assert_eq!(claim.id, 123);
+/// assert_eq!(
+///     claim.area,
+///     GridBox::from_origin_and_size(GridPoint::new(3, 2), GridSize::new(5, 4)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RectClaim {
+    /// The ID of the claim.
+    pub id: usize,
+    /// The area covered by the claim, the maximum corner of which is exclusive.
+    pub area: GridBox,
+}
+impl Parsable<'_> for RectClaim {
+    fn parser(input: &str) -> NomParseResult<&str, Self> {
+        map(
+            tuple((
+                preceded(tag("#"), usize::parser),
+                delimited(
+                    delimited(space0, tag("@"), space0),
+                    separated_pair(usize::parser, tag(","), usize::parser),
+                    preceded(tag(":"), space0),
+                ),
+                separated_pair(usize::parser, tag("x"), usize::parser),
+            )),
+            |(id, (x, y), (w, h))| Self {
+                id,
+                area: GridBox::from_origin_and_size(GridPoint::new(x, y), GridSize::new(w, h)),
+            },
+        )(input.trim())
+    }
+}
+
+/// The largest bounding box area for which [`count_multi_covered`] uses a dense grid
+/// rather than a sweep line.
+const DENSE_AREA_LIMIT: usize = 1 << 24;
+
+/// Returns the area covered by more than one of some `claims`.
+///
+/// If the claims all fit in a reasonably small bounding box, the claims are simply
+/// counted in a dense grid.
+/// Otherwise, a vertical line is swept across the claims, which only depends on
+/// the number of claims and not on their sizes.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{count_multi_covered, RectClaim};
+///
+/// let claims = RectClaim::gather(["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"].into_iter())
+///     .unwrap();
+/// assert_eq!(count_multi_covered(&claims), 4);
+///
+/// // Claims that are far apart
+/// let claims = RectClaim::gather(
+///     [
+///         "#1 @ 0,0: 3x3",
+///         "#2 @ 1,1: 3x3",
+///         "#3 @ 999999998,999999998: 3x3",
+///         "#4 @ 999999999,999999999: 3x3",
+///     ]
+///     .into_iter(),
+/// )
+/// .unwrap();
+/// assert_eq!(count_multi_covered(&claims), 8);
+/// ```
+pub fn count_multi_covered(claims: &[RectClaim]) -> usize {
+    let claims = claims
+        .iter()
+        .map(|c| c.area)
+        .filter(|a| !a.is_empty())
+        .collect_vec();
+    let Some(bounds) = claims.iter().copied().reduce(|a, b| a.union(&b)) else {
+        return 0;
+    };
+
+    if bounds.area() <= DENSE_AREA_LIMIT {
+        count_multi_covered_dense(&claims, &bounds)
+    } else {
+        count_multi_covered_sweep(&claims)
+    }
+}
+
+/// Counts the area covered by more than one of some `claims` that lie within some
+/// `bounds` by counting the claims of every point in a grid.
+///
+/// This is an internal function for [`count_multi_covered`].
+fn count_multi_covered_dense(claims: &[GridBox], bounds: &GridBox) -> usize {
+    // Only whether there is more than one claim matters, so the counts can saturate
+    let mut counts = Grid::<u8>::default(bounds.size());
+    let offset = bounds.min.to_vector();
+    for claim in claims {
+        for (x, y) in iproduct!(claim.x_range(), claim.y_range()) {
+            let count = counts.element_at(&(GridPoint::new(x, y) - offset));
+            *count = count.saturating_add(1);
+        }
+    }
+
+    counts.all_values().filter(|n| **n > 1).count()
+}
+
+/// Counts the area covered by more than one of some `claims` by sweeping a vertical
+/// line across them.
+///
+/// This is an internal function for [`count_multi_covered`].
+fn count_multi_covered_sweep(claims: &[GridBox]) -> usize {
+    let xs = claims
+        .iter()
+        .flat_map(|c| [c.min.x, c.max.x])
+        .sorted_unstable()
+        .dedup()
+        .collect_vec();
+
+    // Within each slab between adjacent edges the covered rows do not change
+    xs.iter()
+        .tuple_windows()
+        .map(|(x0, x1)| {
+            let mut events = claims
+                .iter()
+                .filter(|c| c.min.x <= *x0 && *x1 <= c.max.x)
+                .flat_map(|c| [(c.min.y, 1), (c.max.y, -1)])
+                .collect_vec();
+            events.sort_unstable();
+
+            let (mut depth, mut last_y, mut covered) = (0, 0, 0);
+            for (y, change) in events {
+                if depth > 1 {
+                    covered += y - last_y;
+                }
+                depth += change;
+                last_y = y;
+            }
+            covered * (x1 - x0)
+        })
+        .sum()
+}
+
+/// The points on a plane within some Manhattan distance, the `radius`, of a `center` point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diamond {
+    /// The center point.
+    pub center: AnyGridPoint,
+    /// The largest Manhattan distance from the center of points in the diamond.
+    pub radius: isize,
+}
+impl Diamond {
+    /// Returns the range of `x` values of the diamond in a `row`, if it meets the row.
+    pub fn row(&self, row: isize) -> Option<RangeInclusive<isize>> {
+        let half_width = self.radius - (self.center.y - row).abs();
+        (half_width >= 0).then(|| self.center.x - half_width..=self.center.x + half_width)
+    }
+}
+
+/// The coverage of a plane by a number of [`Diamond`]s, which is determined one row at a time.
+///
+/// Only the diamonds meeting each row are considered, so this is efficient even
+/// when the diamonds are huge.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{Diamond, DiamondCoverage};
+///
+/// let coverage = DiamondCoverage::new([
+///     Diamond {
+///         center: AnyGridPoint::new(0, 0),
+///         radius: 2,
+///     },
+///     Diamond {
+///         center: AnyGridPoint::new(4, 1),
+///         radius: 1,
+///     },
+///     Diamond {
+///         center: AnyGridPoint::new(4, 5),
+///         radius: 3,
+///     },
+/// ]);
+///
+/// assert_eq!(coverage.row(0), vec![-2..=2, 4..=4]);
+/// assert_eq!(coverage.row(1), vec![-1..=1, 3..=5]);
+/// assert_eq!(coverage.row(2), vec![0..=0, 4..=4]);
+/// assert_eq!(coverage.row(3), vec![3..=5]);
+/// assert_eq!(coverage.row(-3), vec![]);
+/// assert_eq!(coverage.covered_len(1), 6);
+///
+/// assert_eq!(
+///     coverage.uncovered_point(0..=4, 0..=4),
+///     Some(AnyGridPoint::new(3, 0)),
+/// );
+/// assert_eq!(coverage.uncovered_point(-1..=1, -1..=1), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiamondCoverage {
+    /// The diamonds.
+    diamonds: Vec<Diamond>,
+}
+impl DiamondCoverage {
+    /// Creates the coverage of some `diamonds`.
+    pub fn new(diamonds: impl IntoIterator<Item = Diamond>) -> Self {
+        Self {
+            diamonds: diamonds.into_iter().collect(),
+        }
+    }
+
+    /// Returns the covered ranges of `x` values in a `row`, which are disjoint, not
+    /// adjacent, and in increasing order.
+    pub fn row(&self, row: isize) -> Vec<RangeInclusive<isize>> {
+        let mut ranges = self
+            .diamonds
+            .iter()
+            .filter_map(|d| d.row(row))
+            .collect_vec();
+        ranges.sort_unstable_by_key(|r| *r.start());
+
+        let mut merged: Vec<RangeInclusive<isize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= *last.end() + 1 => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    /// Returns the number of covered points in a `row`.
+    pub fn covered_len(&self, row: isize) -> usize {
+        self.row(row)
+            .iter()
+            .map(|r| usize::try_from(r.end() - r.start() + 1).unwrap())
+            .sum()
+    }
+
+    /// Returns an [`Iterator`] over the covered ranges of some `rows`, which are
+    /// only determined as needed, see [`DiamondCoverage::row`].
+    pub fn rows(
+        &self,
+        rows: RangeInclusive<isize>,
+    ) -> impl Iterator<Item = (isize, Vec<RangeInclusive<isize>>)> + '_ {
+        rows.map(|row| (row, self.row(row)))
+    }
+
+    /// Returns the first point that is not covered within the bounds given by some
+    /// `x_range` and `y_range`, searching row by row.
+    ///
+    /// The rows are searched in parallel when the `parallel` feature is enabled and
+    /// sequentially otherwise, but the first uncovered point in row-major order is
+    /// always returned.
+    pub fn uncovered_point(
+        &self,
+        x_range: RangeInclusive<isize>,
+        y_range: RangeInclusive<isize>,
+    ) -> Option<AnyGridPoint> {
+        let uncovered = |y| {
+            let mut x = *x_range.start();
+            for range in self.row(y) {
+                if x > *x_range.end() || *range.start() > x {
+                    break;
+                }
+                x = x.max(*range.end() + 1);
+            }
+            (x <= *x_range.end()).then(|| AnyGridPoint::new(x, y))
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            y_range.into_par_iter().find_map_first(uncovered)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut rows = y_range;
+            rows.find_map(uncovered)
+        }
+    }
+}
+
+/// A fold of a plane along a line, which reflects the points beyond the line onto the
+/// points before it, and which can be parsed from text such as `fold along x=5`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::Fold;
+///
+/// let fold = Fold::from_str("fold along y=7").unwrap();
+/// assert_eq!(fold, Fold::Y(7));
+/// assert_eq!(fold.reflect(AnyGridPoint::new(3, 10)), AnyGridPoint::new(3, 4));
+/// assert_eq!(fold.reflect(AnyGridPoint::new(3, 2)), AnyGridPoint::new(3, 2));
+/// assert_eq!(
+///     Fold::X(5).reflect(AnyGridPoint::new(9, 1)),
+///     AnyGridPoint::new(1, 1),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fold {
+    /// A fold along the vertical line with this `x` coordinate.
+    X(isize),
+    /// A fold along the horizontal line with this `y` coordinate.
+    Y(isize),
+}
+impl Parsable<'_> for Fold {
+    fn parser(input: &str) -> NomParseResult<&str, Self> {
+        map(
+            preceded(
+                tuple((tag("fold"), space1, tag("along"), space1)),
+                separated_pair(one_of("xy"), tag("="), usize::parser),
+            ),
+            |(axis, line)| {
+                let line = line.try_into().unwrap();
+                match axis {
+                    'x' => Self::X(line),
+                    _ => Self::Y(line),
+                }
+            },
+        )(input.trim())
+    }
+}
+impl Fold {
+    /// Returns where a `point` ends up after the fold, which is its reflection across the
+    /// line if it is beyond the line, and otherwise the point itself.
+    pub fn reflect(&self, point: AnyGridPoint) -> AnyGridPoint {
+        match *self {
+            Self::X(x) if point.x > x => AnyGridPoint::new(2 * x - point.x, point.y),
+            Self::Y(y) if point.y > y => AnyGridPoint::new(point.x, 2 * y - point.y),
+            _ => point,
+        }
+    }
+}
+
+/// Returns the set of points at which some `points` end up after a `fold`, in which
+/// points that are reflected onto each other are merged.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{reflect_points, Fold};
+///
+/// let points = [(0, 0), (4, 0), (1, 2), (3, 2)].map(|(x, y)| AnyGridPoint::new(x, y));
+/// let folded = reflect_points(points, Fold::X(2));
+/// assert_eq!(folded.len(), 2);
+/// assert!(folded.contains(&AnyGridPoint::new(1, 2)));
+/// ```
+pub fn reflect_points(
+    points: impl IntoIterator<Item = AnyGridPoint>,
+    fold: Fold,
+) -> HashSet<AnyGridPoint> {
+    points.into_iter().map(|p| fold.reflect(p)).collect()
+}
+
+/// Returns the grid of the points at which some `points` end up after some `folds` in
+/// order, see [`reflect_points`], which is just large enough to contain the points.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{fold_all, Fold};
+/// use aoc::grid::StdBool;
+/// use std::str::FromStr;
+///
+/// let points = [(0, 0), (4, 0), (0, 4), (4, 4), (1, 1)].map(|(x, y)| AnyGridPoint::new(x, y));
+/// assert_eq!(
+///     fold_all(points, &[Fold::X(2), Fold::Y(2)]),
+///     Grid::<StdBool>::from_str("#.\n.#").unwrap(),
+/// );
+/// ```
+pub fn fold_all(points: impl IntoIterator<Item = AnyGridPoint>, folds: &[Fold]) -> Grid<StdBool> {
+    let points = folds
+        .iter()
+        .fold(points.into_iter().collect(), |points, fold| {
+            reflect_points(points, *fold)
+        });
+    Grid::from_coordinates(points.iter())
+}
+
+/// Returns the letters drawn by some `points` after some `folds` in order, see
+/// [`fold_all`] and [`read_letters`].
+///
+/// Returns [`AocError::Process`](crate::error::AocError::Process) if the points do not
+/// draw known letters.
+pub fn fold_letters(
+    points: impl IntoIterator<Item = AnyGridPoint>,
+    folds: &[Fold],
+) -> AocResult<String> {
+    read_letters(&fold_all(points, folds))
+}
+
+/// Where a point is relative to a polygon, see [`contains_point`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Containment {
+    /// The point is strictly inside the polygon.
+    Inside,
+    /// The point is on an edge or vertex of the polygon.
+    Boundary,
+    /// The point is outside of the polygon.
+    #[default]
+    Outside,
+}
+
+/// Returns twice the signed area of the triangle with vertices `a`, `b`, and `c`, which is
+/// zero exactly when they are on a line.
+///
+/// This is an internal function of [`contains_point`].
+fn cross(a: &AnyGridPoint, b: &AnyGridPoint, c: &AnyGridPoint) -> i128 {
+    let (ab, ac) = (*b - *a, *c - *a);
+    ab.x as i128 * ac.y as i128 - ac.x as i128 * ab.y as i128
+}
+
+/// Determines where a `point` is relative to the polygon with some `vertices` in order,
+/// using only integer arithmetic.
+///
+/// The polygon is closed, so the last vertex joins the first, and may be concave and
+/// in either orientation.
+/// For self-intersecting polygons, points are inside if the polygon winds around them a
+/// non-zero number of times.
+/// Points on edges and vertices are always on the [`Containment::Boundary`], so that
+/// lattice points are never classified inconsistently due to rounding.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{contains_point, Containment};
+///
+/// // A U shape with a notch cut into the bottom
+/// let polygon = [(0, 0), (6, 0), (6, 6), (4, 6), (4, 2), (2, 2), (2, 6), (0, 6)]
+///     .map(|(x, y)| AnyGridPoint::new(x, y));
+/// let containment = |x, y| contains_point(&polygon, &AnyGridPoint::new(x, y));
+///
+/// assert_eq!(containment(1, 1), Containment::Inside);
+/// assert_eq!(containment(5, 5), Containment::Inside);
+/// assert_eq!(containment(3, 4), Containment::Outside);
+/// assert_eq!(containment(7, 3), Containment::Outside);
+/// assert_eq!(containment(3, 2), Containment::Boundary);
+/// assert_eq!(containment(6, 3), Containment::Boundary);
+/// assert_eq!(containment(4, 6), Containment::Boundary);
+/// ```
+pub fn contains_point(vertices: &[AnyGridPoint], point: &AnyGridPoint) -> Containment {
+    let mut winding = 0;
+    for (a, b) in vertices.iter().circular_tuple_windows() {
+        let side = cross(a, b, point);
+        if side == 0
+            && (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+            && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y)
+        {
+            return Containment::Boundary;
+        }
+
+        // Count the edges that cross the ray to the right of the point, where each edge
+        // includes its lower end but not its upper end so that vertices count only once
+        if a.y <= point.y {
+            if b.y > point.y && side > 0 {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side < 0 {
+            winding -= 1;
+        }
+    }
+
+    if winding == 0 {
+        Containment::Outside
+    } else {
+        Containment::Inside
+    }
+}
+
+/// Determines where every point within some `bounds` is relative to the polygon with some
+/// `vertices` in order, see [`contains_point`].
+///
+/// The point at the origin of the returned grid is the minimum point of the `bounds`.
+/// This scans each row once for all the edges, so is much faster than checking each
+/// point individually when there are many points.
+///
+/// # Panics
+/// This will panic if the `bounds` are empty.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::geometry::{contains_point, polygon_containment, Containment};
+/// use euclid::vec2;
+///
+/// let polygon = [(0, 0), (6, 0), (6, 6), (4, 6), (4, 2), (2, 2), (2, 6), (0, 6)]
+///     .map(|(x, y)| AnyGridPoint::new(x, y));
+/// let bounds = GridBox::new(GridPoint::new(1, 0), GridPoint::new(8, 8));
+/// let grid = polygon_containment(&polygon, &bounds);
+///
+/// for point in grid.all_points() {
+///     assert_eq!(
+///         *grid.get(&point),
+///         contains_point(&polygon, &(point + vec2(1, 0)).to_isize()),
+///     );
+/// }
+/// let inside = grid.all_values().filter(|c| **c == Containment::Inside);
+/// assert_eq!(inside.count(), 13);
+/// ```
+pub fn polygon_containment(vertices: &[AnyGridPoint], bounds: &GridBox) -> Grid<Containment> {
+    let mut grid = Grid::default(bounds.size());
+    let (x_min, width) = (bounds.min.x as i128, bounds.width());
+
+    for (row, y) in bounds.y_range().enumerate() {
+        let y = y as i128;
+
+        // The change in winding number when moving left onto each column, from crossings
+        // counted in the same way as for a single point
+        let mut changes = vec![0; width];
+        for (a, b) in vertices.iter().circular_tuple_windows() {
+            let (ax, ay, bx, by) = (a.x as i128, a.y as i128, b.x as i128, b.y as i128);
+            let change = if ay <= y && y < by {
+                1
+            } else if by <= y && y < ay {
+                -1
+            } else {
+                continue;
+            };
+
+            // The last column strictly to the left of the crossing
+            let (dx, dy) = (bx - ax, by - ay);
+            let numerator = (ax * dy + (y - ay) * dx) * dy.signum();
+            let last = (numerator - 1).div_euclid(dy.abs());
+            if last >= x_min {
+                changes[(last - x_min).min(width as i128 - 1) as usize] += change;
+            }
+        }
+
+        let mut winding = 0;
+        for (cell, change) in grid.row_slice_mut(row).iter_mut().zip(changes).rev() {
+            winding += change;
+            if winding != 0 {
+                *cell = Containment::Inside;
+            }
+        }
+    }
+
+    // Lastly mark every lattice point on the edges
+    let offset = bounds.min.to_isize().to_vector();
+    for (a, b) in vertices.iter().circular_tuple_windows() {
+        let delta = *b - *a;
+        let steps = delta.x.gcd(&delta.y);
+        let step = if steps == 0 { delta } else { delta / steps };
+        for i in 0..=steps {
+            grid.set_any(&(*a + step * i - offset), Containment::Boundary);
+        }
+    }
+
+    grid
+}
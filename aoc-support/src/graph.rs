@@ -0,0 +1,160 @@
+//! Algorithms on implicit graphs, in which the nodes are generated by successor functions.
+//!
+//! Many problems ask how many distinct ways there are to get from one place to
+//! another, which is easy to compute with memoization provided that the paths
+//! cannot loop back on themselves.
+//! The [`count_paths`] function does this for arbitrary nodes, and the
+//! [`Grid::count_paths`](crate::grid::Grid::count_paths) method does so for points in a grid.
+//!
+//! Trees given by the parent of each node are handled by the [`tree`] module, and the
+//! strongly connected components and cycles of directed graphs by the [`components`]
+//! module.
+
+pub mod components;
+pub mod tree;
+
+use crate::error::{AocError, AocResult};
+use std::{collections::HashMap, hash::Hash};
+
+/// The memoized state of a node while counting paths.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) enum PathCount {
+    /// The node has not yet been reached.
+    #[default]
+    Unvisited,
+    /// Paths from the node are still being counted, so that reaching it again
+    /// means that there is a cycle.
+    InProgress,
+    /// The number of paths from the node to a goal.
+    Done(u128),
+}
+
+/// A memo of the [`PathCount`] of every node, as needed by [`count_paths_with`].
+pub(crate) trait PathMemo<N> {
+    /// Returns the state of a `node`.
+    fn get(&self, node: &N) -> PathCount;
+
+    /// Sets the state of a `node`.
+    fn set(&mut self, node: N, count: PathCount);
+}
+impl<N: Hash + Eq> PathMemo<N> for HashMap<N, PathCount> {
+    fn get(&self, node: &N) -> PathCount {
+        HashMap::get(self, node).copied().unwrap_or_default()
+    }
+
+    fn set(&mut self, node: N, count: PathCount) {
+        self.insert(node, count);
+    }
+}
+
+/// A node whose successors are currently being explored in [`count_paths_with`].
+struct Frame<N> {
+    /// The node itself.
+    node: N,
+    /// The successors that have yet to be explored.
+    successors: Vec<N>,
+    /// The number of paths found through the successors explored so far.
+    total: u128,
+}
+
+/// Counts paths as for [`count_paths`], but with a particular `memo`.
+///
+/// This is an internal function that uses an explicit stack so that very long
+/// paths do not overflow the call stack.
+pub(crate) fn count_paths_with<N: Clone, I: IntoIterator<Item = N>>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    successors: impl Fn(&N) -> I,
+    memo: &mut impl PathMemo<N>,
+) -> AocResult<u128> {
+    if is_goal(&start) {
+        return Ok(1);
+    }
+
+    memo.set(start.clone(), PathCount::InProgress);
+    let mut stack = vec![Frame {
+        successors: successors(&start).into_iter().collect(),
+        node: start,
+        total: 0,
+    }];
+
+    loop {
+        let frame = stack.last_mut().unwrap();
+        let Some(next) = frame.successors.pop() else {
+            // All paths from this node have been counted
+            let frame = stack.pop().unwrap();
+            memo.set(frame.node, PathCount::Done(frame.total));
+            match stack.last_mut() {
+                Some(parent) => parent.total += frame.total,
+                None => return Ok(frame.total),
+            }
+            continue;
+        };
+
+        match memo.get(&next) {
+            PathCount::Done(n) => frame.total += n,
+            PathCount::InProgress => {
+                return Err(AocError::Process("Paths contain a cycle".into()));
+            }
+            PathCount::Unvisited => {
+                if is_goal(&next) {
+                    memo.set(next, PathCount::Done(1));
+                    frame.total += 1;
+                } else {
+                    memo.set(next.clone(), PathCount::InProgress);
+                    stack.push(Frame {
+                        successors: successors(&next).into_iter().collect(),
+                        node: next,
+                        total: 0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns the number of distinct paths from a `start` node to any goal node,
+/// where the `successors` function gives the nodes that can be moved to from a node.
+///
+/// Paths end as soon as they reach a goal node, as determined by `is_goal`.
+/// The number of paths from each node is memoized, so this is efficient even
+/// when the number of paths is astronomical.
+/// Returns [`AocError::Process`] if a cycle is reachable from the `start` node,
+/// since there would then be infinitely many paths.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::count_paths;
+/// use std::collections::HashMap;
+///
+/// let edges = HashMap::from([
+///     ("you", vec!["a", "b"]),
+///     ("a", vec!["c", "out"]),
+///     ("b", vec!["c"]),
+///     ("c", vec!["out", "d"]),
+///     ("d", vec!["out"]),
+/// ]);
+/// let successors = |node: &&str| edges.get(node).cloned().unwrap_or_default();
+///
+/// assert_eq!(count_paths("you", |n| *n == "out", successors), Ok(5));
+/// assert_eq!(count_paths("c", |n| *n == "out", successors), Ok(2));
+/// assert_eq!(count_paths("you", |n| *n == "d", successors), Ok(2));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::count_paths;
+///
+/// // A node that leads back to itself
+/// assert!(count_paths(0, |n| *n == 3, |n| [(n + 1) % 3, 3]).is_err());
+/// ```
+pub fn count_paths<N: Hash + Eq + Clone, I: IntoIterator<Item = N>>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    successors: impl Fn(&N) -> I,
+) -> AocResult<u128> {
+    count_paths_with(start, is_goal, successors, &mut HashMap::new())
+}
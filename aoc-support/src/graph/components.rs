@@ -0,0 +1,281 @@
+//! Strongly connected components and cycles of directed graphs given as adjacency lists.
+//!
+//! The graphs here are the lightweight adjacency lists created by
+//! [`LabelMap::adjacency`](crate::intern::LabelMap::adjacency), in which the nodes are
+//! dense IDs and the list at the index of each node contains its successors.
+//! The [`Components`] of a graph are its strongly connected components, which identify
+//! the loops in dependency graphs, and which form the acyclic
+//! [`Components::condensation`] of the graph.
+//! A single cycle can also be found with [`find_cycle`].
+
+use std::collections::VecDeque;
+
+/// The strongly connected components of a directed graph, in which every node can be
+/// reached from every other node in the same component.
+///
+/// The components are indexed in topological order, so that every edge between
+/// different components goes from a component to one with a larger index.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::components::Components;
+/// use aoc::intern::LabelMap;
+///
+/// let mut labels = LabelMap::new();
+/// let adjacency = labels.adjacency([
+///     ("a", "b"),
+///     ("b", "c"),
+///     ("c", "a"),
+///     ("c", "d"),
+///     ("d", "e"),
+///     ("e", "e"),
+///     ("b", "f"),
+/// ]);
+/// let components = Components::new(&adjacency);
+/// let id = |label| usize::from(labels.get(label).unwrap());
+///
+/// assert_eq!(components.len(), 4);
+/// assert_eq!(components.component_of(id("a")), components.component_of(id("c")));
+/// assert_ne!(components.component_of(id("a")), components.component_of(id("d")));
+///
+/// let start = components.component_of(id("a"));
+/// assert_eq!(components.component(start).len(), 3);
+/// assert!(components.is_cyclic(start));
+/// assert!(components.is_cyclic(components.component_of(id("e"))));
+/// assert!(!components.is_cyclic(components.component_of(id("d"))));
+///
+/// // The graph of components has the start component leading to both of the others
+/// assert_eq!(components.condensation()[start].len(), 2);
+/// assert!(components.condensation()[components.component_of(id("f"))].is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Components {
+    /// The index of the component of each node.
+    component_of: Vec<usize>,
+    /// The nodes in each component.
+    components: Vec<Vec<usize>>,
+    /// Whether each component contains a cycle.
+    cyclic: Vec<bool>,
+    /// The adjacency lists of the graph of the components.
+    condensation: Vec<Vec<usize>>,
+}
+impl Components {
+    /// Finds the components of the directed graph with some `adjacency` lists, in which
+    /// the list at the index of each node contains its successors.
+    ///
+    /// This uses Tarjan's algorithm with an explicit stack, so that large graphs do not
+    /// overflow the call stack, and takes time proportional to the size of the graph.
+    ///
+    /// # Panics
+    /// This will panic if a successor is not a node of the graph.
+    pub fn new<N: Copy + Into<usize>>(adjacency: &[Vec<N>]) -> Self {
+        const UNVISITED: usize = usize::MAX;
+
+        let len = adjacency.len();
+        let mut index = vec![UNVISITED; len];
+        let mut low = vec![0; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..len {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            // Each call is a node and the position of its next successor to explore
+            let mut calls = vec![(root, 0)];
+            index[root] = next_index;
+            low[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, position)) = calls.last_mut() {
+                let node = *node;
+                match adjacency[node].get(*position) {
+                    Some(successor) => {
+                        *position += 1;
+                        let successor: usize = (*successor).into();
+                        if index[successor] == UNVISITED {
+                            index[successor] = next_index;
+                            low[successor] = next_index;
+                            next_index += 1;
+                            stack.push(successor);
+                            on_stack[successor] = true;
+                            calls.push((successor, 0));
+                        } else if on_stack[successor] {
+                            low[node] = low[node].min(index[successor]);
+                        }
+                    }
+                    None => {
+                        // All the successors have been explored
+                        calls.pop();
+                        if let Some((parent, _)) = calls.last() {
+                            low[*parent] = low[*parent].min(low[node]);
+                        }
+                        if low[node] == index[node] {
+                            let mut component = Vec::new();
+                            loop {
+                                let member = stack.pop().unwrap();
+                                on_stack[member] = false;
+                                component.push(member);
+                                if member == node {
+                                    break;
+                                }
+                            }
+                            component.sort_unstable();
+                            components.push(component);
+                        }
+                    }
+                }
+            }
+        }
+
+        // The components are found in reverse topological order
+        components.reverse();
+        let mut component_of = vec![0; len];
+        for (idx, component) in components.iter().enumerate() {
+            for node in component {
+                component_of[*node] = idx;
+            }
+        }
+
+        let mut cyclic: Vec<_> = components.iter().map(|c| c.len() > 1).collect();
+        let mut condensation = vec![Vec::new(); components.len()];
+        for (node, successors) in adjacency.iter().enumerate() {
+            let from = component_of[node];
+            for successor in successors {
+                let successor: usize = (*successor).into();
+                let to = component_of[successor];
+                if to == from {
+                    cyclic[from] |= successor == node;
+                } else {
+                    condensation[from].push(to);
+                }
+            }
+        }
+        for successors in condensation.iter_mut() {
+            successors.sort_unstable();
+            successors.dedup();
+        }
+
+        Self {
+            component_of,
+            components,
+            cyclic,
+            condensation,
+        }
+    }
+
+    /// Returns the number of components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns whether there are no components, which is only the case for an empty graph.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns the index of the component that contains a `node`.
+    ///
+    /// # Panics
+    /// This will panic if the `node` is not in the graph.
+    pub fn component_of(&self, node: usize) -> usize {
+        self.component_of[node]
+    }
+
+    /// Returns the nodes in the component with an index, in increasing order.
+    ///
+    /// # Panics
+    /// This will panic if there is no component with the index.
+    pub fn component(&self, idx: usize) -> &[usize] {
+        &self.components[idx]
+    }
+
+    /// Returns an [`Iterator`] over the nodes in each component, in the order of their
+    /// indices.
+    pub fn components(&self) -> impl Iterator<Item = &[usize]> {
+        self.components.iter().map(|c| c.as_slice())
+    }
+
+    /// Returns whether the component with an index contains a cycle, which is the case if
+    /// it has more than one node or its node is its own successor.
+    ///
+    /// # Panics
+    /// This will panic if there is no component with the index.
+    pub fn is_cyclic(&self, idx: usize) -> bool {
+        self.cyclic[idx]
+    }
+
+    /// Returns the adjacency lists of the condensation of the graph, which is the acyclic
+    /// graph in which the nodes are the components, with an edge between two components
+    /// if there is an edge between any of their nodes.
+    ///
+    /// The successors of each component are in increasing order and, because of the
+    /// topological order, all have larger indices than the component itself.
+    pub fn condensation(&self) -> &[Vec<usize>] {
+        &self.condensation
+    }
+}
+
+/// Returns a cycle in the directed graph with some `adjacency` lists, see
+/// [`Components::new`], or `None` if the graph is acyclic.
+///
+/// The cycle is the nodes in order, in which the first node is a successor of the last.
+/// It is a shortest cycle through its first node, which is the smallest node of the first
+/// cyclic component, see [`Components`].
+///
+/// # Panics
+/// This will panic if a successor is not a node of the graph.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::components::find_cycle;
+///
+/// let adjacency: Vec<Vec<u16>> = vec![vec![1], vec![2, 4], vec![3], vec![1], vec![]];
+/// assert_eq!(find_cycle(&adjacency), Some(vec![1, 2, 3]));
+///
+/// // A node that is its own successor
+/// let adjacency: Vec<Vec<u16>> = vec![vec![1], vec![1]];
+/// assert_eq!(find_cycle(&adjacency), Some(vec![1]));
+///
+/// // An acyclic graph
+/// let adjacency: Vec<Vec<u16>> = vec![vec![1, 2], vec![2], vec![]];
+/// assert_eq!(find_cycle(&adjacency), None);
+/// ```
+pub fn find_cycle<N: Copy + Into<usize>>(adjacency: &[Vec<N>]) -> Option<Vec<usize>> {
+    let components = Components::new(adjacency);
+    let cyclic = (0..components.len()).find(|c| components.is_cyclic(*c))?;
+    let start = components.component(cyclic)[0];
+
+    // Search breadth first within the component for the way back to the start
+    let mut previous = vec![None; adjacency.len()];
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for successor in &adjacency[node] {
+            let successor: usize = (*successor).into();
+            if successor == start {
+                let mut cycle = vec![node];
+                while let Some(prev) = previous[*cycle.last().unwrap()] {
+                    cycle.push(prev);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if components.component_of(successor) == cyclic && previous[successor].is_none() {
+                previous[successor] = Some(node);
+                queue.push_back(successor);
+            }
+        }
+    }
+
+    // Every node in a cyclic component is on a cycle
+    unreachable!()
+}
@@ -0,0 +1,173 @@
+//! Rooted trees given as the parent of each node, such as maps of orbits.
+//!
+//! A [`ParentTree`] is built from a list of parent-child edges, after which the depth of
+//! every node, the lowest common ancestor of two nodes, and the path between two nodes
+//! can be found.
+
+use crate::{
+    collections::HashMap,
+    error::{AocError, AocResult},
+};
+use std::{fmt::Debug, hash::Hash};
+
+/// A forest of rooted trees, in which each node has at most one parent.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::tree::ParentTree;
+///
+/// // Orbits in which the second object orbits the first
+/// let orbits = "COM)B B)C C)D D)E E)F B)G G)H D)I E)J J)K K)L K)YOU I)SAN";
+/// let tree = ParentTree::from_edges(
+///     orbits.split_whitespace().map(|o| o.split_once(')').unwrap()),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(tree.depths().values().sum::<usize>(), 54);
+/// assert_eq!(tree.depth(&"L"), Some(7));
+/// assert_eq!(tree.parent(&"YOU"), Some(&"K"));
+/// assert_eq!(tree.lca(&"YOU", &"SAN"), Some(&"D"));
+/// assert_eq!(
+///     tree.path_between(&"K", &"I"),
+///     Some(vec![&"K", &"J", &"E", &"D", &"I"]),
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::graph::tree::ParentTree;
+///
+/// // A node with two parents
+/// assert!(ParentTree::from_edges([(1, 2), (3, 2)]).is_err());
+/// // The edges form a cycle
+/// assert!(ParentTree::from_edges([(1, 2), (2, 3), (3, 1)]).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParentTree<N> {
+    /// The parent of each node that has one.
+    parents: HashMap<N, N>,
+    /// The depth of every node, where roots have a depth of zero.
+    depths: HashMap<N, usize>,
+}
+impl<N: Hash + Eq + Clone + Debug> ParentTree<N> {
+    /// Creates a tree from the `edges`, each of which is a pair of a parent and its child.
+    ///
+    /// Returns [`AocError::InvalidInput`] if a node has more than one parent or the edges
+    /// form a cycle.
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N)>) -> AocResult<Self> {
+        let mut parents = HashMap::default();
+        let mut nodes = Vec::new();
+        for (parent, child) in edges {
+            if let Some(other) = parents.insert(child.clone(), parent.clone()) {
+                return Err(AocError::InvalidInput(
+                    format!("The node {child:?} has both {other:?} and {parent:?} as parents")
+                        .into(),
+                ));
+            }
+            nodes.push(parent);
+            nodes.push(child);
+        }
+
+        // Determine the depths, walking up from each node until one with a known depth
+        let mut depths = HashMap::default();
+        for node in nodes {
+            let mut chain = Vec::new();
+            let mut current = node;
+            let base = loop {
+                if let Some(depth) = depths.get(&current) {
+                    break *depth;
+                }
+                if chain.len() > parents.len() {
+                    return Err(AocError::InvalidInput(
+                        format!("The node {current:?} is its own ancestor").into(),
+                    ));
+                }
+                match parents.get(&current) {
+                    Some(parent) => {
+                        let parent = parent.clone();
+                        chain.push(current);
+                        current = parent;
+                    }
+                    None => {
+                        depths.insert(current.clone(), 0);
+                        break 0;
+                    }
+                }
+            };
+            for (depth, node) in chain.into_iter().rev().enumerate() {
+                depths.insert(node, base + depth + 1);
+            }
+        }
+
+        Ok(Self { parents, depths })
+    }
+
+    /// Returns the parent of a `node`, or `None` if it is a root or not in the tree.
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.parents.get(node)
+    }
+
+    /// Returns the depth of a `node`, which is the number of its ancestors, or `None` if
+    /// it is not in the tree.
+    pub fn depth(&self, node: &N) -> Option<usize> {
+        self.depths.get(node).copied()
+    }
+
+    /// Returns the depth of every node, see [`ParentTree::depth`].
+    pub fn depths(&self) -> &HashMap<N, usize> {
+        &self.depths
+    }
+
+    /// Returns an [`Iterator`] over the ancestors of a `node`, starting with its parent.
+    pub fn ancestors<'a>(&'a self, node: &'a N) -> impl Iterator<Item = &'a N> {
+        std::iter::successors(self.parent(node), |n| self.parent(n))
+    }
+
+    /// Returns the lowest common ancestor of two nodes `a` and `b`, which is the deepest
+    /// node that is an ancestor of both of them, where each node counts as its own ancestor.
+    ///
+    /// Returns `None` if either node is not in the tree or the nodes are in different
+    /// trees.
+    pub fn lca<'a>(&'a self, a: &'a N, b: &'a N) -> Option<&'a N> {
+        let (mut a, mut b) = (a, b);
+        let (mut depth_a, mut depth_b) = (self.depth(a)?, self.depth(b)?);
+
+        // Move up to the same depth, then up together until the nodes meet
+        while depth_a > depth_b {
+            a = self.parent(a)?;
+            depth_a -= 1;
+        }
+        while depth_b > depth_a {
+            b = self.parent(b)?;
+            depth_b -= 1;
+        }
+        while a != b {
+            a = self.parent(a)?;
+            b = self.parent(b)?;
+        }
+        Some(a)
+    }
+
+    /// Returns the nodes on the path from node `a` to node `b`, including both, which
+    /// passes through their lowest common ancestor, see [`ParentTree::lca`].
+    ///
+    /// Returns `None` if there is no such path because either node is not in the tree
+    /// or the nodes are in different trees.
+    pub fn path_between<'a>(&'a self, a: &'a N, b: &'a N) -> Option<Vec<&'a N>> {
+        let lca = self.lca(a, b)?;
+        let up = |node: &'a N| {
+            std::iter::once(node)
+                .chain(self.ancestors(node))
+                .take_while(move |n| *n != lca)
+        };
+
+        let mut path: Vec<_> = up(a).collect();
+        path.push(lca);
+        let from_b: Vec<_> = up(b).collect();
+        path.extend(from_b.into_iter().rev());
+        Some(path)
+    }
+}
@@ -0,0 +1,3881 @@
+//! 2D grids of values.
+//!
+//! Contains the main [`Grid`] struct, associated traits, and some useful
+//! grid element types.
+
+pub mod cube_net;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+use super::prelude::*;
+use crate::{
+    collections::{HashMap, HashSet},
+    direction::{Direction4, Turn},
+    graph::{PathCount, PathMemo},
+    scratch::Pool,
+};
+use derive_more::{Add, AddAssign, Deref, From, Into, Not, Sub, SubAssign};
+use euclid::{Box2D, Point2D, Size2D, Vector2D};
+use itertools::{iproduct, process_results, Itertools};
+use num::{FromPrimitive, Integer, NumCast};
+use petgraph::{graph::NodeIndex, stable_graph::IndexType, EdgeType, Graph};
+use std::{cmp::Eq, fmt, hash::Hash, ops::Range, str::FromStr};
+
+/// A grid coordinate system in which the origin is the in upper left of the grid
+/// and increasing `y` moves down in the grid.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridSpace;
+
+/// The size of a [`Grid`].
+///
+/// Sizes in which either element is zero are not valid.
+pub type GridSize<U = GridSpace> = Size2D<usize, U>;
+
+/// A point location in a [`Grid`] that should be within the bounds of the grid.
+///
+/// Refer to [`GridSpace`] for the coordinate system.
+pub type GridPoint<U = GridSpace> = Point2D<usize, U>;
+
+/// A point location in any [`Grid`] regardless of its bounds.
+///
+/// Refer to [`GridSpace`] for the coordinate system.
+pub type AnyGridPoint<U = GridSpace> = Point2D<isize, U>;
+
+/// A box within a [`Grid`] that defines a sub-grid.
+///
+/// Refer to [`GridSpace`] for the coordinate system.
+pub type GridBox<U = GridSpace> = Box2D<usize, U>;
+
+/// Converts a `point` to one with a different coordinate type, such as between a
+/// [`GridPoint`] and an [`AnyGridPoint`].
+///
+/// Returns [`AocError::Process`] if either coordinate cannot be represented in the
+/// new type.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::cast_point;
+///
+/// assert_eq!(
+///     cast_point::<_, usize, GridSpace>(AnyGridPoint::new(3, 4)),
+///     Ok(GridPoint::new(3, 4)),
+/// );
+/// assert_eq!(
+///     cast_point::<_, isize, GridSpace>(GridPoint::new(3, 4)),
+///     Ok(AnyGridPoint::new(3, 4)),
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::cast_point;
+///
+/// assert!(cast_point::<_, usize, GridSpace>(AnyGridPoint::new(3, -4)).is_err());
+/// assert!(cast_point::<_, isize, GridSpace>(GridPoint::new(usize::MAX, 0)).is_err());
+/// ```
+pub fn cast_point<T: NumCast + Copy + fmt::Debug, S: NumCast + Copy, U>(
+    point: Point2D<T, U>,
+) -> AocResult<Point2D<S, U>> {
+    point.try_cast().ok_or_else(|| {
+        AocError::Process(
+            format!(
+                "The point ({:?}, {:?}) cannot be converted to {}",
+                point.x,
+                point.y,
+                std::any::type_name::<S>()
+            )
+            .into(),
+        )
+    })
+}
+
+/// Converts a `size` to one with a different element type, such as from a
+/// [`GridSize`] to a signed size for arithmetic with [`AnyGridPoint`].
+///
+/// Returns [`AocError::Process`] if either dimension cannot be represented in the
+/// new type.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::cast_size;
+/// use euclid::Size2D;
+///
+/// assert_eq!(
+///     cast_size::<_, isize, GridSpace>(GridSize::new(5, 7)),
+///     Ok(Size2D::new(5, 7)),
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::cast_size;
+///
+/// assert!(cast_size::<_, isize, GridSpace>(GridSize::new(5, usize::MAX)).is_err());
+/// ```
+pub fn cast_size<T: NumCast + Copy + fmt::Debug, S: NumCast + Copy, U>(
+    size: Size2D<T, U>,
+) -> AocResult<Size2D<S, U>> {
+    size.try_cast().ok_or_else(|| {
+        AocError::Process(
+            format!(
+                "The size {:?} x {:?} cannot be converted to {}",
+                size.width,
+                size.height,
+                std::any::type_name::<S>()
+            )
+            .into(),
+        )
+    })
+}
+
+/// Extension trait for [`GridSize`].
+pub trait GridSizeExt<U>: Sized {
+    /// Returns whether the size is valid, that is nonzero in both dimensions.
+    fn is_valid(&self) -> bool;
+
+    /// Panics if the size is not valid, see [`GridSizeExt::is_valid`].
+    fn validate(&self) {
+        if !self.is_valid() {
+            panic!("grid size is invalid");
+        }
+    }
+
+    /// Creates a size and verifies that it is valid, see [`GridSizeExt::is_valid`].
+    fn new_valid(width: usize, height: usize) -> Option<Self>;
+
+    /// Returns the size multiplied by a `factor` in both dimensions.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(GridSize::<GridSpace>::new(3, 2).scaled(4), GridSize::new(12, 8));
+    /// ```
+    fn scaled(&self, factor: usize) -> Self;
+
+    /// Returns the size divided by a `divisor` in both dimensions, or `None` if the
+    /// `divisor` is zero or does not exactly divide both dimensions.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let size = GridSize::<GridSpace>::new(12, 8);
+    /// assert_eq!(size.divided_exact(4), Some(GridSize::new(3, 2)));
+    /// assert_eq!(size.divided_exact(3), None);
+    /// assert_eq!(size.divided_exact(0), None);
+    /// ```
+    fn divided_exact(&self, divisor: usize) -> Option<Self>;
+}
+impl<U> GridSizeExt<U> for GridSize<U> {
+    fn new_valid(width: usize, height: usize) -> Option<Self> {
+        let size = Self::new(width, height);
+        (size.is_valid()).then_some(size)
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.is_empty()
+    }
+
+    fn scaled(&self, factor: usize) -> Self {
+        *self * factor
+    }
+
+    fn divided_exact(&self, divisor: usize) -> Option<Self> {
+        if divisor > 0 && self.width % divisor == 0 && self.height % divisor == 0 {
+            Some(*self / divisor)
+        } else {
+            None
+        }
+    }
+}
+
+/// Extension trait for [`GridBox`].
+pub trait GridBoxExt<U>: Sized {
+    /// The iterator type returned from [`GridBoxExt::tiles`].
+    type Tiles: Iterator<Item = GridBox<U>>;
+
+    /// Returns the point in the exact center of the box, or `None` if the box does not
+    /// have one because either dimension is even.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid_box = GridBox::<GridSpace>::new(GridPoint::new(2, 1), GridPoint::new(7, 4));
+    /// assert_eq!(grid_box.center_point(), Some(GridPoint::new(4, 2)));
+    ///
+    /// let grid_box = GridBox::<GridSpace>::new(GridPoint::new(2, 1), GridPoint::new(6, 4));
+    /// assert_eq!(grid_box.center_point(), None);
+    /// ```
+    fn center_point(&self) -> Option<GridPoint<U>>;
+
+    /// Returns the four quadrants of the box in order from left to right and then top
+    /// to bottom.
+    ///
+    /// In any dimension that is odd, the middle row or column is in none of the
+    /// quadrants.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let [top_left, top_right, bottom_left, bottom_right] =
+    ///     GridBox::<GridSpace>::new(GridPoint::new(0, 0), GridPoint::new(11, 7)).quadrants();
+    /// assert_eq!(top_left, GridBox::new(GridPoint::new(0, 0), GridPoint::new(5, 3)));
+    /// assert_eq!(top_right, GridBox::new(GridPoint::new(6, 0), GridPoint::new(11, 3)));
+    /// assert_eq!(bottom_left, GridBox::new(GridPoint::new(0, 4), GridPoint::new(5, 7)));
+    /// assert_eq!(bottom_right, GridBox::new(GridPoint::new(6, 4), GridPoint::new(11, 7)));
+    /// ```
+    fn quadrants(&self) -> [GridBox<U>; 4];
+
+    /// Returns an [`Iterator`] over the tiles of some `tile_size` that exactly cover the
+    /// box in order from left to right and then top to bottom, or `None` if the
+    /// `tile_size` is not valid or does not divide the size of the box.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid_box = GridBox::<GridSpace>::new(GridPoint::new(1, 1), GridPoint::new(5, 3));
+    /// assert_eq!(
+    ///     grid_box.tiles(GridSize::new(2, 2)).unwrap().collect_vec(),
+    ///     vec![
+    ///         GridBox::new(GridPoint::new(1, 1), GridPoint::new(3, 3)),
+    ///         GridBox::new(GridPoint::new(3, 1), GridPoint::new(5, 3)),
+    ///     ],
+    /// );
+    /// assert!(grid_box.tiles(GridSize::new(3, 2)).is_none());
+    /// assert!(grid_box.tiles(GridSize::new(0, 2)).is_none());
+    /// ```
+    fn tiles(&self, tile_size: GridSize<U>) -> Option<Self::Tiles>;
+}
+impl<U> GridBoxExt<U> for GridBox<U> {
+    type Tiles = impl Iterator<Item = GridBox<U>>;
+
+    fn center_point(&self) -> Option<GridPoint<U>> {
+        let size = self.size();
+        if size.width % 2 == 1 && size.height % 2 == 1 {
+            Some(GridPoint::new(
+                self.min.x + size.width / 2,
+                self.min.y + size.height / 2,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn quadrants(&self) -> [GridBox<U>; 4] {
+        let (half_width, half_height) = (self.width() / 2, self.height() / 2);
+        let columns = [
+            (self.min.x, self.min.x + half_width),
+            (self.max.x - half_width, self.max.x),
+        ];
+        let rows = [
+            (self.min.y, self.min.y + half_height),
+            (self.max.y - half_height, self.max.y),
+        ];
+        [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(column, row)| {
+            GridBox::new(
+                GridPoint::new(columns[column].0, rows[row].0),
+                GridPoint::new(columns[column].1, rows[row].1),
+            )
+        })
+    }
+
+    fn tiles(&self, tile_size: GridSize<U>) -> Option<Self::Tiles> {
+        let size = self.size();
+        if !tile_size.is_valid()
+            || size.width % tile_size.width != 0
+            || size.height % tile_size.height != 0
+        {
+            return None;
+        }
+
+        let origin = self.min;
+        Some(
+            iproduct!(
+                (0..size.height).step_by(tile_size.height),
+                (0..size.width).step_by(tile_size.width)
+            )
+            .map(move |(y, x)| {
+                GridBox::from_origin_and_size(GridPoint::new(origin.x + x, origin.y + y), tile_size)
+            }),
+        )
+    }
+}
+
+/// Extension trait for [`GridPoint`].
+pub trait GridPointExt<U>: Sized {
+    /// Offsets the point by a signed `vector`, returning `None` if either coordinate
+    /// would become negative or overflow.
+    ///
+    /// This is not aware of the bounds of any grid, see [`Grid::step`] for that.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use euclid::vec2;
+    ///
+    /// let point = GridPoint::<GridSpace>::new(2, 1);
+    /// assert_eq!(point.offset_by(vec2(3, -1)), Some(GridPoint::new(5, 0)));
+    /// assert_eq!(point.offset_by(vec2(-3, 0)), None);
+    /// ```
+    fn offset_by(&self, vector: Vector2D<isize, U>) -> Option<Self>;
+
+    /// Offsets the point by a single step in some `direction`, returning `None` if
+    /// either coordinate would become negative or overflow.
+    ///
+    /// This is not aware of the bounds of any grid, see [`Grid::step`] for that.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::direction::Direction4;
+    ///
+    /// let point = GridPoint::<GridSpace>::new(0, 1);
+    /// assert_eq!(point.offset(Direction4::Up), Some(GridPoint::new(0, 0)));
+    /// assert_eq!(point.offset(Direction4::Right), Some(GridPoint::new(1, 1)));
+    /// assert_eq!(point.offset(Direction4::Left), None);
+    /// assert_eq!(GridPoint::<GridSpace>::new(usize::MAX, 0).offset(Direction4::Right), None);
+    /// ```
+    fn offset(&self, direction: Direction4) -> Option<Self> {
+        self.offset_by(direction.as_vector())
+    }
+}
+impl<U> GridPointExt<U> for GridPoint<U> {
+    fn offset_by(&self, vector: Vector2D<isize, U>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+}
+
+/// Extension trait for [`AnyGridPoint`].
+pub trait AnyGridPointExt<U> {
+    /// The iterator type returned from [`AnyGridPointExt::all_neighbor_points`].
+    ///
+    /// This is needed due to a
+    /// [limitation of RPITIT](https://users.rust-lang.org/t/fully-owned-iterator-causing-lifetime-problems/107677).
+    type NeighborPoints: Iterator<Item = AnyGridPoint<U>>;
+
+    /// Converts an infinitely repeating grid point into the actual addressable point
+    /// for a given grid size.
+    ///
+    /// This is effectively just a modulo operation in both dimensions using the `size`
+    /// values as the modulo.
+    ///
+    /// # Panics
+    /// This will panic if the `size` is too large to be converted to signed values,
+    /// see [`cast_size`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let size = GridSize::<GridSpace>::new(5, 4);
+    /// assert_eq!(
+    ///     AnyGridPoint::new(1, 2).wrapped_grid_point(&size),
+    ///     GridPoint::new(1, 2),
+    /// );
+    /// assert_eq!(
+    ///     AnyGridPoint::new(-1, -2).wrapped_grid_point(&size),
+    ///     GridPoint::new(4, 2),
+    /// );
+    /// assert_eq!(
+    ///     AnyGridPoint::new(-789, 27615).wrapped_grid_point(&size),
+    ///     GridPoint::new(1, 3),
+    /// );
+    /// ```
+    fn wrapped_grid_point(&self, size: &GridSize<U>) -> GridPoint<U>;
+
+    /// Returns an [`Iterator`] over all the neighboring points around a `point`
+    /// in row-major order.
+    ///
+    /// The set of points may optionally include the four diagonal neighbor points
+    /// as well as this `point` itself. These options will dictate the length of
+    /// the [`Iterator`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(
+    ///     AnyGridPoint::<GridSpace>::new(0, 0).all_neighbor_points(true, true).collect_vec(),
+    ///     vec![
+    ///         AnyGridPoint::new(-1, -1),
+    ///         AnyGridPoint::new(0, -1),
+    ///         AnyGridPoint::new(1, -1),
+    ///         AnyGridPoint::new(-1, 0),
+    ///         AnyGridPoint::new(0, 0),
+    ///         AnyGridPoint::new(1, 0),
+    ///         AnyGridPoint::new(-1, 1),
+    ///         AnyGridPoint::new(0, 1),
+    ///         AnyGridPoint::new(1, 1),
+    ///     ],
+    /// );
+    /// assert_eq!(
+    ///     AnyGridPoint::<GridSpace>::new(-4, -2).all_neighbor_points(true, false).collect_vec(),
+    ///     vec![
+    ///         AnyGridPoint::new(-5, -3),
+    ///         AnyGridPoint::new(-4, -3),
+    ///         AnyGridPoint::new(-3, -3),
+    ///         AnyGridPoint::new(-5, -2),
+    ///         AnyGridPoint::new(-3, -2),
+    ///         AnyGridPoint::new(-5, -1),
+    ///         AnyGridPoint::new(-4, -1),
+    ///         AnyGridPoint::new(-3, -1),
+    ///     ],
+    /// );
+    /// assert_eq!(
+    ///     AnyGridPoint::<GridSpace>::new(5, 6).all_neighbor_points(false, false).collect_vec(),
+    ///     vec![
+    ///         AnyGridPoint::new(5, 5),
+    ///         AnyGridPoint::new(4, 6),
+    ///         AnyGridPoint::new(6, 6),
+    ///         AnyGridPoint::new(5, 7),
+    ///     ],
+    /// );
+    /// ```
+    fn all_neighbor_points(
+        &self,
+        include_diagonals: bool,
+        include_self: bool,
+    ) -> Self::NeighborPoints;
+}
+impl<U> AnyGridPointExt<U> for AnyGridPoint<U> {
+    type NeighborPoints = impl Iterator<Item = Self>;
+
+    fn wrapped_grid_point(&self, size: &GridSize<U>) -> GridPoint<U> {
+        let wrapped = self.rem_euclid(&cast_size(*size).unwrap());
+        cast_point(wrapped).unwrap()
+    }
+
+    fn all_neighbor_points(
+        &self,
+        include_diagonals: bool,
+        include_self: bool,
+    ) -> Self::NeighborPoints {
+        let point = *self;
+
+        iproduct!(-1isize..=1, -1isize..=1).filter_map(move |(dy, dx)| {
+            let vector = Vector2D::new(dx, dy);
+            match vector.manhattan_len() {
+                0 => include_self,
+                2 => include_diagonals,
+                _ => true,
+            }
+            .then(|| point + vector)
+        })
+    }
+}
+
+/// Tracks a closed path of horizontal and vertical segments through any grid points.
+///
+/// The path is built by moving from a starting point, recording each vertex along the way.
+/// The path is always considered to be closed, that is there is an implied segment from the
+/// last vertex back to the starting point.
+/// Properties of the path and the polygon it bounds, such as its perimeter length and enclosed
+/// area, are computed only when requested, so that building even very long paths is cheap.
+///
+/// This is useful for problems in which a loop is walked or dug, after which the number of
+/// grid points on or inside the loop is needed.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::PathTracker;
+/// use euclid::vec2;
+/// use itertools::Itertools;
+///
+/// // This is the following path, starting at the upper left:
+/// // ####
+/// // #..#
+/// // ####
+/// let mut path = PathTracker::<GridSpace>::new(AnyGridPoint::new(0, 0));
+/// path.move_by(vec2(3, 0));
+/// path.move_by(vec2(0, 2));
+/// path.move_by(vec2(-3, 0));
+///
+/// assert_eq!(path.current(), AnyGridPoint::new(0, 2));
+/// assert_eq!(path.perimeter_len(), 10);
+/// assert_eq!(path.enclosed_area(), 6);
+/// assert_eq!(path.interior_points(), 2);
+/// assert_eq!(path.points_on_path().count(), 10);
+/// assert_eq!(
+///     path.points_on_path().take(5).collect_vec(),
+///     vec![
+///         AnyGridPoint::new(0, 0),
+///         AnyGridPoint::new(1, 0),
+///         AnyGridPoint::new(2, 0),
+///         AnyGridPoint::new(3, 0),
+///         AnyGridPoint::new(3, 1),
+///     ],
+/// );
+/// ```
+#[derive(Clone)]
+pub struct PathTracker<U = GridSpace> {
+    /// The vertices of the path in order, beginning with the starting point.
+    vertices: Vec<AnyGridPoint<U>>,
+}
+impl<U> PathTracker<U> {
+    /// Creates a new path with only a `start` point.
+    pub fn new(start: AnyGridPoint<U>) -> Self {
+        Self {
+            vertices: vec![start],
+        }
+    }
+
+    /// Returns the vertices of the path in order, beginning with the starting point.
+    pub fn vertices(&self) -> &[AnyGridPoint<U>] {
+        &self.vertices
+    }
+
+    /// Returns the current point, which is the last vertex of the path.
+    pub fn current(&self) -> AnyGridPoint<U> {
+        *self.vertices.last().unwrap()
+    }
+
+    /// Extends the path from the current point by a displacement `vector`.
+    ///
+    /// # Panics
+    /// This will panic if the `vector` is neither horizontal nor vertical.
+    pub fn move_by(&mut self, vector: Vector2D<isize, U>) {
+        if vector.x != 0 && vector.y != 0 {
+            panic!("path segments must be horizontal or vertical");
+        }
+        let point = self.current() + vector;
+        self.vertices.push(point);
+    }
+
+    /// Returns an [`Iterator`] over the segments of the closed path as pairs of
+    /// vertex points.
+    fn segments(&self) -> impl Iterator<Item = (AnyGridPoint<U>, AnyGridPoint<U>)> + '_ {
+        self.vertices.iter().copied().circular_tuple_windows()
+    }
+
+    /// Returns the total length of the closed path.
+    ///
+    /// This is also the number of distinct grid points on the path, provided that the path
+    /// never crosses or retraces itself.
+    pub fn perimeter_len(&self) -> usize {
+        self.segments()
+            .map(|(a, b)| (b - a).manhattan_len().unsigned_abs())
+            .sum()
+    }
+
+    /// Returns the area of the polygon whose vertices are the path vertices.
+    ///
+    /// This is calculated using the [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula)
+    /// and treats each grid point as a point rather than a cell, so the result is generally
+    /// smaller than the number of grid points on or inside the path.
+    pub fn enclosed_area(&self) -> usize {
+        let twice_area: isize = self.segments().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+        twice_area.unsigned_abs() / 2
+    }
+
+    /// Returns the number of grid points strictly inside the closed path.
+    ///
+    /// This is calculated from the enclosed area and perimeter using
+    /// [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+    /// Adding this to the [`PathTracker::perimeter_len`] gives the total number of grid points
+    /// either on or inside the path.
+    pub fn interior_points(&self) -> usize {
+        (self.enclosed_area() + 1).saturating_sub(self.perimeter_len() / 2)
+    }
+
+    /// Returns an [`Iterator`] over every grid point on the closed path in order,
+    /// beginning with the starting point.
+    pub fn points_on_path(&self) -> impl Iterator<Item = AnyGridPoint<U>> + '_ {
+        self.segments().flat_map(|(a, b)| {
+            let delta = b - a;
+            let step = Vector2D::new(delta.x.signum(), delta.y.signum());
+            (0..delta.manhattan_len()).map(move |i| a + step * i)
+        })
+    }
+}
+
+/// A 2D grid of values.
+///
+/// The values are addressed by a [`GridPoint`].
+/// Conceptually, the origin point (0, 0) is in the upper-left corner of the grid
+/// with increasing `x` moving to the right, and increasing `y` moving down.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, U = GridSpace> {
+    /// The size of the grid.
+    size: GridSize<U>,
+    /// The actual grid data of fixed size.
+    ///
+    /// This is a flat array of the rows in order, so that its length is the area of
+    /// the grid, see [`Grid::index_of`].
+    data: Box<[T]>,
+}
+impl<T: Default + Clone, U> Grid<T, U> {
+    /// Creates a default grid of a particular `size` with default values.
+    ///
+    /// # Panics
+    /// This will panic if the `size` is invalid, that is it contains zero in either dimension.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::default(GridSize::new(3, 3));
+    ///
+    /// assert_eq!(grid, Grid::from_data(vec![vec![0, 0, 0], vec![0, 0, 0], vec![0, 0, 0]]).unwrap());
+    /// ```
+    pub fn default(size: GridSize<U>) -> Self {
+        size.validate();
+        Self {
+            size,
+            data: vec![T::default(); size.area()].into_boxed_slice(),
+        }
+    }
+}
+impl<T, U> Grid<T, U> {
+    /// Creates a grid from raw data.
+    ///
+    /// The raw data should be a [`Vec`] for rows, with each row being
+    /// itself a [`Vec`] of the same length. Returns an [`AocError::Other`]
+    /// if either the passed data is empty, or if there are rows with different
+    /// lengths.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// // This is a grid with the following values:
+    /// // 0 1
+    /// // 2 3
+    /// // 4 5
+    /// let grid = Grid::<u8>::from_data(vec![vec![0, 1], vec![2, 3], vec![4, 5]]).unwrap();
+    ///
+    /// // This is a grid with a single element.
+    /// let grid = Grid::<u8>::from_data(vec![vec![5]]).unwrap();
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// assert_matches!(Grid::<u8>::from_data(vec![]), Err(AocError::Other(_)));
+    ///
+    /// let result = Grid::from_data(vec![
+    ///     vec![1, 2, 3],
+    ///     vec![4, 5],
+    ///     vec![6],
+    /// ]);
+    /// assert_matches!(result, Err(AocError::Other(_)));
+    /// ```
+    pub fn from_data(data: Vec<Vec<T>>) -> AocResult<Self> {
+        // Verify that we have at least one row
+        let height = data.len();
+        if height < 1 {
+            return Err(AocError::Other("The grid has no content!".into()));
+        }
+
+        // Verify that all the row widths are the same
+        let width = data[0].len();
+        let data = process_results(
+            data.into_iter().map(|row| {
+                if row.len() != width {
+                    Err(AocError::Other(
+                        format!(
+                            "Grid row has a length of {} instead of the expected {}",
+                            row.len(),
+                            width
+                        )
+                        .into(),
+                    ))
+                } else {
+                    Ok(row)
+                }
+            }),
+            |iter| iter.flatten().collect(),
+        )?;
+
+        Ok(Self {
+            size: GridSize::new(width, height),
+            data,
+        })
+    }
+
+    /// Returns the size the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_eq!(*grid.size(), GridSize::new(2, 3));
+    /// ```
+    pub fn size(&self) -> &GridSize<U> {
+        &self.size
+    }
+
+    /// Gets a reference to the element at a location.
+    ///
+    /// See [`Grid::try_get`] for a version that does not panic.
+    ///
+    /// # Panics
+    /// This will panic if the location is out of the bounds of the grid based on
+    /// its size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_eq!(*grid.get(&GridPoint::new(0, 1)), 3);
+    /// assert_eq!(*grid.get(&GridPoint::new(1, 1)), 4);
+    /// assert_eq!(*grid.get(&GridPoint::new(0, 0)), 1);
+    /// ```
+    pub fn get(&self, point: &GridPoint<U>) -> &T {
+        self.try_get(point).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Gets a reference to the element at a location, or returns an [`AocError::Process`]
+    /// if the location is out of the bounds of the grid based on its size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_eq!(grid.try_get(&GridPoint::new(1, 2)), Ok(&6));
+    /// assert!(grid.try_get(&GridPoint::new(2, 0)).is_err());
+    /// ```
+    pub fn try_get(&self, point: &GridPoint<U>) -> AocResult<&T> {
+        self.checked_index(point)
+            .map(|idx| &self.data[idx])
+            .ok_or_else(|| out_of_bounds(point, &self.size))
+    }
+
+    /// Gets a reference to the element at any location, if the location is within
+    /// the bounds of the grid.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is within the grid bounds but cannot be converted to a
+    /// [`GridPoint`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_matches!(grid.get_any(&AnyGridPoint::new(0, 1)), Some(&3));
+    /// assert_matches!(grid.get_any(&AnyGridPoint::new(-1, 1)), None);
+    /// assert_matches!(grid.get_any(&AnyGridPoint::new(1, 2)), Some(&6));
+    /// assert_matches!(grid.get_any(&AnyGridPoint::new(3, 1)), None);
+    /// ```
+    pub fn get_any(&self, point: &AnyGridPoint<U>) -> Option<&T> {
+        self.bounded_point(point)
+            .and_then(|p| self.try_get(&p).ok())
+    }
+
+    /// Sets the element at a location.
+    ///
+    /// See [`Grid::try_set`] for a version that does not panic.
+    ///
+    /// # Panics
+    /// This will panic if the location is out of the bounds of the grid based on
+    /// its size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// let point = GridPoint::new(1, 1);
+    ///
+    /// assert_eq!(*grid.get(&point), 4);
+    /// grid.set(&point, 21);
+    /// assert_eq!(*grid.get(&point), 21);
+    /// ```
+    pub fn set(&mut self, point: &GridPoint<U>, value: T) {
+        self.try_set(point, value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Sets the element at a location, or returns an [`AocError::Process`] if the
+    /// location is out of the bounds of the grid based on its size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_eq!(grid.try_set(&GridPoint::new(0, 2), 21), Ok(()));
+    /// assert_eq!(*grid.get(&GridPoint::new(0, 2)), 21);
+    /// assert!(grid.try_set(&GridPoint::new(0, 3), 21).is_err());
+    /// ```
+    pub fn try_set(&mut self, point: &GridPoint<U>, value: T) -> AocResult<()> {
+        *self.try_element_at(point)? = value;
+        Ok(())
+    }
+
+    /// Sets the element at any location, if the location is within
+    /// the bounds of the grid.
+    ///
+    /// Returns whether or not the `point` is within the grid bound.
+    /// If this was the case, then the element will have been set.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is within the grid bounds but cannot be converted to a
+    /// [`GridPoint`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// let point = AnyGridPoint::new(1, 1);
+    ///
+    /// assert_matches!(grid.get_any(&point), Some(&4));
+    /// assert!(grid.set_any(&point, 21));
+    /// assert_matches!(grid.get_any(&point), Some(&21));
+    /// assert!(!grid.set_any(&AnyGridPoint::new(-1, 1), 21));
+    /// ```
+    pub fn set_any(&mut self, point: &AnyGridPoint<U>, value: T) -> bool {
+        match self.bounded_point(point) {
+            Some(p) => self.try_set(&p, value).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Gets a reference to the element at any location, where the grid repeats
+    /// infinitely in both dimensions.
+    ///
+    /// See [`TorusGrid`] for a grid in which every location wraps around.
+    ///
+    /// # Panics
+    /// This will panic if the grid is too large for its size to be converted to signed
+    /// values, see [`AnyGridPointExt::wrapped_grid_point`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// assert_eq!(*grid.get_wrapped(&AnyGridPoint::new(1, 1)), 4);
+    /// assert_eq!(*grid.get_wrapped(&AnyGridPoint::new(-1, 3)), 2);
+    /// assert_eq!(*grid.get_wrapped(&AnyGridPoint::new(4, -1)), 5);
+    /// ```
+    pub fn get_wrapped(&self, point: &AnyGridPoint<U>) -> &T {
+        self.get(&point.wrapped_grid_point(&self.size))
+    }
+
+    /// Sets the element at any location, where the grid repeats infinitely in both
+    /// dimensions.
+    ///
+    /// See [`TorusGrid`] for a grid in which every location wraps around.
+    ///
+    /// # Panics
+    /// This will panic if the grid is too large for its size to be converted to signed
+    /// values, see [`AnyGridPointExt::wrapped_grid_point`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    ///
+    /// grid.set_wrapped(&AnyGridPoint::new(-2, -1), 21);
+    /// assert_eq!(*grid.get(&GridPoint::new(0, 2)), 21);
+    /// ```
+    pub fn set_wrapped(&mut self, point: &AnyGridPoint<U>, value: T) {
+        let point = point.wrapped_grid_point(&self.size);
+        self.set(&point, value)
+    }
+
+    /// Gets a mutable reference to an element.
+    ///
+    /// See [`Grid::try_element_at`] for a version that does not panic.
+    ///
+    /// # Panics
+    /// This will panic if the location is out of the bounds of the grid based on
+    /// its size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// let point = GridPoint::new(1, 1);
+    ///
+    /// assert_eq!(*grid.element_at(&point), 4);
+    /// *grid.element_at(&point) =  21;
+    /// assert_eq!(*grid.element_at(&point), 21);
+    /// ```
+    pub fn element_at(&mut self, point: &GridPoint<U>) -> &mut T {
+        self.try_element_at(point).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Gets a mutable reference to an element, or returns an [`AocError::Process`] if
+    /// the location is out of the bounds of the grid based on its size.
+    pub fn try_element_at(&mut self, point: &GridPoint<U>) -> AocResult<&mut T> {
+        match self.checked_index(point) {
+            Some(idx) => Ok(&mut self.data[idx]),
+            None => Err(out_of_bounds(point, &self.size)),
+        }
+    }
+
+    /// Verifies that any grid point is in the bounds of the grid and converts it if so.
+    ///
+    /// If the signed `point` is out bounds, then `None` will be returned.
+    /// If it is in bounds then the corresponding unsigned point will be returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<bool>::default(GridSize::new(2, 3));
+    ///
+    /// assert_eq!(grid.bounded_point(&AnyGridPoint::new(0, 1)), Some(GridPoint::new(0, 1)));
+    /// assert_eq!(grid.bounded_point(&AnyGridPoint::new(1, 2)), Some(GridPoint::new(1, 2)));
+    /// assert_eq!(grid.bounded_point(&AnyGridPoint::new(0, -1)), None);
+    /// assert_eq!(grid.bounded_point(&AnyGridPoint::new(2, 0)), None);
+    /// ```
+    pub fn bounded_point(&self, point: &AnyGridPoint<U>) -> Option<GridPoint<U>> {
+        cast_point(*point)
+            .ok()
+            .filter(|p: &GridPoint<U>| p.x < self.size.width && p.y < self.size.height)
+    }
+
+    /// Returns the point a single step from a `point` in some `direction`, or `None` if
+    /// it is out of the bounds of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::direction::Direction4;
+    ///
+    /// let grid = Grid::<bool>::default(GridSize::new(2, 3));
+    ///
+    /// assert_eq!(grid.step(&GridPoint::new(0, 1), Direction4::Down), Some(GridPoint::new(0, 2)));
+    /// assert_eq!(grid.step(&GridPoint::new(0, 2), Direction4::Down), None);
+    /// assert_eq!(grid.step(&GridPoint::new(0, 2), Direction4::Left), None);
+    /// ```
+    pub fn step(&self, point: &GridPoint<U>, direction: Direction4) -> Option<GridPoint<U>> {
+        point
+            .offset(direction)
+            .filter(|p| p.x < self.size.width && p.y < self.size.height)
+    }
+
+    /// Returns the first point in the line of sight from a `point` in the `direction` of
+    /// a vector whose element satisfies a `predicate`, not including the `point` itself.
+    ///
+    /// The line of sight steps by the whole vector at a time, so for example a diagonal
+    /// vector looks along a diagonal.
+    /// Returns `None` if the line of sight leaves the grid without any such element.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use euclid::Vector2D;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 0, 0, 2], vec![0, 0, 3, 0]]).unwrap();
+    /// let point = GridPoint::new(0, 0);
+    ///
+    /// assert_eq!(
+    ///     grid.first_visible(&point, Vector2D::new(1, 0), |v| *v > 0),
+    ///     Some(GridPoint::new(3, 0))
+    /// );
+    /// assert_eq!(grid.first_visible(&point, Vector2D::new(1, 1), |v| *v > 0), None);
+    /// assert_eq!(
+    ///     grid.first_visible(&GridPoint::new(3, 0), Vector2D::new(-1, 1), |v| *v > 0),
+    ///     Some(GridPoint::new(2, 1))
+    /// );
+    /// assert_eq!(grid.first_visible(&point, Vector2D::new(-1, 0), |v| *v > 0), None);
+    /// ```
+    pub fn first_visible(
+        &self,
+        point: &GridPoint<U>,
+        direction: Vector2D<isize, U>,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Option<GridPoint<U>> {
+        std::iter::successors(Some(*point), |p| {
+            p.offset_by(direction)
+                .filter(|p| p.x < self.size.width && p.y < self.size.height)
+        })
+        .skip(1)
+        .find(|p| predicate(self.get(p)))
+    }
+
+    /// Returns an [`Iterator`] over all valid grid points in row-major order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let points = vec![
+    ///     GridPoint::new(0, 0),
+    ///     GridPoint::new(1, 0),
+    ///     GridPoint::new(2, 0),
+    ///     GridPoint::new(0, 1),
+    ///     GridPoint::new(1, 1),
+    ///     GridPoint::new(2, 1),
+    /// ];
+    ///
+    /// assert_eq!(grid.all_points().collect_vec(), points);
+    /// ```
+    pub fn all_points(&self) -> impl Iterator<Item = GridPoint<U>> {
+        self.size().all_points()
+    }
+
+    /// Returns an [`Iterator`] over all grid values in row-major order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(
+    ///     grid.all_values().copied().collect_vec(),
+    ///     vec![1, 2, 3, 4, 5, 6]
+    /// );
+    /// ```
+    pub fn all_values(&self) -> impl Iterator<Item = &T> {
+        self.all_points().map(|p| self.get(&p))
+    }
+
+    /// Returns the first point in row-major order at which the element is equal to a
+    /// `value`, or `None` if there is no such element.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::<char>::from_str("S.#\n#.E\n..#").unwrap();
+    /// assert_eq!(grid.position_of(&'S'), Some(GridPoint::new(0, 0)));
+    /// assert_eq!(grid.position_of(&'E'), Some(GridPoint::new(2, 1)));
+    /// assert_eq!(grid.position_of(&'#'), Some(GridPoint::new(2, 0)));
+    /// assert_eq!(grid.position_of(&'x'), None);
+    /// ```
+    pub fn position_of(&self, value: &T) -> Option<GridPoint<U>>
+    where
+        T: PartialEq,
+    {
+        self.positions_where(|v| v == value).next()
+    }
+
+    /// Returns an [`Iterator`] over the points in row-major order at which the element
+    /// satisfies a `predicate`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 7, 3], vec![9, 2, 8]]).unwrap();
+    /// assert_eq!(
+    ///     grid.positions_where(|v| *v > 5).collect_vec(),
+    ///     vec![GridPoint::new(1, 0), GridPoint::new(0, 1), GridPoint::new(2, 1)],
+    /// );
+    /// assert_eq!(grid.positions_where(|v| *v > 9).count(), 0);
+    /// ```
+    pub fn positions_where<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = GridPoint<U>> + 'a {
+        self.all_points().filter(move |p| predicate(self.get(p)))
+    }
+
+    /// Returns an [`Iterator`] over the values in a `row`.
+    ///
+    /// # Panics
+    /// This will panic if the `row` has a value that is out of bounds for the size of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(grid.row_iter(1).copied().collect_vec(), vec![3, 4]);
+    /// ```
+    pub fn row_iter(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.row_slice(row).iter()
+    }
+
+    /// Returns an [`Iterator`] over the values in a `column`.
+    ///
+    /// # Panics
+    /// This will panic if the `column` has a value that is out of bounds for the size of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+    /// assert_eq!(grid.column_iter(1).copied().collect_vec(), vec![4, 5, 6]);
+    /// ```
+    pub fn column_iter(&self, column: usize) -> impl Iterator<Item = &T> {
+        (0..self.size.height).map(move |y| self.get(&GridPoint::new(column, y)))
+    }
+
+    /// Returns an [`Iterator`] over the rows as slices.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+    /// let mut iter = grid.rows_iter();
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[1, 4]);
+    /// assert_eq!(iter.next().unwrap(), &[2, 5]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 6]);
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// // Rows with no elements are still rows
+    /// let grid = Grid::<u8>::from_data(vec![vec![]]).unwrap();
+    /// assert_eq!(grid.rows_iter().collect::<Vec<_>>(), vec![&[] as &[u8]]);
+    /// ```
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        // Chunks cannot be empty, so the rows are sliced to allow for a width of zero
+        let width = self.size.width;
+        (0..self.size.height).map(move |y| &self.data[y * width..(y + 1) * width])
+    }
+
+    /// Returns the elements of all the rows in order as a single slice, which is how they
+    /// are stored.
+    ///
+    /// The element at a point is at its [`Grid::index_of`] in the slice.
+    /// This is for loops over all the elements that need to be fast, without the bounds
+    /// checks of each point.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(grid.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// grid.as_mut_slice().iter_mut().for_each(|v| *v *= 2);
+    /// assert_eq!(grid.as_slice(), &[2, 4, 6, 8, 10, 12]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the elements of all the rows in order as a single mutable slice, see
+    /// [`Grid::as_slice`].
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the elements of a `row` as a slice.
+    ///
+    /// # Panics
+    /// This will panic if the `row` has a value that is out of bounds for the size of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let mut grid = Grid::<u8>::from_data(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(grid.row_slice(1), &[3, 4]);
+    ///
+    /// // Copy a whole row to another
+    /// let row = grid.row_slice(2).to_vec();
+    /// grid.row_slice_mut(0).copy_from_slice(&row);
+    /// assert_eq!(grid.row_slice(0), &[5, 6]);
+    /// ```
+    pub fn row_slice(&self, row: usize) -> &[T] {
+        &self.data[self.row_range(row)]
+    }
+
+    /// Returns the elements of a `row` as a mutable slice, see [`Grid::row_slice`].
+    ///
+    /// # Panics
+    /// This will panic if the `row` has a value that is out of bounds for the size of the grid.
+    pub fn row_slice_mut(&mut self, row: usize) -> &mut [T] {
+        let range = self.row_range(row);
+        &mut self.data[range]
+    }
+
+    /// Returns the range of the indices of the elements of a `row` in the storage.
+    ///
+    /// This is an internal function of [`Grid::row_slice`] and [`Grid::row_slice_mut`].
+    ///
+    /// # Panics
+    /// This will panic if the `row` has a value that is out of bounds for the size of the grid.
+    fn row_range(&self, row: usize) -> Range<usize> {
+        assert!(
+            row < self.size.height,
+            "row {row} is out of the bounds of the {}x{} grid",
+            self.size.width,
+            self.size.height
+        );
+        let start = row * self.size.width;
+        start..start + self.size.width
+    }
+
+    /// Returns the index of the element at a `point` in the slice of all the elements,
+    /// see [`Grid::as_slice`], which is in row-major order.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is out of the bounds of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let idx = grid.index_of(&GridPoint::new(1, 1));
+    ///
+    /// assert_eq!(idx, 4);
+    /// assert_eq!(grid.as_slice()[idx], 5);
+    /// assert_eq!(grid.point_of(idx), GridPoint::new(1, 1));
+    /// ```
+    pub fn index_of(&self, point: &GridPoint<U>) -> usize {
+        self.checked_index(point)
+            .unwrap_or_else(|| panic!("{}", out_of_bounds(point, &self.size)))
+    }
+
+    /// Returns the point of the element at an index in the slice of all the elements,
+    /// which is the inverse of [`Grid::index_of`].
+    ///
+    /// # Panics
+    /// This will panic if the index is out of bounds for the number of elements.
+    pub fn point_of(&self, idx: usize) -> GridPoint<U> {
+        assert!(
+            idx < self.data.len(),
+            "index {idx} is out of the bounds of the {}x{} grid",
+            self.size.width,
+            self.size.height
+        );
+        GridPoint::new(idx % self.size.width, idx / self.size.width)
+    }
+
+    /// Returns the index of the element at a `point`, see [`Grid::index_of`], or `None`
+    /// if the point is out of the bounds of the grid.
+    ///
+    /// This is an internal function of the accessors of [`Grid`].
+    fn checked_index(&self, point: &GridPoint<U>) -> Option<usize> {
+        if point.x < self.size.width && point.y < self.size.height {
+            Some(point.y * self.size.width + point.x)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an [`Iterator`] over the neighboring points around a `point`
+    /// in row-major order such that all the points are bounded in the grid.
+    ///
+    /// The length of the [`Iterator`] will depend on the location of the `point`.
+    /// For example, points on the edge of the grid will have fewer neighboring
+    /// points than the points in the middle of the grid. The set of points may
+    /// optionally include the (up to) four diagonal neighbor points as well as
+    /// this `point` itself.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    /// assert_eq!(
+    ///     grid.neighbor_points(&GridPoint::new(0, 0), true, true).collect_vec(),
+    ///     vec![
+    ///         GridPoint::new(0, 0),
+    ///         GridPoint::new(1, 0),
+    ///         GridPoint::new(0, 1),
+    ///         GridPoint::new(1, 1),
+    ///     ],    
+    /// );
+    /// assert_eq!(
+    ///     grid.neighbor_points(&GridPoint::new(1, 1), false, true).collect_vec(),
+    ///     vec![
+    ///         GridPoint::new(1, 0),
+    ///         GridPoint::new(0, 1),
+    ///         GridPoint::new(1, 1),
+    ///         GridPoint::new(2, 1),
+    ///         GridPoint::new(1, 2),
+    ///     ],    
+    /// );
+    /// assert_eq!(
+    ///     grid.neighbor_points(&GridPoint::new(1, 2), true, false).collect_vec(),
+    ///     vec![
+    ///         GridPoint::new(0, 1),
+    ///         GridPoint::new(1, 1),
+    ///         GridPoint::new(2, 1),
+    ///         GridPoint::new(0, 2),
+    ///         GridPoint::new(2, 2),
+    ///     ],    
+    /// );
+    /// ```
+    pub fn neighbor_points<'a>(
+        &'a self,
+        point: &GridPoint<U>,
+        include_diagonals: bool,
+        include_self: bool,
+    ) -> impl Iterator<Item = GridPoint<U>> + 'a {
+        // Points too large to be signed have no neighbors in the grid anyway
+        cast_point(*point)
+            .ok()
+            .into_iter()
+            .flat_map(move |p: AnyGridPoint<U>| {
+                p.all_neighbor_points(include_diagonals, include_self)
+            })
+            .filter_map(|p| self.bounded_point(&p))
+    }
+
+    /// Returns the points of the connected region containing a `start` point, in which
+    /// every element is one to `include`, in the order that they are reached.
+    ///
+    /// The region is connected through orthogonal neighbors, and optionally diagonal
+    /// neighbors as well. If the element at the `start` point is not included, then
+    /// the region is empty.
+    ///
+    /// # Panics
+    /// This will panic if the `start` point is out of the bounds of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![
+    ///     vec![1, 1, 0, 1],
+    ///     vec![0, 1, 0, 0],
+    ///     vec![1, 0, 1, 1],
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.flood_fill(&GridPoint::new(0, 0), false, |v| *v == 1),
+    ///     vec![GridPoint::new(0, 0), GridPoint::new(1, 0), GridPoint::new(1, 1)],
+    /// );
+    /// assert_eq!(grid.flood_fill(&GridPoint::new(0, 0), true, |v| *v == 1).len(), 6);
+    /// assert!(grid.flood_fill(&GridPoint::new(2, 0), false, |v| *v == 1).is_empty());
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: &GridPoint<U>,
+        include_diagonals: bool,
+        include: impl Fn(&T) -> bool,
+    ) -> Vec<GridPoint<U>> {
+        let mut region = Vec::new();
+        if !include(self.get(start)) {
+            return region;
+        }
+
+        let mut reached = Grid::<bool, U>::default(self.size);
+        reached.set(start, true);
+        region.push(*start);
+        let mut next = 0;
+        while let Some(point) = region.get(next).copied() {
+            next += 1;
+            for neighbor in self.neighbor_points(&point, include_diagonals, false) {
+                if !*reached.get(&neighbor) && include(self.get(&neighbor)) {
+                    reached.set(&neighbor, true);
+                    region.push(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Returns the number of distinct paths from a `start` point to any goal point,
+    /// where the `successors` function gives the points that can be moved to from a point.
+    ///
+    /// This is the same as [`count_paths`](crate::graph::count_paths), but memoizes
+    /// the path counts in a grid rather than a hash map, and so is faster.
+    /// Returns [`AocError::Process`] if a cycle is reachable from the `start` point.
+    ///
+    /// # Panics
+    /// This will panic if the `start` point or any successor point is out of the bounds
+    /// of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::Digit;
+    /// use itertools::Itertools;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::<Digit>::from_str(
+    ///     "89010123
+    /// 78121874
+    /// 87430965
+    /// 96549874
+    /// 45678903
+    /// 32019012
+    /// 01329801
+    /// 10456732",
+    /// )
+    /// .unwrap();
+    ///
+    /// // Trails climb by exactly one at each step from a height of zero to nine
+    /// let successors = |p: &GridPoint| {
+    ///     grid.neighbor_points(p, false, false)
+    ///         .filter(|n| **grid.get(n) == **grid.get(p) + 1)
+    ///         .collect_vec()
+    /// };
+    /// let is_goal = |p: &GridPoint| **grid.get(p) == 9;
+    ///
+    /// assert_eq!(grid.count_paths(&GridPoint::new(2, 0), is_goal, successors), Ok(20));
+    /// assert_eq!(
+    ///     grid.all_points()
+    ///         .filter(|p| **grid.get(p) == 0)
+    ///         .map(|p| grid.count_paths(&p, is_goal, successors).unwrap())
+    ///         .sum::<u128>(),
+    ///     81,
+    /// );
+    /// ```
+    pub fn count_paths<I: IntoIterator<Item = GridPoint<U>>>(
+        &self,
+        start: &GridPoint<U>,
+        is_goal: impl Fn(&GridPoint<U>) -> bool,
+        successors: impl Fn(&GridPoint<U>) -> I,
+    ) -> AocResult<u128> {
+        crate::graph::count_paths_with(
+            *start,
+            is_goal,
+            successors,
+            &mut Grid::<PathCount, U>::default(self.size),
+        )
+    }
+
+    /// Creates a sub-grid by cloning the applicable elements of this grid.
+    ///
+    /// The sub-grid location is given by the `sub_grid_box`.
+    /// See [`Grid::try_sub_grid`] for a version that does not panic.
+    ///
+    /// # Panics
+    /// This will panic if any part of the sub-grid is out of the bounds of this
+    /// grid, or if `sub_grid_box` has an invalid size, see [`GridSizeExt::is_valid`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.sub_grid(&GridBox::<GridSpace>::from_origin_and_size(GridPoint::new(0, 0), GridSize::new(2, 3))),
+    ///     Grid::from_data(vec![vec![1, 2], vec![4, 5], vec![7, 8]]).unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     grid.sub_grid(&GridBox::<GridSpace>::from_origin_and_size(GridPoint::new(1, 0), GridSize::new(2, 2))),
+    ///     Grid::from_data(vec![vec![2, 3], vec![5, 6]]).unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     grid.sub_grid(&GridBox::<GridSpace>::from_origin_and_size(GridPoint::new(1, 2), GridSize::new(2, 1))),
+    ///     Grid::from_data(vec![vec![8, 9]]).unwrap(),
+    /// );
+    /// ```
+    pub fn sub_grid(&self, sub_grid_box: &GridBox<U>) -> Self
+    where
+        T: Default + Clone,
+    {
+        self.view(sub_grid_box).to_grid()
+    }
+
+    /// Creates a sub-grid by cloning the applicable elements of this grid, or returns an
+    /// [`AocError::Process`] if the sub-grid is not valid, see [`Grid::try_view`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.try_sub_grid(&GridBox::from_origin_and_size(GridPoint::new(2, 0), GridSize::new(1, 2))),
+    ///     Ok(Grid::from_data(vec![vec![3], vec![6]]).unwrap()),
+    /// );
+    /// assert!(grid
+    ///     .try_sub_grid(&GridBox::from_origin_and_size(GridPoint::new(2, 0), GridSize::new(2, 2)))
+    ///     .is_err());
+    /// ```
+    pub fn try_sub_grid(&self, sub_grid_box: &GridBox<U>) -> AocResult<Self>
+    where
+        T: Default + Clone,
+    {
+        Ok(self.try_view(sub_grid_box)?.to_grid())
+    }
+
+    /// Creates a borrowed, read-only view into a portion of this grid without cloning any elements.
+    ///
+    /// The view location is given by the `view_box`.
+    /// This is much cheaper than [`Grid::sub_grid`] when many portions of a grid need to be
+    /// examined, such as when scanning a grid for a pattern.
+    /// See [`Grid::try_view`] for a version that does not panic.
+    ///
+    /// # Panics
+    /// This will panic if any part of the view is out of the bounds of this
+    /// grid, or if `view_box` has an invalid size, see [`GridSizeExt::is_valid`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    /// let view = grid.view(&GridBox::from_origin_and_size(GridPoint::new(1, 1), GridSize::new(2, 2)));
+    ///
+    /// assert_eq!(view.size(), GridSize::new(2, 2));
+    /// assert_eq!(*view.get(&GridPoint::new(0, 0)), 5);
+    /// assert_eq!(*view.get(&GridPoint::new(1, 1)), 9);
+    /// assert_eq!(view, Grid::from_data(vec![vec![5, 6], vec![8, 9]]).unwrap());
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```should_panic
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    /// let _ = grid.view(&GridBox::from_origin_and_size(GridPoint::new(2, 1), GridSize::new(2, 2)));
+    /// ```
+    pub fn view(&self, view_box: &GridBox<U>) -> GridView<'_, T, U> {
+        self.try_view(view_box).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a borrowed, read-only view into a portion of this grid, or returns an
+    /// [`AocError::Process`] if any part of the view is out of the bounds of this grid
+    /// or the `view_box` has an invalid size, see [`GridSizeExt::is_valid`].
+    pub fn try_view(&self, view_box: &GridBox<U>) -> AocResult<GridView<'_, T, U>> {
+        if !view_box.size().is_valid() {
+            return Err(AocError::Process("grid view size is invalid".into()));
+        }
+        if !Box2D::from(self.size).contains_box(view_box) {
+            return Err(AocError::Process(
+                "grid view is out of the bounds of the grid".into(),
+            ));
+        }
+        Ok(GridView {
+            grid: self,
+            view_box: *view_box,
+        })
+    }
+
+    /// Returns a borrowed, read-only view of the entire grid.
+    ///
+    /// This is mostly useful for comparing the grid with a [`GridView`].
+    pub fn as_view(&self) -> GridView<'_, T, U> {
+        GridView {
+            grid: self,
+            view_box: Box2D::from(self.size),
+        }
+    }
+}
+
+/// Returns the error for a `point` that is out of the bounds of a grid of some `size`.
+fn out_of_bounds<U>(point: &GridPoint<U>, size: &GridSize<U>) -> AocError {
+    AocError::Process(
+        format!(
+            "point ({}, {}) is out of the bounds of the {}x{} grid",
+            point.x, point.y, size.width, size.height
+        )
+        .into(),
+    )
+}
+
+/// A borrowed, read-only view of a rectangular portion of a [`Grid`].
+///
+/// This is created with [`Grid::view`], and is addressed by [`GridPoint`]s relative
+/// to the upper left corner of the view.
+/// Views can be compared with other views or with [`Grid`]s, with which they are equal
+/// if they have the same size and elements.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use itertools::Itertools;
+///
+/// let grid = Grid::<u8>::from_data(vec![vec![1, 2, 1], vec![3, 4, 3], vec![1, 2, 1]]).unwrap();
+/// let size = GridSize::new(1, 3);
+/// let left = grid.view(&GridBox::from_origin_and_size(GridPoint::new(0, 0), size));
+/// let middle = grid.view(&GridBox::from_origin_and_size(GridPoint::new(1, 0), size));
+/// let right = grid.view(&GridBox::from_origin_and_size(GridPoint::new(2, 0), size));
+///
+/// assert_eq!(left, right);
+/// assert_ne!(left, middle);
+/// assert_eq!(middle.all_values().copied().collect_vec(), vec![2, 4, 2]);
+/// assert_eq!(right.to_grid(), Grid::from_data(vec![vec![1], vec![3], vec![1]]).unwrap());
+/// ```
+pub struct GridView<'a, T, U = GridSpace> {
+    /// The grid being viewed.
+    grid: &'a Grid<T, U>,
+    /// The portion of the grid that is visible in the view.
+    view_box: GridBox<U>,
+}
+impl<T, U> Clone for GridView<'_, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T, U> Copy for GridView<'_, T, U> {}
+impl<'a, T, U> GridView<'a, T, U> {
+    /// Returns the size of the view.
+    pub fn size(&self) -> GridSize<U> {
+        self.view_box.size()
+    }
+
+    /// Gets a reference to the element at a location in the view.
+    ///
+    /// # Panics
+    /// This will panic if the location is out of the bounds of the view based on
+    /// its size.
+    pub fn get(&self, point: &GridPoint<U>) -> &'a T {
+        let size = self.size();
+        if point.x >= size.width || point.y >= size.height {
+            panic!("point is out of the bounds of the grid view");
+        }
+        self.grid.get(&(*point + self.view_box.min.to_vector()))
+    }
+
+    /// Returns an [`Iterator`] over all valid view points in row-major order.
+    pub fn all_points(&self) -> impl Iterator<Item = GridPoint<U>> {
+        self.size().all_points()
+    }
+
+    /// Returns an [`Iterator`] over all elements in the view in row-major order.
+    pub fn all_values(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        self.all_points().map(move |p| view.get(&p))
+    }
+
+    /// Creates a new [`Grid`] by cloning the elements in the view.
+    pub fn to_grid(&self) -> Grid<T, U>
+    where
+        T: Default + Clone,
+    {
+        let size = self.size();
+        let mut out = Grid::default(size);
+        for point in size.all_points() {
+            out.set(&point, self.get(&point).clone());
+        }
+        out
+    }
+}
+impl<'b, T: PartialEq, U> PartialEq<GridView<'b, T, U>> for GridView<'_, T, U> {
+    fn eq(&self, other: &GridView<'b, T, U>) -> bool {
+        self.size() == other.size() && self.all_values().eq(other.all_values())
+    }
+}
+impl<T: PartialEq, U> PartialEq<Grid<T, U>> for GridView<'_, T, U> {
+    fn eq(&self, other: &Grid<T, U>) -> bool {
+        *self == other.as_view()
+    }
+}
+/// Debug display for a [`GridView`] whose elements implement [`Debug`].
+impl<T: fmt::Debug> fmt::Debug for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = self.size();
+        for y in 0..size.height {
+            for x in 0..size.width {
+                write!(f, "{:?}", self.get(&GridPoint::new(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`Grid`] that repeats infinitely in both dimensions, as if it were on the
+/// surface of a torus.
+///
+/// Every location is an [`AnyGridPoint`] that wraps around to the corresponding point
+/// in the bounds of the grid, see [`AnyGridPointExt::wrapped_grid_point`].
+/// The underlying grid can be accessed by dereferencing.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::TorusGrid;
+/// use itertools::Itertools;
+///
+/// let mut torus = TorusGrid::from(
+///     Grid::<u8>::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap(),
+/// );
+///
+/// assert_eq!(*torus.get(&AnyGridPoint::new(-1, -1)), 9);
+/// assert_eq!(torus.wrap(&AnyGridPoint::new(5, -4)), GridPoint::new(2, 2));
+///
+/// torus.set(&AnyGridPoint::new(3, 4), 21);
+/// assert_eq!(*torus.get(&AnyGridPoint::new(0, 1)), 21);
+/// assert_eq!(
+///     torus.all_values().copied().collect_vec(),
+///     vec![1, 2, 3, 21, 5, 6, 7, 8, 9],
+/// );
+///
+/// // The neighbors of points on the edges wrap around as well
+/// assert_eq!(
+///     torus
+///         .neighbor_points(&AnyGridPoint::new(0, 0), false, false)
+///         .map(|p| *torus.get(&p.to_isize()))
+///         .collect_vec(),
+///     vec![7, 3, 2, 21],
+/// );
+/// ```
+#[derive(Deref, From, Into, Clone, PartialEq, Eq, Hash)]
+pub struct TorusGrid<T, U = GridSpace>(Grid<T, U>);
+impl<T, U> TorusGrid<T, U> {
+    /// Returns the actual addressable point in the underlying grid for any `point`.
+    pub fn wrap(&self, point: &AnyGridPoint<U>) -> GridPoint<U> {
+        point.wrapped_grid_point(self.0.size())
+    }
+
+    /// Gets a reference to the element at any location.
+    pub fn get(&self, point: &AnyGridPoint<U>) -> &T {
+        self.0.get_wrapped(point)
+    }
+
+    /// Sets the element at any location.
+    pub fn set(&mut self, point: &AnyGridPoint<U>, value: T) {
+        self.0.set_wrapped(point, value)
+    }
+
+    /// Returns an [`Iterator`] over the wrapped neighboring points around any `point`
+    /// in row-major order.
+    ///
+    /// The set of points may optionally include the four diagonal neighbor points
+    /// as well as this `point` itself, see [`AnyGridPointExt::all_neighbor_points`].
+    /// Note that the same point can occur more than once if the grid is small enough
+    /// that neighbors in different directions wrap around to it.
+    pub fn neighbor_points(
+        &self,
+        point: &AnyGridPoint<U>,
+        include_diagonals: bool,
+        include_self: bool,
+    ) -> impl Iterator<Item = GridPoint<U>> + '_ {
+        point
+            .all_neighbor_points(include_diagonals, include_self)
+            .map(move |p| self.wrap(&p))
+    }
+}
+/// Debug display for a [`TorusGrid`] whose elements implement [`Debug`].
+impl<T: fmt::Debug> fmt::Debug for TorusGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The length of the sides of the square chunks of a [`ChunkedSparseGrid`].
+pub const CHUNK_LEN: usize = 64;
+
+/// An unbounded grid that stores only the square chunks of elements that have been set,
+/// so that its memory use is proportional to the number of occupied chunks rather than
+/// the extent of the points.
+///
+/// Every location is an [`AnyGridPoint`], where elements that are not in a chunk are the
+/// [`Default`].
+/// Each chunk is a dense [`Grid`] with sides of [`CHUNK_LEN`], which is identified by its
+/// chunk coordinates, that is the coordinates of its points divided by [`CHUNK_LEN`] and
+/// rounded down.
+/// The chunks are independent of each other, so they can be processed in parallel,
+/// see [`ChunkedSparseGrid::par_chunks_mut`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{ChunkedSparseGrid, CHUNK_LEN};
+/// use itertools::Itertools;
+///
+/// let mut grid = ChunkedSparseGrid::<u8>::default();
+/// grid.set(&AnyGridPoint::new(3, 4), 7);
+/// grid.set(&AnyGridPoint::new(-1_000_000, 2_000_000), 9);
+/// *grid.element_at(&AnyGridPoint::new(5, 4)) += 2;
+///
+/// assert_eq!(*grid.get(&AnyGridPoint::new(3, 4)), 7);
+/// assert_eq!(*grid.get(&AnyGridPoint::new(-1_000_000, 2_000_000)), 9);
+/// assert_eq!(*grid.get(&AnyGridPoint::new(5, 4)), 2);
+/// assert_eq!(*grid.get(&AnyGridPoint::new(-8, 12)), 0);
+///
+/// // Only the two chunks containing the set points take memory
+/// assert_eq!(grid.num_chunks(), 2);
+/// assert_eq!(grid.all_points().count(), 2 * CHUNK_LEN * CHUNK_LEN);
+/// assert_eq!(
+///     grid.points_where(|v| *v > 0).sorted_by_key(|p| (p.y, p.x)).collect_vec(),
+///     vec![
+///         AnyGridPoint::new(3, 4),
+///         AnyGridPoint::new(5, 4),
+///         AnyGridPoint::new(-1_000_000, 2_000_000),
+///     ],
+/// );
+///
+/// // Chunks can be processed independently
+/// grid.par_chunks_mut(|_, chunk| {
+///     for point in chunk.all_points() {
+///         *chunk.element_at(&point) *= 10;
+///     }
+/// });
+/// assert_eq!(*grid.get(&AnyGridPoint::new(5, 4)), 20);
+///
+/// // Chunks that only contain default elements can be removed
+/// grid.set(&AnyGridPoint::new(-1_000_000, 2_000_000), 0);
+/// grid.remove_default_chunks();
+/// assert_eq!(grid.num_chunks(), 1);
+/// assert_eq!(
+///     grid.chunks().map(|(c, _)| c).collect_vec(),
+///     vec![AnyGridPoint::new(0, 0)],
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ChunkedSparseGrid<T, U = GridSpace> {
+    /// The occupied chunks indexed by their chunk coordinates.
+    chunks: HashMap<AnyGridPoint<U>, Grid<T, U>>,
+    /// The element at every location that is not in a chunk.
+    default: T,
+}
+impl<T: Default, U> Default for ChunkedSparseGrid<T, U> {
+    fn default() -> Self {
+        Self {
+            chunks: HashMap::default(),
+            default: T::default(),
+        }
+    }
+}
+impl<T, U> ChunkedSparseGrid<T, U> {
+    /// Returns the chunk coordinates of the chunk containing a `point`, along with the
+    /// point within the chunk.
+    fn locate(point: &AnyGridPoint<U>) -> (AnyGridPoint<U>, GridPoint<U>) {
+        // The cast is lossless since the chunk length is small
+        let len = CHUNK_LEN as isize;
+        (
+            AnyGridPoint::new(point.x.div_euclid(len), point.y.div_euclid(len)),
+            GridPoint::new(
+                point.x.rem_euclid(len).try_into().unwrap(),
+                point.y.rem_euclid(len).try_into().unwrap(),
+            ),
+        )
+    }
+
+    /// Returns the point at the upper left corner of the chunk with some chunk
+    /// coordinates, which is the origin of the points within the chunk.
+    pub fn chunk_origin(chunk: &AnyGridPoint<U>) -> AnyGridPoint<U> {
+        // The cast is lossless since the chunk length is small
+        *chunk * CHUNK_LEN as isize
+    }
+
+    /// Gets a reference to the element at any location.
+    pub fn get(&self, point: &AnyGridPoint<U>) -> &T {
+        let (chunk, point) = Self::locate(point);
+        match self.chunks.get(&chunk) {
+            Some(grid) => grid.get(&point),
+            None => &self.default,
+        }
+    }
+
+    /// Returns the number of occupied chunks.
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns an [`Iterator`] over the occupied chunks, along with their chunk
+    /// coordinates, in an arbitrary order.
+    pub fn chunks(&self) -> impl Iterator<Item = (AnyGridPoint<U>, &Grid<T, U>)> {
+        self.chunks.iter().map(|(c, g)| (*c, g))
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the occupied chunks, along
+    /// with their chunk coordinates, in an arbitrary order.
+    pub fn chunks_mut(&mut self) -> impl Iterator<Item = (AnyGridPoint<U>, &mut Grid<T, U>)> {
+        self.chunks.iter_mut().map(|(c, g)| (*c, g))
+    }
+
+    /// Calls a function `f` on every occupied chunk and its chunk coordinates.
+    ///
+    /// The chunks are processed in parallel when the `parallel` feature is enabled and
+    /// sequentially otherwise, see [`ChunkedSparseGrid::chunks_mut`].
+    pub fn par_chunks_mut(&mut self, f: impl Fn(AnyGridPoint<U>, &mut Grid<T, U>) + Sync)
+    where
+        T: Send,
+        U: Send + Sync,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            self.chunks.par_iter_mut().for_each(|(c, g)| f(*c, g));
+        }
+        #[cfg(not(feature = "parallel"))]
+        self.chunks_mut().for_each(|(c, g)| f(c, g));
+    }
+
+    /// Returns an [`Iterator`] over all the points in the occupied chunks, in an
+    /// arbitrary order of the chunks and in row-major order within each chunk.
+    pub fn all_points(&self) -> impl Iterator<Item = AnyGridPoint<U>> + '_ {
+        self.chunks.iter().flat_map(|(chunk, grid)| {
+            let origin = Self::chunk_origin(chunk);
+            grid.all_points()
+                .map(move |p| origin + p.to_isize().to_vector())
+        })
+    }
+
+    /// Returns an [`Iterator`] over the points in the occupied chunks at which the
+    /// elements satisfy a `predicate`, in the same order as
+    /// [`ChunkedSparseGrid::all_points`].
+    pub fn points_where<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = AnyGridPoint<U>> + 'a {
+        self.all_points().filter(move |p| predicate(self.get(p)))
+    }
+}
+impl<T: Default + Clone, U> ChunkedSparseGrid<T, U> {
+    /// Gets a mutable reference to the element at any location, creating its chunk if
+    /// it is not yet occupied.
+    pub fn element_at(&mut self, point: &AnyGridPoint<U>) -> &mut T {
+        let (chunk, point) = Self::locate(point);
+        self.chunks
+            .entry(chunk)
+            .or_insert_with(|| Grid::default(GridSize::new(CHUNK_LEN, CHUNK_LEN)))
+            .element_at(&point)
+    }
+
+    /// Sets the element at any location, creating its chunk if it is not yet occupied.
+    pub fn set(&mut self, point: &AnyGridPoint<U>, value: T) {
+        *self.element_at(point) = value;
+    }
+}
+impl<T: Default + PartialEq, U> ChunkedSparseGrid<T, U> {
+    /// Removes the chunks in which every element is the [`Default`], which frees their
+    /// memory without changing any elements.
+    pub fn remove_default_chunks(&mut self) {
+        let default = &self.default;
+        self.chunks
+            .retain(|_, grid| grid.all_values().any(|v| v != default));
+    }
+}
+
+// Additional methods for clone-able elements.
+impl<T: Clone> Grid<T> {
+    /// Creates a [`Graph`] representation of the grid.
+    ///
+    /// This can be useful to, for example, find the shortest path from one point
+    /// to another using [`petgraph::algo::dijkstra`](petgraph::algo::dijkstra::dijkstra).
+    /// A node is created for each point in the grid, with the node weight being a
+    /// clone of the corresponding element in this grid.
+    ///
+    /// The `edge_creator` closure can be used to create edges between adjacent points.
+    /// For each point in the grid, the closure will be called for each neighbor of the point,
+    /// optionally including diagonal neighbors when `include_diagonals` is `true`.
+    /// The first argument of the closure will be the element of the main point, while the
+    /// second argument is the element of the neighboring point.
+    /// The closure should return an edge weight if an edge should be created.
+    /// If [`None`] is returned, then an edge will not be created.
+    /// Note that the closure will be called twice for each pair of adjacent points, once
+    /// where one point is the main point, and again with other point as the main point.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::{Itertools, iproduct};
+    /// use petgraph::{graph::DefaultIx, Directed};
+    ///
+    /// // Create a grid of numbers.
+    /// let grid = Grid::from_data(vec![vec![2, 2, 3], vec![3, 1, 2], vec![4, 5, 1]]).unwrap();
+    ///
+    /// // Generate a corresponding graph in which directed pathways exist between adjacent
+    /// // spaces only when going from one number to the same or successor number,
+    /// // including diagonal neighbors. The edge pathways have no weights.
+    /// //
+    /// // This graph would then look as follows:
+    /// // 2↔︎2→3
+    /// // ↓⤪↑⤡↑
+    /// // 3 1→2
+    /// // ↓  ⤡↑
+    /// // 4→5 1
+    /// let (graph, node_grid) = grid
+    ///     .as_graph::<_, Directed, DefaultIx>(true, |p, n| (n == p || *n == *p + 1).then_some(()));
+    ///
+    /// // Get all the node indices in row-major order.
+    /// let nodes = node_grid.all_values().copied().collect_vec();
+    ///
+    /// // Make a list of expected edges.
+    /// let expected_edges = [
+    ///     // First row
+    ///     (nodes[0], nodes[1]),
+    ///     (nodes[0], nodes[3]),
+    ///     (nodes[1], nodes[0]),
+    ///     (nodes[1], nodes[2]),
+    ///     (nodes[1], nodes[3]),
+    ///     (nodes[1], nodes[5]),
+    ///     // Second row
+    ///     (nodes[3], nodes[6]),
+    ///     (nodes[4], nodes[0]),
+    ///     (nodes[4], nodes[1]),
+    ///     (nodes[4], nodes[5]),
+    ///     (nodes[4], nodes[8]),
+    ///     (nodes[5], nodes[1]),
+    ///     (nodes[5], nodes[2]),
+    ///     // Third row
+    ///     (nodes[6], nodes[7]),
+    ///     (nodes[8], nodes[4]),
+    ///     (nodes[8], nodes[5]),
+    /// ];
+    ///
+    /// // Check the node weights.
+    /// assert!(nodes
+    ///     .iter()
+    ///     .map(|n| graph.node_weight(*n).unwrap())
+    ///     .copied()
+    ///     .eq([2, 2, 3, 3, 1, 2, 4, 5, 1]));
+    ///
+    /// // Check every possible pair of nodes for the expected edges.
+    /// for (a, b) in iproduct!(nodes.iter(), nodes.iter()).map(|(a, b)| (*a, *b)) {
+    ///     assert!(graph.contains_edge(a, b) == expected_edges.contains(&(a, b)));
+    /// }
+    /// ```
+    pub fn as_graph<E, Ty: EdgeType, Ix: IndexType>(
+        &self,
+        include_diagonals: bool,
+        edge_creator: impl Fn(&T, &T) -> Option<E>,
+    ) -> (Graph<T, E, Ty, Ix>, Grid<NodeIndex<Ix>>) {
+        let mut graph = Graph::default();
+
+        // Create nodes
+        let node_grid = Grid::from_data(
+            self.rows_iter()
+                .map(|row| row.iter().map(|t| graph.add_node(t.clone())).collect())
+                .collect(),
+        )
+        .unwrap();
+
+        // Create edges
+        for point in node_grid.all_points() {
+            for neighbor_point in node_grid.neighbor_points(&point, include_diagonals, false) {
+                // Possibly add an edge
+                if let Some(e) = edge_creator(self.get(&point), self.get(&neighbor_point)) {
+                    let _ =
+                        graph.add_edge(*node_grid.get(&point), *node_grid.get(&neighbor_point), e);
+                }
+            }
+        }
+
+        (graph, node_grid)
+    }
+}
+
+impl<U> PathMemo<GridPoint<U>> for Grid<PathCount, U> {
+    fn get(&self, node: &GridPoint<U>) -> PathCount {
+        *Grid::get(self, node)
+    }
+
+    fn set(&mut self, node: GridPoint<U>, count: PathCount) {
+        Grid::set(self, &node, count);
+    }
+}
+
+// Additional methods for grids with boolean-like elements.
+impl<T: From<bool> + Default + Clone, U> Grid<T, U> {
+    /// Builds a [`Grid`] from a set of [`AnyGridPoint`]s for any value type that can be
+    /// created from [`bool`] values.
+    ///
+    /// The size of the grid will automatically be determined in order to tightly
+    /// contain all of the elements that correspond to `true`. The order of the points
+    /// in the `points` [`Iterator`] makes no difference.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let grid = Grid::<u8>::from_coordinates([
+    ///     AnyGridPoint::new(-2, -2),
+    ///     AnyGridPoint::new(-1, -1),
+    ///     AnyGridPoint::new(0, 0),
+    ///     AnyGridPoint::new(1, 0),
+    ///     AnyGridPoint::new(2, -1),
+    ///     AnyGridPoint::new(3, -2),
+    ///     AnyGridPoint::new(-1, 1),
+    ///     AnyGridPoint::new(-2, 2),
+    ///     AnyGridPoint::new(2, 1),
+    ///     AnyGridPoint::new(3, 2),
+    /// ].iter());
+    /// let values = vec![
+    ///     vec![1, 0, 0, 0, 0, 1],
+    ///     vec![0, 1, 0, 0, 1, 0],
+    ///     vec![0, 0, 1, 1, 0, 0],
+    ///     vec![0, 1, 0, 0, 1, 0],
+    ///     vec![1, 0, 0, 0, 0, 1],
+    /// ];
+    ///
+    /// assert_eq!(grid.size(), &GridSize::new(6, 5));
+    /// assert_eq!(grid, Grid::from_data(values).unwrap());
+    /// ```
+    pub fn from_coordinates<'a>(points: impl Iterator<Item = &'a AnyGridPoint<U>> + Clone) -> Self
+    where
+        U: 'a,
+    {
+        let x_range = points.clone().map(|p| p.x).range().unwrap_or(0..=0);
+        let y_range = points.clone().map(|p| p.y).range().unwrap_or(0..=0);
+        let size = GridSize::new(
+            x_range.size().try_into().unwrap(),
+            y_range.size().try_into().unwrap(),
+        );
+        let mut grid = Self::default(size);
+
+        for point in points.map(|p| {
+            GridPoint::new(
+                (p.x - x_range.start()).try_into().unwrap(),
+                (p.y - y_range.start()).try_into().unwrap(),
+            )
+        }) {
+            grid.set(&point, true.into());
+        }
+        grid
+    }
+
+    /// Returns a grid of the points that are `true` in either this grid or an `other` grid,
+    /// where the upper left corners of the grids are at the same point.
+    ///
+    /// Like the other set operations, the resulting grid is bounded to just contain the
+    /// points that are `true`, see [`Grid::from_coordinates`], so it may be shifted
+    /// relative to the original grids.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::StdBool;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = |s| Grid::<StdBool>::from_str(s).unwrap();
+    /// let a = grid("##.\n#..\n...");
+    /// let b = grid("...\n##.\n.##");
+    ///
+    /// assert_eq!(a.union(&b), grid("##.\n##.\n.##"));
+    /// assert_eq!(a.intersection(&b), grid("#"));
+    /// assert_eq!(a.difference(&b), grid("##"));
+    /// assert_eq!(b.difference(&a), grid("#.\n##"));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Into<bool>,
+    {
+        Self::from_point_set(self.as_coordinates().union(&other.as_coordinates()))
+    }
+
+    /// Returns a grid of the points that are `true` in both this grid and an `other`
+    /// grid, see [`Grid::union`].
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Into<bool>,
+    {
+        Self::from_point_set(self.as_coordinates().intersection(&other.as_coordinates()))
+    }
+
+    /// Returns a grid of the points that are `true` in this grid but not in an `other`
+    /// grid, see [`Grid::union`].
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Into<bool>,
+    {
+        Self::from_point_set(self.as_coordinates().difference(&other.as_coordinates()))
+    }
+
+    /// Creates a grid that is bounded to contain a set of `points`, see
+    /// [`Grid::from_coordinates`].
+    ///
+    /// This is an internal function of the set operations such as [`Grid::union`].
+    fn from_point_set<'a>(points: impl Iterator<Item = &'a GridPoint<U>>) -> Self
+    where
+        U: 'a,
+    {
+        let points = points.map(|p| p.to_isize()).collect_vec();
+        Self::from_coordinates(points.iter())
+    }
+}
+impl<T: Into<bool> + Clone, U> Grid<T, U> {
+    /// Returns a set of grid point coordinates for grid elements corresponding
+    /// to `true` for value types that can be converted to [`bool`].
+    ///
+    /// The grid points will be those with (0, 0) as the upper left element
+    /// such that every [`GridPoint`] is in the bounds of the grid.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let (t, f) = (true, false);
+    /// let values = vec![
+    ///     vec![t, f, f, f, f, t],
+    ///     vec![f, t, f, f, t, f],
+    ///     vec![f, f, t, t, f, f],
+    ///     vec![f, t, f, f, t, f],
+    ///     vec![t, f, f, f, f, t],
+    /// ];
+    /// let grid = Grid::<bool>::from_data(values).unwrap();
+    /// let coordinates = vec![
+    ///     GridPoint::new(0, 0),
+    ///     GridPoint::new(1, 1),
+    ///     GridPoint::new(2, 2),
+    ///     GridPoint::new(3, 2),
+    ///     GridPoint::new(4, 1),
+    ///     GridPoint::new(5, 0),
+    ///     GridPoint::new(1, 3),
+    ///     GridPoint::new(0, 4),
+    ///     GridPoint::new(4, 3),
+    ///     GridPoint::new(5, 4),
+    /// ];
+    ///
+    /// assert_eq!(grid.as_coordinates(), coordinates.into_iter().collect());
+    /// ```
+    pub fn as_coordinates(&self) -> HashSet<GridPoint<U>> {
+        self.all_points()
+            .filter(|p| Into::<bool>::into(self.get(p).clone()))
+            .collect()
+    }
+}
+
+impl<T, U> Grid<T, U> {
+    /// Returns a grid of the same size as this grid and an `other` grid, in which each
+    /// element is the `combine`d elements of the two grids at that point.
+    ///
+    /// This composes images, for example with a logical operation on grids of boolean
+    /// elements.
+    /// Returns an [`AocError::Process`] if the grids are of different sizes.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::StdBool;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = |s| Grid::<StdBool>::from_str(s).unwrap();
+    /// let a = grid("##.\n#..\n...");
+    /// let b = grid("...\n##.\n.##");
+    /// let heights = Grid::<u8>::from_data(vec![vec![1, 2, 3]; 3]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a.overlay(&b, |x, y| StdBool::from(**x != **y)),
+    ///     Ok(grid("##.\n.#.\n.##")),
+    /// );
+    /// assert_eq!(
+    ///     heights.overlay(&a, |h, x| if **x { *h } else { 0 }),
+    ///     Grid::from_data(vec![vec![1, 2, 0], vec![1, 0, 0], vec![0, 0, 0]]),
+    /// );
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let a = Grid::<u8>::default(GridSize::new(2, 3));
+    /// let b = Grid::<u8>::default(GridSize::new(3, 2));
+    /// assert!(a.overlay(&b, |x, y| x + y).is_err());
+    /// ```
+    pub fn overlay<V, R>(
+        &self,
+        other: &Grid<V, U>,
+        combine: impl Fn(&T, &V) -> R,
+    ) -> AocResult<Grid<R, U>> {
+        if self.size != other.size {
+            return Err(AocError::Process(
+                format!(
+                    "Cannot overlay a {}x{} grid onto a {}x{} grid",
+                    other.size.width, other.size.height, self.size.width, self.size.height
+                )
+                .into(),
+            ));
+        }
+
+        Ok(Grid {
+            size: self.size,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| combine(a, b))
+                .collect(),
+        })
+    }
+}
+
+impl<T, U> Grid<T, U> {
+    /// Returns the fewest steps needed to climb from a `start` point to a `goal` point, or
+    /// `None` if the goal cannot be reached.
+    ///
+    /// Each step is to one of the four neighboring points, and is only allowed if
+    /// `can_step` holds for the elements of the point being stepped from and the point
+    /// being stepped to, for example if the height of the second is at most one more than
+    /// that of the first.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid =
+    ///     Grid::<char>::from_str("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    /// let height = |c: &char| match c {
+    ///     'S' => 'a',
+    ///     'E' => 'z',
+    ///     c => *c,
+    /// };
+    /// let can_step = |from: &char, to: &char| u32::from(height(to)) <= u32::from(height(from)) + 1;
+    /// let start = grid.position_of(&'S').unwrap();
+    /// let goal = grid.position_of(&'E').unwrap();
+    ///
+    /// assert_eq!(grid.climbing_shortest_path(&start, &goal, can_step), Some(31));
+    /// assert_eq!(grid.climbing_shortest_path(&start, &start, can_step), Some(0));
+    /// assert_eq!(grid.climbing_shortest_path(&goal, &start, |_, _| false), None);
+    /// ```
+    pub fn climbing_shortest_path(
+        &self,
+        start: &GridPoint<U>,
+        goal: &GridPoint<U>,
+        can_step: impl Fn(&T, &T) -> bool,
+    ) -> Option<usize> {
+        self.fewest_steps(start, |p| p == goal, can_step)
+    }
+
+    /// Returns the fewest steps needed to climb to a `goal` point from any of the points
+    /// whose elements satisfy `is_start`, or `None` if the goal cannot be reached from any
+    /// of them, see [`Grid::climbing_shortest_path`].
+    ///
+    /// This searches backwards from the goal, so takes no longer than a single search
+    /// regardless of the number of possible starts.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid =
+    ///     Grid::<char>::from_str("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    /// let height = |c: &char| match c {
+    ///     'S' => 'a',
+    ///     'E' => 'z',
+    ///     c => *c,
+    /// };
+    /// let can_step = |from: &char, to: &char| u32::from(height(to)) <= u32::from(height(from)) + 1;
+    /// let goal = grid.position_of(&'E').unwrap();
+    ///
+    /// // From any of the lowest points
+    /// assert_eq!(
+    ///     grid.climbing_shortest_path_from_any(|c| height(c) == 'a', &goal, can_step),
+    ///     Some(29),
+    /// );
+    /// assert_eq!(grid.climbing_shortest_path_from_any(|c| *c == '?', &goal, can_step), None);
+    /// ```
+    pub fn climbing_shortest_path_from_any(
+        &self,
+        is_start: impl Fn(&T) -> bool,
+        goal: &GridPoint<U>,
+        can_step: impl Fn(&T, &T) -> bool,
+    ) -> Option<usize> {
+        self.fewest_steps(
+            goal,
+            |p| is_start(self.get(p)),
+            |from, to| can_step(to, from),
+        )
+    }
+
+    /// Returns the fewest steps from a `start` point to the nearest point that is an `end`,
+    /// in which each step is to a neighboring point for which `allowed` holds for the
+    /// elements of the two points, by searching breadth first.
+    ///
+    /// This is an internal function of [`Grid::climbing_shortest_path`] and
+    /// [`Grid::climbing_shortest_path_from_any`].
+    fn fewest_steps(
+        &self,
+        start: &GridPoint<U>,
+        end: impl Fn(&GridPoint<U>) -> bool,
+        allowed: impl Fn(&T, &T) -> bool,
+    ) -> Option<usize> {
+        let mut visited = HashSet::default();
+        visited.insert(*start);
+        let pool = Pool::new();
+        let mut frontier = pool.take();
+        frontier.push(*start);
+        let mut steps = 0;
+        while !frontier.is_empty() {
+            if frontier.iter().any(&end) {
+                return Some(steps);
+            }
+
+            steps += 1;
+            let mut next_frontier = pool.take();
+            for point in frontier.iter() {
+                let element = self.get(point);
+                for neighbor in self.neighbor_points(point, false, false) {
+                    if allowed(element, self.get(&neighbor)) && visited.insert(neighbor) {
+                        next_frontier.push(neighbor);
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+        None
+    }
+}
+
+/// The ID of a seed point in a [`distance_transform`], which is its index in the list of seeds.
+pub type SeedId = usize;
+
+/// A metric for the distance between grid points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// The Manhattan or taxicab distance, which is the sum of the horizontal and
+    /// vertical distances.
+    Manhattan,
+    /// The Chebyshev or chessboard distance, which is the larger of the horizontal and
+    /// vertical distances.
+    Chebyshev,
+}
+
+/// Returns a grid of some `size` in which each element is the nearest of some `seeds`
+/// to that point and its distance according to a `metric`.
+///
+/// The nearest seed is `None` if more than one seed is at the smallest distance.
+/// The transform is computed with a breadth-first search from all of the seeds at
+/// once, so takes time proportional to the size of the grid regardless of the number
+/// of seeds.
+///
+/// # Panics
+/// This will panic if there are no `seeds` or any seed is outside the grid.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{distance_transform, DistanceMetric};
+///
+/// let seeds = [GridPoint::new(0, 0), GridPoint::new(4, 0)];
+/// let transform = distance_transform(GridSize::new(5, 3), &seeds, DistanceMetric::Manhattan);
+///
+/// assert_eq!(transform.get(&GridPoint::new(1, 2)), &(Some(0), 3));
+/// assert_eq!(transform.get(&GridPoint::new(2, 1)), &(None, 3));
+/// assert_eq!(transform.get(&GridPoint::new(4, 2)), &(Some(1), 2));
+///
+/// let transform = distance_transform(GridSize::new(5, 3), &seeds, DistanceMetric::Chebyshev);
+/// assert_eq!(transform.get(&GridPoint::new(1, 2)), &(Some(0), 2));
+/// ```
+pub fn distance_transform<U>(
+    size: GridSize<U>,
+    seeds: &[GridPoint<U>],
+    metric: DistanceMetric,
+) -> Grid<(Option<SeedId>, u32), U> {
+    assert!(
+        !seeds.is_empty(),
+        "a distance transform needs at least one seed"
+    );
+
+    let mut reached = Grid::<Option<(Option<SeedId>, u32)>, U>::default(size);
+    let mut frontier = Vec::new();
+    for (id, seed) in seeds.iter().enumerate() {
+        let element = reached.element_at(seed);
+        match element {
+            None => {
+                *element = Some((Some(id), 0));
+                frontier.push(*seed);
+            }
+            Some((nearest, _)) => *nearest = None,
+        }
+    }
+
+    // The nearest seeds of each point are those of its neighbors that are one step closer
+    let mut distance = 0;
+    while !frontier.is_empty() {
+        distance += 1;
+        let mut next_frontier = Vec::new();
+        for point in frontier {
+            let nearest = reached.get(&point).unwrap().0;
+            let neighbors = reached
+                .neighbor_points(&point, metric == DistanceMetric::Chebyshev, false)
+                .collect::<Vec<_>>();
+            for neighbor in neighbors {
+                let element = reached.element_at(&neighbor);
+                match element {
+                    None => {
+                        *element = Some((nearest, distance));
+                        next_frontier.push(neighbor);
+                    }
+                    Some((other, d)) => {
+                        if *d == distance && *other != nearest {
+                            *other = None;
+                        }
+                    }
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    Grid::from_data(
+        reached
+            .rows_iter()
+            .map(|row| row.iter().map(|e| e.unwrap()).collect())
+            .collect(),
+    )
+    .unwrap()
+}
+
+/// Returns the number of points in the region of each seed of a [`distance_transform`],
+/// where the seeds are identified by their IDs from zero up to `num_seeds`.
+///
+/// The area is `None` for regions that reach the edge of the grid, which are infinite
+/// when the grid contains the bounding box of the seeds and the metric is Manhattan.
+/// Points that are equally near to more than one seed are not in any region.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{distance_transform, voronoi_areas, DistanceMetric};
+///
+/// let seeds = [(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)].map(|(x, y)| GridPoint::new(x, y));
+/// let transform = distance_transform(GridSize::new(9, 10), &seeds, DistanceMetric::Manhattan);
+///
+/// assert_eq!(
+///     voronoi_areas(&transform, seeds.len()),
+///     vec![None, None, None, Some(9), Some(17), None],
+/// );
+/// ```
+pub fn voronoi_areas<U>(
+    transform: &Grid<(Option<SeedId>, u32), U>,
+    num_seeds: usize,
+) -> Vec<Option<usize>> {
+    let size = transform.size();
+    let mut areas = vec![Some(0); num_seeds];
+
+    for point in transform.all_points() {
+        if let Some(id) = transform.get(&point).0 {
+            if point.x == 0
+                || point.y == 0
+                || point.x == size.width - 1
+                || point.y == size.height - 1
+            {
+                areas[id] = None;
+            } else if let Some(area) = areas[id].as_mut() {
+                *area += 1;
+            }
+        }
+    }
+
+    areas
+}
+
+/// A chain of knots on an unbounded grid, in which the head knot is moved and each
+/// following knot pursues the knot in front of it.
+///
+/// A knot only moves when it is no longer touching the knot in front of it, including
+/// diagonally, and then moves one space towards that knot in each dimension in which
+/// they differ.
+/// The points visited by the last knot, the tail, are tracked as the chain moves.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::grid::FollowerChain;
+///
+/// let moves = [
+///     (Direction4::Right, 4),
+///     (Direction4::Up, 4),
+///     (Direction4::Left, 3),
+///     (Direction4::Down, 1),
+///     (Direction4::Right, 4),
+///     (Direction4::Down, 1),
+///     (Direction4::Left, 5),
+///     (Direction4::Right, 2),
+/// ];
+///
+/// let mut chain = FollowerChain::<GridSpace>::new(2, AnyGridPoint::origin()).unwrap();
+/// chain.apply(moves);
+/// assert_eq!(chain.head(), AnyGridPoint::new(2, -2));
+/// assert_eq!(chain.tail(), AnyGridPoint::new(1, -2));
+/// assert_eq!(chain.visited().len(), 13);
+///
+/// let mut chain = FollowerChain::<GridSpace>::new(10, AnyGridPoint::origin()).unwrap();
+/// chain.apply(moves);
+/// assert_eq!(chain.visited().len(), 1);
+///
+/// // Knots follow diagonally
+/// let mut chain = FollowerChain::<GridSpace>::new(3, AnyGridPoint::origin()).unwrap();
+/// chain.apply([(Direction4::Right, 1), (Direction4::Up, 2)]);
+/// assert_eq!(
+///     chain.knots(),
+///     &[
+///         AnyGridPoint::new(1, -2),
+///         AnyGridPoint::new(1, -1),
+///         AnyGridPoint::new(0, 0),
+///     ],
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::FollowerChain;
+///
+/// assert!(FollowerChain::<GridSpace>::new(0, AnyGridPoint::origin()).is_err());
+/// ```
+#[derive(Clone)]
+pub struct FollowerChain<U = GridSpace> {
+    /// The current points of every knot, starting with the head.
+    knots: Vec<AnyGridPoint<U>>,
+    /// The points that have been visited by the tail knot.
+    visited: HashSet<AnyGridPoint<U>>,
+}
+impl<U> FollowerChain<U> {
+    /// Creates a chain of some number of knots, which all start at the `start` point.
+    ///
+    /// Returns [`AocError::InvalidInput`] if there are no knots.
+    pub fn new(num_knots: usize, start: AnyGridPoint<U>) -> AocResult<Self> {
+        if num_knots == 0 {
+            return Err(AocError::InvalidInput(
+                "A follower chain must have at least one knot".into(),
+            ));
+        }
+
+        Ok(Self {
+            knots: vec![start; num_knots],
+            visited: HashSet::from_iter([start]),
+        })
+    }
+
+    /// Returns the current points of every knot, starting with the head.
+    pub fn knots(&self) -> &[AnyGridPoint<U>] {
+        &self.knots
+    }
+
+    /// Returns the current point of the head knot.
+    pub fn head(&self) -> AnyGridPoint<U> {
+        self.knots[0]
+    }
+
+    /// Returns the current point of the tail knot.
+    pub fn tail(&self) -> AnyGridPoint<U> {
+        *self.knots.last().unwrap()
+    }
+
+    /// Returns the set of points that have been visited by the tail knot, including
+    /// its starting point.
+    pub fn visited(&self) -> &HashSet<AnyGridPoint<U>> {
+        &self.visited
+    }
+
+    /// Moves the head knot a single space in some `direction`, with the following
+    /// knots pursuing it.
+    pub fn step(&mut self, direction: Direction4) {
+        self.knots[0] += direction.as_vector();
+
+        for i in 1..self.knots.len() {
+            let delta = self.knots[i - 1] - self.knots[i];
+            if delta.x.abs() > 1 || delta.y.abs() > 1 {
+                self.knots[i] += Vector2D::new(delta.x.signum(), delta.y.signum());
+            }
+        }
+        self.visited.insert(self.tail());
+    }
+
+    /// Applies some `moves` to the head knot in order, each of which is a direction
+    /// and the number of single spaces to move in that direction, see
+    /// [`FollowerChain::step`].
+    pub fn apply(&mut self, moves: impl IntoIterator<Item = (Direction4, usize)>) {
+        for (direction, spaces) in moves {
+            for _ in 0..spaces {
+                self.step(direction);
+            }
+        }
+    }
+}
+
+/// Returns the box of the largest rectangle in a `grid` whose elements are all set,
+/// or `None` if no elements are set.
+///
+/// Each row is treated as the base of a histogram of the runs of set elements above it,
+/// see [`largest_histogram_rectangle`](crate::iter::largest_histogram_rectangle), so this
+/// takes time proportional to the size of the grid.
+/// If several rectangles have the largest area, any one of these is returned.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{largest_true_rectangle, StdBool};
+/// use std::str::FromStr;
+///
+/// let grid = Grid::<StdBool>::from_str(
+///     "#.#..
+/// #.###
+/// #####
+/// #..#.",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     largest_true_rectangle(&grid),
+///     Some(GridBox::new(GridPoint::new(2, 1), GridPoint::new(5, 3))),
+/// );
+///
+/// let grid = Grid::<bool>::default(GridSize::new(3, 3));
+/// assert_eq!(largest_true_rectangle(&grid), None);
+/// ```
+pub fn largest_true_rectangle<T: Copy + Into<bool>, U>(grid: &Grid<T, U>) -> Option<GridBox<U>> {
+    let size = grid.size();
+    let mut heights = vec![0; size.width];
+    let mut best: Option<GridBox<U>> = None;
+
+    for y in 0..size.height {
+        for (height, value) in heights.iter_mut().zip(grid.row_iter(y)) {
+            *height = if (*value).into() { *height + 1 } else { 0 };
+        }
+
+        if let Some((columns, height)) = crate::iter::largest_histogram_rectangle(&heights) {
+            if best
+                .map(|b| columns.len() * height > b.area())
+                .unwrap_or(true)
+            {
+                best = Some(GridBox::new(
+                    GridPoint::new(columns.start, y + 1 - height),
+                    GridPoint::new(columns.end, y + 1),
+                ));
+            }
+        }
+    }
+
+    best
+}
+
+/// Returns the box of the largest square in a `grid` whose elements are all set,
+/// or `None` if no elements are set.
+///
+/// If several squares have the largest size, the one whose lower right corner is first
+/// in row-major order is returned.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{largest_square, StdBool};
+/// use std::str::FromStr;
+///
+/// let grid = Grid::<StdBool>::from_str(
+///     "#.#..
+/// #.###
+/// #####
+/// #..#.",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     largest_square(&grid),
+///     Some(GridBox::new(GridPoint::new(2, 1), GridPoint::new(4, 3))),
+/// );
+///
+/// let grid = Grid::<bool>::default(GridSize::new(3, 3));
+/// assert_eq!(largest_square(&grid), None);
+/// ```
+pub fn largest_square<T: Copy + Into<bool>, U>(grid: &Grid<T, U>) -> Option<GridBox<U>> {
+    let size = grid.size();
+    // The side length of the largest square whose lower right corner is at each point
+    let mut sides = Grid::<usize, U>::default(*size);
+    let mut best: Option<(usize, GridPoint<U>)> = None;
+
+    for point in grid.all_points() {
+        if !(*grid.get(&point)).into() {
+            continue;
+        }
+
+        let side = if point.x == 0 || point.y == 0 {
+            1
+        } else {
+            [(1, 0), (0, 1), (1, 1)]
+                .into_iter()
+                .map(|(dx, dy)| *sides.get(&GridPoint::new(point.x - dx, point.y - dy)))
+                .min()
+                .unwrap()
+                + 1
+        };
+        sides.set(&point, side);
+
+        if best.map(|(s, _)| side > s).unwrap_or(true) {
+            best = Some((side, point));
+        }
+    }
+
+    best.map(|(side, corner)| {
+        GridBox::new(
+            GridPoint::new(corner.x + 1 - side, corner.y + 1 - side),
+            GridPoint::new(corner.x + 1, corner.y + 1),
+        )
+    })
+}
+
+/// The boundary of a region of points, see [`region_boundary`].
+#[derive(Clone)]
+pub struct RegionBoundary<U = GridSpace> {
+    /// The number of edges between points in the region and points not in the region,
+    /// including points outside of the grid.
+    pub perimeter: usize,
+    /// The number of straight sides of the boundary, which is also its number of corners.
+    pub sides: usize,
+    /// The closed loops that make up the boundary, each as the corners at which its edges
+    /// start, in order.
+    ///
+    /// The corners are between points, where the upper left corner of a point has the same
+    /// coordinates as the point.
+    /// The loops keep the region on their right, so that the outer boundary is clockwise
+    /// and the boundaries of any holes are counter-clockwise.
+    /// At a corner where the region touches itself diagonally, a loop turns right.
+    pub loops: Vec<Vec<AnyGridPoint<U>>>,
+}
+
+/// Returns the boundary of a region of points, such as one found with [`Grid::flood_fill`],
+/// by walking around it.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::region_boundary;
+/// use std::str::FromStr;
+///
+/// let boundary = |grid: &Grid<char>, x, y| {
+///     let point = GridPoint::new(x, y);
+///     let plant = *grid.get(&point);
+///     region_boundary(&grid.flood_fill(&point, false, |p| *p == plant))
+/// };
+///
+/// let grid = Grid::<char>::from_str("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+/// let region = boundary(&grid, 2, 1);
+/// assert_eq!((region.perimeter, region.sides), (10, 8));
+/// let region = boundary(&grid, 3, 1);
+/// assert_eq!((region.perimeter, region.sides), (4, 4));
+/// assert_eq!(
+///     region.loops,
+///     vec![vec![
+///         AnyGridPoint::new(3, 1),
+///         AnyGridPoint::new(4, 1),
+///         AnyGridPoint::new(4, 2),
+///         AnyGridPoint::new(3, 2),
+///     ]],
+/// );
+///
+/// // A region with holes
+/// let grid = Grid::<char>::from_str("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO").unwrap();
+/// let region = boundary(&grid, 0, 0);
+/// assert_eq!((region.perimeter, region.sides, region.loops.len()), (36, 20, 5));
+///
+/// // A region that touches itself diagonally
+/// let grid = Grid::<char>::from_str(
+///     "AAAAAA
+/// AAABBA
+/// AAABBA
+/// ABBAAA
+/// ABBAAA
+/// AAAAAA",
+/// )
+/// .unwrap();
+/// let region = boundary(&grid, 0, 0);
+/// assert_eq!((region.perimeter, region.sides), (40, 12));
+/// ```
+pub fn region_boundary<U>(region: &[GridPoint<U>]) -> RegionBoundary<U> {
+    let points: HashSet<AnyGridPoint<U>> = region.iter().map(|p| p.to_isize()).collect();
+
+    // The edges that keep the region on their right, as their starting corners and directions
+    let mut edges = HashSet::default();
+    let mut order = Vec::new();
+    for point in region.iter().map(|p| p.to_isize()) {
+        for side in Direction4::all() {
+            if points.contains(&(point + side.as_vector())) {
+                continue;
+            }
+            let corner = match side {
+                Direction4::Up => Vector2D::new(0, 0),
+                Direction4::Right => Vector2D::new(1, 0),
+                Direction4::Down => Vector2D::new(1, 1),
+                Direction4::Left => Vector2D::new(0, 1),
+            };
+            let edge = (point + corner, side * Turn::Right);
+            if edges.insert(edge) {
+                order.push(edge);
+            }
+        }
+    }
+    let perimeter = order.len();
+
+    let mut sides = 0;
+    let mut loops = Vec::new();
+    for start in order {
+        if !edges.remove(&start) {
+            continue;
+        }
+
+        let (mut corner, mut direction) = start;
+        let mut corners = vec![corner];
+        let mut directions = vec![direction];
+        loop {
+            corner += direction.as_vector();
+            let next = [direction * Turn::Right, direction, direction * Turn::Left]
+                .into_iter()
+                .find(|d| (corner, *d) == start || edges.contains(&(corner, *d)));
+            match next {
+                Some(d) if (corner, d) != start => {
+                    edges.remove(&(corner, d));
+                    corners.push(corner);
+                    directions.push(d);
+                    direction = d;
+                }
+                _ => break,
+            }
+        }
+
+        sides += directions
+            .iter()
+            .circular_tuple_windows()
+            .filter(|(a, b)| a != b)
+            .count();
+        loops.push(corners);
+    }
+
+    RegionBoundary {
+        perimeter,
+        sides,
+        loops,
+    }
+}
+
+/// How the placement of a shape treats the points of the shape beyond an edge of the
+/// grid, see [`EdgePolicies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgePolicy {
+    /// Points beyond the edge collide with it.
+    Collide,
+    /// Points beyond the edge are ignored, as if the grid continued beyond the edge with
+    /// nothing in it.
+    Ignore,
+}
+
+/// The [`EdgePolicy`] of each edge of a grid when placing shapes, see [`check_placement`].
+///
+/// By default, every edge collides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgePolicies {
+    /// The policy of the top edge, at a `y` of zero.
+    pub top: EdgePolicy,
+    /// The policy of the bottom edge, at the height of the grid.
+    pub bottom: EdgePolicy,
+    /// The policy of the left edge, at an `x` of zero.
+    pub left: EdgePolicy,
+    /// The policy of the right edge, at the width of the grid.
+    pub right: EdgePolicy,
+}
+impl EdgePolicies {
+    /// Returns the same `policy` for every edge.
+    pub fn all(policy: EdgePolicy) -> Self {
+        Self {
+            top: policy,
+            bottom: policy,
+            left: policy,
+            right: policy,
+        }
+    }
+
+    /// Returns the policy of the edge in some `direction`, where up is the top edge.
+    pub fn edge(&self, direction: Direction4) -> EdgePolicy {
+        match direction {
+            Direction4::Up => self.top,
+            Direction4::Down => self.bottom,
+            Direction4::Left => self.left,
+            Direction4::Right => self.right,
+        }
+    }
+}
+impl Default for EdgePolicies {
+    fn default() -> Self {
+        Self::all(EdgePolicy::Collide)
+    }
+}
+
+/// The reason that a shape cannot be placed in a grid, see [`check_placement`].
+pub enum Collision<U = GridSpace> {
+    /// A point of the shape is beyond the edge of the grid in a direction, where up is the
+    /// top edge.
+    Edge(Direction4),
+    /// A point of the shape is at a point of the grid that is already occupied.
+    Occupied(GridPoint<U>),
+}
+impl<U> Clone for Collision<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<U> Copy for Collision<U> {}
+impl<U> PartialEq for Collision<U> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Edge(a), Self::Edge(b)) => a == b,
+            (Self::Occupied(a), Self::Occupied(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+impl<U> Eq for Collision<U> {}
+impl<U> fmt::Debug for Collision<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Edge(direction) => f.debug_tuple("Edge").field(direction).finish(),
+            Self::Occupied(point) => f.debug_tuple("Occupied").field(point).finish(),
+        }
+    }
+}
+
+/// Checks whether a `shape` can be placed at an `origin` in a grid of some `size`, without
+/// any of its points being `occupied` or beyond an edge that collides according to the
+/// `edges` policies.
+///
+/// The points of the shape are relative to the origin, and can be in any space.
+/// Every point is checked against the edges before any are checked for being occupied, so
+/// that a collision with an edge is returned in preference to an occupied point.
+/// This is the basis of [`can_place`] and [`place_shape`] for shapes in a [`Grid`], and
+/// can be used directly when the occupied points are not stored in a grid.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::grid::{check_placement, Collision, EdgePolicies, EdgePolicy};
+///
+/// // A horizontal line in a well that is open at the top, with a single occupied point
+/// let size = GridSize::new(4, 3);
+/// let line = [AnyGridPoint::new(0, 0), AnyGridPoint::new(1, 0)];
+/// let edges = EdgePolicies {
+///     top: EdgePolicy::Ignore,
+///     ..Default::default()
+/// };
+/// let check = |x, y| {
+///     check_placement(&size, &line, &AnyGridPoint::new(x, y), &edges, |p| {
+///         *p == GridPoint::new(1, 2)
+///     })
+/// };
+///
+/// assert_eq!(check(2, 2), Ok(()));
+/// assert_eq!(check(0, -5), Ok(()));
+/// assert_eq!(check(0, 2), Err(Collision::Occupied(GridPoint::new(1, 2))));
+/// assert_eq!(check(3, 1), Err(Collision::Edge(Direction4::Right)));
+/// assert_eq!(check(0, 3), Err(Collision::Edge(Direction4::Down)));
+/// ```
+pub fn check_placement<S, U>(
+    size: &GridSize<U>,
+    shape: &[Point2D<isize, S>],
+    origin: &AnyGridPoint<U>,
+    edges: &EdgePolicies,
+    mut occupied: impl FnMut(&GridPoint<U>) -> bool,
+) -> Result<(), Collision<U>> {
+    let points = || shape.iter().map(|p| *origin + p.to_vector().cast_unit());
+    let (width, height) = (size.width as isize, size.height as isize);
+
+    // Determine the edge, if any, beyond which a point is
+    let beyond = |point: &AnyGridPoint<U>| {
+        if point.x < 0 {
+            Some(Direction4::Left)
+        } else if point.x >= width {
+            Some(Direction4::Right)
+        } else if point.y < 0 {
+            Some(Direction4::Up)
+        } else if point.y >= height {
+            Some(Direction4::Down)
+        } else {
+            None
+        }
+    };
+
+    for point in points() {
+        if let Some(edge) = beyond(&point) {
+            if edges.edge(edge) == EdgePolicy::Collide {
+                return Err(Collision::Edge(edge));
+            }
+        }
+    }
+    for point in points() {
+        if beyond(&point).is_none() {
+            let point = point.to_usize();
+            if occupied(&point) {
+                return Err(Collision::Occupied(point));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether a `shape` can be placed at an `origin` in a `grid`, in which the `true`
+/// points are occupied, see [`check_placement`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::grid::{can_place, Collision, EdgePolicies, EdgePolicy, StdBool};
+/// use std::str::FromStr;
+///
+/// let grid = Grid::<StdBool>::from_str("....\n..#.\n....").unwrap();
+/// let corner = [
+///     AnyGridPoint::new(0, 0),
+///     AnyGridPoint::new(0, 1),
+///     AnyGridPoint::new(1, 1),
+/// ];
+/// let edges = EdgePolicies::default();
+///
+/// assert_eq!(can_place(&grid, &corner, &AnyGridPoint::new(0, 0), &edges), Ok(()));
+/// assert_eq!(
+///     can_place(&grid, &corner, &AnyGridPoint::new(1, 0), &edges),
+///     Err(Collision::Occupied(GridPoint::new(2, 1))),
+/// );
+/// assert_eq!(
+///     can_place(&grid, &corner, &AnyGridPoint::new(3, 0), &edges),
+///     Err(Collision::Edge(Direction4::Right)),
+/// );
+/// assert_eq!(
+///     can_place(&grid, &corner, &AnyGridPoint::new(0, -1), &edges),
+///     Err(Collision::Edge(Direction4::Up)),
+/// );
+///
+/// // Points beyond an ignored edge are dropped
+/// let open_top = EdgePolicies {
+///     top: EdgePolicy::Ignore,
+///     ..edges
+/// };
+/// assert_eq!(can_place(&grid, &corner, &AnyGridPoint::new(0, -1), &open_top), Ok(()));
+/// ```
+pub fn can_place<T: Copy + Into<bool>, S, U>(
+    grid: &Grid<T, U>,
+    shape: &[Point2D<isize, S>],
+    origin: &AnyGridPoint<U>,
+    edges: &EdgePolicies,
+) -> Result<(), Collision<U>> {
+    check_placement(grid.size(), shape, origin, edges, |p| (*grid.get(p)).into())
+}
+
+/// Places a `shape` at an `origin` in a `grid` by setting its points to `true`, provided
+/// that it can be placed without collisions, see [`can_place`].
+///
+/// The grid is unchanged if there is a collision.
+/// Points of the shape beyond edges that are ignored are dropped.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::{place_shape, Collision, EdgePolicies, StdBool};
+/// use std::str::FromStr;
+///
+/// let mut grid = Grid::<StdBool>::from_str("....\n..#.\n....").unwrap();
+/// let corner = [
+///     AnyGridPoint::new(0, 0),
+///     AnyGridPoint::new(0, 1),
+///     AnyGridPoint::new(1, 1),
+/// ];
+/// let edges = EdgePolicies::default();
+///
+/// assert_eq!(place_shape(&mut grid, &corner, &AnyGridPoint::new(0, 1), &edges), Ok(()));
+/// assert_eq!(
+///     place_shape(&mut grid, &corner, &AnyGridPoint::new(0, 0), &edges),
+///     Err(Collision::Occupied(GridPoint::new(0, 1))),
+/// );
+/// assert_eq!(grid, Grid::from_str("....\n#.#.\n##..").unwrap());
+/// ```
+pub fn place_shape<T: Copy + Into<bool> + From<bool>, S, U>(
+    grid: &mut Grid<T, U>,
+    shape: &[Point2D<isize, S>],
+    origin: &AnyGridPoint<U>,
+    edges: &EdgePolicies,
+) -> Result<(), Collision<U>> {
+    can_place(grid, shape, origin, edges)?;
+    for point in shape {
+        if let Some(point) = grid.bounded_point(&(*origin + point.to_vector().cast_unit())) {
+            grid.set(&point, true.into());
+        }
+    }
+    Ok(())
+}
+
+/// Finds the earliest times at which a mover can reach each of some `goals` in turn,
+/// starting from a `start` point at time zero, while avoiding obstacles that move over
+/// time.
+///
+/// Each unit of time, the mover either waits or moves to one of the four neighboring
+/// points, and can only be at points that are not `true` in the `grid` of walls and
+/// are not among the obstacles at that time, as determined by `obstacles_at`.
+/// The obstacles are assumed to repeat with the least common multiple of their `periods`,
+/// so that they are only determined once for each time in this cycle, and the search is
+/// over the points combined with the time in the cycle.
+/// Every leg of the trip starts from the goal of the previous leg at the time it was
+/// reached, so that there and back again trips are a list of goals.
+///
+/// Returns the time at which each goal is reached, or `None` if a goal cannot be reached.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::{
+///     collections::HashSet,
+///     direction::Direction4,
+///     grid::{time_expanded_bfs, StdBool},
+/// };
+/// use euclid::vec2;
+/// use itertools::Itertools;
+/// use std::str::FromStr;
+///
+/// // A valley with blizzards that move every minute, wrapping around the walls
+/// let valley = Grid::<char>::from_str(
+///     "#.######
+/// #>>.<^<#
+/// #.<..<<#
+/// #>v.><>#
+/// #<^v^^>#
+/// ######.#",
+/// )
+/// .unwrap();
+/// let walls = Grid::<StdBool>::from_data(
+///     valley
+///         .rows_iter()
+///         .map(|row| row.iter().map(|c| (*c == '#').into()).collect())
+///         .collect(),
+/// )
+/// .unwrap();
+/// let (width, height) = (valley.size().width - 2, valley.size().height - 2);
+///
+/// let blizzards = valley
+///     .all_points()
+///     .filter_map(|p| Direction4::try_from(*valley.get(&p)).ok().map(|d| (p, d)))
+///     .collect_vec();
+/// let obstacles_at = |time: usize| {
+///     let time = isize::try_from(time).unwrap();
+///     blizzards
+///         .iter()
+///         .map(|(point, direction)| {
+///             let point = point.to_isize() - vec2(1, 1)
+///                 + direction.as_vector::<isize, GridSpace>() * time;
+///             GridPoint::new(
+///                 point.x.rem_euclid(width.try_into().unwrap()).unsigned_abs() + 1,
+///                 point.y.rem_euclid(height.try_into().unwrap()).unsigned_abs() + 1,
+///             )
+///         })
+///         .collect::<HashSet<_>>()
+/// };
+///
+/// let (start, goal) = (GridPoint::new(1, 0), GridPoint::new(6, 5));
+/// assert_eq!(
+///     time_expanded_bfs(&walls, [width, height], obstacles_at, start, &[goal, start, goal]),
+///     Some(vec![18, 41, 54]),
+/// );
+/// ```
+pub fn time_expanded_bfs<T: Copy + Into<bool>, U>(
+    grid: &Grid<T, U>,
+    periods: impl IntoIterator<Item = usize>,
+    obstacles_at: impl FnMut(usize) -> HashSet<GridPoint<U>>,
+    start: GridPoint<U>,
+    goals: &[GridPoint<U>],
+) -> Option<Vec<usize>> {
+    let cycle = periods
+        .into_iter()
+        .fold(1, |cycle, period| cycle.lcm(&period));
+    let obstacles = (0..cycle).map(obstacles_at).collect_vec();
+    let open = |point: &GridPoint<U>, time: usize| {
+        !(*grid.get(point)).into() && !obstacles[time % cycle].contains(point)
+    };
+
+    let mut time = 0;
+    let mut leg_start = start;
+    let mut arrivals = Vec::with_capacity(goals.len());
+    let pool = Pool::new();
+    for goal in goals {
+        // Search forward in time from the start of the leg, one time at once
+        let mut frontier = pool.take();
+        frontier.push(leg_start);
+        let mut visited = HashSet::default();
+        visited.insert((leg_start, time % cycle));
+        while !frontier.contains(goal) {
+            time += 1;
+            let mut next = pool.take();
+            for point in frontier.iter() {
+                for neighbor in grid.neighbor_points(point, false, true) {
+                    if open(&neighbor, time) && visited.insert((neighbor, time % cycle)) {
+                        next.push(neighbor);
+                    }
+                }
+            }
+
+            // Every state that can be reached from the start of the leg has been visited
+            if next.is_empty() {
+                return None;
+            }
+            frontier = next;
+        }
+
+        arrivals.push(time);
+        leg_start = *goal;
+    }
+    Some(arrivals)
+}
+
+/// Parses a [`Grid`] from a string of characters with each row on a separate line.
+///
+/// This can be done for element types that can be fallibly converted from characters.
+/// Note that the error type of the [`FromStr`] implementation for the element type
+/// does not matter so it is recommended just to use the unit type. An [`Err`] will
+/// be returns if not every row has the same number of characters, or if any of the
+/// characters is invalid, in which case the error gives the line and column of the
+/// character.
+/// Each character is an element, so to parse elements that take more than one character,
+/// such as some emoji, see `Grid::from_graphemes` with the `graphemes` feature.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// # use std::str::FromStr;
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Direction {
+///     Up,
+///     Down,
+///     Left,
+///     Right,
+/// }
+/// use Direction::*;
+/// impl TryFrom<char> for Direction {
+///     type Error = ();
+///     
+///     fn try_from(value: char) -> Result<Self, Self::Error> {
+///         match value {
+///             'U' => Ok(Up),
+///             'D' => Ok(Down),
+///             'L' => Ok(Left),
+///             'R' => Ok(Right),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// let string = "UDLRU
+/// DLRUD
+/// LRUDL";
+/// let grid = Grid::from_data(vec![
+///     vec![Up, Down, Left, Right, Up],
+///     vec![Down, Left, Right, Up, Down],
+///     vec![Left, Right, Up, Down, Left],
+/// ]).unwrap();
+///
+/// assert_eq!(Grid::from_str(string).unwrap(), grid);
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use std::str::FromStr;
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Correctness {
+///     Wrong,
+///     Right,
+/// }
+/// use Correctness::*;
+/// impl TryFrom<char> for Correctness {
+///     type Error = ();
+///     
+///     fn try_from(value: char) -> Result<Self, Self::Error> {
+///         match value {
+///             'W' => Ok(Wrong),
+///             'R' => Ok(Right),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// let string = "WRRW
+/// RWWX";
+///
+/// assert_matches!(Grid::<Correctness>::from_str(string), Err(AocError::Other(_)));
+/// assert_eq!(
+///     Grid::<Correctness>::from_str(string).unwrap_err().to_string(),
+///     "Other error: Invalid character 'X' at line 2, column 4",
+/// );
+/// ```
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_data(parse_rows(s, str::chars)?)
+    }
+}
+#[cfg(feature = "graphemes")]
+impl<T: for<'a> TryFrom<&'a str>> Grid<T> {
+    /// Parses a grid from a string with each row on a separate line, in which each element
+    /// is a grapheme cluster rather than a character, see the [`FromStr`] implementation.
+    ///
+    /// This is for inputs with elements such as emoji or symbols with variation selectors,
+    /// which take more than one character but appear as a single one.
+    /// This is only available with the `graphemes` feature.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// enum Arrow {
+    ///     Up,
+    ///     Down,
+    /// }
+    /// use Arrow::*;
+    /// impl TryFrom<&str> for Arrow {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(value: &str) -> Result<Self, Self::Error> {
+    ///         // Arrows followed by the emoji variation selector
+    ///         match value {
+    ///             "\u{2b06}\u{fe0f}" => Ok(Up),
+    ///             "\u{2b07}\u{fe0f}" => Ok(Down),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let string = "\u{2b06}\u{fe0f}\u{2b07}\u{fe0f}\n\u{2b07}\u{fe0f}\u{2b06}\u{fe0f}";
+    /// assert_eq!(
+    ///     Grid::from_graphemes(string).unwrap(),
+    ///     Grid::from_data(vec![vec![Up, Down], vec![Down, Up]]).unwrap(),
+    /// );
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// # #[derive(Debug)]
+    /// # struct Arrow;
+    /// # impl TryFrom<&str> for Arrow {
+    /// #     type Error = ();
+    /// #
+    /// #     fn try_from(value: &str) -> Result<Self, Self::Error> {
+    /// #         (value == "\u{2b06}\u{fe0f}").then_some(Arrow).ok_or(())
+    /// #     }
+    /// # }
+    /// // An arrow without the variation selector
+    /// assert_eq!(
+    ///     Grid::<Arrow>::from_graphemes("\u{2b06}\u{fe0f}\u{2b06}").unwrap_err().to_string(),
+    ///     "Other error: Invalid character \"\u{2b06}\" at line 1, column 2",
+    /// );
+    /// ```
+    pub fn from_graphemes(s: &str) -> AocResult<Self> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        Self::from_data(parse_rows(s, |line| line.graphemes(true))?)
+    }
+}
+
+/// Parses the rows of elements of a grid from a string with each row on a separate line,
+/// in which each row is split into the text of its elements by a `split` function.
+///
+/// This is an internal function of the [`FromStr`] implementation for [`Grid`].
+/// The error for an invalid element includes its line and column, both of which start
+/// from one, where the column counts elements rather than bytes.
+fn parse_rows<'a, C: Copy + fmt::Debug, I: Iterator<Item = C>, T: TryFrom<C>>(
+    s: &'a str,
+    split: impl Fn(&'a str) -> I,
+) -> AocResult<Vec<Vec<T>>> {
+    s.lines()
+        .enumerate()
+        .map(|(y, line)| {
+            split(line)
+                .enumerate()
+                .map(|(x, cell)| {
+                    T::try_from(cell).map_err(|_| {
+                        AocError::Other(
+                            format!(
+                                "Invalid character {cell:?} at line {}, column {}",
+                                y + 1,
+                                x + 1
+                            )
+                            .into(),
+                        )
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Conversions to and from [`ndarray`] arrays, for numeric operations on whole grids.
+///
+/// Arrays are indexed by the row and then the column, that is `y` and then `x`, so that
+/// each row of the grid is a row of the array, preserving the [`GridSpace`] orientation.
+/// These are only available with the `ndarray` feature.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use ndarray::{s, Array2};
+///
+/// let grid = Grid::<u8>::from_data(vec![vec![0, 1, 0], vec![0, 1, 0], vec![0, 1, 0]]).unwrap();
+/// let array = grid.to_ndarray();
+/// assert_eq!(array[[0, 1]], 1);
+///
+/// // Count the neighbors of each element by padding the array with zeros and
+/// // summing the eight shifted copies, which is a convolution.
+/// let (height, width) = array.dim();
+/// let mut padded = Array2::zeros((height + 2, width + 2));
+/// padded.slice_mut(s![1..=height, 1..=width]).assign(&array);
+/// let mut neighbors = Array2::<u8>::zeros((height, width));
+/// for dy in 0..3 {
+///     for dx in 0..3 {
+///         if (dx, dy) != (1, 1) {
+///             neighbors += &padded.slice(s![dy..dy + height, dx..dx + width]);
+///         }
+///     }
+/// }
+///
+/// assert_eq!(
+///     Grid::from_ndarray(&neighbors).unwrap(),
+///     Grid::from_data(vec![vec![2, 1, 2], vec![3, 2, 3], vec![2, 1, 2]]).unwrap(),
+/// );
+///
+/// // Grids can also be converted by value
+/// let array: Array2<u8> = grid.clone().into();
+/// assert_eq!(Grid::try_from(array).unwrap(), grid);
+/// ```
+#[cfg(feature = "ndarray")]
+impl<T: Clone, U> Grid<T, U> {
+    /// Returns a copy of the grid as a 2D array, see the [`Grid`] implementation.
+    pub fn to_ndarray(&self) -> ndarray::Array2<T> {
+        ndarray::Array2::from_shape_vec((self.size.height, self.size.width), self.data.to_vec())
+            .unwrap()
+    }
+
+    /// Creates a grid from a copy of a 2D `array`, see the [`Grid`] implementation.
+    ///
+    /// Returns an [`AocError::Other`] if the array is empty.
+    pub fn from_ndarray(array: &ndarray::Array2<T>) -> AocResult<Self> {
+        if array.is_empty() {
+            return Err(AocError::Other("The grid has no content!".into()));
+        }
+        Self::from_data(array.rows().into_iter().map(|row| row.to_vec()).collect())
+    }
+}
+#[cfg(feature = "ndarray")]
+impl<T, U> From<Grid<T, U>> for ndarray::Array2<T> {
+    fn from(value: Grid<T, U>) -> Self {
+        let shape = (value.size.height, value.size.width);
+        Self::from_shape_vec(shape, value.data.into_vec()).unwrap()
+    }
+}
+#[cfg(feature = "ndarray")]
+impl<T: Clone, U> TryFrom<ndarray::Array2<T>> for Grid<T, U> {
+    type Error = AocError;
+
+    fn try_from(value: ndarray::Array2<T>) -> Result<Self, Self::Error> {
+        Self::from_ndarray(&value)
+    }
+}
+
+/// Debug display for a [`Grid`] whose elements implement [`Debug`].
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = self.size();
+        for y in 0..size.height {
+            for x in 0..size.width {
+                write!(f, "{:?}", self.get(&GridPoint::new(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Create an object from default [`Grid`] of a particular size.
+///
+/// Automatically implemented for types that implement `From<Grid<T>>` for some
+/// appropriate `T` that itself implements [`Default`].
+pub trait GridDefault<T: Default + Clone>: From<Grid<T>> {
+    /// Returns a default object from a default [`Grid`] of some `size`.
+    ///
+    /// # Panics
+    /// This will panic if the `size` is invalid, that is either dimension is zero.
+    fn default(size: GridSize) -> Self {
+        Grid::default(size).into()
+    }
+}
+impl<T: Default + Clone, O: From<Grid<T>>> GridDefault<T> for O {
+    fn default(size: GridSize) -> Self {
+        Self::from(Grid::default(size))
+    }
+}
+
+/// Parse objects that can be created from a [`Grid`] from a grid of characters.
+///
+/// This is a blanket implementation on implementors of `From<Grid<T>>` for some `T`.
+/// Note that we cannot just blanket implement [`FromStr`] due to the orphan rule.
+pub trait FromGridStr<T>: Sized {
+    /// The error type if the conversion fails.
+    type Err;
+
+    /// Creates an object from a grid of characters.
+    ///
+    /// Refer to [`Grid::from_str`] for examples of how grids can be parsed from
+    /// strings. This returns an [`Err`] under the same conditions as
+    /// [`Grid::from_str`].
+    fn from_grid_str(s: &str) -> Result<Self, Self::Err>;
+}
+impl<T: TryFrom<char>, O: From<Grid<T>>> FromGridStr<T> for O {
+    type Err = AocError;
+
+    fn from_grid_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(<Grid<T> as FromStr>::from_str(s)?.into())
+    }
+}
+
+/// Standard boolean [`Grid`] element that can be converted from characters,
+/// where '.' is false and '#' is true.
+///
+/// This conversion allows grids of these elements to be parsed from strings using
+/// [`Grid::from_str`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// # use aoc::grid::StdBool;
+/// # use std::str::FromStr;
+/// let (t, f) = (true.into(), false.into());
+/// let string = ".#..
+/// #.##
+/// .#.#";
+/// let grid = Grid::<StdBool>::from_data(vec![
+///     vec![f, t, f, f],
+///     vec![t, f, t, t],
+///     vec![f, t, f, t],
+/// ]).unwrap();
+///
+/// assert_eq!(Grid::from_str(string).unwrap(), grid);
+/// ```
+#[derive(Deref, From, Into, Not, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StdBool(bool);
+impl TryFrom<char> for StdBool {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(true.into()),
+            '.' => Ok(false.into()),
+            _ => Err(()),
+        }
+    }
+}
+impl fmt::Debug for StdBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if **self { '#' } else { '.' })
+    }
+}
+
+/// Standard number digit [`Grid`] element that can be converted from characters,
+/// where the digits can be from `0` to `9`.
+///
+/// This conversion allows grids of these elements to be parsed from strings using
+/// [`Grid::from_str`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// # use aoc::grid::Digit;
+/// # use std::str::FromStr;
+/// let string = "01
+/// 23
+/// 45
+/// 67
+/// 89";
+/// let grid = Grid::<Digit>::from_data(vec![
+///     vec![0.into(), 1.into()],
+///     vec![2.into(), 3.into()],
+///     vec![4.into(), 5.into()],
+///     vec![6.into(), 7.into()],
+///     vec![8.into(), 9.into()],
+/// ]).unwrap();
+///
+/// assert_eq!(Grid::from_str(string).unwrap(), grid);
+/// ```
+#[derive(
+    Deref,
+    From,
+    Into,
+    Default,
+    Clone,
+    Copy,
+    Add,
+    Sub,
+    AddAssign,
+    SubAssign,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub struct Digit(u8);
+impl TryFrom<char> for Digit {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        value
+            .to_digit(10)
+            .map(|d| Self(d.try_into().unwrap()))
+            .ok_or(())
+    }
+}
+impl fmt::Debug for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", **self)
+    }
+}
+impl FromPrimitive for Digit {
+    fn from_i64(n: i64) -> Option<Self> {
+        u8::try_from(n).ok().map(|n| n.into())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        u8::try_from(n).ok().map(|n| n.into())
+    }
+}
+
+/// The ID of a basin found by [`Grid::watershed_basins`], which is its index in the list of
+/// basins.
+pub type BasinId = usize;
+
+/// The basins of a height map found by [`Grid::watershed_basins`].
+#[derive(Debug, Clone)]
+pub struct Basins<U = GridSpace> {
+    /// The basin to which each point belongs, or `None` for points on the boundaries
+    /// between basins.
+    pub labels: Grid<Option<BasinId>, U>,
+    /// The number of points in each basin.
+    pub sizes: Vec<usize>,
+}
+
+// Methods for height maps of digits.
+impl<U> Grid<Digit, U> {
+    /// Returns an [`Iterator`] over the local minima of the height map in row-major order,
+    /// which are the points that are lower than all of their orthogonal neighbors.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::Digit;
+    /// use itertools::Itertools;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::<Digit>::from_str(
+    ///     "2199943210
+    /// 3987894921
+    /// 9856789892
+    /// 8767896789
+    /// 9899965678",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.local_minima().collect_vec(),
+    ///     vec![
+    ///         GridPoint::new(1, 0),
+    ///         GridPoint::new(9, 0),
+    ///         GridPoint::new(2, 2),
+    ///         GridPoint::new(6, 4),
+    ///     ],
+    /// );
+    /// ```
+    pub fn local_minima(&self) -> impl Iterator<Item = GridPoint<U>> + '_ {
+        self.all_points().filter(|point| {
+            let height = self.get(point);
+            self.neighbor_points(point, false, false)
+                .all(|p| height < self.get(&p))
+        })
+    }
+
+    /// Divides the height map into basins, which are the regions of orthogonally connected
+    /// points that are separated by points of the `boundary_value`.
+    ///
+    /// The basins are labeled in the row-major order of their first points.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::Digit;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::<Digit>::from_str(
+    ///     "2199943210
+    /// 3987894921
+    /// 9856789892
+    /// 8767896789
+    /// 9899965678",
+    /// )
+    /// .unwrap();
+    /// let basins = grid.watershed_basins(9);
+    ///
+    /// assert_eq!(basins.sizes, vec![3, 9, 14, 9]);
+    /// assert_eq!(basins.labels.get(&GridPoint::new(0, 0)), &Some(0));
+    /// assert_eq!(basins.labels.get(&GridPoint::new(2, 0)), &None);
+    /// assert_eq!(basins.labels.get(&GridPoint::new(9, 1)), &Some(1));
+    /// ```
+    pub fn watershed_basins(&self, boundary_value: u8) -> Basins<U> {
+        let mut labels = Grid::<Option<BasinId>, U>::default(self.size);
+        let mut sizes = Vec::new();
+
+        for point in self.all_points() {
+            if labels.get(&point).is_some() || **self.get(&point) == boundary_value {
+                continue;
+            }
+
+            let region = self.flood_fill(&point, false, |h| **h != boundary_value);
+            for p in region.iter() {
+                labels.set(p, Some(sizes.len()));
+            }
+            sizes.push(region.len());
+        }
+
+        Basins { labels, sizes }
+    }
+}
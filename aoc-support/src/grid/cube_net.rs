@@ -0,0 +1,328 @@
+//! Walking on the surface of a cube that is unfolded into a net of square faces.
+//!
+//! The net is laid out in a grid of faces, for example the following net has six square
+//! faces that fold up into a cube.
+//! ```text
+//!   #
+//! ###
+//!   ##
+//! ```
+//! A [`CubeNet`] determines how the faces of any valid net fold together, so that a
+//! point on the net can be moved across the edges between faces on the folded cube,
+//! even when the faces are not adjacent in the net.
+//!
+//! Points on the net are in the coordinates of a map [`Grid`] in which each face
+//! is a square of elements, which is how problems usually present cube nets.
+
+use super::{Grid, GridPoint, GridSizeExt};
+use crate::{
+    collections::HashMap,
+    direction::Direction4,
+    error::{AocError, AocResult},
+};
+use euclid::default::Vector3D;
+
+/// The orientation of a face of the folded cube.
+///
+/// The vectors are unit vectors along the axes, where the cube is centered on the
+/// origin.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// The outward normal of the face.
+    normal: Vector3D<isize>,
+    /// The direction of increasing `x` on the face in the net.
+    right: Vector3D<isize>,
+    /// The direction of increasing `y` on the face in the net.
+    down: Vector3D<isize>,
+}
+impl Face {
+    /// Returns the vector on the cube of a `direction` on this face in the net.
+    fn vector(&self, direction: Direction4) -> Vector3D<isize> {
+        match direction {
+            Direction4::Up => -self.down,
+            Direction4::Right => self.right,
+            Direction4::Down => self.down,
+            Direction4::Left => -self.right,
+        }
+    }
+
+    /// Returns the orientation of the face adjacent to this one in a `direction`
+    /// in the net, as folded on the cube.
+    fn fold(&self, direction: Direction4) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+        } = *self;
+        match direction {
+            Direction4::Up => Self::new(-down, right, normal),
+            Direction4::Right => Self::new(right, -normal, down),
+            Direction4::Down => Self::new(down, right, -normal),
+            Direction4::Left => Self::new(-right, normal, down),
+        }
+    }
+
+    /// Creates a new face orientation.
+    fn new(normal: Vector3D<isize>, right: Vector3D<isize>, down: Vector3D<isize>) -> Self {
+        Self {
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+/// A net of six square faces that fold up into a cube.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::grid::cube_net::CubeNet;
+///
+/// let map = Grid::<char>::from_data(
+///     [
+///         "        ...#    ",
+///         "        .#..    ",
+///         "        #...    ",
+///         "        ....    ",
+///         "...#.......#    ",
+///         "........#...    ",
+///         "..#....#....    ",
+///         "..........#.    ",
+///         "        ...#....",
+///         "        .....#..",
+///         "        .#......",
+///         "        ......#.",
+///     ]
+///     .into_iter()
+///     .map(|row| row.chars().collect())
+///     .collect(),
+/// )
+/// .unwrap();
+/// let net = CubeNet::from_map(&map, |c| *c != ' ').unwrap();
+/// assert_eq!(net.face_size(), 4);
+/// assert_eq!(net.faces().count(), 6);
+///
+/// // Faces that are not adjacent in the net can be adjacent on the cube
+/// assert_eq!(
+///     net.neighbor(&GridPoint::new(2, 1), Direction4::Right),
+///     (GridPoint::new(3, 2), Direction4::Down),
+/// );
+///
+/// // Moving within a face or to an adjacent face in the net is just a step
+/// assert_eq!(
+///     net.step(&GridPoint::new(5, 5), Direction4::Right),
+///     (GridPoint::new(6, 5), Direction4::Right),
+/// );
+/// assert_eq!(
+///     net.step(&GridPoint::new(7, 5), Direction4::Right),
+///     (GridPoint::new(8, 5), Direction4::Right),
+/// );
+///
+/// // Otherwise the edge is crossed as on the folded cube
+/// assert_eq!(
+///     net.step(&GridPoint::new(11, 5), Direction4::Right),
+///     (GridPoint::new(14, 8), Direction4::Down),
+/// );
+/// assert_eq!(
+///     net.step(&GridPoint::new(10, 11), Direction4::Down),
+///     (GridPoint::new(1, 7), Direction4::Up),
+/// );
+/// assert_eq!(
+///     net.step(&GridPoint::new(6, 4), Direction4::Up),
+///     (GridPoint::new(8, 2), Direction4::Right),
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::cube_net::CubeNet;
+///
+/// // The faces overlap when folded
+/// let layout = Grid::from_data(vec![vec![true; 6]]).unwrap();
+/// assert!(CubeNet::new(&layout, 3).is_err());
+///
+/// // The faces are not connected
+/// let layout = Grid::from_data(vec![
+///     vec![true, true, true, false],
+///     vec![false, false, false, false],
+///     vec![false, true, true, true],
+/// ])
+/// .unwrap();
+/// assert!(CubeNet::new(&layout, 3).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CubeNet {
+    /// The length of the sides of each face in elements of the map.
+    face_size: usize,
+    /// The orientation on the cube of each face keyed on its location in the layout.
+    faces: HashMap<GridPoint, Face>,
+}
+impl CubeNet {
+    /// Creates a net from the `layout` of its faces, in which `true` elements are faces,
+    /// where each face is a square of elements with sides of length `face_size` in the map.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the `face_size` is zero or the faces are not
+    /// a valid net, that is six connected faces that do not overlap when folded.
+    pub fn new(layout: &Grid<bool>, face_size: usize) -> AocResult<Self> {
+        let invalid =
+            |reason: &str| AocError::InvalidInput(format!("Invalid cube net: {reason}").into());
+        if face_size == 0 {
+            return Err(invalid("the faces have no size"));
+        }
+
+        let Some(start) = layout.all_points().find(|p| *layout.get(p)) else {
+            return Err(invalid("there are no faces"));
+        };
+
+        // Fold up the faces by walking over the layout from the first face
+        let mut faces = HashMap::default();
+        faces.insert(
+            start,
+            Face::new(
+                Vector3D::new(0, 0, 1),
+                Vector3D::new(1, 0, 0),
+                Vector3D::new(0, 1, 0),
+            ),
+        );
+        let mut stack = vec![start];
+        while let Some(point) = stack.pop() {
+            let face = faces[&point];
+            for direction in Direction4::all() {
+                let Some(next) = layout.step(&point, direction).filter(|p| *layout.get(p)) else {
+                    continue;
+                };
+                if !faces.contains_key(&next) {
+                    faces.insert(next, face.fold(direction));
+                    stack.push(next);
+                }
+            }
+        }
+
+        if faces.len() != layout.all_values().filter(|f| **f).count() {
+            return Err(invalid("the faces are not connected"));
+        }
+        if faces.len() != 6 {
+            return Err(invalid("there are not six faces"));
+        }
+        if faces
+            .values()
+            .any(|a| faces.values().filter(|b| b.normal == a.normal).count() > 1)
+        {
+            return Err(invalid("the faces overlap when folded"));
+        }
+
+        Ok(Self { face_size, faces })
+    }
+
+    /// Creates a net from a `map` in which the elements for which `is_face` returns `true`
+    /// are on the faces, making up six squares of equal size.
+    ///
+    /// The size of the faces is determined from the number of elements on the faces.
+    /// Returns [`AocError::InvalidInput`] if the faces cannot be of equal size or are not a
+    /// valid net, see [`CubeNet::new`].
+    pub fn from_map<T>(map: &Grid<T>, is_face: impl Fn(&T) -> bool) -> AocResult<Self> {
+        let count = map.all_values().filter(|v| is_face(v)).count();
+        let face_size = num::integer::sqrt(count / 6);
+        let layout_size = match map.size().divided_exact(face_size) {
+            Some(size) if 6 * face_size * face_size == count => size,
+            _ => {
+                return Err(AocError::InvalidInput(
+                    "Invalid cube net: the faces are not six squares of equal size".into(),
+                ))
+            }
+        };
+
+        let mut layout = Grid::default(layout_size);
+        for point in layout.all_points() {
+            layout.set(&point, is_face(map.get(&(point * face_size))));
+        }
+        Self::new(&layout, face_size)
+    }
+
+    /// Returns the length of the sides of each face in elements of the map.
+    pub fn face_size(&self) -> usize {
+        self.face_size
+    }
+
+    /// Returns an [`Iterator`] over the locations of the faces in the layout.
+    pub fn faces(&self) -> impl Iterator<Item = GridPoint> + '_ {
+        self.faces.keys().copied()
+    }
+
+    /// Returns the location in the layout of the face that contains a `point` of the map,
+    /// if it is on a face.
+    pub fn face_of(&self, point: &GridPoint) -> Option<GridPoint> {
+        let face = *point / self.face_size;
+        self.faces.contains_key(&face).then_some(face)
+    }
+
+    /// Returns the face reached by crossing the edge of a `face` in the layout while
+    /// heading in some `direction`, along with the direction in which it is entered.
+    ///
+    /// # Panics
+    /// This will panic if there is no `face` at this location in the layout.
+    pub fn neighbor(&self, face: &GridPoint, direction: Direction4) -> (GridPoint, Direction4) {
+        let from = self.face(face);
+        let heading = from.vector(direction);
+        let (to, face) = self
+            .faces
+            .iter()
+            .find(|(_, f)| f.normal == heading)
+            .unwrap();
+        let direction = Direction4::all()
+            .into_iter()
+            .find(|d| face.vector(*d) == -from.normal)
+            .unwrap();
+        (*to, direction)
+    }
+
+    /// Moves a `point` of the map by one element while heading in some `direction`,
+    /// returning the new point and the new heading, which only changes when crossing
+    /// an edge to a face that is not adjacent in the net.
+    ///
+    /// # Panics
+    /// This will panic if the `point` is not on a face.
+    pub fn step(&self, point: &GridPoint, direction: Direction4) -> (GridPoint, Direction4) {
+        let size = self.face_size;
+        let size_i = isize::try_from(size).unwrap();
+        let face = *point / size;
+        let from = self.face(&face);
+
+        // Stay on the same face if possible
+        let local = point.to_isize() - (face * size).to_isize() + direction.as_vector();
+        if (0..size_i).contains(&local.x) && (0..size_i).contains(&local.y) {
+            return (face * size + local.to_usize(), direction);
+        }
+
+        // Otherwise cross the edge on the cube, where positions of the element centers
+        // are doubled so that they are integers, with the cube spanning from minus the
+        // face size to the face size in every axis
+        let offset = |v: usize| 2 * isize::try_from(v % size).unwrap() + 1 - size_i;
+        let position =
+            from.normal * size_i + from.right * offset(point.x) + from.down * offset(point.y);
+        let position = position + from.vector(direction) - from.normal;
+
+        let (to, direction) = self.neighbor(&face, direction);
+        let to_face = self.faces[&to];
+        let local =
+            |axis: Vector3D<isize>| usize::try_from((position.dot(axis) + size_i - 1) / 2).unwrap();
+        (
+            to * size + GridPoint::new(local(to_face.right), local(to_face.down)).to_vector(),
+            direction,
+        )
+    }
+
+    /// Returns the orientation of a `face` in the layout.
+    ///
+    /// # Panics
+    /// This will panic if there is no `face` at this location in the layout.
+    fn face(&self, face: &GridPoint) -> &Face {
+        self.faces
+            .get(face)
+            .unwrap_or_else(|| panic!("there is no face at {face:?} in the cube net"))
+    }
+}
@@ -0,0 +1,279 @@
+//! Generation of random grids for testing algorithms on grids.
+//!
+//! This is only available with the `test-util` feature, which is always enabled for
+//! the tests of this crate.
+//! The generated grids are `Grid<bool>`s in which `true` elements are open and
+//! `false` elements are walls.
+//! In mazes and terrain every open element can be reached from every other open element
+//! by moving horizontally and vertically.
+//! The same seed always generates the same grid so that test failures are reproducible.
+//!
+//! There are also [`proptest`] strategies for grid sizes, points, and grids
+//! for use in property tests.
+
+use super::{Grid, GridPoint, GridSize};
+use crate::{collections::HashSet, math::SplitMix};
+use proptest::prelude::*;
+
+/// Generates a random maze of some `size` from a `seed`.
+///
+/// The open elements of the maze form a tree of passages, so that there is exactly one
+/// path between any two open points, and point `(0, 0)` is always open.
+/// Open points have even coordinates or lie between two such points, so the bottom and
+/// right edges are all walls when the size is even in that dimension.
+///
+/// # Panics
+/// This will panic if the `size` is empty.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::{is_connected, maze};
+///
+/// let size = GridSize::new(9, 7);
+/// let grid = maze(size, 44);
+///
+/// assert_eq!(grid, maze(size, 44));
+/// assert!(*grid.get(&GridPoint::origin()));
+/// assert!(is_connected(&grid));
+///
+/// // As a tree of passages there must be one more open point than passages.
+/// let open = grid.as_coordinates();
+/// let passages = open
+///     .iter()
+///     .filter(|p| p.x % 2 == 1 || p.y % 2 == 1)
+///     .count();
+/// assert_eq!(open.len(), 2 * passages + 1);
+/// ```
+pub fn maze(size: GridSize, seed: u64) -> Grid<bool> {
+    assert!(!size.is_empty(), "cannot generate a maze with no elements");
+
+    let mut rng = SplitMix::new(seed);
+    let mut grid = Grid::<bool>::default(size);
+
+    // Carve passages using a randomized depth-first search over the cells at even coordinates
+    let mut stack = vec![GridPoint::origin()];
+    grid.set(&GridPoint::origin(), true);
+    while let Some(cell) = stack.last().copied() {
+        let unvisited = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let next = GridPoint::new(
+                    cell.x.checked_add_signed(dx)?,
+                    cell.y.checked_add_signed(dy)?,
+                );
+                (next.x < size.width && next.y < size.height && !grid.get(&next)).then_some(next)
+            })
+            .collect::<Vec<_>>();
+
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = unvisited[rng.below(unvisited.len())];
+        grid.set(&((cell + next.to_vector()) / 2), true);
+        grid.set(&next, true);
+        stack.push(next);
+    }
+
+    grid
+}
+
+/// Generates random terrain of some `size` from a `seed`, which is a [`maze`] with
+/// some of its walls removed so that there are many paths between points.
+///
+/// Each wall is removed with approximately some `openness` probability, where zero
+/// generates a maze and one generates a grid that is mostly open.
+/// Walls are only removed next to open points, so that all open points remain connected.
+///
+/// # Panics
+/// This will panic if the `size` is empty.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::{is_connected, maze, terrain};
+///
+/// let size = GridSize::new(20, 10);
+/// let grid = terrain(size, 7, 0.5);
+///
+/// assert!(is_connected(&grid));
+/// assert!(grid.as_coordinates().len() > maze(size, 7).as_coordinates().len());
+/// assert_eq!(terrain(size, 7, 0.0), maze(size, 7));
+/// ```
+pub fn terrain(size: GridSize, seed: u64, openness: f64) -> Grid<bool> {
+    let mut grid = maze(size, seed);
+    let mut rng = SplitMix::new(!seed);
+
+    for point in grid.all_points() {
+        if !grid.get(&point)
+            && rng.chance(openness)
+            && grid
+                .neighbor_points(&point, false, false)
+                .any(|p| *grid.get(&p))
+        {
+            grid.set(&point, true);
+        }
+    }
+
+    grid
+}
+
+/// Returns whether every open element of a `grid` can be reached from every other
+/// open element by moving horizontally and vertically.
+///
+/// A grid with no open elements is considered to be connected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::is_connected;
+///
+/// let grid = Grid::from_data(vec![
+///     vec![true, true, false],
+///     vec![false, true, false],
+///     vec![false, true, true],
+/// ])
+/// .unwrap();
+/// assert!(is_connected(&grid));
+///
+/// let grid = Grid::from_data(vec![vec![true, false, true]]).unwrap();
+/// assert!(!is_connected(&grid));
+/// ```
+pub fn is_connected(grid: &Grid<bool>) -> bool {
+    let open = grid.as_coordinates();
+    let Some(start) = open.iter().next() else {
+        return true;
+    };
+
+    let mut reached = HashSet::default();
+    reached.insert(*start);
+    let mut stack = vec![*start];
+    while let Some(point) = stack.pop() {
+        for next in grid.neighbor_points(&point, false, false) {
+            if *grid.get(&next) && reached.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    reached.len() == open.len()
+}
+
+/// Returns a [`proptest`] strategy for non-empty grid sizes with dimensions up to `max`.
+pub fn grid_size(max: usize) -> impl Strategy<Value = GridSize> {
+    (1..=max, 1..=max).prop_map(|(width, height)| GridSize::new(width, height))
+}
+
+/// Returns a [`proptest`] strategy for points within a grid of some `size`.
+pub fn grid_point(size: GridSize) -> impl Strategy<Value = GridPoint> {
+    (0..size.width, 0..size.height).prop_map(|(x, y)| GridPoint::new(x, y))
+}
+
+/// Returns a [`proptest`] strategy for grids with dimensions up to `max`, in which each
+/// element is independently open or a wall, so that the open elements need not be
+/// connected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::{grid_point, is_connected, random_grid};
+/// use proptest::prelude::*;
+///
+/// // The open points are connected exactly when there is a path between every pair
+/// proptest!(|(grid in random_grid(6))| {
+///     let open = grid.as_coordinates();
+///     if let Some(start) = open.iter().next() {
+///         let paths = open
+///             .iter()
+///             .all(|end| grid.climbing_shortest_path(start, end, |_, to| *to).is_some());
+///         let region = grid.flood_fill(start, false, |v| *v);
+///
+///         prop_assert_eq!(paths, is_connected(&grid));
+///         prop_assert_eq!(region.len() == open.len(), is_connected(&grid));
+///     }
+/// });
+///
+/// // Flood filling from any point only reaches open points, and none if it is a wall
+/// let grids = random_grid(8).prop_flat_map(|grid| {
+///     let size = *grid.size();
+///     (Just(grid), grid_point(size))
+/// });
+/// proptest!(|((grid, start) in grids)| {
+///     let region = grid.flood_fill(&start, false, |v| *v);
+///
+///     prop_assert!(region.iter().all(|p| *grid.get(p)));
+///     prop_assert_eq!(region.is_empty(), !*grid.get(&start));
+/// });
+/// ```
+pub fn random_grid(max: usize) -> impl Strategy<Value = Grid<bool>> {
+    grid_size(max).prop_flat_map(|size| {
+        proptest::collection::vec(any::<bool>(), size.width * size.height).prop_map(move |values| {
+            Grid::from_data(values.chunks(size.width).map(<[bool]>::to_vec).collect()).unwrap()
+        })
+    })
+}
+
+/// Returns a [`proptest`] strategy for mazes with dimensions up to `max`, see [`maze`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::maze_grid;
+/// use proptest::prelude::*;
+///
+/// // Every open point can be reached from the origin, which is always open
+/// proptest!(|(grid in maze_grid(15))| {
+///     let region = grid.flood_fill(&GridPoint::origin(), false, |v| *v);
+///     prop_assert_eq!(region.len(), grid.as_coordinates().len());
+/// });
+/// ```
+pub fn maze_grid(max: usize) -> impl Strategy<Value = Grid<bool>> {
+    (grid_size(max), any::<u64>()).prop_map(|(size, seed)| maze(size, seed))
+}
+
+/// Returns a [`proptest`] strategy for terrain with dimensions up to `max`, see [`terrain`].
+pub fn terrain_grid(max: usize) -> impl Strategy<Value = Grid<bool>> {
+    (grid_size(max), any::<u64>(), 0.0..=1.0)
+        .prop_map(|(size, seed, openness)| terrain(size, seed, openness))
+}
+
+/// Returns a [`proptest`] strategy for terrain with dimensions up to `max` along
+/// with two of its open points, see [`terrain`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::test_util::terrain_with_endpoints;
+/// use proptest::prelude::*;
+///
+/// proptest!(|((grid, start, end) in terrain_with_endpoints(12))| {
+///     prop_assert!(*grid.get(&start));
+///     prop_assert!(*grid.get(&end));
+///
+///     // The terrain is connected, so there is always a path that is no shorter than
+///     // the Manhattan distance between the points
+///     let steps = grid.climbing_shortest_path(&start, &end, |_, to| *to).unwrap();
+///     prop_assert!(steps >= start.x.abs_diff(end.x) + start.y.abs_diff(end.y));
+///     prop_assert_eq!(steps == 0, start == end);
+/// });
+/// ```
+pub fn terrain_with_endpoints(
+    max: usize,
+) -> impl Strategy<Value = (Grid<bool>, GridPoint, GridPoint)> {
+    terrain_grid(max).prop_flat_map(|grid| {
+        let open = grid
+            .all_points()
+            .filter(|p| *grid.get(p))
+            .collect::<Vec<_>>();
+        let points = proptest::sample::select(open);
+        (Just(grid), points.clone(), points)
+    })
+}
@@ -0,0 +1,237 @@
+//! Shortest path searches through grids in which movement is constrained.
+//!
+//! Some problems constrain how a mover can move through a grid based on its
+//! recent moves, for example limiting how far it can move in a straight line.
+//! The best path to a point then depends on more than just the point, so the search
+//! must be performed over augmented [`MoveState`]s.
+//! The [`constrained_astar`] function manages this, with the constraints, costs, and
+//! goal being supplied by implementing the [`MoveRules`] trait.
+
+use crate::{
+    collections::HashMap,
+    error::{AocError, AocResult},
+    grid::{Digit, Grid, GridPoint, GridSpace},
+};
+use euclid::Vector2D;
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// A vector for a move in a grid.
+pub type GridVector = Vector2D<isize, GridSpace>;
+
+/// The state of a mover in a grid, which is its current point augmented with
+/// information about its recent moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoveState {
+    /// The current point of the mover.
+    pub point: GridPoint,
+    /// The unit vector of the last move, or `None` if no moves have been made.
+    pub direction: Option<GridVector>,
+    /// The number of consecutive moves made in the current direction.
+    pub run: usize,
+}
+impl MoveState {
+    /// Creates the state for a mover at a `point` that has not moved yet.
+    pub fn start(point: GridPoint) -> Self {
+        Self {
+            point,
+            direction: None,
+            run: 0,
+        }
+    }
+
+    /// Returns the new state after moving to a `point` in a unit vector `direction`.
+    fn moved(&self, point: GridPoint, direction: GridVector) -> Self {
+        Self {
+            point,
+            direction: Some(direction),
+            run: if self.direction == Some(direction) {
+                self.run + 1
+            } else {
+                1
+            },
+        }
+    }
+}
+
+/// The rules for moving through a [`Grid`] with elements of type `T`, as needed
+/// by [`constrained_astar`].
+pub trait MoveRules<T> {
+    /// Returns whether a move from a `state` in a unit vector `direction` is allowed.
+    fn allowed(&self, state: &MoveState, direction: &GridVector) -> bool;
+
+    /// Returns the cost of moving into a grid element with some `value`.
+    fn cost(&self, value: &T) -> u64;
+
+    /// Returns whether a `state` is a goal, at which the search ends.
+    fn is_goal(&self, state: &MoveState) -> bool;
+
+    /// Returns an estimate of the cost from a `state` to the nearest goal, which is
+    /// zero by default.
+    ///
+    /// For the search to find the best path, the estimate must never exceed the actual
+    /// cost, and, moreover, must not decrease by more than the cost of any move.
+    fn heuristic(&self, _state: &MoveState) -> u64 {
+        0
+    }
+}
+
+/// The standard [`MoveRules`] for a mover that cannot reverse and that has minimum
+/// and maximum lengths of its straight runs, moving through a grid of costs.
+///
+/// The mover can only reach the `goal` at the end of a run with at least the minimum
+/// length. The Manhattan distance to the goal is used as the heuristic, which assumes
+/// that every cost is at least one.
+#[derive(Debug, Clone, Copy)]
+pub struct StraightRunRules {
+    /// The minimum number of moves in a straight line before the mover can turn.
+    pub min_run: usize,
+    /// The maximum number of moves in a straight line.
+    pub max_run: usize,
+    /// The goal point.
+    pub goal: GridPoint,
+}
+impl MoveRules<Digit> for StraightRunRules {
+    fn allowed(&self, state: &MoveState, direction: &GridVector) -> bool {
+        match state.direction {
+            None => true,
+            Some(d) if d == *direction => state.run < self.max_run,
+            Some(d) if d == -*direction => false,
+            Some(_) => state.run >= self.min_run,
+        }
+    }
+
+    fn cost(&self, value: &Digit) -> u64 {
+        (**value).into()
+    }
+
+    fn is_goal(&self, state: &MoveState) -> bool {
+        state.point == self.goal && state.run >= self.min_run
+    }
+
+    fn heuristic(&self, state: &MoveState) -> u64 {
+        (self.goal.x.abs_diff(state.point.x) + self.goal.y.abs_diff(state.point.y))
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// A state that is open for exploration in a [`constrained_astar`] search.
+///
+/// These are ordered in reverse by the estimated total cost so that a
+/// [`BinaryHeap`] of them yields the most promising state first.
+struct OpenState {
+    /// The cost to this state plus the heuristic estimate to a goal.
+    estimate: u64,
+    /// The cost to this state.
+    cost: u64,
+    /// The state itself.
+    state: MoveState,
+}
+impl PartialEq for OpenState {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+impl Eq for OpenState {}
+impl PartialOrd for OpenState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for OpenState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// Returns the least cost of moving from a `start` point in a `grid` to a goal according
+/// to some `rules`, using the [A* search algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+///
+/// The cost of the starting element is not included.
+/// Returns [`AocError::NoSolution`] if no goal can be reached.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::grid::Digit;
+/// use aoc::grid_search::{constrained_astar, StraightRunRules};
+/// use std::str::FromStr;
+///
+/// let grid = Grid::<Digit>::from_str(
+///     "2413432311323
+/// 3215453535623
+/// 3255245654254
+/// 3446585845452
+/// 4546657867536
+/// 1438598798454
+/// 4457876987766
+/// 3637877979653
+/// 4654967986887
+/// 4564679986453
+/// 1224686865563
+/// 2546548887735
+/// 4322674655533",
+/// )
+/// .unwrap();
+/// let goal = GridPoint::new(12, 12);
+///
+/// let rules = StraightRunRules { min_run: 1, max_run: 3, goal };
+/// assert_eq!(constrained_astar(&grid, GridPoint::origin(), &rules), Ok(102));
+///
+/// let rules = StraightRunRules { min_run: 4, max_run: 10, goal };
+/// assert_eq!(constrained_astar(&grid, GridPoint::origin(), &rules), Ok(94));
+/// ```
+pub fn constrained_astar<T>(
+    grid: &Grid<T>,
+    start: GridPoint,
+    rules: &impl MoveRules<T>,
+) -> AocResult<u64> {
+    let start = MoveState::start(start);
+    let mut best_costs = HashMap::from_iter([(start, 0)]);
+    let mut open = BinaryHeap::from([OpenState {
+        estimate: rules.heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+
+    while let Some(OpenState { cost, state, .. }) = open.pop() {
+        if rules.is_goal(&state) {
+            return Ok(cost);
+        }
+
+        // Skip states for which a better path has since been found
+        if best_costs.get(&state).is_some_and(|best| *best < cost) {
+            continue;
+        }
+
+        for direction in [
+            GridVector::new(1, 0),
+            GridVector::new(0, 1),
+            GridVector::new(-1, 0),
+            GridVector::new(0, -1),
+        ] {
+            if !rules.allowed(&state, &direction) {
+                continue;
+            }
+            let Some(point) =
+                grid.bounded_point(&(state.point.try_cast::<isize>().unwrap() + direction))
+            else {
+                continue;
+            };
+
+            let next = state.moved(point, direction);
+            let next_cost = cost + rules.cost(grid.get(&point));
+            if !best_costs.get(&next).is_some_and(|best| *best <= next_cost) {
+                best_costs.insert(next, next_cost);
+                open.push(OpenState {
+                    estimate: next_cost + rules.heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    Err(AocError::NoSolution)
+}
@@ -0,0 +1,70 @@
+//! Loading of the example inputs of problems from files.
+//!
+//! Long example inputs make the tests of a solution hard to read when they are string
+//! literals, so they can instead be kept in text files under the [`EXAMPLES_DIR`]
+//! directory, in a sub-directory for each year.
+//! By convention the `n`th example of a day is in a file named like `day_05_2.txt`,
+//! see [`example_name`], and the [`solution_tests`](crate::solution_tests) macro loads
+//! example files given as `input_file = "...";` instead of `input = ...;`.
+
+use crate::solution::{Day, Year};
+use anyhow::Context;
+use std::fs;
+
+/// The directory containing the example input files, relative to the workspace.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Returns the conventional file name of the `n`th example, counting from one, of the
+/// problem of a `day`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::{input::example_name, solution::Day};
+///
+/// assert_eq!(example_name(Day::new(5).unwrap(), 2), "day_05_2.txt");
+/// ```
+pub fn example_name(day: Day, n: usize) -> String {
+    format!("day_{:02}_{n}.txt", day.get())
+}
+
+/// Returns the path of the example input file with a `name` for a problem in some `year`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::{input::example_path, solution::Year};
+///
+/// assert_eq!(
+///     example_path(Year::new(2022).unwrap(), "day_10_2.txt"),
+///     "examples/2022/day_10_2.txt"
+/// );
+/// ```
+pub fn example_path(year: Year, name: &str) -> String {
+    format!("{EXAMPLES_DIR}/{year}/{name}")
+}
+
+/// Reads the example input file with a `name` for a problem in some `year`.
+///
+/// A single line ending at the end of the file is removed, so that the input is the
+/// same as the string literal that an example would otherwise be written as.
+pub fn example_file(year: Year, name: &str) -> anyhow::Result<String> {
+    let path = example_path(year, name);
+    let mut input = fs::read_to_string(&path)
+        .with_context(|| format!("Could not read example input file {path}"))?;
+    if input.ends_with('\n') {
+        input.pop();
+        if input.ends_with('\r') {
+            input.pop();
+        }
+    }
+    Ok(input)
+}
+
+/// Reads the `n`th example input, counting from one, of the problem of a `day` in some
+/// `year`, from its conventionally named file, see [`example_name`].
+pub fn example(year: Year, day: Day, n: usize) -> anyhow::Result<String> {
+    example_file(year, &example_name(day, n))
+}
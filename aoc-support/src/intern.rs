@@ -0,0 +1,134 @@
+//! Interning of string labels as small integer IDs.
+//!
+//! Graph problems often identify nodes by short labels, such as `AA` or `bqr`.
+//! Hashing these strings at every step of a search is relatively slow, so the
+//! [`LabelMap`] assigns each label a dense ID with which nodes can instead be
+//! looked up in [`Vec`]s, for example in the adjacency lists created by
+//! [`LabelMap::adjacency`].
+
+use std::collections::HashMap;
+
+/// A bidirectional map between string labels and dense [`u16`] IDs.
+///
+/// Each new label is assigned the next ID when it is first interned, starting from zero.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::intern::LabelMap;
+///
+/// let mut labels = LabelMap::new();
+/// assert_eq!(labels.intern("AA"), 0);
+/// assert_eq!(labels.intern("BB"), 1);
+/// assert_eq!(labels.intern("AA"), 0);
+///
+/// assert_eq!(labels.len(), 2);
+/// assert_eq!(labels.get("BB"), Some(1));
+/// assert_eq!(labels.get("CC"), None);
+/// assert_eq!(labels.label(1), "BB");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LabelMap {
+    /// Map of labels to their IDs.
+    ids: HashMap<String, u16>,
+    /// The labels indexed by their IDs.
+    labels: Vec<String>,
+}
+impl LabelMap {
+    /// Creates a new map with no labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ID of a `label`, assigning it the next ID if it is new.
+    ///
+    /// # Panics
+    /// This will panic if there are already as many labels as there are [`u16`] IDs.
+    pub fn intern(&mut self, label: &str) -> u16 {
+        if let Some(id) = self.ids.get(label) {
+            return *id;
+        }
+
+        let id = u16::try_from(self.labels.len()).expect("too many labels to intern");
+        self.ids.insert(label.to_string(), id);
+        self.labels.push(label.to_string());
+        id
+    }
+
+    /// Returns the ID of a `label`, or `None` if it has not been interned.
+    pub fn get(&self, label: &str) -> Option<u16> {
+        self.ids.get(label).copied()
+    }
+
+    /// Returns the label with an `id`.
+    ///
+    /// # Panics
+    /// This will panic if no label has the `id`.
+    pub fn label(&self, id: u16) -> &str {
+        &self.labels[usize::from(id)]
+    }
+
+    /// Returns the number of labels.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns whether there are no labels.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns an [`Iterator`] over the labels in the order of their IDs.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.labels.iter().map(|s| s.as_str())
+    }
+
+    /// Interns the labels of some directed `edges`, returning the adjacency lists
+    /// of the graph indexed by ID.
+    ///
+    /// The adjacency lists include every label in this map, including any that
+    /// were interned beforehand, so that nodes without edges have empty lists.
+    ///
+    /// # Panics
+    /// This will panic if there are too many labels, see [`LabelMap::intern`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::graph::count_paths;
+    /// use aoc::intern::LabelMap;
+    ///
+    /// let mut labels = LabelMap::new();
+    /// let adjacency = labels.adjacency([
+    ///     ("svr", "aaa"),
+    ///     ("svr", "bbb"),
+    ///     ("aaa", "out"),
+    ///     ("bbb", "out"),
+    ///     ("bbb", "aaa"),
+    /// ]);
+    ///
+    /// assert_eq!(adjacency[usize::from(labels.get("svr").unwrap())], vec![1, 2]);
+    /// assert!(adjacency[usize::from(labels.get("out").unwrap())].is_empty());
+    ///
+    /// let out = labels.get("out").unwrap();
+    /// assert_eq!(
+    ///     count_paths(labels.get("svr").unwrap(), |n| *n == out, |n| adjacency[usize::from(*n)].clone()),
+    ///     Ok(3),
+    /// );
+    /// ```
+    pub fn adjacency<'a>(
+        &mut self,
+        edges: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Vec<Vec<u16>> {
+        let mut adjacency = Vec::new();
+        for (from, to) in edges {
+            let (from, to) = (self.intern(from), self.intern(to));
+            adjacency.resize(self.len(), Vec::new());
+            adjacency[usize::from(from)].push(to);
+        }
+        adjacency.resize(self.len(), Vec::new());
+        adjacency
+    }
+}
@@ -0,0 +1,1346 @@
+//! Collection of extension methods for various items that involve iteration.
+//!
+//! This includes the [`IteratorExt`] trait, which provides iterator adapter methods
+//! and simple statistics such as [`IteratorExt::median`] and [`IteratorExt::mean`],
+//! and the [`StrExt`] trait, which provides methods to iterate over and search strings.
+//! Elements can be ranked by bespoke orderings with [`IteratorExt::rank_by`], which
+//! are often chained with [`cmp_chain`](crate::cmp_chain).
+//! There are also functions for scanning sequences of values, such as the monotonic stack
+//! scans [`next_greater_indices`], [`visible_from_left`] and
+//! [`largest_histogram_rectangle`], and [`IndexedStates`] for
+//! random access to the states of a process that evolves in steps.
+//! Sequences can be extrapolated from the rows of their successive differences, see
+//! [`difference_rows`], [`extrapolate_next`] and [`extrapolate_previous`].
+//! Independent sub-problems can be solved in parallel with [`par_map_solve`].
+
+use gat_lending_iterator::LendingIterator;
+use itertools::{Itertools, MinMaxResult};
+use num::Zero;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Range, RangeInclusive, Sub},
+};
+
+use crate::prelude::{AocError, AocResult};
+
+/// Extension methods for [`Iterator`]s.
+pub trait IteratorExt<T> {
+    /// This is a convenience method to count the elements of an iterator after filtering by
+    /// some predicate.
+    ///
+    /// The numeric return type is anything that can be fallibly
+    /// converted from a [`usize`]. An empty iterator will of course return zero
+    /// regardless of the predicate.
+    ///
+    /// # Panics
+    /// This will panic if the [`usize`] count cannot be converted into the numeric return type.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().filter_count::<usize>(|_| true), 0);
+    /// assert_eq!([-1, 3, 5, -7, 0, 8, -9, -2, 5].into_iter().filter_count::<u32>(|x| *x <= 0), 5);
+    /// ```
+    fn filter_count<O: TryFrom<usize>>(self, f: impl Fn(&T) -> bool) -> O
+    where
+        <O as TryFrom<usize>>::Error: Debug;
+
+    /// Returns an inclusive range for an [`Iterator`] over applicable ordered types.
+    ///
+    /// Will return `None` if the iterator is empty, and the single-element range
+    /// `x..=x` if the iterator yields only a single element `x`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().range(), None);
+    /// assert_eq!([5u8].into_iter().range(), Some(5..=5));
+    /// assert_eq!([-9, 4, 7, -11, 8, 5, -6, -3, 15].into_iter().range(), Some(-11..=15));
+    /// ```
+    fn range(self) -> Option<RangeInclusive<T>>
+    where
+        T: PartialOrd + Copy;
+
+    /// Advances the [`Iterator`] by some number of iterations and return the resulting item.
+    ///
+    /// Note that this is the same as [`Iterator::nth`], but just offset by one, which can
+    /// be more convenient in some situations. If `0` is passed then `None` will be returned
+    /// and likewise if the iterator is exhausted before `n` iterations.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!([0, 1, 2, 3, 4, 5, 6].into_iter().iterations(0), None);
+    /// assert_eq!([0, 1, 2, 3, 4, 5, 6].into_iter().iterations(20), None);
+    /// assert_eq!([0, 1, 2, 3, 4, 5, 6].into_iter().iterations(4), Some(3));
+    /// ```
+    fn iterations(&mut self, n: usize) -> Option<T>;
+
+    /// Advances the [`Iterator`], returning the next item, or an error if there is none.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// let mut iter = (1..=5).into_iter();
+    /// assert_matches!(iter.expect_next(), Ok(1));
+    /// assert_matches!(iter.expect_next(), Ok(2));
+    /// assert_matches!(iter.expect_next(), Ok(3));
+    /// assert_matches!(iter.expect_next(), Ok(4));
+    /// assert_matches!(iter.expect_next(), Ok(5));
+    /// assert_matches!(iter.expect_next(), Err(AocError::Process(_)));
+    /// ```
+    fn expect_next(&mut self) -> AocResult<T>;
+
+    /// Returns the most common item, or `None` if the [`Iterator`] is empty.
+    ///
+    /// If several items are equally common, the least of these is returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().mode(), None);
+    /// assert_eq!([3, 1, 4, 1, 5, 9, 2, 6, 5].into_iter().mode(), Some(1));
+    /// assert_eq!("mississippi".chars().mode(), Some('i'));
+    /// ```
+    fn mode(self) -> Option<T>
+    where
+        T: Hash + Ord;
+
+    /// Returns the least common item, or `None` if the [`Iterator`] is empty.
+    ///
+    /// If several items are equally uncommon, the least of these is returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().anti_mode(), None);
+    /// assert_eq!([3, 1, 4, 1, 5, 9, 2, 6, 5].into_iter().anti_mode(), Some(2));
+    /// assert_eq!("mississippi".chars().anti_mode(), Some('m'));
+    /// ```
+    fn anti_mode(self) -> Option<T>
+    where
+        T: Hash + Ord;
+
+    /// Returns the index of the first item of the first window of `size` consecutive
+    /// items that are all distinct, or `None` if there is no such window.
+    ///
+    /// The items are counted as the window slides along the [`Iterator`], so this takes
+    /// time proportional to the number of items regardless of the window `size`.
+    /// An empty window is always distinct, so a `size` of zero returns index zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().first_distinct_window(4), Some(3));
+    /// assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().first_distinct_window(14), Some(5));
+    /// assert_eq!([1, 2, 1, 2, 1].into_iter().first_distinct_window(2), Some(0));
+    /// assert_eq!([1, 2, 1, 2, 1].into_iter().first_distinct_window(3), None);
+    /// assert_eq!(std::iter::empty::<u8>().first_distinct_window(0), Some(0));
+    /// ```
+    fn first_distinct_window(self, size: usize) -> Option<usize>
+    where
+        T: Hash + Eq + Clone;
+
+    /// Merges streams that are each sorted in ascending order into a single sorted
+    /// [`Iterator`], where the items of this [`Iterator`] are the streams.
+    ///
+    /// Only the next item of each stream is held at any time, so the streams are not
+    /// collected.
+    /// Equal items are taken from the streams in the order of the streams.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(
+    ///     [vec![1, 4, 7], vec![2, 5, 8, 9], vec![], vec![0, 4]]
+    ///         .into_iter()
+    ///         .kway_merge()
+    ///         .collect_vec(),
+    ///     vec![0, 1, 2, 4, 4, 5, 7, 8, 9],
+    /// );
+    /// assert_eq!(
+    ///     [(1..).step_by(2), (2..).step_by(2)]
+    ///         .into_iter()
+    ///         .kway_merge()
+    ///         .take(5)
+    ///         .collect_vec(),
+    ///     vec![1, 2, 3, 4, 5],
+    /// );
+    /// ```
+    fn kway_merge(self) -> KWayMerge<T::IntoIter>
+    where
+        T: IntoIterator,
+        T::Item: Ord;
+
+    /// Returns the index of the first item whose `key` is less than that of the
+    /// previous item, or `None` if the items are sorted by the key.
+    ///
+    /// This is like [`Iterator::is_sorted_by_key`], but locates where a sequence
+    /// stops being monotone.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!([1, 2, 2, 5].into_iter().first_unsorted_by_key(|n| *n), None);
+    /// assert_eq!([1, 3, 2, 5].into_iter().first_unsorted_by_key(|n| *n), Some(2));
+    /// assert_eq!(["a", "bb", "c"].into_iter().first_unsorted_by_key(|s| s.len()), Some(2));
+    /// assert_eq!(std::iter::empty::<u8>().first_unsorted_by_key(|n| *n), None);
+    /// ```
+    fn first_unsorted_by_key<K: PartialOrd>(self, key: impl FnMut(&T) -> K) -> Option<usize>;
+
+    /// Returns the median item, or `None` if the [`Iterator`] is empty.
+    ///
+    /// For an even number of items the lesser of the two middle items is returned, so
+    /// that the median is always one of the items.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().median(), None);
+    /// assert_eq!([16, 1, 2, 0, 4, 2, 7, 1, 2, 14].into_iter().median(), Some(2));
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().median(), Some(3));
+    /// assert_eq!([8, 2, 6, 4].into_iter().median(), Some(4));
+    /// ```
+    fn median(self) -> Option<T>
+    where
+        T: Ord;
+
+    /// Returns the mean of the items, or `None` if the [`Iterator`] is empty.
+    ///
+    /// # Panics
+    /// This will panic if an item cannot be converted to an [`f64`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().mean(), None);
+    /// assert_eq!([16, 1, 2, 0, 4, 2, 7, 1, 2, 14].into_iter().mean(), Some(4.9));
+    /// assert_eq!([-3, 1].into_iter().mean(), Some(-1.0));
+    /// ```
+    fn mean(self) -> Option<f64>
+    where
+        T: num::ToPrimitive;
+
+    /// Returns the population variance of the items, or `None` if the [`Iterator`]
+    /// is empty.
+    ///
+    /// The variance is computed in a single pass over the items.
+    ///
+    /// # Panics
+    /// This will panic if an item cannot be converted to an [`f64`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().variance(), None);
+    /// assert_eq!([7, 7, 7].into_iter().variance(), Some(0.0));
+    /// let variance = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().variance().unwrap();
+    /// assert!((variance - 4.0).abs() < 1e-12);
+    /// ```
+    fn variance(self) -> Option<f64>
+    where
+        T: num::ToPrimitive;
+
+    /// Returns the items with the minimum and maximum `key` found in a single pass, or
+    /// `None` if the [`Iterator`] is empty.
+    ///
+    /// If several items have the same key, the first is the minimum and the last is
+    /// the maximum, as with [`Itertools::minmax_by_key`], of which this is a more
+    /// convenient form.
+    /// This is not named `minmax_by_key` so that it does not clash with that method when
+    /// both traits are imported.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(std::iter::empty::<u8>().min_max_by_key(|n| *n), None);
+    /// assert_eq!([5].into_iter().min_max_by_key(|n| *n), Some((5, 5)));
+    /// assert_eq!(
+    ///     ["bb", "a", "ccc", "d", "eee"].into_iter().min_max_by_key(|s| s.len()),
+    ///     Some(("a", "eee")),
+    /// );
+    /// ```
+    fn min_max_by_key<K: Ord>(self, key: impl FnMut(&T) -> K) -> Option<(T, T)>
+    where
+        T: Clone;
+
+    /// Returns an [`Iterator`] over the differences between successive elements, that is
+    /// each element minus the previous one, which has one less element.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!([1, 3, 6, 10].into_iter().differences().collect_vec(), vec![2, 3, 4]);
+    /// assert_eq!([5].into_iter().differences().count(), 0);
+    /// ```
+    fn differences(self) -> impl Iterator<Item = T>
+    where
+        T: Copy + Sub<Output = T>;
+
+    /// Returns an [`Iterator`] over the cumulative sums of the elements, that is the sum
+    /// of each element and all those before it.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(
+    ///     [1, 2, 3, 4].into_iter().cumulative_sum().collect_vec(),
+    ///     vec![1, 3, 6, 10],
+    /// );
+    /// // This undoes the differences, apart from the first element
+    /// assert_eq!(
+    ///     [1, 3, 6, 10].into_iter().differences().cumulative_sum().collect_vec(),
+    ///     vec![2, 5, 9],
+    /// );
+    /// ```
+    fn cumulative_sum(self) -> impl Iterator<Item = T>
+    where
+        T: Copy + Add<Output = T>;
+
+    /// Returns the rank of each element in order when the elements are sorted by a
+    /// comparison function `cmp`, where the least element has a rank of one.
+    ///
+    /// The sort is stable, so that equal elements are ranked in the order in which they
+    /// appear.
+    /// This is useful for puzzles that define a bespoke ordering, for which the
+    /// [`cmp_chain`](crate::cmp_chain) macro can break ties.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!([30, 10, 20].into_iter().rank_by(|a, b| a.cmp(b)), vec![3, 1, 2]);
+    /// assert_eq!(["b", "a", "b"].into_iter().rank_by(|a, b| a.cmp(b)), vec![2, 1, 3]);
+    /// ```
+    ///
+    /// Ranking poker-like hands by their type and then their cards, like 2023 day 7:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// let strengths = |hand: &str| -> Vec<usize> {
+    ///     hand.chars().map(|c| "23456789TJQKA".find(c).unwrap()).collect()
+    /// };
+    /// let kind = |hand: &str| -> Vec<usize> {
+    ///     hand.chars().counts().into_values().sorted().rev().collect()
+    /// };
+    ///
+    /// let hands: [(&str, usize); 5] = [
+    ///     ("32T3K", 765),
+    ///     ("T55J5", 684),
+    ///     ("KK677", 28),
+    ///     ("KTJJT", 220),
+    ///     ("QQQJA", 483),
+    /// ];
+    /// let ranks = hands.iter().rank_by(|(a, _), (b, _)| {
+    ///     cmp_chain!(kind(a).cmp(&kind(b)), strengths(a).cmp(&strengths(b)))
+    /// });
+    /// assert_eq!(ranks, vec![1, 4, 3, 2, 5]);
+    ///
+    /// let winnings: usize = hands.iter().zip(ranks).map(|((_, bid), rank)| bid * rank).sum();
+    /// assert_eq!(winnings, 6440);
+    /// ```
+    fn rank_by(self, cmp: impl FnMut(&T, &T) -> Ordering) -> Vec<usize>;
+}
+impl<T, I: Iterator<Item = T>> IteratorExt<T> for I {
+    fn filter_count<O: TryFrom<usize>>(self, f: impl Fn(&T) -> bool) -> O
+    where
+        <O as TryFrom<usize>>::Error: Debug,
+    {
+        self.filter(f).count().try_into().unwrap()
+    }
+
+    fn range(self) -> Option<RangeInclusive<T>>
+    where
+        T: PartialOrd + Copy,
+    {
+        match self.minmax() {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(n) => Some(n..=n),
+            MinMaxResult::MinMax(a, b) => Some(a..=b),
+        }
+    }
+
+    fn iterations(&mut self, n: usize) -> Option<T> {
+        if n > 0 {
+            self.nth(n - 1)
+        } else {
+            None
+        }
+    }
+
+    fn expect_next(&mut self) -> AocResult<T> {
+        self.next().ok_or(AocError::Process(
+            "Expected another item but there was none!".into(),
+        ))
+    }
+    fn mode(self) -> Option<T>
+    where
+        T: Hash + Ord,
+    {
+        self.counts()
+            .into_iter()
+            .max_by(|(a, na), (b, nb)| na.cmp(nb).then_with(|| b.cmp(a)))
+            .map(|(t, _)| t)
+    }
+
+    fn anti_mode(self) -> Option<T>
+    where
+        T: Hash + Ord,
+    {
+        self.counts()
+            .into_iter()
+            .min_by(|(a, na), (b, nb)| na.cmp(nb).then_with(|| a.cmp(b)))
+            .map(|(t, _)| t)
+    }
+
+    fn first_distinct_window(self, size: usize) -> Option<usize>
+    where
+        T: Hash + Eq + Clone,
+    {
+        if size == 0 {
+            return Some(0);
+        }
+
+        let mut window = VecDeque::with_capacity(size);
+        let mut counts = HashMap::new();
+        for (idx, item) in self.enumerate() {
+            *counts.entry(item.clone()).or_insert(0usize) += 1;
+            window.push_back(item);
+
+            if window.len() > size {
+                let old = window.pop_front().unwrap();
+                let count = counts.get_mut(&old).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    counts.remove(&old);
+                }
+            }
+            if counts.len() == size {
+                return Some(idx + 1 - size);
+            }
+        }
+
+        None
+    }
+
+    fn kway_merge(self) -> KWayMerge<T::IntoIter>
+    where
+        T: IntoIterator,
+        T::Item: Ord,
+    {
+        let mut streams = self.map(IntoIterator::into_iter).collect_vec();
+        let heads = streams
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(idx, stream)| stream.next().map(|item| Reverse((item, idx))))
+            .collect();
+
+        KWayMerge { streams, heads }
+    }
+
+    fn first_unsorted_by_key<K: PartialOrd>(self, mut key: impl FnMut(&T) -> K) -> Option<usize> {
+        let mut previous = None;
+        for (idx, item) in self.enumerate() {
+            let current = key(&item);
+            if previous.is_some_and(|p| current < p) {
+                return Some(idx);
+            }
+            previous = Some(current);
+        }
+
+        None
+    }
+
+    fn median(self) -> Option<T>
+    where
+        T: Ord,
+    {
+        let mut items = self.collect_vec();
+        if items.is_empty() {
+            return None;
+        }
+        let middle = (items.len() - 1) / 2;
+        items.select_nth_unstable(middle);
+        Some(items.swap_remove(middle))
+    }
+
+    fn mean(self) -> Option<f64>
+    where
+        T: num::ToPrimitive,
+    {
+        let (count, sum) = self.fold((0usize, 0.0), |(count, sum), item| {
+            (count + 1, sum + item.to_f64().unwrap())
+        });
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    fn variance(self) -> Option<f64>
+    where
+        T: num::ToPrimitive,
+    {
+        // Welford's algorithm, which is numerically stable
+        let (count, _, sum_squares) = self.fold((0usize, 0.0, 0.0), |(count, mean, m2), item| {
+            let x = item.to_f64().unwrap();
+            let count = count + 1;
+            let delta = x - mean;
+            let mean = mean + delta / count as f64;
+            (count, mean, m2 + delta * (x - mean))
+        });
+        (count > 0).then(|| sum_squares / count as f64)
+    }
+
+    fn min_max_by_key<K: Ord>(self, key: impl FnMut(&T) -> K) -> Option<(T, T)>
+    where
+        T: Clone,
+    {
+        match self.minmax_by_key(key) {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(item) => Some((item.clone(), item)),
+            MinMaxResult::MinMax(min, max) => Some((min, max)),
+        }
+    }
+
+    fn differences(self) -> impl Iterator<Item = T>
+    where
+        T: Copy + Sub<Output = T>,
+    {
+        self.tuple_windows().map(|(a, b)| b - a)
+    }
+
+    fn cumulative_sum(self) -> impl Iterator<Item = T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.scan(None, |sum: &mut Option<T>, x| {
+            let next = sum.map_or(x, |s| s + x);
+            *sum = Some(next);
+            Some(next)
+        })
+    }
+
+    fn rank_by(self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Vec<usize> {
+        let items = self.collect_vec();
+        let mut order = (0..items.len()).collect_vec();
+        order.sort_by(|a, b| cmp(&items[*a], &items[*b]));
+
+        let mut ranks = vec![0; items.len()];
+        for (rank, idx) in order.into_iter().enumerate() {
+            ranks[idx] = rank + 1;
+        }
+        ranks
+    }
+}
+
+/// Chains [`Ordering`]s so that each one after the first only breaks ties of those
+/// before it, which is how bespoke orderings with several criteria are usually defined.
+///
+/// Each ordering is only evaluated if all of those before it are
+/// [`Ordering::Equal`], see [`Ordering::then_with`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use std::cmp::Ordering;
+///
+/// let cmp = |a: &(u8, &str), b: &(u8, &str)| {
+///     cmp_chain!(b.0.cmp(&a.0), a.1.len().cmp(&b.1.len()), a.1.cmp(&b.1))
+/// };
+/// assert_eq!(cmp(&(2, "bb"), &(1, "a")), Ordering::Less);
+/// assert_eq!(cmp(&(1, "bb"), &(1, "a")), Ordering::Greater);
+/// assert_eq!(cmp(&(1, "b"), &(1, "a")), Ordering::Greater);
+/// assert_eq!(cmp(&(1, "a"), &(1, "a")), Ordering::Equal);
+///
+/// let mut items = vec![(1, "bb"), (2, "c"), (1, "a"), (1, "ab")];
+/// items.sort_by(cmp);
+/// assert_eq!(items, vec![(2, "c"), (1, "a"), (1, "ab"), (1, "bb")]);
+/// ```
+#[macro_export]
+macro_rules! cmp_chain {
+    ($first: expr $(, $rest: expr)* $(,)?) => {
+        $first$(.then_with(|| $rest))*
+    };
+}
+
+/// Extension methods for [`LendingIterator`]s.
+///
+/// This is a mirror of [`IteratorExt`], but a distinct trait is unfortunately needed
+/// because Rust does not currently support blanket trait implementations for types
+/// having disjoint trait bounds, or, alternatively, specifying negative trait bounds.
+///
+/// NOTE: Had trouble implementing this with the GAT, which was solved
+/// [here](https://users.rust-lang.org/t/trouble-writing-an-extension-trait-for-a-trait-that-includes-a-gat/107628).
+pub trait LendingIteratorExt: LendingIterator {
+    /// This is a mirror of [`IteratorExt::filter_count`] for lending iterators.
+    fn filter_count<P, O: TryFrom<usize>>(self, f: impl FnMut(&Self::Item<'_>) -> bool) -> O
+    where
+        O::Error: Debug;
+
+    /// This is a mirror of [`IteratorExt::iterations`] for lending iterators.
+    fn iterations(&mut self, n: usize) -> Option<Self::Item<'_>>;
+
+    /// This is a mirror of [`IteratorExt::expect_next`] for lending iterators.
+    fn expect_next(&mut self) -> AocResult<Self::Item<'_>>;
+}
+impl<I: LendingIterator + Sized> LendingIteratorExt for I {
+    fn filter_count<P, O: TryFrom<usize>>(self, f: impl FnMut(&Self::Item<'_>) -> bool) -> O
+    where
+        O::Error: Debug,
+    {
+        self.filter(f).count().try_into().unwrap()
+    }
+
+    fn iterations(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        if n > 0 {
+            self.nth(n - 1)
+        } else {
+            None
+        }
+    }
+
+    fn expect_next(&mut self) -> AocResult<Self::Item<'_>> {
+        self.next().ok_or(AocError::Process(
+            "Expected another item but there was none!".into(),
+        ))
+    }
+}
+
+/// Extension methods for iteration over strings.
+pub trait StrExt {
+    /// Returns an [`Iterator`] the performs substring replacements on a string, one replacement
+    /// at a time, yielding the resulting string after each replacement.
+    ///
+    /// The replacements are independent and not cumulative. If the `from` string is not found
+    /// in the string, then the [`Iterator`] will be empty.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let string = "The red fox jumps over the blue fox and lands on the yellow fox";
+    /// let mut replacements = string.individual_replacements("fox", "dog");
+    ///
+    /// assert_eq!(replacements.next().unwrap(), "The red dog jumps over the blue fox and lands on the yellow fox");
+    /// assert_eq!(replacements.next().unwrap(), "The red fox jumps over the blue dog and lands on the yellow fox");
+    /// assert_eq!(replacements.next().unwrap(), "The red fox jumps over the blue fox and lands on the yellow dog");
+    /// assert_eq!(replacements.next(), None);
+    ///
+    /// assert_eq!(string.individual_replacements("tiger", "dog").next(), None);
+    /// ```
+    fn individual_replacements<'a, 'b, 'c>(
+        &'a self,
+        from: &'b str,
+        to: &'c str,
+    ) -> Replacements<'a, 'b, 'c>;
+
+    /// Returns an [`Iterator`] over runs of repeated characters in a string.
+    ///
+    /// The iterator yields substrings of one or more characters that are the same. Only if the
+    /// string is empty will the iterator also be empty.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!("".split_runs().next(), None);
+    /// assert_eq!("X".split_runs().collect_vec(), vec!["X"]);
+    /// assert_eq!("ABCDEF".split_runs().collect_vec(), vec!["A", "B", "C", "D", "E", "F"]);
+    /// assert_eq!("abbbcddddeefggg".split_runs().collect_vec(), vec!["a", "bbb", "c", "dddd", "ee", "f", "ggg"]);
+    /// ```
+    fn split_runs(&self) -> Runs;
+
+    /// Returns an [`Iterator`] over the byte indices of all non-overlapping occurrences of
+    /// a `needle` in a string.
+    ///
+    /// Like [`str::match_indices`], the occurrences are found from the start of the string,
+    /// so that any occurrences overlapping an earlier one are skipped.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!("abcabcab".find_all("ab").collect_vec(), vec![0, 3, 6]);
+    /// assert_eq!("aaaa".find_all("aa").collect_vec(), vec![0, 2]);
+    /// assert_eq!("abc".find_all("d").next(), None);
+    /// ```
+    fn find_all<'a>(&'a self, needle: &'a str) -> impl Iterator<Item = usize> + 'a;
+
+    /// Returns the number of occurrences of a `needle` in a string, including those that
+    /// overlap.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!("aaaa".count_overlapping("aa"), 3);
+    /// assert_eq!("abababa".count_overlapping("aba"), 3);
+    /// assert_eq!("abc".count_overlapping("d"), 0);
+    /// ```
+    fn count_overlapping(&self, needle: &str) -> usize;
+
+    /// Returns the byte indices of all occurrences of any of the `needles` in a string,
+    /// including those that overlap, along with the index of the needle found.
+    ///
+    /// The occurrences are ordered by their position in the string, with multiple needles
+    /// found at the same position being ordered as in `needles`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(
+    ///     "abcdxyab".positions_of_any(&["ab", "cd", "abc"]),
+    ///     vec![(0, 0), (0, 2), (2, 1), (6, 0)]
+    /// );
+    /// assert_eq!("abc".positions_of_any(&["pq", "xy"]), vec![]);
+    /// ```
+    fn positions_of_any(&self, needles: &[&str]) -> Vec<(usize, usize)>;
+
+    /// Returns every distinct character in a string along with the number of times it
+    /// occurs, from the most to the least common.
+    ///
+    /// Characters that are equally common are in alphabetical order, which is the
+    /// order typically used to compute checksums.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// assert_eq!(
+    ///     "aaaaabbbzyx".char_frequencies(),
+    ///     vec![('a', 5), ('b', 3), ('x', 1), ('y', 1), ('z', 1)]
+    /// );
+    /// assert_eq!("".char_frequencies(), vec![]);
+    /// ```
+    fn char_frequencies(&self) -> Vec<(char, usize)>;
+}
+impl StrExt for str {
+    fn individual_replacements<'a, 'b, 'c>(
+        &'a self,
+        from: &'b str,
+        to: &'c str,
+    ) -> Replacements<'a, 'b, 'c> {
+        Replacements {
+            original: self,
+            idx: 0,
+            from,
+            to,
+        }
+    }
+
+    fn split_runs(&self) -> Runs {
+        Runs { remaining: self }
+    }
+
+    fn find_all<'a>(&'a self, needle: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.match_indices(needle).map(|(i, _)| i)
+    }
+
+    fn count_overlapping(&self, needle: &str) -> usize {
+        self.char_indices()
+            .filter(|(i, _)| self[*i..].starts_with(needle))
+            .count()
+    }
+
+    fn positions_of_any(&self, needles: &[&str]) -> Vec<(usize, usize)> {
+        self.char_indices()
+            .flat_map(|(i, _)| {
+                needles
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, needle)| self[i..].starts_with(**needle))
+                    .map(move |(j, _)| (i, j))
+            })
+            .collect()
+    }
+    fn char_frequencies(&self) -> Vec<(char, usize)> {
+        self.chars()
+            .counts()
+            .into_iter()
+            .sorted_unstable_by(|(a, na), (b, nb)| nb.cmp(na).then_with(|| a.cmp(b)))
+            .collect()
+    }
+}
+
+/// [`Iterator`] to perform string replacements.
+///
+/// See [`StrExt::individual_replacements`].
+pub struct Replacements<'a, 'b, 'c> {
+    /// Original string.
+    original: &'a str,
+    /// Current index in the string.
+    idx: usize,
+    /// Substring to replace.
+    from: &'b str,
+    /// String to which to replace substrings.
+    to: &'c str,
+}
+impl Iterator for Replacements<'_, '_, '_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.original.len() {
+            let (pre, post) = self.original.split_at(self.idx);
+            self.idx += 1;
+            if post.starts_with(self.from) {
+                return Some(format!("{}{}", pre, post.replacen(self.from, self.to, 1)));
+            }
+        }
+        None
+    }
+}
+
+/// [`Iterator`] over runs of the same characters in strings.
+///
+/// See [`StrExt::split_runs`].
+pub struct Runs<'a> {
+    /// String portion remaining after the current replacement.
+    remaining: &'a str,
+}
+impl<'a> Iterator for Runs<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let first_char = self.remaining.chars().next().unwrap();
+        let end = match self.remaining.chars().position(|c| c != first_char) {
+            None => self.remaining.len(),
+            Some(i) => i,
+        };
+        let next = &self.remaining[0..end];
+        self.remaining = &self.remaining[end..];
+        Some(next)
+    }
+}
+
+/// [`Iterator`] that merges sorted streams into a single sorted stream.
+///
+/// See [`IteratorExt::kway_merge`].
+pub struct KWayMerge<I: Iterator> {
+    /// The streams being merged.
+    streams: Vec<I>,
+    /// The next item of each stream that is not yet exhausted, along with the
+    /// index of its stream.
+    heads: BinaryHeap<Reverse<(I::Item, usize)>>,
+}
+impl<I: Iterator> Iterator for KWayMerge<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((item, idx)) = self.heads.pop()?;
+        if let Some(next) = self.streams[idx].next() {
+            self.heads.push(Reverse((next, idx)));
+        }
+        Some(item)
+    }
+}
+
+/// The states of a process that evolves in discrete steps, which are computed lazily
+/// and can be accessed in any order by their step index.
+///
+/// Rather than keeping every state, only every `interval`th state is cached, so that
+/// getting any state computes fewer than `interval` steps beyond those already computed.
+/// This is useful when the states at several different steps are needed.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::IndexedStates;
+/// use std::cell::Cell;
+///
+/// let steps = Cell::new(0);
+/// let mut states = IndexedStates::new(1u64, 10, |n| {
+///     steps.set(steps.get() + 1);
+///     n * 3 % 1000
+/// });
+///
+/// assert_eq!(states.get(25), 3u64.pow(25) % 1000);
+/// assert_eq!(steps.get(), 25);
+///
+/// // Earlier states are recomputed from the nearest cached state
+/// assert_eq!(states.get(4), 81);
+/// assert_eq!(steps.get(), 29);
+/// assert_eq!(states.get(20), 3u64.pow(20) % 1000);
+/// assert_eq!(steps.get(), 29);
+/// assert_eq!(states.get(0), 1);
+/// ```
+pub struct IndexedStates<S, F> {
+    /// The function that computes the next state from the current one.
+    step: F,
+    /// The number of steps between cached states.
+    interval: usize,
+    /// The cached states, which are those at steps that are multiples of the interval.
+    checkpoints: Vec<S>,
+}
+impl<S: Clone, F: FnMut(&S) -> S> IndexedStates<S, F> {
+    /// Creates the states starting from an `initial` state at step zero, where the
+    /// `step` function computes the next state and every `interval`th state is cached.
+    ///
+    /// # Panics
+    /// This will panic if the `interval` is zero.
+    pub fn new(initial: S, interval: usize, step: F) -> Self {
+        assert!(interval > 0, "the cache interval must be positive");
+        Self {
+            step,
+            interval,
+            checkpoints: vec![initial],
+        }
+    }
+
+    /// Returns the state at some `index`, that is after that many steps.
+    pub fn get(&mut self, index: usize) -> S {
+        let checkpoint = index / self.interval;
+
+        while self.checkpoints.len() <= checkpoint {
+            let state = self.advance(self.checkpoints.len() - 1, self.interval);
+            self.checkpoints.push(state);
+        }
+
+        self.advance(checkpoint, index % self.interval)
+    }
+
+    /// Returns the state some number of `steps` after a cached `checkpoint`.
+    fn advance(&mut self, checkpoint: usize, steps: usize) -> S {
+        let mut state = self.checkpoints[checkpoint].clone();
+        for _ in 0..steps {
+            state = (self.step)(&state);
+        }
+        state
+    }
+}
+
+/// Solves a sub-problem for each of some `items` with a `solver`, returning the
+/// solutions in the same order as the items.
+///
+/// The items are solved in parallel on all cores when the `parallel` feature is enabled
+/// and sequentially otherwise, with the same result either way.
+/// If any item cannot be solved then the error for the first such item is returned.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::par_map_solve;
+///
+/// let squares = par_map_solve(&[1u64, 2, 3, 4, 5], |n| Ok(n * n));
+/// assert_eq!(squares, Ok(vec![1, 4, 9, 16, 25]));
+///
+/// let result = par_map_solve(&[6u64, 0, 3, 0], |n| {
+///     60u64
+///         .checked_div(*n)
+///         .ok_or(AocError::Process(format!("Cannot divide by {n}").into()))
+/// });
+/// assert_eq!(result, Err(AocError::Process("Cannot divide by 0".into())));
+/// ```
+pub fn par_map_solve<T: Sync, R: Send>(
+    items: &[T],
+    solver: impl Fn(&T) -> AocResult<R> + Sync,
+) -> AocResult<Vec<R>> {
+    #[cfg(feature = "parallel")]
+    let solutions: Vec<_> = {
+        use rayon::prelude::*;
+        items.par_iter().map(solver).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let solutions: Vec<_> = items.iter().map(solver).collect();
+
+    solutions.into_iter().collect()
+}
+
+/// Returns the columns of characters of some text `lines`, that is the text transposed.
+///
+/// Lines that are shorter than others simply do not contribute to the later columns.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::columns;
+///
+/// assert_eq!(columns(["abc", "def"]), vec!["ad", "be", "cf"]);
+/// assert_eq!(columns(["abc", "d", "ef"]), vec!["ade", "bf", "c"]);
+///
+/// // Recovering a repeated message from its most and least common characters
+/// let lines = "eedadn
+/// drvtee
+/// eandsr
+/// raavrd
+/// atevrs
+/// tsrnev
+/// sdttsa
+/// rasrtv
+/// nssdts
+/// ntnada
+/// svetve
+/// tesnvt
+/// vntsnd
+/// vrdear
+/// dvrsen
+/// enarar";
+/// let columns = columns(lines.lines());
+///
+/// let message: String = columns.iter().map(|c| c.chars().mode().unwrap()).collect();
+/// assert_eq!(message, "easter");
+/// let message: String = columns.iter().map(|c| c.chars().anti_mode().unwrap()).collect();
+/// assert_eq!(message, "advent");
+/// ```
+pub fn columns<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for line in lines {
+        for (i, c) in line.chars().enumerate() {
+            if i == columns.len() {
+                columns.push(String::new());
+            }
+            columns[i].push(c);
+        }
+    }
+    columns
+}
+
+/// Rearranges a slice of `items` into the next lexicographically greater permutation,
+/// returning whether there was such a permutation.
+///
+/// If the `items` are already the last permutation, that is in descending order,
+/// they are instead rearranged into the first permutation, that is sorted in ascending
+/// order, and `false` is returned.
+/// Repeatedly calling this starting with sorted items thus enumerates all distinct
+/// permutations in order without allocating.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::next_permutation;
+///
+/// let mut items = [1, 2, 3];
+/// assert!(next_permutation(&mut items));
+/// assert_eq!(items, [1, 3, 2]);
+/// assert!(next_permutation(&mut items));
+/// assert_eq!(items, [2, 1, 3]);
+///
+/// let mut items = [3, 2, 1];
+/// assert!(!next_permutation(&mut items));
+/// assert_eq!(items, [1, 2, 3]);
+///
+/// // Repeated items only yield distinct permutations
+/// let mut items = ['a', 'a', 'b'];
+/// let mut count = 1;
+/// while next_permutation(&mut items) {
+///     count += 1;
+/// }
+/// assert_eq!(count, 3);
+/// ```
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    // Find the start of the longest non-increasing suffix
+    let Some(pivot) = (1..items.len()).rev().find(|i| items[i - 1] < items[*i]) else {
+        items.reverse();
+        return false;
+    };
+
+    // Swap the element before the suffix with the smallest greater element in the suffix,
+    // which leaves the suffix non-increasing
+    let successor = (pivot..items.len())
+        .rev()
+        .find(|i| items[*i] > items[pivot - 1])
+        .unwrap();
+    items.swap(pivot - 1, successor);
+    items[pivot..].reverse();
+    true
+}
+
+/// Returns the `n`th lexicographic permutation (starting from zero) of some `items`,
+/// or `None` if there are fewer than `n + 1` permutations.
+///
+/// The `items` are considered to be in the first permutation order as given, so should
+/// be sorted for the permutations to be lexicographic.
+/// The permutation is computed directly using the factorial number system, so that none
+/// of the earlier permutations need to be generated.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::nth_permutation;
+///
+/// assert_eq!(nth_permutation(&[0, 1, 2], 0), Some(vec![0, 1, 2]));
+/// assert_eq!(nth_permutation(&[0, 1, 2], 3), Some(vec![1, 2, 0]));
+/// assert_eq!(nth_permutation(&[0, 1, 2], 5), Some(vec![2, 1, 0]));
+/// assert_eq!(nth_permutation(&[0, 1, 2], 6), None);
+/// assert_eq!(
+///     nth_permutation(&['a', 'b', 'c', 'd', 'e'], 100).unwrap().into_iter().collect::<String>(),
+///     "eadbc"
+/// );
+/// ```
+pub fn nth_permutation<T: Clone>(items: &[T], n: usize) -> Option<Vec<T>> {
+    // The factorials of the lengths of all the suffixes
+    let mut factorials = vec![1usize; items.len() + 1];
+    for i in 1..=items.len() {
+        factorials[i] = factorials[i - 1].checked_mul(i).unwrap_or(usize::MAX);
+    }
+    if n >= factorials[items.len()] {
+        return None;
+    }
+
+    let mut remaining = items.to_vec();
+    let mut n = n;
+    Some(
+        (0..items.len())
+            .rev()
+            .map(|i| {
+                let item = remaining.remove(n / factorials[i]);
+                n %= factorials[i];
+                item
+            })
+            .collect(),
+    )
+}
+
+/// Returns the index of the next value that is strictly greater than each of some `values`,
+/// or `None` if there is no greater value after it.
+///
+/// This uses a monotonic stack so takes linear time.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::next_greater_indices;
+///
+/// assert_eq!(
+///     next_greater_indices(&[2, 1, 2, 4, 3]),
+///     vec![Some(3), Some(2), Some(3), None, None],
+/// );
+/// assert!(next_greater_indices::<u8>(&[]).is_empty());
+/// ```
+pub fn next_greater_indices<T: PartialOrd>(values: &[T]) -> Vec<Option<usize>> {
+    let mut next = vec![None; values.len()];
+
+    // The indices of values that have not yet found a greater value, which are
+    // non-increasing
+    let mut stack: Vec<usize> = Vec::new();
+    for (i, value) in values.iter().enumerate() {
+        while let Some(top) = stack.last() {
+            if values[*top] >= *value {
+                break;
+            }
+            next[*top] = Some(i);
+            stack.pop();
+        }
+        stack.push(i);
+    }
+
+    next
+}
+
+/// Returns the largest rectangle that fits under a histogram of column `heights`, as the
+/// range of columns that it spans and its height, or `None` if every height is zero.
+///
+/// The rectangle is found with a monotonic stack of increasing heights, so this takes
+/// time proportional to the number of columns.
+/// If several rectangles have the largest area, any one of these is returned.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::largest_histogram_rectangle;
+///
+/// assert_eq!(largest_histogram_rectangle(&[2, 1, 5, 6, 2, 3]), Some((2..4, 5)));
+/// assert_eq!(largest_histogram_rectangle(&[3, 3, 0, 1]), Some((0..2, 3)));
+/// assert_eq!(largest_histogram_rectangle(&[0, 0]), None);
+/// ```
+pub fn largest_histogram_rectangle(heights: &[usize]) -> Option<(Range<usize>, usize)> {
+    let mut best: Option<(Range<usize>, usize)> = None;
+    // Indices of columns with strictly increasing heights that may still be extended
+    let mut stack: Vec<usize> = Vec::new();
+
+    for idx in 0..=heights.len() {
+        let height = heights.get(idx).copied().unwrap_or(0);
+        while let Some(&top) = stack.last() {
+            if heights[top] < height {
+                break;
+            }
+            stack.pop();
+
+            // The column at the top extends back to after the next column on the stack
+            let start = stack.last().map(|i| i + 1).unwrap_or(0);
+            let area = (idx - start) * heights[top];
+            if area > 0
+                && best
+                    .as_ref()
+                    .map(|(r, h)| area > r.len() * h)
+                    .unwrap_or(true)
+            {
+                best = Some((start..idx, heights[top]));
+            }
+        }
+        stack.push(idx);
+    }
+
+    best
+}
+
+/// Returns whether each of some `values` is visible from before the first value,
+/// which is when it is strictly greater than every value before it.
+///
+/// For example, a tree in a row is visible from the left if every tree to its left is
+/// shorter.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::visible_from_left;
+///
+/// assert_eq!(
+///     visible_from_left(&[3, 0, 3, 7, 3]),
+///     vec![true, false, false, true, false],
+/// );
+///
+/// // Visibility from the right just requires reversing the values
+/// let mut values = vec![3, 3, 5, 4, 9];
+/// values.reverse();
+/// let mut visible = visible_from_left(&values);
+/// visible.reverse();
+/// assert_eq!(visible, vec![false, false, false, false, true]);
+/// ```
+pub fn visible_from_left<T: PartialOrd>(values: &[T]) -> Vec<bool> {
+    let mut tallest: Option<&T> = None;
+    values
+        .iter()
+        .map(|value| {
+            let visible = tallest.is_none_or(|t| value > t);
+            if visible {
+                tallest = Some(value);
+            }
+            visible
+        })
+        .collect()
+}
+
+/// Returns the rows of successive differences of some `values`, starting with the values
+/// themselves, in which each row is the [`IteratorExt::differences`] of the row above it,
+/// ending with the first row whose elements are all zero.
+///
+/// The last row is empty if no row of the values and their differences is all zero.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::difference_rows;
+///
+/// assert_eq!(
+///     difference_rows([1, 3, 6, 10, 15]),
+///     vec![vec![1, 3, 6, 10, 15], vec![2, 3, 4, 5], vec![1, 1, 1], vec![0, 0]],
+/// );
+/// assert_eq!(difference_rows([2, 7]), vec![vec![2, 7], vec![5], vec![]]);
+/// ```
+pub fn difference_rows<T: Copy + Zero + Sub<Output = T>>(
+    values: impl IntoIterator<Item = T>,
+) -> Vec<Vec<T>> {
+    let mut rows = vec![values.into_iter().collect_vec()];
+    while let Some(row) = rows.last().filter(|r| !r.iter().all(T::is_zero)) {
+        let next = row.iter().copied().differences().collect();
+        rows.push(next);
+    }
+    rows
+}
+
+/// Returns the next value after some `values` of a sequence, extrapolating from their
+/// [`difference_rows`], or `None` if there are no values.
+///
+/// This is exact when the values are those of a polynomial of a degree less than the
+/// number of values.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::extrapolate_next;
+///
+/// assert_eq!(extrapolate_next([0, 3, 6, 9, 12, 15]), Some(18));
+/// assert_eq!(extrapolate_next([1, 3, 6, 10, 15, 21]), Some(28));
+/// assert_eq!(extrapolate_next([10, 13, 16, 21, 30, 45]), Some(68));
+/// assert_eq!(extrapolate_next([7]), Some(7));
+/// assert_eq!(extrapolate_next::<i64>([]), None);
+/// ```
+pub fn extrapolate_next<T: Copy + Zero + Sub<Output = T>>(
+    values: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    let rows = difference_rows(values);
+    rows[0].last()?;
+    Some(
+        rows.iter()
+            .filter_map(|row| row.last())
+            .fold(T::zero(), |sum, last| sum + *last),
+    )
+}
+
+/// Returns the value before some `values` of a sequence, extrapolating from their
+/// [`difference_rows`], or `None` if there are no values.
+///
+/// This is exact when the values are those of a polynomial of a degree less than the
+/// number of values.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::iter::extrapolate_previous;
+///
+/// assert_eq!(extrapolate_previous([0, 3, 6, 9, 12, 15]), Some(-3));
+/// assert_eq!(extrapolate_previous([1, 3, 6, 10, 15, 21]), Some(0));
+/// assert_eq!(extrapolate_previous([10, 13, 16, 21, 30, 45]), Some(5));
+/// assert_eq!(extrapolate_previous::<i64>([]), None);
+/// ```
+pub fn extrapolate_previous<T: Copy + Zero + Sub<Output = T>>(
+    values: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    let rows = difference_rows(values);
+    rows[0].first()?;
+    Some(
+        rows.iter()
+            .rev()
+            .filter_map(|row| row.first())
+            .fold(T::zero(), |previous, first| *first - previous),
+    )
+}
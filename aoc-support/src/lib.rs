@@ -0,0 +1,2470 @@
+//! Support crate for solving [Advent of Code](https://adventofcode.com/) problems.
+//!
+//! Contains useful abstractions that are used for more than one solution, and which
+//! do not depend on the solutions themselves so that the crate can be used elsewhere.
+//! The main building blocks are the [`grid`], [`parse`], [`tree_search`] and
+//! [`grid_search`] modules, along with the [`solution`] framework for defining and
+//! testing solutions.
+#![feature(slice_pattern)]
+#![feature(assert_matches)]
+#![warn(missing_docs)]
+#![feature(let_chains)]
+#![feature(step_trait)]
+#![feature(associated_type_defaults)]
+#![feature(impl_trait_in_assoc_type)]
+#![warn(clippy::missing_docs_in_private_items)]
+
+pub mod answer_db;
+pub mod automaton;
+pub mod cache;
+pub mod collections;
+pub mod counter;
+pub mod direction;
+pub mod evolver;
+pub mod expression;
+pub mod fenwick;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod grid_search;
+pub mod input;
+pub mod intern;
+pub mod iter;
+pub mod math;
+pub mod ocr;
+pub mod optimize;
+pub mod parse;
+pub mod puzzles;
+pub mod range_map;
+pub mod record;
+pub mod run_history;
+pub mod scratch;
+pub mod sim;
+pub mod spatial;
+pub mod tree_search;
+pub mod vm;
+
+/// The prelude.
+pub mod prelude {
+    pub use super::{
+        cmp_chain,
+        direction::RotateQuarter,
+        ensure,
+        error::{AocError, AocResult},
+        evolver::Evolver,
+        extension::{
+            euclid::{
+                AllPoints, BoxInclusive, CheckedScale, CheckedVolume, ConversionExt, ManhattanLen,
+                UnitVectors,
+            },
+            RangeExt,
+        },
+        grid::{
+            AnyGridPoint, AnyGridPointExt, FromGridStr, Grid, GridBox, GridBoxExt, GridDefault,
+            GridPoint, GridPointExt, GridSize, GridSizeExt, GridSpace,
+        },
+        iter::{IteratorExt, LendingIteratorExt, StrExt},
+        parse::{BitInput, DiscardInput, NomParseError, NomParseResult, Parsable, Sections},
+        scan,
+        solution::{
+            from_str_preprocessor, parsable_preprocessor, Answer, IntoAnswer, IntoAnswerResult,
+            Solution, SolverInput, YearSolutions,
+        },
+    };
+}
+
+/// The prelude for solutions, which adds the items from other crates and the tree searches
+/// that solutions most commonly use to the [`prelude`].
+///
+/// This way a solution module usually needs only a single glob import, as is the case for
+/// new solutions created by the scaffolder.
+pub mod solution_prelude {
+    pub use super::{
+        prelude::*,
+        tree_search::{
+            ApplyNodeAction, BasicSolutionState, BestCostChild, BestCostTreeNode, ChildPool,
+            GlobalStateTreeNode, LeastStepsTreeNode, Metric, NodeAction,
+        },
+    };
+    pub use derive_more::{Add, Deref, DerefMut, From, Into};
+    pub use derive_new::new;
+    pub use itertools::{iproduct, Itertools};
+    pub use nom::{
+        branch::alt,
+        bytes::complete::{is_not, tag, take_until},
+        character::complete::{
+            alphanumeric1, anychar, line_ending, multispace1, one_of, space0, space1,
+        },
+        combinator::{all_consuming, map, opt, value},
+        multi::{many1, separated_list1},
+        sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+        Finish,
+    };
+    pub use std::str::FromStr;
+}
+
+/// Prelude for the tests, mainly when using [`solution_tests`].
+pub mod prelude_test {
+    #[cfg(feature = "rational")]
+    pub use super::rational;
+    pub use super::{
+        answers, signed, solution::Answer, solution_results, solution_tests, string, unsigned,
+    };
+}
+
+/// General errors.
+pub mod error {
+    use crate::parse::NomParseError;
+    use crate::solution::{Day, Year};
+    use std::borrow::Cow;
+    use std::ops::RangeInclusive;
+    use thiserror::Error;
+
+    /// Errors that can occur in general and solution functions.
+    #[derive(Debug, Clone, Error, PartialEq, Eq)]
+    pub enum AocError {
+        /// The year has not been solved.
+        #[error("Year {0} is not yet solved")]
+        NoYear(Year),
+        /// The day has not been solved.
+        #[error("Day {0} is not yet solved")]
+        NoDay(Day),
+        /// The year is before the first event.
+        #[error("Year {0} is before the first Advent of Code in {}", Year::FIRST)]
+        YearRange(u16),
+        /// The day is out of range.
+        #[error("Day {0} is not in the range of {} to {}", .1.start(), .1.end())]
+        DayRange(u8, RangeInclusive<u8>),
+        /// The part is out of range.
+        #[error("Part {0} is not in the range of {} to {}", .1.start(), .1.end())]
+        PartRange(u8, RangeInclusive<u8>),
+        /// Could not parse the problem input.
+        #[error("Could not parse input")]
+        NomParse(
+            #[source]
+            #[from]
+            NomParseError,
+        ),
+        /// Invalid problem input.
+        #[error("Invalid input: {0}")]
+        InvalidInput(Cow<'static, str>),
+        /// Error while processing the solution.
+        #[error("Error while processing: {0}")]
+        Process(Cow<'static, str>),
+        /// No solution found.
+        #[error("No solution found!")]
+        NoSolution,
+        /// Other, miscellaneous error with description.
+        #[error("Other error: {0}")]
+        Other(Cow<'static, str>),
+    }
+
+    /// Result with an [`AocError`].
+    pub type AocResult<T> = Result<T, AocError>;
+
+    /// Returns an [`AocError::InvalidInput`] from the enclosing function if a condition
+    /// does not hold.
+    ///
+    /// The remaining arguments after the condition are passed to [`format!`] to construct
+    /// the error message, which should describe the input assumption that was violated.
+    /// This is intended for surfacing problems with the input instead of panicking deep
+    /// inside an algorithm.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// fn check_even(n: u32) -> AocResult<u32> {
+    ///     ensure!(n % 2 == 0, "the number {} is not even", n);
+    ///     Ok(n / 2)
+    /// }
+    ///
+    /// assert_eq!(check_even(6), Ok(3));
+    /// assert_eq!(
+    ///     check_even(7),
+    ///     Err(AocError::InvalidInput("the number 7 is not even".into()))
+    /// );
+    /// ```
+    #[macro_export]
+    macro_rules! ensure {
+        ($cond: expr, $($arg: tt)+) => {
+            if !$cond {
+                return Err($crate::error::AocError::InvalidInput(
+                    format!($($arg)+).into(),
+                ));
+            }
+        };
+    }
+}
+
+/// Collection of general extension traits.
+pub mod extension {
+    use num::Integer;
+    use std::ops::RangeInclusive;
+
+    /// Extension traits for items in the `euclid` geometry crate.
+    pub mod euclid {
+        use std::borrow::Borrow;
+
+        use euclid::{
+            num::{One, Zero},
+            Box2D, Box3D, Point2D, Point3D, Size2D, Size3D, Vector2D, Vector3D,
+        };
+        use itertools::iproduct;
+        use num::{CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, NumCast, Signed, ToPrimitive};
+
+        /// Extension trait for mathematical vectors from that calculates their
+        /// [Manhattan length](https://en.wikipedia.org/wiki/Taxicab_geometry).
+        pub trait ManhattanLen<T, U> {
+            /// Calculates the [Manhattan length](https://en.wikipedia.org/wiki/Taxicab_geometry)
+            /// of the vector.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Vector2D, Vector3D};
+            /// assert_eq!(Vector2D::new(0, 0).manhattan_len(), 0);
+            /// assert_eq!(Vector2D::new(3, -10).manhattan_len(), 13);
+            /// assert_eq!(Vector3D::new(-5, 2, -4).manhattan_len(), 11);
+            /// ```
+            fn manhattan_len(&self) -> T;
+
+            /// Calculates the Manhattan length of the vector, or `None` if this overflows.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Vector2D, Vector3D};
+            /// assert_eq!(Vector2D::new(3i32, -10).manhattan_len_checked(), Some(13));
+            /// assert_eq!(Vector2D::new(i32::MAX, 1).manhattan_len_checked(), None);
+            /// assert_eq!(Vector3D::new(i32::MIN, 0, 0).manhattan_len_checked(), None);
+            /// ```
+            fn manhattan_len_checked(&self) -> Option<T>
+            where
+                T: CheckedAdd + CheckedNeg + Copy;
+
+            /// Calculates the Manhattan length of the vector as an [`i128`], so that it
+            /// cannot overflow for smaller component types.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Vector2D, Vector3D};
+            /// assert_eq!(Vector2D::new(i64::MAX, i64::MIN).manhattan_len_i128(), 2 * i128::from(i64::MAX) + 1);
+            /// assert_eq!(Vector3D::new(-5i8, 2, -4).manhattan_len_i128(), 11);
+            /// ```
+            fn manhattan_len_i128(&self) -> i128
+            where
+                T: Into<i128> + Copy;
+        }
+
+        /// Returns the absolute value of `n`, or `None` if this overflows.
+        ///
+        /// This is an internal function for [`ManhattanLen::manhattan_len_checked`].
+        fn checked_abs<T: Signed + CheckedNeg + Copy>(n: &T) -> Option<T> {
+            if n.is_negative() {
+                n.checked_neg()
+            } else {
+                Some(*n)
+            }
+        }
+
+        impl<T: Signed, U> ManhattanLen<T, U> for Vector2D<T, U> {
+            fn manhattan_len(&self) -> T {
+                self.x.abs() + self.y.abs()
+            }
+
+            fn manhattan_len_checked(&self) -> Option<T>
+            where
+                T: CheckedAdd + CheckedNeg + Copy,
+            {
+                checked_abs(&self.x)?.checked_add(&checked_abs(&self.y)?)
+            }
+
+            fn manhattan_len_i128(&self) -> i128
+            where
+                T: Into<i128> + Copy,
+            {
+                Into::<i128>::into(self.x).abs() + Into::<i128>::into(self.y).abs()
+            }
+        }
+        impl<T: Signed, U> ManhattanLen<T, U> for Vector3D<T, U> {
+            fn manhattan_len(&self) -> T {
+                self.x.abs() + self.y.abs() + self.z.abs()
+            }
+
+            fn manhattan_len_checked(&self) -> Option<T>
+            where
+                T: CheckedAdd + CheckedNeg + Copy,
+            {
+                checked_abs(&self.x)?
+                    .checked_add(&checked_abs(&self.y)?)?
+                    .checked_add(&checked_abs(&self.z)?)
+            }
+
+            fn manhattan_len_i128(&self) -> i128
+            where
+                T: Into<i128> + Copy,
+            {
+                Into::<i128>::into(self.x).abs()
+                    + Into::<i128>::into(self.y).abs()
+                    + Into::<i128>::into(self.z).abs()
+            }
+        }
+
+        /// Extension trait the provides unit vectors for each 3D axis.
+        pub trait UnitVectors {
+            /// Returns the positive `x` unit vector.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Vector2D, Vector3D};
+            ///
+            /// assert_eq!(Vector2D::<u8>::unit_x(), Vector2D::new(1, 0));
+            /// assert_eq!(Vector3D::<u8>::unit_x(), Vector3D::new(1, 0, 0));
+            /// ```
+            fn unit_x() -> Self;
+
+            /// Returns the positive `y` unit vector.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Vector2D, Vector3D};
+            ///
+            /// assert_eq!(Vector2D::<u8>::unit_y(), Vector2D::new(0, 1));
+            /// assert_eq!(Vector3D::<u8>::unit_y(), Vector3D::new(0, 1, 0));
+            /// ```
+            fn unit_y() -> Self;
+
+            /// Returns the positive `z` unit vector.
+            ///
+            /// # Panics
+            /// This will panic if called on a 2D vector.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::Vector3D;
+            ///
+            /// assert_eq!(Vector3D::<u8>::unit_z(), Vector3D::new(0, 0, 1));
+            /// ```
+            ///
+            /// Incorrect usage:
+            /// ```should_panic
+            /// # use aoc::prelude::*;
+            /// use euclid::default::Vector2D;
+            ///
+            /// let _ = Vector2D::<u8>::unit_z();
+            /// ```
+            fn unit_z() -> Self;
+        }
+        impl<T: One + Zero, U> UnitVectors for Vector2D<T, U> {
+            fn unit_x() -> Self {
+                Self::new(T::one(), T::zero())
+            }
+
+            fn unit_y() -> Self {
+                Self::new(T::zero(), T::one())
+            }
+
+            fn unit_z() -> Self {
+                panic!("a 2D vector has no z coordinate")
+            }
+        }
+        impl<T: One + Zero, U> UnitVectors for Vector3D<T, U> {
+            fn unit_x() -> Self {
+                Self::new(T::one(), T::zero(), T::zero())
+            }
+
+            fn unit_y() -> Self {
+                Self::new(T::zero(), T::one(), T::zero())
+            }
+
+            fn unit_z() -> Self {
+                Self::new(T::zero(), T::zero(), T::one())
+            }
+        }
+
+        /// Extension trait to provide additional component type conversions.
+        pub trait ConversionExt {
+            /// The item we are extending with its generic component type.
+            type Item<S>;
+
+            /// Converts the item components to [`isize`].
+            ///  
+            /// # Panics
+            /// This will panic if any of the components cannot be converted.
+            fn to_isize(self) -> Self::Item<isize>;
+
+            /// Converts the item components to [`u64`].
+            ///
+            /// # Panics
+            /// This will panic if any of the components cannot be converted.
+            fn to_u64(self) -> Self::Item<u64>;
+
+            /// Converts the item components to [`i128`], which is useful to avoid overflow
+            /// in subsequent calculations.
+            ///
+            /// # Panics
+            /// This will panic if any of the components cannot be converted.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Point2D, Vector3D};
+            ///
+            /// assert_eq!(Point2D::new(3u8, 4).to_i128(), Point2D::new(3i128, 4));
+            /// assert_eq!(
+            ///     Vector3D::new(i64::MAX, 0, 0).to_i128() * 2,
+            ///     Vector3D::new(2 * i128::from(i64::MAX), 0, 0)
+            /// );
+            /// ```
+            fn to_i128(self) -> Self::Item<i128>;
+        }
+
+        /// Implements [`ConversionExt`] for a particular `euclid` item.
+        ///
+        /// The item must have the `try_cast` method.
+        macro_rules! impl_euclid_conversions {
+            ($T:ident) => {
+                impl<T: NumCast + Copy, U> ConversionExt for $T<T, U> {
+                    type Item<S> = $T<S, U>;
+
+                    fn to_isize(self) -> Self::Item<isize> {
+                        self.try_cast().unwrap()
+                    }
+
+                    fn to_u64(self) -> Self::Item<u64> {
+                        self.try_cast().unwrap()
+                    }
+
+                    fn to_i128(self) -> Self::Item<i128> {
+                        self.try_cast().unwrap()
+                    }
+                }
+            };
+        }
+
+        impl_euclid_conversions!(Point2D);
+        impl_euclid_conversions!(Point3D);
+        impl_euclid_conversions!(Size2D);
+        impl_euclid_conversions!(Size3D);
+        impl_euclid_conversions!(Vector2D);
+        impl_euclid_conversions!(Vector3D);
+        impl_euclid_conversions!(Box2D);
+        impl_euclid_conversions!(Box3D);
+
+        /// Extension trait for the overflow-checked volume of `euclid` sizes and boxes,
+        /// which is the area for 2D items.
+        pub trait CheckedVolume {
+            /// The component type of the item.
+            type Scalar;
+
+            /// Returns the volume, or `None` if this overflows.
+            ///
+            /// The volume of an empty box is zero.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Box3D, Point3D, Size2D, Size3D};
+            ///
+            /// assert_eq!(Size2D::new(3i32, 4).volume_checked(), Some(12));
+            /// assert_eq!(Size3D::new(100_000i32, 100_000, 1).volume_checked(), None);
+            /// assert_eq!(
+            ///     Box3D::new(Point3D::new(-2i32, -2, -2), Point3D::new(2, 2, 2)).volume_checked(),
+            ///     Some(64)
+            /// );
+            /// assert_eq!(
+            ///     Box3D::new(Point3D::new(i32::MIN, 0, 0), Point3D::new(i32::MAX, 1, 1)).volume_checked(),
+            ///     None
+            /// );
+            /// ```
+            fn volume_checked(&self) -> Option<Self::Scalar>;
+
+            /// Returns the volume as an [`i128`], calculated so that it cannot overflow
+            /// for smaller component types.
+            ///
+            /// The volume of an empty box is zero.
+            ///
+            /// # Panics
+            /// This will panic if any of the components cannot be converted to an [`i128`],
+            /// or if the volume overflows an [`i128`].
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Box2D, Point2D, Size3D};
+            ///
+            /// assert_eq!(Size3D::new(100_000i32, 100_000, 1000).volume_i128(), 10_000_000_000_000);
+            /// assert_eq!(
+            ///     Box2D::new(Point2D::new(i32::MIN, 0), Point2D::new(i32::MAX, 2)).volume_i128(),
+            ///     2 * (i128::from(u32::MAX))
+            /// );
+            /// assert_eq!(Box2D::new(Point2D::new(5, 5), Point2D::new(0, 0)).volume_i128(), 0);
+            /// ```
+            fn volume_i128(&self) -> i128;
+        }
+
+        /// Returns the product of some box or size `extents` as an [`i128`].
+        ///
+        /// This is an internal function for [`CheckedVolume::volume_i128`].
+        fn extents_product_i128<T: ToPrimitive>(extents: &[T]) -> i128 {
+            extents
+                .iter()
+                .map(|n| n.to_i128().expect("component does not fit in an i128"))
+                .try_fold(1i128, |product, n| product.checked_mul(n))
+                .expect("volume overflows an i128")
+        }
+
+        /// Returns the product of some box or size `extents`, or `None` if this overflows.
+        ///
+        /// This is an internal function for [`CheckedVolume::volume_checked`].
+        fn extents_product_checked<T: CheckedMul + One + Copy>(extents: &[T]) -> Option<T> {
+            extents
+                .iter()
+                .try_fold(T::one(), |product, n| product.checked_mul(n))
+        }
+
+        /// Implements [`CheckedVolume`] for a `euclid` size item with particular component fields.
+        macro_rules! impl_size_volume {
+            ($T:ident; $($field:ident),+) => {
+                impl<T: CheckedMul + ToPrimitive + One + Copy, U> CheckedVolume for $T<T, U> {
+                    type Scalar = T;
+
+                    fn volume_checked(&self) -> Option<T> {
+                        extents_product_checked(&[$(self.$field),+])
+                    }
+
+                    fn volume_i128(&self) -> i128 {
+                        extents_product_i128(&[$(self.$field),+])
+                    }
+                }
+            };
+        }
+
+        /// Implements [`CheckedVolume`] for a `euclid` box item with particular component fields.
+        macro_rules! impl_box_volume {
+            ($T:ident; $($field:ident),+) => {
+                impl<T, U> CheckedVolume for $T<T, U>
+                where
+                    T: CheckedMul + CheckedSub + ToPrimitive + One + Zero + PartialOrd + Copy,
+                {
+                    type Scalar = T;
+
+                    fn volume_checked(&self) -> Option<T> {
+                        if self.is_empty() {
+                            return Some(T::zero());
+                        }
+                        extents_product_checked(&[$(self.max.$field.checked_sub(&self.min.$field)?),+])
+                    }
+
+                    fn volume_i128(&self) -> i128 {
+                        if self.is_empty() {
+                            return 0;
+                        }
+                        let extent = |min: T, max: T| {
+                            max.to_i128().expect("component does not fit in an i128")
+                                - min.to_i128().expect("component does not fit in an i128")
+                        };
+                        extents_product_i128(&[$(extent(self.min.$field, self.max.$field)),+])
+                    }
+                }
+            };
+        }
+
+        impl_size_volume!(Size2D; width, height);
+        impl_size_volume!(Size3D; width, height, depth);
+        impl_box_volume!(Box2D; x, y);
+        impl_box_volume!(Box3D; x, y, z);
+
+        /// Extension trait for the overflow-checked scaling of `euclid` points and vectors.
+        pub trait CheckedScale: Sized {
+            /// The component type of the item.
+            type Scalar;
+
+            /// Returns the item with every component multiplied by a `factor`, or `None`
+            /// if this overflows.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Point2D, Vector3D};
+            ///
+            /// assert_eq!(Point2D::new(3i32, -4).scale_checked(5), Some(Point2D::new(15, -20)));
+            /// assert_eq!(Vector3D::new(1i32, 2, 3).scale_checked(i32::MAX), None);
+            /// ```
+            fn scale_checked(&self, factor: Self::Scalar) -> Option<Self>;
+        }
+
+        /// Implements [`CheckedScale`] for a `euclid` item with particular component fields.
+        macro_rules! impl_checked_scale {
+            ($T:ident; $($field:ident),+) => {
+                impl<T: CheckedMul, U> CheckedScale for $T<T, U> {
+                    type Scalar = T;
+
+                    fn scale_checked(&self, factor: T) -> Option<Self> {
+                        Some(Self::new($(self.$field.checked_mul(&factor)?),+))
+                    }
+                }
+            };
+        }
+
+        impl_checked_scale!(Point2D; x, y);
+        impl_checked_scale!(Point3D; x, y, z);
+        impl_checked_scale!(Vector2D; x, y);
+        impl_checked_scale!(Vector3D; x, y, z);
+
+        /// Extension trait to define boxes using inclusive points.
+        ///
+        /// The compensates for the fact that the `max` point of a `euclid`
+        /// box item is exclusive.
+        pub trait BoxInclusive {
+            /// The point type that defines the box.
+            type Point;
+
+            /// Returns a new box with `min` and `max` points that are both contained in the box.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Box2D, Box3D, Point2D, Point3D, Size2D, Size3D, Vector2D, Vector3D};
+            ///
+            /// let min = Point2D::new(1, 2);
+            /// let max = Point2D::new(6, 7);
+            /// let bounds = Box2D::new_inclusive(min, max);
+            /// assert!(bounds.contains(min));
+            /// assert!(bounds.contains(max));
+            /// assert!(!bounds.contains(min - Vector2D::unit_x()));
+            /// assert!(!bounds.contains(max + Vector2D::unit_x()));
+            /// assert_eq!(bounds.size(), Size2D::new(6, 6));
+            ///
+            /// let min = Point3D::new(1, 2, 3);
+            /// let max = Point3D::new(6, 7, 8);
+            /// let bounds = Box3D::new_inclusive(min, max);
+            /// assert!(bounds.contains(min));
+            /// assert!(bounds.contains(max));
+            /// assert!(!bounds.contains(min - Vector3D::unit_y()));
+            /// assert!(!bounds.contains(max + Vector3D::unit_y()));
+            /// assert_eq!(bounds.size(), Size3D::new(6, 6, 6));
+            /// ```
+            fn new_inclusive(min: Self::Point, max: Self::Point) -> Self;
+
+            /// Returns the smallest box containing **all** of the provided points.
+            ///
+            /// [`Box2D::from_points`] and [`Box3D::from_points`] will create
+            /// boxes that actually exclude one or more points on the back edges of
+            /// the box.
+            /// I thought this may be a bug, but it turns out that this is the
+            /// [intended behavior](https://github.com/servo/euclid/issues/519).
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use euclid::default::{Box2D, Box3D, Point2D, Point3D};
+            ///
+            /// let points = [(1, 2), (2, 0), (4, 9), (6, 7), (7, 8)].map(Point2D::from);
+            /// assert_eq!(
+            ///     Box2D::from_points_inclusive(points),
+            ///     Box2D::new_inclusive((1, 0).into(), (7, 9).into())
+            /// );
+            ///
+            /// let points = [(1, 2, 8), (2, 0, 4), (4, 9, -8), (6, 7, -5), (7, 8, 3)].map(Point3D::from);
+            /// assert_eq!(
+            ///     Box3D::from_points_inclusive(points),
+            ///     Box3D::new_inclusive((1, 0, -8).into(), (7, 9, 8).into())
+            /// );
+            /// ```
+            fn from_points_inclusive<I>(points: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: Borrow<Self::Point>;
+        }
+        impl<T: Copy + std::ops::Add<Output = T> + One + Zero + PartialOrd, U> BoxInclusive
+            for Box2D<T, U>
+        {
+            type Point = Point2D<T, U>;
+
+            fn new_inclusive(min: Self::Point, max: Self::Point) -> Self {
+                Self::new(min, max + Vector2D::new(T::one(), T::one()))
+            }
+
+            fn from_points_inclusive<I>(points: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: Borrow<Self::Point>,
+            {
+                {
+                    let bounds = Self::from_points(points);
+                    Self::new_inclusive(bounds.min, bounds.max)
+                }
+            }
+        }
+        impl<T: Copy + std::ops::Add<Output = T> + One + Zero + PartialOrd, U> BoxInclusive
+            for Box3D<T, U>
+        {
+            type Point = Point3D<T, U>;
+
+            fn new_inclusive(min: Self::Point, max: Self::Point) -> Self {
+                Self::new(min, max + Vector3D::new(T::one(), T::one(), T::one()))
+            }
+
+            fn from_points_inclusive<I>(points: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: Borrow<Self::Point>,
+            {
+                {
+                    let bounds = Self::from_points(points);
+                    Self::new_inclusive(bounds.min, bounds.max)
+                }
+            }
+        }
+
+        /// Extension trait for iterating over all the points contained in an
+        /// appropriate `euclid` item.
+        pub trait AllPoints {
+            /// The type of the point to be contained in the item.
+            type Point;
+
+            /// The iterator type returned from [`AllPoints::all_points`].
+            ///
+            /// This is needed due to a
+            /// [limitation of RPITIT](https://users.rust-lang.org/t/fully-owned-iterator-causing-lifetime-problems/107677).
+            type AllPointsIterator: Iterator<Item = Self::Point>;
+
+            /// Returns an [`Iterator`] over all points contained in the item in row-major order.
+            ///
+            /// # Examples
+            /// Basic usage:
+            /// ```
+            /// # use aoc::prelude::*;
+            /// use itertools::Itertools;
+            /// use euclid::default::{Box2D, Box3D, Point2D, Point3D, Size2D, Size3D};
+            ///
+            /// assert_eq!(
+            ///     Size2D::new(2, 3).all_points().collect_vec(),
+            ///     vec![
+            ///         Point2D::new(0, 0),
+            ///         Point2D::new(1, 0),
+            ///         Point2D::new(0, 1),
+            ///         Point2D::new(1, 1),
+            ///         Point2D::new(0, 2),
+            ///         Point2D::new(1, 2),
+            ///     ],
+            /// );
+            /// assert_eq!(
+            ///     Size3D::new(2, 2, 3).all_points().collect_vec(),
+            ///     vec![
+            ///         Point3D::new(0, 0, 0),
+            ///         Point3D::new(1, 0, 0),
+            ///         Point3D::new(0, 1, 0),
+            ///         Point3D::new(1, 1, 0),
+            ///         Point3D::new(0, 0, 1),
+            ///         Point3D::new(1, 0, 1),
+            ///         Point3D::new(0, 1, 1),
+            ///         Point3D::new(1, 1, 1),
+            ///         Point3D::new(0, 0, 2),
+            ///         Point3D::new(1, 0, 2),
+            ///         Point3D::new(0, 1, 2),
+            ///         Point3D::new(1, 1, 2),
+            ///     ],
+            /// );
+            /// assert_eq!(
+            ///     Box2D::from_origin_and_size(Point2D::new(3, 4), Size2D::new(2, 3)).all_points().collect_vec(),
+            ///     vec![
+            ///         Point2D::new(3, 4),
+            ///         Point2D::new(4, 4),
+            ///         Point2D::new(3, 5),
+            ///         Point2D::new(4, 5),
+            ///         Point2D::new(3, 6),
+            ///         Point2D::new(4, 6),
+            ///     ],
+            /// );
+            /// assert_eq!(
+            ///     Box3D::from_origin_and_size(Point3D::new(3, 4, 5), Size3D::new(2, 2, 3)).all_points().collect_vec(),
+            ///     vec![
+            ///         Point3D::new(3, 4, 5),
+            ///         Point3D::new(4, 4, 5),
+            ///         Point3D::new(3, 5, 5),
+            ///         Point3D::new(4, 5, 5),
+            ///         Point3D::new(3, 4, 6),
+            ///         Point3D::new(4, 4, 6),
+            ///         Point3D::new(3, 5, 6),
+            ///         Point3D::new(4, 5, 6),
+            ///         Point3D::new(3, 4, 7),
+            ///         Point3D::new(4, 4, 7),
+            ///         Point3D::new(3, 5, 7),
+            ///         Point3D::new(4, 5, 7),
+            ///     ],
+            /// );
+            /// ```
+            fn all_points(&self) -> Self::AllPointsIterator;
+        }
+        impl<T: Copy + std::iter::Step + euclid::num::Zero, U> AllPoints for Size2D<T, U> {
+            type Point = Point2D<T, U>;
+            type AllPointsIterator = impl Iterator<Item = Self::Point>;
+
+            fn all_points(&self) -> Self::AllPointsIterator {
+                iproduct!(T::zero()..self.height, T::zero()..self.width)
+                    .map(|(y, x)| Self::Point::new(x, y))
+            }
+        }
+        impl<T: Copy + std::iter::Step + euclid::num::Zero, U> AllPoints for Size3D<T, U> {
+            type Point = Point3D<T, U>;
+            type AllPointsIterator = impl Iterator<Item = Self::Point>;
+
+            fn all_points(&self) -> Self::AllPointsIterator {
+                iproduct!(
+                    T::zero()..self.depth,
+                    T::zero()..self.height,
+                    T::zero()..self.width
+                )
+                .map(|(z, y, x)| Self::Point::new(x, y, z))
+            }
+        }
+        impl<T: Copy + std::iter::Step, U> AllPoints for Box2D<T, U> {
+            type Point = Point2D<T, U>;
+            type AllPointsIterator = impl Iterator<Item = Self::Point>;
+
+            fn all_points(&self) -> Self::AllPointsIterator {
+                iproduct!(self.min.y..self.max.y, self.min.x..self.max.x)
+                    .map(|(y, x)| Self::Point::new(x, y))
+            }
+        }
+        impl<T: Copy + std::iter::Step, U> AllPoints for Box3D<T, U> {
+            type Point = Point3D<T, U>;
+            type AllPointsIterator = impl Iterator<Item = Self::Point>;
+
+            fn all_points(&self) -> Self::AllPointsIterator {
+                iproduct!(
+                    self.min.z..self.max.z,
+                    self.min.y..self.max.y,
+                    self.min.x..self.max.x
+                )
+                .map(|(z, y, x)| Self::Point::new(x, y, z))
+            }
+        }
+    }
+
+    /// Extension trait for inclusive ranges.
+    pub trait RangeExt<T>: Sized {
+        /// Returns the number of discrete elements or steps in the range.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!((0..=5).size(), 6);
+        /// assert_eq!((-3..=3).size(), 7);
+        /// assert_eq!((4..=-7).size(), 0);
+        /// assert_eq!((6..=6).size(), 1);
+        /// ```
+        fn size(&self) -> T;
+
+        /// Returns the intersection of two ranges if they are not disjoint.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!((-4..=3).intersection(&(5..=9)), None);
+        /// assert_eq!((0..=5).intersection(&(10..=2)), None);
+        /// assert_eq!((0..=5).intersection(&(2..=10)), Some(2..=5));
+        /// assert_eq!((-5..=10).intersection(&(-19..=-3)), Some(-5..=-3));
+        /// assert_eq!((-5..=10).intersection(&(-2..=1)), Some(-2..=1));
+        /// ```
+        fn intersection(&self, other: &Self) -> Option<Self>;
+
+        /// Returns whether this range totally contains another range.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!((-4..=3).contains_range(&(-2..=3)), true);
+        /// assert_eq!((-4..=3).contains_range(&(-5..=2)), false);
+        /// assert_eq!((-4..=3).contains_range(&(-2..=7)), false);
+        /// assert_eq!((0..=8).contains_range(&(0..=8)), true);
+        /// ```
+        fn contains_range(&self, other: &Self) -> bool;
+    }
+    impl<T> RangeExt<T> for RangeInclusive<T>
+    where
+        T: Integer + Copy,
+    {
+        fn size(&self) -> T {
+            if self.end() >= self.start() {
+                *self.end() - *self.start() + T::one()
+            } else {
+                T::zero()
+            }
+        }
+
+        fn intersection(&self, other: &Self) -> Option<Self> {
+            let range = *self.start().max(other.start())..=*self.end().min(other.end());
+            if self.is_empty() || other.is_empty() || range.is_empty() {
+                None
+            } else {
+                Some(range)
+            }
+        }
+
+        fn contains_range(&self, other: &Self) -> bool {
+            self.start() <= other.start() && other.end() <= self.end()
+        }
+    }
+}
+
+/// Types and utilities for implementing problem solutions.
+pub mod solution {
+    use std::{
+        any::{Any, TypeId},
+        borrow::Cow,
+        cell::RefCell,
+        fmt, fs,
+        ops::RangeInclusive,
+        rc::Rc,
+        str::FromStr,
+        time::{Duration, Instant},
+    };
+
+    use anyhow::Context;
+    use colored::{ColoredString, Colorize};
+    use itertools::Itertools;
+    #[cfg(feature = "rational")]
+    use num::rational::Ratio;
+
+    use crate::{
+        answer_db::{active_profile, AnswerDb},
+        collections::HashMap,
+        grid::{Grid, GridPoint},
+        ocr::read_letters,
+        parse::Parsable,
+        prelude::{AocError, AocResult},
+        run_history::RunHistory,
+    };
+
+    /// Parses a number from a string for the [`FromStr`] implementations of
+    /// the [`Year`], [`Day`], and [`Part`] types.
+    fn parse_number<T: FromStr>(s: &str, what: &str) -> AocResult<T> {
+        s.trim()
+            .parse()
+            .map_err(|_| AocError::InvalidInput(format!("'{s}' is not a valid {what}").into()))
+    }
+
+    /// The year of an Advent of Code event, which is validated on construction.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::Year;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Year::new(2020).unwrap().get(), 2020);
+    /// assert_eq!(Year::from_str("2015"), Year::new(2015));
+    /// assert_eq!(Year::new(2014), Err(AocError::YearRange(2014)));
+    /// assert_matches!(Year::from_str("twenty"), Err(AocError::InvalidInput(_)));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Year(u16);
+    impl Year {
+        /// The year of the first Advent of Code event.
+        pub const FIRST: u16 = 2015;
+
+        /// Creates a new year, returning an [`AocError::YearRange`] if it is before
+        /// the [`Year::FIRST`] event.
+        pub fn new(year: u16) -> AocResult<Self> {
+            if year >= Self::FIRST {
+                Ok(Self(year))
+            } else {
+                Err(AocError::YearRange(year))
+            }
+        }
+
+        /// Creates a new year in a constant context.
+        ///
+        /// # Panics
+        /// This will panic, or fail to compile when used in a constant, if the year
+        /// is before the [`Year::FIRST`] event.
+        pub const fn new_const(year: u16) -> Self {
+            assert!(year >= Self::FIRST, "the year is before the first event");
+            Self(year)
+        }
+
+        /// Returns the numeric year.
+        pub fn get(self) -> u16 {
+            self.0
+        }
+    }
+    impl FromStr for Year {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::new(parse_number(s, "year")?)
+        }
+    }
+    impl fmt::Display for Year {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    /// The day of a problem within an event, which is validated on construction.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::Day;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Day::new(7).unwrap().get(), 7);
+    /// assert_eq!(Day::from_str("25"), Day::new(25));
+    /// assert_eq!(Day::new(26), Err(AocError::DayRange(26, Day::range())));
+    /// assert_matches!(Day::new(0), Err(AocError::DayRange(0, _)));
+    /// assert_matches!(Day::from_str("-1"), Err(AocError::InvalidInput(_)));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Day(u8);
+    impl Day {
+        /// The last day of an event.
+        pub const LAST: u8 = 25;
+
+        /// Returns the range of valid days.
+        pub fn range() -> RangeInclusive<u8> {
+            1..=Self::LAST
+        }
+
+        /// Creates a new day, returning an [`AocError::DayRange`] if it is out of range.
+        pub fn new(day: u8) -> AocResult<Self> {
+            if Self::range().contains(&day) {
+                Ok(Self(day))
+            } else {
+                Err(AocError::DayRange(day, Self::range()))
+            }
+        }
+
+        /// Returns the numeric day.
+        pub fn get(self) -> u8 {
+            self.0
+        }
+    }
+    impl FromStr for Day {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::new(parse_number(s, "day")?)
+        }
+    }
+    impl fmt::Display for Day {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    /// The part of a day's problem, which is validated on construction.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::Part;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Part::new(2).unwrap().index(), 1);
+    /// assert_eq!(Part::from_str("1").unwrap().name(), "one");
+    /// assert_matches!(Part::new(3), Err(AocError::PartRange(3, _)));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Part(u8);
+    impl Part {
+        /// The number of parts of a problem.
+        pub const LAST: u8 = 2;
+
+        /// Returns the range of valid parts.
+        pub fn range() -> RangeInclusive<u8> {
+            1..=Self::LAST
+        }
+
+        /// Creates a new part, returning an [`AocError::PartRange`] if it is out of range.
+        pub fn new(part: u8) -> AocResult<Self> {
+            if Self::range().contains(&part) {
+                Ok(Self(part))
+            } else {
+                Err(AocError::PartRange(part, Self::range()))
+            }
+        }
+
+        /// Returns an [`Iterator`] over all the parts in order.
+        pub fn all() -> impl Iterator<Item = Self> {
+            Self::range().map(Self)
+        }
+
+        /// Returns the numeric part.
+        pub fn get(self) -> u8 {
+            self.0
+        }
+
+        /// Returns the zero-based index of the part, for example to index solvers.
+        pub fn index(self) -> usize {
+            (self.0 - 1).into()
+        }
+
+        /// Returns the name of the part as an English word.
+        pub fn name(self) -> &'static str {
+            ["one", "two"][self.index()]
+        }
+    }
+    impl FromStr for Part {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::new(parse_number(s, "part")?)
+        }
+    }
+    impl fmt::Display for Part {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    /// Different types of answers to problems.
+    ///
+    /// Answers are only equal if they are the same variant with the same value, but
+    /// numeric answers of different variants can still match, see [`Answer::matches`].
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Answer {
+        /// Unsigned number.
+        Unsigned(u64),
+        /// Signed number.
+        Signed(i64),
+        /// Text.
+        String(Cow<'static, str>),
+        /// Exact rational number, for analytic solutions that would otherwise need to
+        /// round a fractional result.
+        ///
+        /// This matches an unsigned or signed answer of the same integer value.
+        #[cfg(feature = "rational")]
+        Rational(Ratio<i64>),
+    }
+    impl From<u64> for Answer {
+        fn from(n: u64) -> Self {
+            Answer::Unsigned(n)
+        }
+    }
+    impl From<i64> for Answer {
+        fn from(n: i64) -> Self {
+            Answer::Signed(n)
+        }
+    }
+
+    /// Implements [`From`] for [`Answer`] for primitive integer types that can be
+    /// losslessly converted to the inner type of the `$variant`.
+    macro_rules! impl_answer_from {
+        ($variant: ident; $($t: ty),+) => {
+            $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n.into())
+                }
+            }
+            )+
+        };
+    }
+
+    /// Implements [`From`] for [`Answer`] for primitive integer types that may not
+    /// fit into the inner type of the `$variant`.
+    ///
+    /// The conversions will panic if a value does not fit, which should never happen
+    /// for a correct problem answer.
+    macro_rules! impl_answer_from_checked {
+        ($variant: ident; $($t: ty),+) => {
+            $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(
+                        n.try_into()
+                            .unwrap_or_else(|_| panic!("answer {n} is out of range")),
+                    )
+                }
+            }
+            )+
+        };
+    }
+
+    impl_answer_from!(Unsigned; u8, u16, u32);
+    impl_answer_from!(Signed; i8, i16, i32);
+    impl_answer_from_checked!(Unsigned; usize, u128);
+    impl_answer_from_checked!(Signed; isize, i128);
+    #[cfg(feature = "rational")]
+    impl From<Ratio<i64>> for Answer {
+        fn from(r: Ratio<i64>) -> Self {
+            Answer::Rational(r)
+        }
+    }
+    impl From<&'static str> for Answer {
+        fn from(s: &'static str) -> Self {
+            Answer::String(s.into())
+        }
+    }
+    impl From<String> for Answer {
+        fn from(s: String) -> Self {
+            Answer::String(s.into())
+        }
+    }
+    impl std::fmt::Display for Answer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Answer::Unsigned(n) => n.fmt(f),
+                Answer::Signed(n) => n.fmt(f),
+                Answer::String(s) => s.fmt(f),
+                #[cfg(feature = "rational")]
+                Answer::Rational(r) => r.fmt(f),
+            }
+        }
+    }
+    impl Answer {
+        /// Returns whether this is a text answer of more than one line, such as ASCII art.
+        pub fn is_multi_line(&self) -> bool {
+            matches!(self, Answer::String(s) if s.contains('\n'))
+        }
+
+        /// Determines whether this answer matches an `expected` answer.
+        ///
+        /// Numeric answers match if they have the same value regardless of their variant,
+        /// and multi-line text answers are compared after normalizing them with
+        /// [`normalize_text`].
+        /// Otherwise the answers must be equal.
+        /// This is how answers are compared in tests and when verifying answers, so that
+        /// the sign or exactness of a numeric answer and the trailing whitespace of the
+        /// ASCII art of an answer need not match exactly.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert!(Answer::Signed(74).matches(&Answer::Unsigned(74)));
+        /// assert_ne!(Answer::Signed(74), Answer::Unsigned(74));
+        /// assert!(!Answer::Signed(-3).matches(&Answer::Unsigned(3)));
+        /// assert!(!Answer::from("74").matches(&Answer::Unsigned(74)));
+        ///
+        /// let art = Answer::from("#..#  \r\n####\n\n");
+        /// assert!(art.matches(&Answer::from("#..#\n####")));
+        /// assert_ne!(art, Answer::from("#..#\n####"));
+        ///
+        /// // Single line text must match exactly
+        /// assert!(!Answer::from("abc ").matches(&Answer::from("abc")));
+        /// ```
+        pub fn matches(&self, expected: &Self) -> bool {
+            match (self, expected) {
+                (Answer::String(a), Answer::String(b))
+                    if self.is_multi_line() || expected.is_multi_line() =>
+                {
+                    normalize_text(a) == normalize_text(b)
+                }
+                _ => match (self.integer_value(), expected.integer_value()) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => self == expected,
+                },
+            }
+        }
+
+        /// Returns the value of this answer if it is an integer of any variant.
+        ///
+        /// This is an internal function of [`Answer::matches`].
+        fn integer_value(&self) -> Option<i128> {
+            match self {
+                Answer::Unsigned(n) => Some((*n).into()),
+                Answer::Signed(n) => Some((*n).into()),
+                #[cfg(feature = "rational")]
+                Answer::Rational(r) if r.is_integer() => Some(r.to_integer().into()),
+                _ => None,
+            }
+        }
+
+        /// Creates a text answer that draws a `grid`, with `#` for the elements that are
+        /// `true` and `.` for the others, and each row on a separate line.
+        ///
+        /// The rows and columns without any `true` elements around the drawing are
+        /// trimmed, so that the answer does not depend on any margin of the grid.
+        /// To read the letters of a drawing instead, see [`Answer::from_grid_ocr`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// use aoc::grid::StdBool;
+        /// use std::str::FromStr;
+        ///
+        /// let grid = Grid::<StdBool>::from_str("......\n.#..#.\n..##..\n......").unwrap();
+        /// assert_eq!(Answer::from_grid(&grid), Answer::from("#..#\n.##."));
+        /// ```
+        pub fn from_grid<T: Clone + Into<bool>, U>(grid: &Grid<T, U>) -> Self {
+            let set = |point: &GridPoint<U>| -> bool { grid.get(point).clone().into() };
+            let set_points = grid.all_points().filter(set).collect_vec();
+            let xs = set_points.iter().map(|p| p.x).minmax().into_option();
+            let ys = set_points.iter().map(|p| p.y).minmax().into_option();
+
+            let art = match (xs, ys) {
+                (Some((left, right)), Some((top, bottom))) => (top..=bottom)
+                    .map(|y| {
+                        (left..=right)
+                            .map(|x| if set(&GridPoint::new(x, y)) { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .join("\n"),
+                _ => String::new(),
+            };
+            Answer::String(art.into())
+        }
+
+        /// Creates a text answer of the letters drawn in a `grid`, in which the elements
+        /// that are `true` are set, see [`read_letters`].
+        ///
+        /// If the letters cannot be read, which is usually the case for the examples of
+        /// such problems, then the answer draws the grid, see [`Answer::from_grid`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// use aoc::grid::StdBool;
+        /// use std::str::FromStr;
+        ///
+        /// let grid = Grid::<StdBool>::from_str(
+        ///     ".##..###.
+        /// #..#.#..#
+        /// #..#.###.
+        /// ####.#..#
+        /// #..#.#..#
+        /// #..#.###.",
+        /// )
+        /// .unwrap();
+        /// assert_eq!(Answer::from_grid_ocr(&grid), Answer::from("AB"));
+        ///
+        /// // A square is not a letter
+        /// let grid = Grid::<StdBool>::from_str("###\n#.#\n###").unwrap();
+        /// assert_eq!(Answer::from_grid_ocr(&grid), Answer::from("###\n#.#\n###"));
+        /// ```
+        pub fn from_grid_ocr<T: Clone + Into<bool>, U>(grid: &Grid<T, U>) -> Self {
+            match read_letters(grid) {
+                Ok(letters) => letters.into(),
+                Err(_) => Self::from_grid(grid),
+            }
+        }
+
+        /// Returns an [`AocError::Other`] for an answer that is not of the `expected` kind.
+        ///
+        /// This is an internal function of the accessors such as [`Answer::as_unsigned`].
+        fn mismatch(&self, expected: &str) -> AocError {
+            AocError::Other(format!("The answer {self} is not {expected}").into())
+        }
+
+        /// Returns the number of an unsigned answer, or an [`AocError::Other`] for any other
+        /// kind of answer.
+        ///
+        /// This is also available as a [`TryFrom`] conversion into a [`u64`], for example
+        /// to compute with the answers in tests.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!(Answer::Unsigned(42).as_unsigned(), Ok(42));
+        /// assert_eq!(u64::try_from(Answer::from(7u8)), Ok(7));
+        /// ```
+        ///
+        /// Invalid usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert!(Answer::Signed(42).as_unsigned().is_err());
+        /// assert!(Answer::from("42").as_unsigned().is_err());
+        /// ```
+        pub fn as_unsigned(&self) -> AocResult<u64> {
+            match self {
+                Answer::Unsigned(n) => Ok(*n),
+                _ => Err(self.mismatch("an unsigned number")),
+            }
+        }
+
+        /// Returns the number of a signed answer, or an [`AocError::Other`] for any other
+        /// kind of answer.
+        ///
+        /// This is also available as a [`TryFrom`] conversion into an [`i64`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!(Answer::Signed(-5).as_signed(), Ok(-5));
+        /// assert_eq!(i64::try_from(Answer::from(-3i32)), Ok(-3));
+        /// ```
+        ///
+        /// Invalid usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert!(Answer::Unsigned(5).as_signed().is_err());
+        /// ```
+        pub fn as_signed(&self) -> AocResult<i64> {
+            match self {
+                Answer::Signed(n) => Ok(*n),
+                _ => Err(self.mismatch("a signed number")),
+            }
+        }
+
+        /// Returns the text of a text answer, or an [`AocError::Other`] for any other kind
+        /// of answer.
+        ///
+        /// This is also available as a [`TryFrom`] conversion into a [`String`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!(Answer::from("abc").as_str(), Ok("abc"));
+        /// assert_eq!(String::try_from(Answer::from("abc")), Ok("abc".to_string()));
+        /// ```
+        ///
+        /// Invalid usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert!(Answer::Unsigned(5).as_str().is_err());
+        /// ```
+        pub fn as_str(&self) -> AocResult<&str> {
+            match self {
+                Answer::String(s) => Ok(s),
+                _ => Err(self.mismatch("text")),
+            }
+        }
+    }
+    impl TryFrom<Answer> for u64 {
+        type Error = AocError;
+
+        fn try_from(answer: Answer) -> Result<Self, Self::Error> {
+            answer.as_unsigned()
+        }
+    }
+    impl TryFrom<Answer> for i64 {
+        type Error = AocError;
+
+        fn try_from(answer: Answer) -> Result<Self, Self::Error> {
+            answer.as_signed()
+        }
+    }
+    impl TryFrom<Answer> for String {
+        type Error = AocError;
+
+        fn try_from(answer: Answer) -> Result<Self, Self::Error> {
+            match answer {
+                Answer::String(s) => Ok(s.into_owned()),
+                _ => Err(answer.mismatch("text")),
+            }
+        }
+    }
+
+    /// Normalizes multi-line `text` by using only `\n` line endings, removing trailing
+    /// whitespace from every line, and removing any trailing empty lines.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::normalize_text;
+    ///
+    /// assert_eq!(normalize_text("#.#  \r\n.#.\t\n\n"), "#.#\n.#.");
+    /// assert_eq!(normalize_text("  indented\n"), "  indented");
+    /// ```
+    pub fn normalize_text(text: &str) -> String {
+        let mut lines = text.lines().map(str::trim_end).collect_vec();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    /// Extension trait to convert values into an [`Answer`] using method call syntax.
+    ///
+    /// This is automatically implemented for anything that can be converted into an [`Answer`],
+    /// which includes all of the primitive integer types.
+    /// It is mainly useful at the end of a method chain in a solver, where an [`Into::into`]
+    /// call would be ambiguous.
+    pub trait IntoAnswer {
+        /// Converts the value into an [`Answer`].
+        ///
+        /// # Panics
+        /// This will panic if the value is an integer that does not fit into the
+        /// corresponding [`Answer`] variant.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!(7usize.into_answer(), Answer::Unsigned(7));
+        /// assert_eq!(12u8.into_answer(), Answer::Unsigned(12));
+        /// assert_eq!((-3i32).into_answer(), Answer::Signed(-3));
+        /// assert_eq!("text".into_answer(), Answer::String("text".into()));
+        /// assert_eq!(vec![1, 2, 3].into_iter().filter(|x| x % 2 == 1).count().into_answer(), Answer::Unsigned(2));
+        /// ```
+        fn into_answer(self) -> Answer;
+    }
+    impl<T: Into<Answer>> IntoAnswer for T {
+        fn into_answer(self) -> Answer {
+            self.into()
+        }
+    }
+
+    /// Extension trait to convert results of values into results of [`Answer`]s.
+    pub trait IntoAnswerResult {
+        /// Converts the [`Ok`] value of the result into an [`Answer`], passing any error through.
+        ///
+        /// # Panics
+        /// This will panic if the value is an integer that does not fit into the
+        /// corresponding [`Answer`] variant.
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// assert_eq!(AocResult::Ok(5u32).into_answer_result(), Ok(Answer::Unsigned(5)));
+        /// assert_eq!(
+        ///     AocResult::<u32>::Err(AocError::NoSolution).into_answer_result(),
+        ///     Err(AocError::NoSolution)
+        /// );
+        /// ```
+        fn into_answer_result(self) -> AocResult<Answer>;
+    }
+    impl<T: Into<Answer>> IntoAnswerResult for AocResult<T> {
+        fn into_answer_result(self) -> AocResult<Answer> {
+            self.map(|v| v.into())
+        }
+    }
+
+    /// The text or pre-parsed data of a [`SolverInput`].
+    pub enum InputData<'a> {
+        /// A string input.
+        Text(&'a str),
+        /// Pre-parsed data of some kind.
+        Data(Box<dyn Any>),
+    }
+
+    /// Represents data that can be passed to a solver function, along with a
+    /// [`SolverCache`] that is shared by the solvers of all the parts.
+    pub struct SolverInput<'a> {
+        /// The text or data.
+        data: InputData<'a>,
+        /// The intermediate results computed by the solvers.
+        cache: SolverCache,
+    }
+    impl<'a> SolverInput<'a> {
+        /// Returns the text or data.
+        pub fn data(&self) -> &InputData<'a> {
+            &self.data
+        }
+
+        /// Returns the cache of intermediate results shared by the solvers.
+        pub fn cache(&self) -> &SolverCache {
+            &self.cache
+        }
+
+        /// Returns the string input if selected, otherwise an [`AocError::InvalidInput`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # #![feature(assert_matches)]
+        /// # use std::assert_matches::assert_matches;
+        /// # use aoc::prelude::*;
+        /// assert_eq!(SolverInput::from("test").expect_input().unwrap(), "test");
+        /// assert_matches!(SolverInput::from(Box::new(7)).expect_input(), Err(AocError::InvalidInput(_)));
+        /// ```
+        pub fn expect_input(&self) -> AocResult<&'a str> {
+            if let InputData::Text(s) = self.data {
+                Ok(s)
+            } else {
+                Err(AocError::InvalidInput(
+                    "Expected string input but got something else".into(),
+                ))
+            }
+        }
+
+        /// Returns the data input of a particular type if selected and the data is the correct type,
+        /// otherwise an [`AocError::InvalidInput`].
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # #![feature(assert_matches)]
+        /// # use std::assert_matches::assert_matches;
+        /// # use aoc::prelude::*;
+        /// assert_eq!(SolverInput::from(Box::new(6u8)).expect_data::<u8>().unwrap(), &6);
+        /// assert_matches!(SolverInput::from("text").expect_data::<u8>(), Err(AocError::InvalidInput(_)));
+        /// assert_matches!(SolverInput::from(Box::new(6u16)).expect_data::<u8>(), Err(AocError::InvalidInput(_)));
+        /// ```
+        pub fn expect_data<T: 'static>(&self) -> AocResult<&T> {
+            if let InputData::Data(obj) = &self.data {
+                obj.downcast_ref::<T>().ok_or(AocError::InvalidInput(
+                    "Expected data of one type but got a different type".into(),
+                ))
+            } else {
+                Err(AocError::InvalidInput(
+                    "Expected data input but got something else".into(),
+                ))
+            }
+        }
+    }
+    /// Converts text or data to a [`SolverInput`] with an empty cache.
+    impl<'a> From<InputData<'a>> for SolverInput<'a> {
+        fn from(value: InputData<'a>) -> Self {
+            Self {
+                data: value,
+                cache: SolverCache::default(),
+            }
+        }
+    }
+    /// Converts text to [`InputData::Text`].
+    impl<'a> From<&'a str> for SolverInput<'a> {
+        fn from(value: &'a str) -> Self {
+            InputData::Text(value).into()
+        }
+    }
+    /// Converts boxed data to [`InputData::Data`].
+    impl<T: Any> From<Box<T>> for SolverInput<'_> {
+        fn from(value: Box<T>) -> Self {
+            InputData::Data(value).into()
+        }
+    }
+
+    /// Intermediate results that the solvers of some parts of a problem lazily compute and
+    /// share with the others, with at most one result of each type.
+    ///
+    /// This way a part can reuse something that another part computes, without it having to
+    /// be computed by the preprocessor, which would slow down running only the other parts.
+    /// Results are often wrapped in a new type to distinguish them.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use std::rc::Rc;
+    ///
+    /// struct Sorted(Vec<u64>);
+    ///
+    /// let input = SolverInput::from("3 1 2");
+    /// let mut computed = 0;
+    /// let mut sorted = || {
+    ///     input.cache().get_or_compute(|| {
+    ///         computed += 1;
+    ///         let mut values = input
+    ///             .expect_input()?
+    ///             .split(' ')
+    ///             .map(|s| s.parse().unwrap())
+    ///             .collect::<Vec<u64>>();
+    ///         values.sort();
+    ///         Ok(Sorted(values))
+    ///     })
+    /// };
+    ///
+    /// let first = sorted().unwrap();
+    /// let second = sorted().unwrap();
+    /// assert_eq!(first.0, vec![1, 2, 3]);
+    /// assert!(Rc::ptr_eq(&first, &second));
+    /// assert_eq!(computed, 1);
+    /// ```
+    #[derive(Default)]
+    pub struct SolverCache {
+        /// The result of each type that has been computed.
+        values: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+    }
+    impl SolverCache {
+        /// Returns the result of some type, first computing it with `compute` if it has not
+        /// yet been computed.
+        ///
+        /// Errors from `compute` are returned without caching anything, and `compute` may
+        /// itself get other results from the cache.
+        pub fn get_or_compute<T: Any>(
+            &self,
+            compute: impl FnOnce() -> AocResult<T>,
+        ) -> AocResult<Rc<T>> {
+            let id = TypeId::of::<T>();
+            let existing = self.values.borrow().get(&id).cloned();
+            if let Some(value) = existing {
+                return Ok(value.downcast().unwrap());
+            }
+
+            let value = Rc::new(compute()?);
+            self.values.borrow_mut().insert(id, value.clone());
+            Ok(value)
+        }
+    }
+
+    /// Parses the `input` text as some type of data with its [`FromStr`] implementation,
+    /// returning it boxed in an [`InputData::Data`].
+    ///
+    /// This is the most common [`Solution::preprocessor`], for which a solution need only
+    /// name the type of its data.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::grid::StdBool;
+    ///
+    /// let solution = Solution {
+    ///     day: 1,
+    ///     name: "Test",
+    ///     preprocessor: Some(from_str_preprocessor::<Grid<StdBool>>),
+    ///     validate: None,
+    ///     solvers: &[|input| {
+    ///         let grid = input.expect_data::<Grid<StdBool>>()?;
+    ///         Ok(Answer::Unsigned(grid.all_values().filter(|b| ***b).count() as u64))
+    ///     }],
+    /// };
+    ///
+    /// let data = solution.preprocess(".#\n##").unwrap();
+    /// assert_eq!(data.expect_data::<Grid<StdBool>>().unwrap().size(), &GridSize::new(2, 2));
+    /// assert_eq!(solution.solvers[0](&data), Ok(Answer::Unsigned(3)));
+    /// assert!(solution.preprocess(".#\n#").is_err());
+    /// ```
+    pub fn from_str_preprocessor<T: FromStr + Any>(input: &str) -> AocResult<SolverInput<'_>>
+    where
+        AocError: From<T::Err>,
+    {
+        Ok(Box::new(T::from_str(input)?).into())
+    }
+
+    /// Parses the `input` text as some type of data with its [`Parsable`] implementation,
+    /// returning it boxed in an [`InputData::Data`].
+    ///
+    /// This is the same as [`from_str_preprocessor`] but for types that are only
+    /// [`Parsable`].
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// let data = parsable_preprocessor::<u32>("42").unwrap();
+    /// assert_eq!(data.expect_data::<u32>().unwrap(), &42);
+    /// assert!(parsable_preprocessor::<u32>("forty-two").is_err());
+    /// ```
+    pub fn parsable_preprocessor<T: for<'a> Parsable<'a> + Any>(
+        input: &str,
+    ) -> AocResult<SolverInput<'_>> {
+        Ok(Box::new(T::from_str(input)?).into())
+    }
+
+    /// Formats a `duration` in adaptive units so that it is easily readable.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::format_duration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3 µs");
+    /// assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6 ms");
+    /// assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50 s");
+    /// ```
+    pub fn format_duration(duration: Duration) -> String {
+        let secs = duration.as_secs_f64();
+        if duration < Duration::from_millis(1) {
+            format!("{:.1} µs", secs * 1e6)
+        } else if duration < Duration::from_secs(1) {
+            format!("{:.1} ms", secs * 1e3)
+        } else {
+            format!("{secs:.2} s")
+        }
+    }
+
+    /// Thresholds for color-coding the run times of solutions, so that slow solutions
+    /// are visually obvious.
+    #[derive(Debug, Clone, Copy)]
+    pub struct TimingTiers {
+        /// Run times less than this are considered fast.
+        pub fast: Duration,
+        /// Run times less than this, but not fast, are considered moderate,
+        /// with anything else being slow.
+        pub slow: Duration,
+    }
+    impl Default for TimingTiers {
+        fn default() -> Self {
+            Self {
+                fast: Duration::from_millis(10),
+                slow: Duration::from_secs(1),
+            }
+        }
+    }
+    impl TimingTiers {
+        /// Returns a `duration` formatted with [`format_duration`] and colored according
+        /// to its tier.
+        ///
+        /// Fast times are green, moderate times are yellow, and slow times are red.
+        pub fn colorize(&self, duration: Duration) -> ColoredString {
+            let text = format_duration(duration);
+            if duration < self.fast {
+                text.green()
+            } else if duration < self.slow {
+                text.yellow()
+            } else {
+                text.red()
+            }
+        }
+    }
+
+    /// A solver function for any parts of a day's problem.
+    ///
+    /// Solvers will either return an [`Answer`] or an [`AocError`] if there is some kind of problem.
+    pub type SolverFunc = fn(&SolverInput) -> AocResult<Answer>;
+
+    /// The solution for a day's problem.
+    pub struct Solution {
+        /// The day of the problem (1 to 25).
+        pub day: u8,
+        /// The name of the day's problem.
+        pub name: &'static str,
+        /// An optional preprocessing function to parse the input text and possibly perform
+        /// other preprocessing only once.
+        ///
+        /// The output of this will be passed to all solvers as their input.
+        /// If not preprocessor is set, the raw problem input will be passed to all solvers.
+        /// This may also return an [`AocError`] if a problem is encountered.
+        /// When the input just needs to be parsed as some type, this can be
+        /// [`from_str_preprocessor`] or [`parsable_preprocessor`] for that type.
+        pub preprocessor: Option<fn(&str) -> AocResult<SolverInput>>,
+        /// An optional function to check that the preprocessed input satisfies any
+        /// assumptions made by the solvers.
+        ///
+        /// This is run after the preprocessor and before any solvers, and should return
+        /// an [`AocError::InvalidInput`] describing the violated assumption, which is most
+        /// easily done using the [`ensure`](crate::ensure) macro.
+        pub validate: Option<fn(&SolverInput) -> AocResult<()>>,
+        /// Solve functions for each part of the day's problem.
+        ///
+        /// These can share intermediate results using the [`SolverInput::cache`].
+        pub solvers: &'static [SolverFunc],
+    }
+    impl Solution {
+        /// Constructs a nice title from the day and name.
+        pub fn title(&self) -> String {
+            format!("Day {}: {}", self.day, self.name)
+        }
+
+        /// Runs the preprocessing function if applicable with the `input` text, followed
+        /// by the validation function if applicable.
+        ///
+        /// If no preprocessor is set, the `input` is just returned wrapped in an [`InputData::Text`].
+        pub fn preprocess<'a>(&self, input: &'a str) -> AocResult<SolverInput<'a>> {
+            let data = if let Some(pf) = self.preprocessor {
+                pf(input)?
+            } else {
+                input.into()
+            };
+
+            if let Some(vf) = self.validate {
+                vf(&data)?;
+            }
+            Ok(data)
+        }
+
+        /// Returns the path of the input text file for the problem in some `year`.
+        pub fn input_path(&self, year: Year) -> String {
+            format!("input/{year}/day_{:02}.txt", self.day)
+        }
+
+        /// Reads the input text file for the problem in some `year`.
+        pub fn read_input(&self, year: Year) -> anyhow::Result<String> {
+            let input_path = self.input_path(year);
+            fs::read_to_string(&input_path)
+                .with_context(|| format!("Could not read input file {input_path}"))
+        }
+
+        /// Runs the preprocessor if set on the `input` text, then runs the solver for
+        /// a `part` if given or otherwise the solvers for all parts, timing everything.
+        ///
+        /// Messages logged with [`tracing`] while preprocessing and solving are within
+        /// spans that identify the day and part.
+        /// If the preprocessor, validation function, or any of the solvers return an
+        /// [`AocError`], further processing will stop and this will be returned.
+        pub fn run(&self, input: &str, part: Option<Part>) -> AocResult<SolutionRun> {
+            let start = Instant::now();
+            let data = {
+                let _span = tracing::info_span!("preprocess", day = self.day).entered();
+                self.preprocess(input)?
+            };
+            let preprocess_time = start.elapsed();
+            let parts = self
+                .solvers
+                .iter()
+                .zip(Part::all())
+                .map(|(s, p)| {
+                    if part.is_some_and(|part| part != p) {
+                        return Ok(None);
+                    }
+                    let _span =
+                        tracing::info_span!("solve", day = self.day, part = p.get()).entered();
+                    let start = Instant::now();
+                    let answer = s(&data)?;
+                    Ok(Some((answer, start.elapsed())))
+                })
+                .collect::<AocResult<Vec<_>>>()?;
+
+            Ok(SolutionRun {
+                preprocess_time,
+                parts,
+            })
+        }
+
+        /// Reads the input from the text file, runs the preprocessor if set, then runs the solver
+        /// for a `part` if given or otherwise the solvers for all parts, and prints their answers
+        /// along with the run times color-coded by the `tiers`.
+        ///
+        /// If the preprocessor, validation function, or any of the solvers return an [`AocError`],
+        /// further processing will stop and this will be returned. Otherwise the list of answers
+        /// corresponding to each solver are returned, which are `None` for parts that were not run.
+        pub fn run_and_print(
+            &self,
+            year: Year,
+            part: Option<Part>,
+            tiers: &TimingTiers,
+        ) -> anyhow::Result<Vec<Option<Answer>>> {
+            let input = self.read_input(year)?;
+            let run = self.run(&input, part)?;
+
+            println!("{}", format!("Year {} {}", year, self.title()).yellow());
+            if self.preprocessor.is_some() || self.validate.is_some() {
+                println!(
+                    "Preprocessing time: {}",
+                    tiers.colorize(run.preprocess_time)
+                );
+            }
+            for (part, result) in Part::all().zip(run.parts.iter()) {
+                let Some((answer, time)) = result else {
+                    continue;
+                };
+                if run.parts.len() > 1 {
+                    println!("{}", format!("Part {}:", part.name()).bold().underline());
+                }
+                println!("Answer: {answer}");
+                println!("Time: {}", tiers.colorize(*time));
+            }
+            println!("Total time: {}", tiers.colorize(run.total_time()));
+
+            Ok(run.answers())
+        }
+    }
+
+    /// The answers and run times from one run of a [`Solution`].
+    #[derive(Debug)]
+    pub struct SolutionRun {
+        /// The time taken to preprocess and validate the input.
+        pub preprocess_time: Duration,
+        /// The answer and solve time for each part, or `None` for parts that were not run.
+        pub parts: Vec<Option<(Answer, Duration)>>,
+    }
+    impl SolutionRun {
+        /// Returns the answer to each part, or `None` for parts that were not run.
+        pub fn answers(self) -> Vec<Option<Answer>> {
+            self.parts
+                .into_iter()
+                .map(|result| result.map(|(answer, _)| answer))
+                .collect()
+        }
+
+        /// Returns the total time taken for the preprocessing and all the parts run.
+        pub fn total_time(&self) -> Duration {
+            self.preprocess_time
+                + self
+                    .parts
+                    .iter()
+                    .flatten()
+                    .map(|(_, t)| *t)
+                    .sum::<Duration>()
+        }
+    }
+
+    /// Package of solutions for a year's problems.
+    pub struct YearSolutions {
+        /// Year.
+        pub year: Year,
+        /// The solutions for each day's problem for this year.
+        pub solutions: &'static [Solution],
+    }
+    impl YearSolutions {
+        /// Retrieves the [`Solution`] for a day, if it exists.
+        pub fn get_day(&self, day: Day) -> Option<&Solution> {
+            self.solutions.iter().find(|s| s.day == day.get())
+        }
+
+        /// Returns a table listing the solution for every day, with a row for each day.
+        ///
+        /// Each row has the day, the name of the problem, the number of parts implemented,
+        /// a star for each known answer in the `answers` manifest, if it could be loaded,
+        /// and the most recent run time in the run `history`, if one has been recorded.
+        /// The known answers are those of the active profile, see
+        /// [`active_profile`](crate::answer_db::active_profile).
+        ///
+        /// # Examples
+        /// Basic usage:
+        /// ```
+        /// # use aoc::prelude::*;
+        /// use aoc::{
+        ///     answer_db::AnswerDb,
+        ///     run_history::RunHistory,
+        ///     solution::{Year, YearSolutions},
+        /// };
+        /// use itertools::Itertools;
+        ///
+        /// const SOLUTIONS: &[Solution] = &[
+        ///     Solution {
+        ///         day: 1,
+        ///         name: "Not Quite Lisp",
+        ///         preprocessor: None,
+        ///         validate: None,
+        ///         solvers: &[|_| Ok(0u64.into()), |_| Ok(0u64.into())],
+        ///     },
+        ///     Solution {
+        ///         day: 2,
+        ///         name: "Wrapping",
+        ///         preprocessor: None,
+        ///         validate: None,
+        ///         solvers: &[|_| Ok(0u64.into())],
+        ///     },
+        /// ];
+        /// let year_solutions = YearSolutions {
+        ///     year: Year::new_const(2015),
+        ///     solutions: SOLUTIONS,
+        /// };
+        /// let history = RunHistory::parse("2015 1 0.25");
+        /// let answers = AnswerDb::parse("[2015]\n1 = [280, 1797]").unwrap();
+        ///
+        /// assert_eq!(
+        ///     year_solutions
+        ///         .solution_list(&history, Some(&answers))
+        ///         .lines()
+        ///         .collect_vec(),
+        ///     vec![
+        ///         "Day  Name            Parts  Stars  Last run",
+        ///         "  1  Not Quite Lisp      2  **     250.0 ms",
+        ///         "  2  Wrapping            1         -",
+        ///     ],
+        /// );
+        /// ```
+        pub fn solution_list(&self, history: &RunHistory, answers: Option<&AnswerDb>) -> String {
+            let profile = active_profile();
+            let name_width = self
+                .solutions
+                .iter()
+                .map(|solution| solution.name.len())
+                .chain(["Name".len()])
+                .max()
+                .unwrap();
+
+            let header = format!("Day  {:name_width$}  Parts  Stars  Last run", "Name");
+            let rows = self.solutions.iter().map(|solution| {
+                let stars = answers
+                    .and_then(|db| db.answers(profile.as_deref(), self.year, solution.day).ok())
+                    .map_or(0, |a| a.len());
+                let last_run = history
+                    .latest(self.year, solution.day)
+                    .map_or_else(|| "-".to_string(), format_duration);
+                format!(
+                    "{:>3}  {:name_width$}  {:>5}  {:5}  {last_run}",
+                    solution.day,
+                    solution.name,
+                    solution.solvers.len(),
+                    "*".repeat(stars),
+                )
+            });
+            std::iter::once(header).chain(rows).join("\n")
+        }
+    }
+
+    /// Macro to construct the solution table for a year.
+    ///
+    /// See an implemented year for usage example.
+    #[macro_export]
+    macro_rules! year_solutions {
+        (
+            year = $year: expr;
+            days = [
+                $($day: ident,)*
+            ];
+        ) => {
+            $(
+                pub mod $day;
+            )*
+
+            use aoc::solution::{Year, YearSolutions};
+
+            // All of the solutions.
+            pub const YEAR_SOLUTIONS: YearSolutions = YearSolutions {
+                year: Year::new_const($year),
+                solutions: &[
+                $(
+                    $day::SOLUTION,
+                )*
+                ],
+            };
+         }
+    }
+
+    /// Evaluates to a slice of optional answers, in which answers can be of mixed types
+    /// and some may be skipped.
+    ///
+    /// Each element can be one of the following:
+    /// - `skip` for an answer that should not be checked, which becomes `None`.
+    /// - `u` followed by an expression for an [`Answer::Unsigned`].
+    /// - `s` followed by an expression for an [`Answer::Signed`].
+    /// - Any other expression that can be converted into an [`Answer`], for example an
+    ///   [`Answer`] itself or a string.
+    ///
+    /// This is mainly for use with the [`solution_results`](crate::solution_results) macro.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::answers;
+    ///
+    /// let expected: &[Option<Answer>] = &[
+    ///     Some(Answer::Unsigned(64)),
+    ///     None,
+    ///     Some(Answer::Signed(-5)),
+    ///     Some(Answer::String("text".into())),
+    /// ];
+    /// let actual: &[Option<Answer>] = answers![u 64, skip, s -5, "text"];
+    /// assert_eq!(actual, expected);
+    ///
+    /// let actual: &[Option<Answer>] = answers![skip, Answer::Unsigned(3)];
+    /// assert_eq!(actual, &[None, Some(Answer::Unsigned(3))]);
+    /// ```
+    #[macro_export]
+    macro_rules! answers {
+        (@acc [$($acc: expr,)*]) => {
+            &[$($acc,)*]
+        };
+        (@acc [$($acc: expr,)*] skip $(, $($rest: tt)*)?) => {
+            $crate::answers!(@acc [$($acc,)* None,] $($($rest)*)?)
+        };
+        (@acc [$($acc: expr,)*] u $val: expr $(, $($rest: tt)*)?) => {
+            $crate::answers!(
+                @acc [$($acc,)* Some($crate::solution::Answer::Unsigned($val)),] $($($rest)*)?
+            )
+        };
+        (@acc [$($acc: expr,)*] s $val: expr $(, $($rest: tt)*)?) => {
+            $crate::answers!(
+                @acc [$($acc,)* Some($crate::solution::Answer::Signed($val)),] $($($rest)*)?
+            )
+        };
+        (@acc [$($acc: expr,)*] $val: expr $(, $($rest: tt)*)?) => {
+            $crate::answers!(
+                @acc [$($acc,)* Some($crate::solution::Answer::from($val)),] $($($rest)*)?
+            )
+        };
+        [$($elements: tt)+] => {
+            $crate::answers!(@acc [] $($elements)+)
+        };
+    }
+
+    /// Wraps elements in [`Answer::Unsigned`] and evaluates to answer slice.
+    #[macro_export]
+    macro_rules! unsigned {
+        [$($val: expr),+] => {
+            answers![$(Answer::Unsigned($val)),+]
+        };
+    }
+
+    /// Wraps elements in [`Answer::Signed`] and evaluates to answer slice.
+    #[macro_export]
+    macro_rules! signed {
+        [$($val: expr),+] => {
+            answers![$(Answer::Signed($val)),+]
+        };
+    }
+
+    /// Wraps elements in [`Answer::String`] and evaluates to answer slice.
+    ///
+    /// Multi-line text, such as ASCII art, is compared in tests after normalizing its
+    /// whitespace, see [`Answer::matches`].
+    #[macro_export]
+    macro_rules! string {
+        [$($val: expr),+] => {
+            answers![$(Answer::String($val.into())),+]
+        };
+    }
+
+    /// Wraps elements in [`Answer::Rational`] and evaluates to answer slice.
+    ///
+    /// Each element can be anything that can be converted into a rational number,
+    /// such as an integer or a tuple of its numerator and denominator.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// # use aoc::prelude_test::*;
+    /// let actual: &[Option<Answer>] = rational![(14, 4), 3];
+    /// assert_eq!(actual[0], Some(Answer::Rational((7, 2).into())));
+    /// assert_eq!(actual[0].as_ref().unwrap().to_string(), "7/2");
+    /// assert_eq!(actual[1].as_ref().unwrap().to_string(), "3");
+    ///
+    /// // Rational answers with integer values match integer answers
+    /// let (fraction, integer) = (actual[0].as_ref().unwrap(), actual[1].as_ref().unwrap());
+    /// assert!(integer.matches(&Answer::Unsigned(3)));
+    /// assert!(integer.matches(&Answer::Signed(3)));
+    /// assert!(!fraction.matches(&Answer::Signed(3)));
+    /// ```
+    #[cfg(feature = "rational")]
+    #[macro_export]
+    macro_rules! rational {
+        [$($val: expr),+] => {
+            answers![$(Answer::Rational(::core::convert::From::from($val))),+]
+        };
+    }
+
+    /// Compares solution results with a vector.
+    ///
+    /// This typically is not used directly, but rather by the [`solution_tests`](crate::solution_tests)
+    /// macro, and always in the context of a day's solution
+    /// module in which there is a constant [`Solution`] structure called `SOLUTION`
+    /// in the same scope. The `$input` should then be a static `&str` to pass as input
+    /// to the solvers, and the `$answers` should be be a [`Vec<Option<Answer>>`] of the
+    /// answers for each part for that `$input`.
+    #[macro_export]
+    macro_rules! solution_results {
+        ($input: expr, $answers: expr) => {
+            let vans: &[Option<Answer>] = $answers;
+            let input: &str = $input;
+            let data = SOLUTION.preprocess(input).unwrap();
+
+            for (solver, ans) in SOLUTION.solvers.iter().zip(vans.iter()) {
+                if let Some(a) = ans {
+                    $crate::solution::assert_answer_eq(&solver(&data).unwrap(), a);
+                }
+            }
+        };
+    }
+
+    /// Asserts that an `actual` answer matches the `expected` answer, see
+    /// [`Answer::matches`].
+    ///
+    /// This is mainly for use in tests, such as by the
+    /// [`solution_results`](crate::solution_results) macro.
+    ///
+    /// # Panics
+    /// This will panic if the answers are not equal, with a line by line comparison
+    /// for multi-line text answers.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::assert_answer_eq;
+    ///
+    /// assert_answer_eq(&Answer::from("##\n.# \n"), &Answer::from("##\n.#"));
+    /// assert_answer_eq(&Answer::Unsigned(5), &Answer::Unsigned(5));
+    /// ```
+    ///
+    /// Invalid usage:
+    /// ```should_panic
+    /// # use aoc::prelude::*;
+    /// use aoc::solution::assert_answer_eq;
+    ///
+    /// assert_answer_eq(&Answer::from("##\n.#"), &Answer::from("##\n#."));
+    /// ```
+    #[track_caller]
+    pub fn assert_answer_eq(actual: &Answer, expected: &Answer) {
+        if actual.matches(expected) {
+            return;
+        }
+
+        if let (Answer::String(a), Answer::String(e)) = (actual, expected) {
+            if !actual.is_multi_line() && !expected.is_multi_line() {
+                panic!("answers differ\n  actual: {actual:?}\nexpected: {expected:?}");
+            }
+
+            let (a, e) = (normalize_text(a), normalize_text(e));
+            let (a, e) = (a.lines().collect_vec(), e.lines().collect_vec());
+            let mut diff = String::new();
+            for idx in 0..a.len().max(e.len()) {
+                let (al, el) = (a.get(idx), e.get(idx));
+                if al == el {
+                    diff.push_str(&format!("  {:>3} |{}\n", idx + 1, al.unwrap()));
+                } else {
+                    if let Some(el) = el {
+                        diff.push_str(&format!("- {:>3} |{el}\n", idx + 1));
+                    }
+                    if let Some(al) = al {
+                        diff.push_str(&format!("+ {:>3} |{al}\n", idx + 1));
+                    }
+                }
+            }
+            panic!("text answers differ (- expected, + actual):\n{diff}");
+        }
+        panic!("answers differ\n  actual: {actual:?}\nexpected: {expected:?}");
+    }
+
+    /// The environment variable that limits which tiers of expensive example tests are run.
+    pub const EXPENSIVE_TIER_VAR: &str = "AOC_EXPENSIVE_TIER";
+
+    /// Returns whether expensive example tests of a `tier` should be run.
+    ///
+    /// Higher tiers are more expensive. If the [`EXPENSIVE_TIER_VAR`] environment variable
+    /// is set to a number, only tiers up to and including that number are run, otherwise tests
+    /// of all tiers are run.
+    /// This is mainly for use with the [`solution_tests`](crate::solution_tests) macro, in which
+    /// case the `expensive` feature must also be enabled.
+    pub fn expensive_tier_enabled(tier: u8) -> bool {
+        std::env::var(EXPENSIVE_TIER_VAR)
+            .ok()
+            .and_then(|s| s.trim().parse::<u8>().ok())
+            .map(|max| tier <= max)
+            .unwrap_or(true)
+    }
+
+    /// Checks the answers to the actual input, against the `$actual` answers if given or
+    /// otherwise against the [`answer_db`](crate::answer_db) manifest.
+    ///
+    /// This is for internal use by the [`solution_tests`](crate::solution_tests) macro.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! check_actual_answers {
+        ($answers: expr, $year: expr, $day: expr) => {
+            $crate::answer_db::verify_answers($year, $day, &$answers).unwrap()
+        };
+        ($answers: expr, $year: expr, $day: expr, $actual: expr) => {
+            let expected: &[Option<$crate::solution::Answer>] = $actual;
+            assert_eq!($answers.len(), expected.len());
+            for (answer, expected) in $answers.iter().zip(expected) {
+                match (answer, expected) {
+                    (Some(a), Some(e)) => $crate::solution::assert_answer_eq(a, e),
+                    (answer, expected) => assert_eq!(answer, expected),
+                }
+            }
+        };
+    }
+
+    /// Evaluates to the tier of an expensive example test, which is `1` if not specified.
+    ///
+    /// This is for internal use by the [`solution_tests`](crate::solution_tests) macro.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! expensive_tier {
+        () => {
+            1
+        };
+        ($tier: literal) => {
+            $tier
+        };
+    }
+
+    /// Evaluates to the input of an example test, which is either given directly as
+    /// `input = ...;` or read from a file given as `input_file = "...";`, see
+    /// [`example_file`](crate::input::example_file).
+    ///
+    /// This is for internal use by the [`solution_tests`](crate::solution_tests) macro.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! example_input {
+        (input, $input: expr) => {
+            $input
+        };
+        (input_file, $name: expr) => {
+            &$crate::input::example_file(super::super::YEAR_SOLUTIONS.year, $name).unwrap()
+        };
+    }
+
+    /// Macro to build the tests for a solution.
+    ///
+    /// Creates zero or more example tests and also creates an ignored
+    /// test to verify the solution with the actual input. Optionally,
+    /// computationally expensive example tests can be created that are
+    /// only executed when the `expensive` feature is enabled.
+    ///
+    /// Expensive examples can optionally be given a tier, for example
+    /// `expensive_example(tier = 2) { ... }`, with the default tier being `1`.
+    /// The tiers that are run can then be limited using the environment variable
+    /// named by [`EXPENSIVE_TIER_VAR`](crate::solution::EXPENSIVE_TIER_VAR), see
+    /// [`expensive_tier_enabled`](crate::solution::expensive_tier_enabled).
+    ///
+    /// The ignored test checks the answers against the known answers in the
+    /// [`answer_db`](crate::answer_db) manifest, unless they are given explicitly
+    /// as `actual_answers = ...;` after the examples.
+    ///
+    /// Long example inputs can be kept in files in the [`input`](crate::input) examples
+    /// directory rather than in string literals, by giving the file name with
+    /// `input_file = "...";` in place of `input = ...;`.
+    ///
+    /// Refer to the many implemented solutions for how to use this.
+    /// For example, the 2015 Day 10 solution features all of these
+    /// tests.
+    #[macro_export]
+    macro_rules! solution_tests {
+        (
+            $(example {
+                $input_kind: ident = $input: expr;
+                answers = $answers: expr;
+            })*
+            $(expensive_example $((tier = $tier: literal))? {
+                $exp_input_kind: ident = $exp_input: expr;
+                answers = $exp_answers: expr;
+            })*
+            $(actual_answers = $actual: expr;)?
+        ) => {
+            #[test]
+            fn examples() {
+                use super::SOLUTION;
+                $(
+                solution_results!($crate::example_input!($input_kind, $input), $answers);
+                )*
+            }
+
+            #[test]
+            #[cfg(feature = "expensive")]
+            fn expensive_examples() {
+                use super::SOLUTION;
+                $(
+                if $crate::solution::expensive_tier_enabled($crate::expensive_tier!($($tier)?)) {
+                    solution_results!(
+                        $crate::example_input!($exp_input_kind, $exp_input),
+                        $exp_answers
+                    );
+                }
+                )*
+            }
+
+            #[test]
+            #[ignore]
+            fn actual() {
+                use super::SOLUTION;
+                let year = super::super::YEAR_SOLUTIONS.year;
+                let answers = SOLUTION.run_and_print(year, None, &Default::default()).unwrap();
+                $crate::check_actual_answers!(answers, year, SOLUTION.day $(, $actual)?);
+            }
+        };
+    }
+}
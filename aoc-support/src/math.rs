@@ -0,0 +1,324 @@
+//! Exact solutions of mathematical problems.
+//!
+//! Some problems reduce to systems of equations whose solutions must be exact, but
+//! whose coefficients are large enough that floating point arithmetic loses too much
+//! precision. The functions here use arbitrary precision [`BigRational`]s instead.
+//!
+//! Randomized algorithms whose results must be reproducible can use the simple
+//! [`SplitMix`] pseudo-random number generator, and integers of widths other than
+//! those of the primitive types can use [`Wrapping`].
+
+use crate::error::{AocError, AocResult};
+use num::{
+    traits::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub},
+    BigRational, PrimInt, Signed, Zero,
+};
+use std::{
+    fmt,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+    },
+};
+
+/// Solves the square system of linear equations `Ax = b` exactly, where `A` is the
+/// `matrix`, given as a list of rows, and `b` is the right hand side `rhs`.
+///
+/// The system is solved using Gaussian elimination with partial pivoting.
+/// Returns [`AocError::InvalidInput`] if the `matrix` is not square or its size does
+/// not match that of the `rhs`, and [`AocError::NoSolution`] if the `matrix` is
+/// singular, in which case there is either no solution or there are infinitely many.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::math::solve_linear_system;
+/// use num::{BigInt, BigRational};
+///
+/// let rational = |n: i64| BigRational::from_integer(BigInt::from(n));
+/// let matrix = [[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]
+///     .map(|row| row.map(rational).to_vec())
+///     .to_vec();
+/// let rhs = [8, -11, -3].map(rational).to_vec();
+///
+/// assert_eq!(
+///     solve_linear_system(&matrix, &rhs),
+///     Ok(vec![rational(2), rational(3), rational(-1)]),
+/// );
+///
+/// // Solutions need not be integers.
+/// let matrix = vec![vec![rational(3)]];
+/// assert_eq!(
+///     solve_linear_system(&matrix, &[rational(1)]),
+///     Ok(vec![BigRational::new(BigInt::from(1), BigInt::from(3))]),
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::math::solve_linear_system;
+/// use num::{BigInt, BigRational};
+///
+/// let rational = |n: i64| BigRational::from_integer(BigInt::from(n));
+/// let matrix = [[1, 2], [2, 4]].map(|row| row.map(rational).to_vec()).to_vec();
+/// let rhs = [1, 2].map(rational).to_vec();
+///
+/// assert_eq!(solve_linear_system(&matrix, &rhs), Err(AocError::NoSolution));
+/// assert!(solve_linear_system(&matrix, &rhs[..1]).is_err());
+/// ```
+pub fn solve_linear_system(
+    matrix: &[Vec<BigRational>],
+    rhs: &[BigRational],
+) -> AocResult<Vec<BigRational>> {
+    let n = rhs.len();
+    if matrix.len() != n || matrix.iter().any(|row| row.len() != n) {
+        return Err(AocError::InvalidInput(
+            format!("the linear system is not square with {n} equations").into(),
+        ));
+    }
+
+    // Form the augmented matrix
+    let mut rows = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, b)| {
+            let mut row = row.clone();
+            row.push(b.clone());
+            row
+        })
+        .collect::<Vec<_>>();
+
+    // Eliminate to upper triangular form
+    for column in 0..n {
+        let pivot = (column..n)
+            .filter(|r| !rows[*r][column].is_zero())
+            .max_by(|a, b| rows[*a][column].abs().cmp(&rows[*b][column].abs()))
+            .ok_or(AocError::NoSolution)?;
+        rows.swap(column, pivot);
+
+        let (upper, lower) = rows.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for row in lower.iter_mut().filter(|row| !row[column].is_zero()) {
+            let factor = &row[column] / &pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= &factor * pivot_value;
+            }
+        }
+    }
+
+    // Back substitute
+    let mut solution = vec![BigRational::zero(); n];
+    for (r, row) in rows.iter().enumerate().rev() {
+        let sum = (r + 1..n).fold(row[n].clone(), |sum, c| sum - &row[c] * &solution[c]);
+        solution[r] = sum / &row[r];
+    }
+
+    Ok(solution)
+}
+
+/// A simple pseudo-random number generator, which is the SplitMix64 algorithm.
+///
+/// This is used instead of a full random number crate so that the generated numbers
+/// are stable across versions of dependencies, and the same seed always generates the
+/// same numbers.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::math::SplitMix;
+///
+/// let mut rng = SplitMix::new(7);
+/// let numbers = [rng.next_u64(), rng.next_u64()];
+/// assert_ne!(numbers[0], numbers[1]);
+/// assert_eq!(SplitMix::new(7).next_u64(), numbers[0]);
+/// assert!(rng.below(10) < 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplitMix(u64);
+impl SplitMix {
+    /// Creates a new generator from a `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number less than `bound`.
+    ///
+    /// # Panics
+    /// This will panic if the `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % u64::try_from(bound).unwrap()) as usize
+    }
+
+    /// Returns `true` with approximately some `probability`.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Shuffles some `items` in place, with every order being equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+/// A primitive integer type that can store a [`Wrapping`] integer.
+pub trait WrappingInt: PrimInt + WrappingAdd + WrappingSub + WrappingMul + WrappingNeg {}
+impl<T: PrimInt + WrappingAdd + WrappingSub + WrappingMul + WrappingNeg> WrappingInt for T {}
+
+/// An integer with a width of some number of `BITS`, which is stored in a primitive
+/// integer type `T` that may be wider, and that wraps around on overflow.
+///
+/// This is useful for the registers of machines with a word size that is not that of
+/// a primitive type, which can also be reported to the [`Debugger`](crate::vm::Debugger)
+/// by converting them to [`i64`].
+/// If `T` is signed then so is the integer, which is in two's complement, so that its
+/// values range from -2<sup>`BITS` - 1</sup> to 2<sup>`BITS` - 1</sup> - 1, and
+/// otherwise its values range from zero to 2<sup>`BITS`</sup> - 1.
+/// Shifts by at least `BITS` shift every bit out, and division and remainder by zero
+/// panic as they do for primitive integers.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::math::Wrapping;
+///
+/// type Word = Wrapping<i64, 16>;
+/// assert_eq!((Word::new(32767) + Word::new(1)).get(), -32768);
+/// assert_eq!(Word::new(40000).get(), 40000 - 65536);
+/// assert_eq!((Word::new(-3) * Word::new(20000)).get(), 5536);
+/// assert_eq!((Word::new(-32768) / Word::new(-1)).get(), -32768);
+/// assert_eq!((-Word::new(-32768)).get(), -32768);
+/// assert_eq!((Word::new(-32768) >> 20).get(), -1);
+/// assert_eq!(i64::from(Word::new(-5)), -5);
+///
+/// type Unsigned = Wrapping<u64, 16>;
+/// assert_eq!((Unsigned::new(0) - Unsigned::new(1)).get(), 65535);
+/// assert_eq!((!Unsigned::new(123)).get(), 65412);
+/// assert_eq!((Unsigned::new(0xF00F) << 8).get(), 0x0F00);
+/// assert_eq!((Unsigned::new(0x8000) >> 20).get(), 0);
+///
+/// let mut word = Unsigned::new(65535);
+/// word += Unsigned::new(2);
+/// assert_eq!(word, Unsigned::new(1));
+/// assert_eq!(word.to_string(), "1");
+///
+/// // The full width of the primitive type also works
+/// assert_eq!(Wrapping::<i32, 32>::new(i32::MAX) + Wrapping::new(1), Wrapping::new(i32::MIN));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Wrapping<T, const BITS: u32>(T);
+impl<T: WrappingInt, const BITS: u32> Wrapping<T, BITS> {
+    /// Creates an integer from a `value`, which wraps around if it is out of the range of
+    /// the integer.
+    ///
+    /// # Panics
+    /// This will panic if `BITS` is zero or more than the width of `T`.
+    pub fn new(value: T) -> Self {
+        let width = T::zero().count_zeros();
+        assert!(
+            BITS > 0 && BITS <= width,
+            "an integer of {BITS} bits cannot be stored in {width} bits"
+        );
+
+        // Shifting back sign extends signed types and clears the high bits of unsigned ones
+        let excess = usize::try_from(width - BITS).unwrap();
+        Self((value << excess) >> excess)
+    }
+
+    /// Returns the value of the integer.
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+impl<T: fmt::Display, const BITS: u32> fmt::Display for Wrapping<T, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl<T: Into<i64>, const BITS: u32> From<Wrapping<T, BITS>> for i64 {
+    fn from(value: Wrapping<T, BITS>) -> Self {
+        value.0.into()
+    }
+}
+
+/// Implements a binary operator and its assignment operator for [`Wrapping`] integers
+/// by wrapping around the result of an operation on the values.
+macro_rules! impl_wrapping_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, |$a:ident, $b:ident| $op:expr) => {
+        impl<T: WrappingInt, const BITS: u32> $trait for Wrapping<T, BITS> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                let ($a, $b) = (self.0, rhs.0);
+                Self::new($op)
+            }
+        }
+        impl<T: WrappingInt, const BITS: u32> $assign_trait for Wrapping<T, BITS> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_wrapping_op!(Add, add, AddAssign, add_assign, |a, b| a.wrapping_add(&b));
+impl_wrapping_op!(Sub, sub, SubAssign, sub_assign, |a, b| a.wrapping_sub(&b));
+impl_wrapping_op!(Mul, mul, MulAssign, mul_assign, |a, b| a.wrapping_mul(&b));
+impl_wrapping_op!(Div, div, DivAssign, div_assign, |a, b| a / b);
+impl_wrapping_op!(Rem, rem, RemAssign, rem_assign, |a, b| a % b);
+impl_wrapping_op!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+impl_wrapping_op!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+impl_wrapping_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+
+impl<T: WrappingInt, const BITS: u32> Neg for Wrapping<T, BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.0.wrapping_neg())
+    }
+}
+impl<T: WrappingInt, const BITS: u32> Not for Wrapping<T, BITS> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::new(!self.0)
+    }
+}
+impl<T: WrappingInt, const BITS: u32> Shl<u32> for Wrapping<T, BITS> {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        if rhs >= BITS {
+            Self::new(T::zero())
+        } else {
+            Self::new(self.0 << usize::try_from(rhs).unwrap())
+        }
+    }
+}
+impl<T: WrappingInt, const BITS: u32> Shr<u32> for Wrapping<T, BITS> {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        // Shifting out all but the sign bit and then that too leaves only the sign
+        let rhs = usize::try_from(rhs.min(BITS)).unwrap();
+        if rhs == 0 {
+            self
+        } else {
+            Self::new((self.0 >> (rhs - 1)) >> 1)
+        }
+    }
+}
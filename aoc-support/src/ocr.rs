@@ -0,0 +1,129 @@
+//! Recognition of the letters that some problems draw as their answers.
+//!
+//! Several problems produce an answer that is a line of capital letters drawn in a grid,
+//! for example as the dots on a folded page or the pixels of a display.
+//! These letters are always drawn in the same font, in which each letter is six elements
+//! tall and letters are separated by at least one empty column, so that [`read_letters`]
+//! can recognize them.
+
+use crate::{
+    error::{AocError, AocResult},
+    grid::{Grid, GridPoint},
+};
+
+/// The height of the letters of the font.
+const LETTER_HEIGHT: usize = 6;
+
+/// The letters of the font that are known, along with their rows, in which `#` is set.
+const LETTERS: &[(char, [&str; LETTER_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Returns the letters drawn in a `grid`, in which the elements that are `true` are set.
+///
+/// Rows and columns without any set elements around the letters are ignored.
+/// Returns [`AocError::Process`] if the letters are not six elements tall or one of
+/// them is not one of the known letters, which are those that have appeared in problems.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::{grid::StdBool, ocr::read_letters};
+/// use std::str::FromStr;
+///
+/// let grid = Grid::<StdBool>::from_str(
+///     "........................
+/// .####..##..###...##.....
+/// ....#.#..#.#..#.#..#....
+/// ...#..#....###..#..#....
+/// ..#...#....#..#.####....
+/// .#....#..#.#..#.#..#....
+/// .####..##..###..#..#....",
+/// )
+/// .unwrap();
+/// assert_eq!(read_letters(&grid), Ok("ZCBA".to_string()));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::{grid::StdBool, ocr::read_letters};
+/// use std::str::FromStr;
+///
+/// // A square is not a letter
+/// let grid = Grid::<StdBool>::from_str("####\n#..#\n#..#\n#..#\n#..#\n####").unwrap();
+/// assert!(read_letters(&grid).is_err());
+/// // The letters are too short
+/// let grid = Grid::<StdBool>::from_str("###\n#.#\n###").unwrap();
+/// assert!(read_letters(&grid).is_err());
+/// ```
+pub fn read_letters<T: Clone + Into<bool>, U>(grid: &Grid<T, U>) -> AocResult<String> {
+    let set = |x: usize, y: usize| -> bool { grid.get(&GridPoint::new(x, y)).clone().into() };
+    let size = grid.size();
+
+    // Find the rows containing the letters
+    let rows: Vec<usize> = (0..size.height)
+        .filter(|y| (0..size.width).any(|x| set(x, *y)))
+        .collect();
+    let (top, bottom) = match (rows.first(), rows.last()) {
+        (Some(top), Some(bottom)) if bottom - top + 1 == LETTER_HEIGHT => (*top, *bottom),
+        _ => {
+            return Err(AocError::Process(
+                format!("The letters are not {LETTER_HEIGHT} elements tall").into(),
+            ))
+        }
+    };
+    let column_set = |x: usize| (top..=bottom).any(|y| set(x, y));
+
+    // Split the columns into letters at the empty columns
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < size.width {
+        if !column_set(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < size.width && column_set(x) {
+            x += 1;
+        }
+
+        let rows: Vec<String> = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if set(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = LETTERS
+            .iter()
+            .find(|(_, glyph)| glyph.iter().eq(rows.iter()))
+            .ok_or_else(|| {
+                AocError::Process(
+                    format!(
+                        "The letter at column {start} is not known:\n{}",
+                        rows.join("\n")
+                    )
+                    .into(),
+                )
+            })?;
+        letters.push(letter.0);
+    }
+    Ok(letters)
+}
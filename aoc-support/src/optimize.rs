@@ -0,0 +1,214 @@
+//! Exhaustive optimization over small spaces of integer amounts.
+//!
+//! Some problems are small integer programs, such as choosing how much of each of a few
+//! ingredients to use for a fixed total amount.
+//! Enumerating every possibility is then fast enough, and much simpler than a general
+//! solver.
+//! The [`compositions`] of a total into some number of parts can be enumerated directly,
+//! or [`maximize_composition`] can find the best one, while pruning partial compositions
+//! that cannot be completed to valid ones.
+
+use num::Num;
+
+/// An [`Iterator`] over the compositions of a total, which is created by [`compositions`].
+#[derive(Debug, Clone)]
+pub struct Compositions<T> {
+    /// The next composition, or `None` if there are no more.
+    next: Option<Vec<T>>,
+}
+impl<T: Copy + Num + PartialOrd> Iterator for Compositions<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+
+        // Increment the last part that has anything after it, moving everything after it
+        // to the last part
+        let mut next = current.clone();
+        let mut rest = T::zero();
+        for idx in (0..next.len().saturating_sub(1)).rev() {
+            rest = rest + next[idx + 1];
+            if rest > T::zero() {
+                next[idx] = next[idx] + T::one();
+                next[idx + 1..].fill(T::zero());
+                *next.last_mut().unwrap() = rest - T::one();
+                self.next = Some(next);
+                break;
+            }
+        }
+
+        Some(current)
+    }
+}
+
+/// Returns an [`Iterator`] over the compositions of a non-negative `total` into some number
+/// of `parts`, which are all the ways to write the total as an ordered sum of that many
+/// non-negative parts, in lexicographic order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::optimize::compositions;
+/// use itertools::Itertools;
+///
+/// assert_eq!(
+///     compositions(6u8, 3).collect_vec(),
+///     vec![
+///         vec![0, 0, 6],
+///         vec![0, 1, 5],
+///         vec![0, 2, 4],
+///         vec![0, 3, 3],
+///         vec![0, 4, 2],
+///         vec![0, 5, 1],
+///         vec![0, 6, 0],
+///         vec![1, 0, 5],
+///         vec![1, 1, 4],
+///         vec![1, 2, 3],
+///         vec![1, 3, 2],
+///         vec![1, 4, 1],
+///         vec![1, 5, 0],
+///         vec![2, 0, 4],
+///         vec![2, 1, 3],
+///         vec![2, 2, 2],
+///         vec![2, 3, 1],
+///         vec![2, 4, 0],
+///         vec![3, 0, 3],
+///         vec![3, 1, 2],
+///         vec![3, 2, 1],
+///         vec![3, 3, 0],
+///         vec![4, 0, 2],
+///         vec![4, 1, 1],
+///         vec![4, 2, 0],
+///         vec![5, 0, 1],
+///         vec![5, 1, 0],
+///         vec![6, 0, 0],
+///     ]
+/// );
+/// assert_eq!(compositions(100u32, 4).count(), 176851);
+///
+/// // The edge cases
+/// assert_eq!(compositions(5u8, 1).collect_vec(), vec![vec![5]]);
+/// assert_eq!(compositions(0u8, 2).collect_vec(), vec![vec![0, 0]]);
+/// assert_eq!(compositions(0u8, 0).collect_vec(), vec![Vec::<u8>::new()]);
+/// assert_eq!(compositions(5u8, 0).count(), 0);
+/// ```
+pub fn compositions<T: Copy + Num + PartialOrd>(total: T, parts: usize) -> Compositions<T> {
+    let next = if parts == 0 {
+        total.is_zero().then(Vec::new)
+    } else {
+        let mut first = vec![T::zero(); parts];
+        first[parts - 1] = total;
+        Some(first)
+    };
+    Compositions { next }
+}
+
+/// Returns the composition of a non-negative `total` into some number of `parts`, see
+/// [`compositions`], with the maximum `score`, along with the score.
+///
+/// The `score` of a composition is `None` if it is not valid, for example because it
+/// violates a constraint.
+/// Before the compositions that start with some parts are considered, `prune` is called
+/// with these parts and the amount remaining for the rest of the parts, and if it returns
+/// `true` none of these compositions are considered.
+/// This can skip compositions that could not possibly be valid or better than the best
+/// so far.
+/// Ties are broken in favor of the first composition in lexicographic order, and `None`
+/// is returned if there is no valid composition.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::optimize::maximize_composition;
+///
+/// // Maximize 3a + 2b + c where a + b + c = 10 and a is at most 4
+/// let best = maximize_composition(
+///     10,
+///     3,
+///     |parts, _| parts.first().is_some_and(|a| *a > 4),
+///     |parts| Some(3 * parts[0] + 2 * parts[1] + parts[2]),
+/// );
+/// assert_eq!(best, Some((vec![4, 6, 0], 24)));
+///
+/// // Also require that b is odd, so that [3, 7, 0] and [4, 5, 1] tie for the best
+/// let best = maximize_composition(
+///     10,
+///     3,
+///     |parts, _| parts.first().is_some_and(|a| *a > 4),
+///     |parts| (parts[1] % 2 == 1).then(|| 3 * parts[0] + 2 * parts[1] + parts[2]),
+/// );
+/// assert_eq!(best, Some((vec![3, 7, 0], 23)));
+///
+/// // No composition is valid
+/// assert_eq!(maximize_composition(10, 3, |_, _| false, |_| None::<u32>), None);
+/// ```
+pub fn maximize_composition<T, S>(
+    total: T,
+    parts: usize,
+    mut prune: impl FnMut(&[T], T) -> bool,
+    mut score: impl FnMut(&[T]) -> Option<S>,
+) -> Option<(Vec<T>, S)>
+where
+    T: Copy + Num + PartialOrd,
+    S: Ord,
+{
+    /// This is an internal recursive function of [`maximize_composition`], which
+    /// considers every composition that starts with the `prefix`, of which there
+    /// are fewer than `parts`.
+    fn rec_maximize<T: Copy + Num + PartialOrd, S: Ord>(
+        prefix: &mut Vec<T>,
+        remaining: T,
+        parts: usize,
+        prune: &mut impl FnMut(&[T], T) -> bool,
+        score: &mut impl FnMut(&[T]) -> Option<S>,
+        best: &mut Option<(Vec<T>, S)>,
+    ) {
+        if prune(prefix, remaining) {
+            return;
+        }
+
+        if prefix.len() + 1 == parts {
+            // The last part takes whatever remains
+            prefix.push(remaining);
+            if let Some(s) = score(prefix) {
+                if best.as_ref().is_none_or(|(_, b)| s > *b) {
+                    *best = Some((prefix.clone(), s));
+                }
+            }
+            prefix.pop();
+            return;
+        }
+
+        let mut amount = T::zero();
+        loop {
+            prefix.push(amount);
+            rec_maximize(prefix, remaining - amount, parts, prune, score, best);
+            prefix.pop();
+            if amount >= remaining {
+                break;
+            }
+            amount = amount + T::one();
+        }
+    }
+
+    if parts == 0 {
+        return if total.is_zero() && !prune(&[], total) {
+            score(&[]).map(|s| (Vec::new(), s))
+        } else {
+            None
+        };
+    }
+
+    let mut best = None;
+    rec_maximize(
+        &mut Vec::with_capacity(parts),
+        total,
+        parts,
+        &mut prune,
+        &mut score,
+        &mut best,
+    );
+    best
+}
@@ -0,0 +1,1108 @@
+//! Collection of items related to parsing using [`nom`].
+//!
+//! Contains some extension traits and useful [`nom`] parsers.
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, satisfy, space0, space1};
+use nom::character::is_alphanumeric;
+use nom::error::VerboseErrorKind;
+use nom::sequence::{delimited, separated_pair};
+use nom::{
+    character::complete::digit1,
+    combinator::{all_consuming, map},
+};
+use nom::{error::ErrorKind, error::VerboseError, Finish, IResult};
+use nom::{AsChar, InputIter, InputTakeAtPosition, Slice};
+use num::Unsigned;
+use std::fmt;
+use std::ops::{RangeFrom, RangeInclusive};
+use std::str::FromStr;
+
+use crate::collections::HashMap;
+use crate::direction::Direction4;
+use crate::prelude::{AocError, AocResult};
+
+/// Type of nom input when parsing bits.
+pub type BitInput<'a> = (&'a [u8], usize);
+
+/// Custom error type for [`nom`] parsing errors.
+///
+/// This is needed because the desired nom [`VerboseError`]
+/// keeps references to the input string where that could not be parsed.
+/// This does not play well with [`anyhow`], which requires that its errors have
+/// static lifetime since the error chain is passed out of the main function.
+#[derive(Debug, Clone)]
+pub struct NomParseError {
+    /// The corresponding [`VerboseError`] with an owned string.
+    verbose_error: VerboseError<String>,
+}
+impl PartialEq for NomParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.verbose_error.errors == other.verbose_error.errors
+    }
+}
+impl Eq for NomParseError {}
+impl nom::error::ParseError<&str> for NomParseError {
+    fn from_error_kind(input: &str, kind: ErrorKind) -> Self {
+        Self {
+            verbose_error: VerboseError::from_error_kind(input.to_string(), kind),
+        }
+    }
+
+    fn append(input: &str, kind: ErrorKind, other: Self) -> Self {
+        Self {
+            verbose_error: VerboseError::append(input.to_string(), kind, other.verbose_error),
+        }
+    }
+}
+/// A static string when displaying errors innvolving parsing bits.
+const BITS_STR: &str = "(bits)";
+impl nom::error::ParseError<BitInput<'_>> for NomParseError {
+    fn from_error_kind(_input: BitInput, kind: ErrorKind) -> Self {
+        Self {
+            verbose_error: VerboseError::from_error_kind(BITS_STR.to_string(), kind),
+        }
+    }
+
+    fn append(_input: BitInput, kind: ErrorKind, other: Self) -> Self {
+        Self {
+            verbose_error: VerboseError::append(BITS_STR.to_string(), kind, other.verbose_error),
+        }
+    }
+}
+impl nom::error::ContextError<&str> for NomParseError {}
+impl nom::error::ContextError<BitInput<'_>> for NomParseError {}
+impl fmt::Display for NomParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.verbose_error, f)
+    }
+}
+impl NomParseError {
+    /// Creates a parse error with a context string when parsing bits.
+    pub fn nom_err_for_bits(msg: &'static str) -> nom::Err<Self> {
+        nom::Err::Failure(NomParseError {
+            verbose_error: VerboseError {
+                errors: vec![(BITS_STR.to_string(), VerboseErrorKind::Context(msg))],
+            },
+        })
+    }
+}
+impl std::error::Error for NomParseError {}
+
+/// Type representing the result of a [`nom`] parsing.
+pub type NomParseResult<I, U> = IResult<I, U, NomParseError>;
+
+/// Extension trait that simply discards the input portion of a [`nom`]
+/// result.
+///
+/// This should be a part of the nom library in my opinion.
+pub trait DiscardInput<U, E> {
+    /// Discards the input portion of a [`nom`] result and returns a [`Result`] without
+    /// the input such that an [`Ok`] variant will contain only the parsed value.
+    fn discard_input(self) -> Result<U, E>;
+}
+impl<I, U, E> DiscardInput<U, E> for Result<(I, U), E> {
+    fn discard_input(self) -> Result<U, E> {
+        self.map(|(_, o)| o)
+    }
+}
+
+/// Trait for types that can be parsed from text with [`nom`].
+pub trait Parsable<'a> {
+    /// Needs to parse the text using [`nom`] and return the parsed item.
+    fn parser(input: &'a str) -> NomParseResult<&str, Self>
+    where
+        Self: Sized;
+
+    /// Runs the parser and gets the result, stripping out the input from the nom parser.
+    ///
+    /// Note that we cannot blanket implement [`FromStr`] for types that implement this trait
+    /// because this potentially breaks the orphan rule.
+    /// See [here](https://users.rust-lang.org/t/impl-foreign-trait-for-type-bound-by-local-trait/36299).
+    fn from_str(input: &'a str) -> Result<Self, NomParseError>
+    where
+        Self: Sized,
+    {
+        Self::parser(input).finish().discard_input()
+    }
+
+    /// Gathers a [`Vec`] of items from an iterator with each item being a string
+    /// from which to parse the item.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// assert_eq!(u8::gather(vec!["43", "22", "5", "8"].into_iter()), Ok(vec![43, 22, 5, 8]));
+    /// assert_matches!(u8::gather(vec!["43", "22", "5", "text"].into_iter()), Err(_));
+    /// ```
+    fn gather(strs: impl Iterator<Item = &'a str>) -> Result<Vec<Self>, NomParseError>
+    where
+        Self: Sized,
+    {
+        strs.map(|l| Self::from_str(l)).collect()
+    }
+
+    /// Gathers a [`Vec`] of items from an iterator of strings like [`Parsable::gather`],
+    /// but keeps parsing after a string fails to parse so that every failure is reported.
+    ///
+    /// Returns an [`AocError::InvalidInput`] with the line number, which starts from one,
+    /// the string, and the parse error of each string that fails to parse.
+    /// This is useful for finding all the typos in examples that are typed by hand.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// assert_eq!(u8::gather_lenient("43\n22\n5".lines()), Ok(vec![43, 22, 5]));
+    ///
+    /// let result = u8::gather_lenient("43\nx2\n5\n-8".lines());
+    /// assert_matches!(
+    ///     result,
+    ///     Err(AocError::InvalidInput(report))
+    ///         if report.starts_with("2 of 4 lines could not be parsed")
+    ///             && report.contains("line 2 (\"x2\")")
+    ///             && report.contains("line 4 (\"-8\")")
+    /// );
+    /// ```
+    fn gather_lenient(strs: impl Iterator<Item = &'a str>) -> AocResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        let mut items = Vec::new();
+        let mut failures = Vec::new();
+        for (idx, line) in strs.enumerate() {
+            match Self::from_str(line) {
+                Ok(item) => items.push(item),
+                Err(e) => failures.push(format!("line {} ({line:?}): {e}", idx + 1)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(items)
+        } else {
+            Err(AocError::InvalidInput(
+                format!(
+                    "{} of {} lines could not be parsed:\n{}",
+                    failures.len(),
+                    items.len() + failures.len(),
+                    failures.join("\n")
+                )
+                .into(),
+            ))
+        }
+    }
+
+    /// Gathers a [`Vec`] of items from a single string in which each item string
+    /// is separated by commas.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// assert_eq!(u8::from_csv("21,27,82,7"), Ok(vec![21, 27, 82, 7]));
+    /// assert_matches!(u8::from_csv("21,-56,82,7"), Err(_));
+    /// ```
+    fn from_csv(input: &'a str) -> Result<Vec<Self>, NomParseError>
+    where
+        Self: Sized,
+    {
+        Self::gather(input.split(','))
+    }
+}
+
+/// [`Parsable`] implementation for simple numbers.
+impl<T: Unsigned + FromStr> Parsable<'_> for T {
+    fn parser(input: &str) -> NomParseResult<&str, Self> {
+        map(digit1, |ns: &str| match ns.parse() {
+            Ok(v) => v,
+            Err(_) => panic!("nom did not parse a numeric value correctly"),
+        })(input.trim())
+    }
+}
+
+/// Trims whitespace surrounding a parser.
+///
+/// This is a [`nom`] combinator.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::trim;
+/// assert_matches!(
+///     nom::character::complete::i32::<_, NomParseError>("   -45   ").discard_input(),
+///     Err(_)
+/// );
+/// assert_eq!(
+///     trim::<_, _, _, NomParseError>(false, nom::character::complete::i32)("   -45   ")
+///         .discard_input(),
+///     Ok(-45)
+/// );
+/// assert_matches!(
+///     nom::character::complete::u8::<_, NomParseError>("\n67\n").discard_input(),
+///     Err(_)
+/// );
+/// assert_matches!(
+///     trim::<_, _, _, NomParseError>(false, nom::character::complete::i32)("\n67\n")
+///         .discard_input(),
+///     Err(_)
+/// );
+/// assert_eq!(
+///     trim::<_, _, _, NomParseError>(true, nom::character::complete::i32)("\n67\n")
+///         .discard_input(),
+///     Ok(67)
+/// );
+/// ```
+pub fn trim<I, F, O, E>(include_newlines: bool, inner: F) -> impl FnMut(I) -> IResult<I, O, E>
+where
+    I: InputTakeAtPosition,
+    <I as InputTakeAtPosition>::Item: AsChar + Clone,
+    F: FnMut(I) -> IResult<I, O, E>,
+    E: nom::error::ParseError<I>,
+{
+    let space_parser = if include_newlines {
+        multispace0
+    } else {
+        space0
+    };
+
+    delimited(space_parser, inner, space_parser)
+}
+
+/// Parses only a single alphanumeric character from a string.
+///
+/// This is a [`nom`] parser that is somehow not included in [`nom`] itself.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::single_alphanumeric;
+/// assert_eq!(single_alphanumeric::<_, NomParseError>("test").discard_input(), Ok('t'));
+/// assert_eq!(single_alphanumeric::<_, NomParseError>("67").discard_input(), Ok('6'));
+/// assert_eq!(single_alphanumeric::<_, NomParseError>("TEST").discard_input(), Ok('T'));
+/// assert_matches!(single_alphanumeric::<_, NomParseError>("-67").discard_input(), Err(_));
+/// assert_matches!(single_alphanumeric::<_, NomParseError>("&").discard_input(), Err(_));
+/// ```
+pub fn single_alphanumeric<I, E>(input: I) -> IResult<I, char, E>
+where
+    I: Slice<RangeFrom<usize>> + InputIter,
+    <I as InputIter>::Item: AsChar,
+    E: nom::error::ParseError<I>,
+{
+    satisfy(|c| {
+        if let Ok(b) = c.try_into() {
+            is_alphanumeric(b)
+        } else {
+            false
+        }
+    })(input)
+}
+
+/// Requires whitespace around a parser.
+///
+/// This is [`nom`] combinator that requires at least some whitespace before
+/// and after another parser in order to succeed. The whitespace does not
+/// include newlines.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::separated;
+/// assert_matches!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)("64").discard_input(),
+///     Err(_)
+/// );
+/// assert_eq!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)(" 64 ").discard_input(),
+///     Ok(64)
+/// );
+/// assert_eq!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)("    64  ")
+///         .discard_input(),
+///     Ok(64)
+/// );
+/// assert_matches!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)("\n64\n")
+///         .discard_input(),
+///     Err(_)
+/// );
+/// assert_matches!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)("\n  64  \n")
+///         .discard_input(),
+///     Err(_)
+/// );
+/// assert_matches!(
+///     separated::<_, _, _, NomParseError>(nom::character::complete::i32)("   \n64\n  ")
+///         .discard_input(),
+///     Err(_)
+/// );
+/// ```
+pub fn separated<I, F, O, E>(inner: F) -> impl FnMut(I) -> IResult<I, O, E>
+where
+    I: InputTakeAtPosition,
+    <I as InputTakeAtPosition>::Item: AsChar + Clone,
+    F: FnMut(I) -> IResult<I, O, E>,
+    E: nom::error::ParseError<I>,
+{
+    delimited(space1, inner, space1)
+}
+
+/// Parses a single decimal digit.
+///
+/// This is a [`nom`] parser.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::single_digit;
+/// assert_eq!(
+///     single_digit::<_, NomParseError>("76").discard_input(),
+///     Ok(7)
+/// );
+/// assert_eq!(
+///     single_digit::<_, NomParseError>("0text").discard_input(),
+///     Ok(0)
+/// );
+/// assert_matches!(
+///     single_digit::<_, NomParseError>("text").discard_input(),
+///     Err(_)
+/// );
+/// assert_matches!(
+///     single_digit::<_, NomParseError>("-9").discard_input(),
+///     Err(_)
+/// );
+/// ```
+pub fn single_digit<I, E>(input: I) -> IResult<I, u8, E>
+where
+    I: Slice<RangeFrom<usize>> + InputIter,
+    <I as InputIter>::Item: AsChar + Copy,
+    E: nom::error::ParseError<I>,
+{
+    match input
+        .iter_elements()
+        .next()
+        .map(|c| (c, c.as_char().to_digit(10)))
+    {
+        Some((c, Some(d))) => Ok((input.slice(c.len()..), d.try_into().unwrap())),
+        _ => Err(nom::Err::Error(E::from_error_kind(
+            input,
+            ErrorKind::NoneOf,
+        ))),
+    }
+}
+
+/// Parses a label followed by another parser with potential whitespace in between.
+///
+/// This is a [`nom`] parser that will also consume any whitespace (including newlines)
+/// after the `inner` parser.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::field_line_parser;
+/// assert_eq!(
+///     field_line_parser::<_, _, NomParseError>("name:", nom::character::complete::u8)(
+///         "name:        47"
+///     )
+///     .discard_input(),
+///     Ok(47)
+/// );
+/// assert_eq!(
+///     field_line_parser::<_, _, NomParseError>("job =", nom::character::complete::alpha1)(
+///         "job =electrician"
+///     )
+///     .discard_input(),
+///     Ok("electrician")
+/// );
+/// assert_matches!(
+///     field_line_parser::<_, _, NomParseError>("class:", nom::character::complete::alpha1)(
+///         "class = mage"
+///     ),
+///     Err(_)
+/// );
+/// ```
+pub fn field_line_parser<'a, F, O, E>(
+    label: &'static str,
+    inner: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
+where
+    E: nom::error::ParseError<&'a str>,
+    F: FnMut(&'a str) -> IResult<&'a str, O, E>,
+{
+    delimited(tag(label), trim(false, inner), multispace0)
+}
+
+/// Parses an inclusive range.
+///
+/// This is a [`nom`] parser parses two numbers with a dash in between as
+/// an inclusive range.
+/// The numeric type is determined from the `inner` parser output type.
+/// Whitespace is allowed between the dash and the numbers, but not newlines.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// # use aoc::parse::inclusive_range;
+/// assert_eq!(
+///     inclusive_range::<_, NomParseError>(nom::character::complete::u8)("4-13").discard_input(),
+///     Ok(4..=13)
+/// );
+/// assert_eq!(
+///     inclusive_range::<_, NomParseError>(nom::character::complete::i32)("-89765 - -1234").discard_input(),
+///     Ok(-89765..=-1234)
+/// );
+/// assert_matches!(
+///     inclusive_range::<_, NomParseError>(nom::character::complete::u16)("1-xyz"),
+///     Err(_)
+/// );
+/// ```
+pub fn inclusive_range<'a, O, E>(
+    inner: fn(&'a str) -> IResult<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, RangeInclusive<O>, E>
+where
+    E: nom::error::ParseError<&'a str>,
+{
+    map(
+        separated_pair(inner, delimited(space0, tag("-"), space0), inner),
+        |(a, b)| a..=b,
+    )
+}
+
+/// The usual single letter keywords for moves in each [`Direction4`], for use with [`keyword`].
+pub const LETTER_DIRECTIONS: [(&str, Direction4); 4] = [
+    ("U", Direction4::Up),
+    ("R", Direction4::Right),
+    ("D", Direction4::Down),
+    ("L", Direction4::Left),
+];
+
+/// Parses one of several keywords, returning the value to which it is mapped.
+///
+/// This is a [`nom`] parser that tries every keyword in the `mappings`, with the longest
+/// matching keyword being used so that the order of the mappings does not matter.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::parse::{keyword, LETTER_DIRECTIONS};
+///
+/// assert_eq!(
+///     keyword::<_, NomParseError>(&LETTER_DIRECTIONS)("R 4"),
+///     Ok((" 4", Direction4::Right))
+/// );
+/// assert_eq!(
+///     keyword::<_, NomParseError>(&[("a", 1), ("ab", 2)])("abc").discard_input(),
+///     Ok(2)
+/// );
+/// assert_matches!(keyword::<_, NomParseError>(&LETTER_DIRECTIONS)("X 4"), Err(_));
+/// ```
+pub fn keyword<'a, D: Clone, E>(
+    mappings: &'a [(&'a str, D)],
+) -> impl FnMut(&'a str) -> IResult<&'a str, D, E>
+where
+    E: nom::error::ParseError<&'a str>,
+{
+    move |input: &'a str| {
+        mappings
+            .iter()
+            .filter(|(k, _)| input.starts_with(k))
+            .max_by_key(|(k, _)| k.len())
+            .map(|(k, d)| (&input[k.len()..], d.clone()))
+            .ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::Tag)))
+    }
+}
+
+/// Parses a move, which is a direction followed by a signed distance.
+///
+/// This is a [`nom`] combinator in which the `direction` parser can be any parser,
+/// for example [`keyword`] or the parser of a [`Parsable`] type.
+/// The direction and distance must be separated by whitespace, but not newlines.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::parse::{keyword, move_parser, LETTER_DIRECTIONS};
+///
+/// assert_eq!(
+///     move_parser::<_, NomParseError, _>(keyword(&LETTER_DIRECTIONS))("U -12").discard_input(),
+///     Ok((Direction4::Up, -12))
+/// );
+/// ```
+pub fn move_parser<'a, D, E, F>(
+    direction: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (D, i64), E>
+where
+    E: nom::error::ParseError<&'a str>,
+    F: FnMut(&'a str) -> IResult<&'a str, D, E>,
+{
+    separated_pair(direction, space1, nom::character::complete::i64)
+}
+
+/// Parses a list of moves with one on each line, where each move is parsed as for
+/// [`move_parser`] using a `direction` parser.
+///
+/// Empty lines are ignored, and leading and trailing whitespace is ignored on each line.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::parse::{keyword, move_list, LETTER_DIRECTIONS};
+///
+/// assert_eq!(
+///     move_list("R 4\nU 2\n", keyword(&LETTER_DIRECTIONS)),
+///     Ok(vec![(Direction4::Right, 4), (Direction4::Up, 2)])
+/// );
+/// assert_matches!(move_list("R 4\nU 2 3", keyword(&LETTER_DIRECTIONS)), Err(_));
+///
+/// // Commands with keywords
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// enum Command {
+///     Forward,
+///     Down,
+///     Up,
+/// }
+/// let commands = [
+///     ("forward", Command::Forward),
+///     ("down", Command::Down),
+///     ("up", Command::Up),
+/// ];
+/// assert_eq!(
+///     move_list("forward 5\ndown 5\nup 3", keyword(&commands)),
+///     Ok(vec![(Command::Forward, 5), (Command::Down, 5), (Command::Up, 3)])
+/// );
+/// ```
+pub fn move_list<'a, D>(
+    input: &'a str,
+    direction: impl FnMut(&'a str) -> NomParseResult<&'a str, D>,
+) -> AocResult<Vec<(D, i64)>> {
+    let mut parser = all_consuming(move_parser(direction));
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| Ok(parser(line).finish().discard_input()?))
+        .collect()
+}
+
+/// Extension trait to break a string into some number of section substrings.
+pub trait Sections {
+    /// Breaks the string into `num` sections.
+    ///
+    /// Each section is separated by a double newline. This will fail if
+    /// the input string does not contain exactly the correct number of
+    /// sections.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #![feature(assert_matches)]
+    /// # use std::assert_matches::assert_matches;
+    /// # use aoc::prelude::*;
+    /// assert_eq!(
+    ///     "section 1\n\nsection 2\n\nsection 3".sections(3),
+    ///     Ok(vec!["section 1", "section 2", "section 3"])
+    /// );
+    /// assert_eq!(
+    ///     "section\n1\n\nsection\n2\n\nsection\n3".sections(3),
+    ///     Ok(vec!["section\n1", "section\n2", "section\n3"])
+    /// );
+    /// assert_matches!("section 1\n\nsection 2".sections(3), Err(_));
+    /// assert_matches!(
+    ///     "section 1\n\nsection 2\n\nsection 3\n\nsection 4".sections(3),
+    ///     Err(_)
+    /// );
+    /// assert_matches!("section 1\nsection 2\nsection 3".sections(3), Err(_));
+    /// ```
+    fn sections(&self, num: usize) -> AocResult<Vec<&str>>;
+}
+impl Sections for str {
+    fn sections(&self, num: usize) -> AocResult<Vec<&str>> {
+        let secs: Vec<&str> = self.split("\n\n").collect();
+        if secs.len() == num {
+            Ok(secs)
+        } else {
+            Err(AocError::InvalidInput(
+                format!(
+                    "Expected {} sections from the input, found {}",
+                    num,
+                    secs.len()
+                )
+                .into(),
+            ))
+        }
+    }
+}
+
+/// The result of checking a string of nested brackets with a [`BracketMatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BracketMatch {
+    /// Every bracket is closed by its matching bracket.
+    Balanced,
+    /// The first closing bracket that does not match the open bracket it closes,
+    /// or that closes a bracket that was never opened.
+    Illegal(char),
+    /// Every closing bracket matches, but some brackets are left open, with the
+    /// closing brackets needed to complete the string in order.
+    Incomplete(String),
+}
+impl BracketMatch {
+    /// Returns the score of the illegal closing bracket, if there is one, using
+    /// a `score` for each closing bracket.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::parse::BracketMatch;
+    ///
+    /// let score = |c| if c == ')' { 3 } else { 57 };
+    /// assert_eq!(BracketMatch::Illegal(']').illegal_score(score), Some(57));
+    /// assert_eq!(BracketMatch::Balanced.illegal_score(score), None);
+    /// ```
+    pub fn illegal_score(&self, score: impl Fn(char) -> u64) -> Option<u64> {
+        match self {
+            Self::Illegal(c) => Some(score(*c)),
+            _ => None,
+        }
+    }
+
+    /// Returns the score of the completion string, if the string is incomplete.
+    ///
+    /// The completion is scored one closing bracket at a time, by multiplying the
+    /// score so far by the `base` and then adding the `score` of the bracket.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::parse::BracketMatch;
+    ///
+    /// let score = |c| if c == ')' { 1 } else { 2 };
+    /// assert_eq!(
+    ///     BracketMatch::Incomplete("])".into()).completion_score(5, score),
+    ///     Some(11)
+    /// );
+    /// assert_eq!(BracketMatch::Illegal(')').completion_score(5, score), None);
+    /// ```
+    pub fn completion_score(&self, base: u64, score: impl Fn(char) -> u64) -> Option<u64> {
+        match self {
+            Self::Incomplete(s) => Some(s.chars().fold(0, |a, c| base * a + score(c))),
+            _ => None,
+        }
+    }
+}
+
+/// Checks strings of nested brackets, where each kind of bracket is a pair of
+/// open and close characters.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::parse::{BracketMatch, BracketMatcher};
+///
+/// let matcher = BracketMatcher::default();
+/// assert_eq!(matcher.check("[<>({}){}[([])<>]]"), Ok(BracketMatch::Balanced));
+/// assert_eq!(matcher.check("{([(<{}[<>[]}>{[]{[(<()>"), Ok(BracketMatch::Illegal('}')));
+/// assert_eq!(matcher.check("())"), Ok(BracketMatch::Illegal(')')));
+/// assert_eq!(
+///     matcher.check("[({(<(())[]>[[{[]{<()<>>"),
+///     Ok(BracketMatch::Incomplete("}}]])})]".into()))
+/// );
+///
+/// // Any pairs of characters can be brackets
+/// let matcher = BracketMatcher::new(&[('a', 'b'), ('x', 'y')]);
+/// assert_eq!(matcher.check("axyab"), Ok(BracketMatch::Balanced));
+/// assert_eq!(matcher.check("axb"), Ok(BracketMatch::Illegal('b')));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::parse::BracketMatcher;
+///
+/// assert!(BracketMatcher::default().check("(a)").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BracketMatcher {
+    /// The open and close characters of each kind of bracket.
+    pairs: Vec<(char, char)>,
+}
+impl Default for BracketMatcher {
+    /// Creates a matcher for parentheses, square brackets, curly braces, and angle
+    /// brackets.
+    fn default() -> Self {
+        Self::new(&[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')])
+    }
+}
+impl BracketMatcher {
+    /// Creates a matcher for some `pairs` of open and close bracket characters.
+    pub fn new(pairs: &[(char, char)]) -> Self {
+        Self {
+            pairs: pairs.to_vec(),
+        }
+    }
+
+    /// Checks whether the brackets in a string `s` match.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the string contains a character that
+    /// is not one of the brackets.
+    pub fn check(&self, s: &str) -> AocResult<BracketMatch> {
+        let mut stack = Vec::new();
+        for c in s.chars() {
+            if let Some((_, close)) = self.pairs.iter().find(|(o, _)| *o == c) {
+                stack.push(*close);
+            } else if self.pairs.iter().any(|(_, cl)| *cl == c) {
+                if stack.pop() != Some(c) {
+                    return Ok(BracketMatch::Illegal(c));
+                }
+            } else {
+                return Err(AocError::InvalidInput(
+                    format!("'{c}' is not a bracket character").into(),
+                ));
+            }
+        }
+
+        Ok(if stack.is_empty() {
+            BracketMatch::Balanced
+        } else {
+            BracketMatch::Incomplete(stack.into_iter().rev().collect())
+        })
+    }
+}
+
+/// Splits a line of `input` into the fields corresponding to the `{}` placeholders
+/// in a `pattern`, with everything else in the pattern needing to match exactly.
+///
+/// Each field extends until the first occurrence of the literal text that follows
+/// its placeholder in the pattern, so that placeholders should be separated by
+/// some literal text.
+/// This is mainly for use by the [`scan`](crate::scan) macro.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::parse::scan_fields;
+///
+/// assert_eq!(
+///     scan_fields("{}-{} {}: {}", "1-3 a: abcde"),
+///     Ok(vec!["1", "3", "a", "abcde"])
+/// );
+/// assert_eq!(
+///     scan_fields("move {} from {}", "move 5 from 7"),
+///     Ok(vec!["5", "7"])
+/// );
+/// assert_matches!(scan_fields("{}-{}", "1:3"), Err(AocError::InvalidInput(_)));
+/// assert_matches!(
+///     scan_fields("move {} from {}!", "move 5 from 7"),
+///     Err(AocError::InvalidInput(_))
+/// );
+/// ```
+pub fn scan_fields<'a>(pattern: &str, input: &'a str) -> AocResult<Vec<&'a str>> {
+    let mismatch = || {
+        AocError::InvalidInput(format!("'{input}' does not match the pattern '{pattern}'").into())
+    };
+    let mut literals = pattern.split("{}");
+
+    // The first literal is always present, even if it is empty
+    let mut remaining = input
+        .strip_prefix(literals.next().unwrap())
+        .ok_or_else(mismatch)?;
+    let mut fields = Vec::new();
+    for literal in literals {
+        let end = if literal.is_empty() {
+            remaining.len()
+        } else {
+            remaining.find(literal).ok_or_else(mismatch)?
+        };
+        fields.push(&remaining[..end]);
+        remaining = &remaining[end + literal.len()..];
+    }
+
+    if remaining.is_empty() {
+        Ok(fields)
+    } else {
+        Err(mismatch())
+    }
+}
+
+/// Parses a single field extracted by [`scan_fields`] into a type.
+///
+/// This is mainly for use by the [`scan`](crate::scan) macro.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::parse::scan_field;
+///
+/// assert_eq!(scan_field::<u8>("42"), Ok(42));
+/// assert_matches!(scan_field::<char>("ab"), Err(AocError::InvalidInput(_)));
+/// ```
+pub fn scan_field<T: FromStr>(field: &str) -> AocResult<T> {
+    field.parse().map_err(|_| {
+        AocError::InvalidInput(
+            format!(
+                "could not parse '{field}' as {}",
+                std::any::type_name::<T>()
+            )
+            .into(),
+        )
+    })
+}
+
+/// Destructures a line of input according to a simple pattern, evaluating to an
+/// [`AocResult`] tuple of the parsed fields.
+///
+/// Each `{}` placeholder in the pattern is parsed as the corresponding type using
+/// [`FromStr`], and the rest of the pattern must match the input exactly.
+/// This is a lightweight alternative to [`nom`] parsers for simple line formats.
+/// See [`scan_fields`] for how the fields are delimited.
+///
+/// # Panics
+/// This will panic if the number of types does not match the number of placeholders in
+/// the pattern.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// assert_eq!(
+///     scan!("{}-{} {}: {}", "1-3 a: abcde" => u32, u32, char, String),
+///     Ok((1, 3, 'a', "abcde".to_string()))
+/// );
+/// assert_matches!(
+///     scan!("{}-{} {}: {}", "1-3 ab: abcde" => u32, u32, char, String),
+///     Err(AocError::InvalidInput(_))
+/// );
+/// assert_matches!(
+///     scan!("{}x{}", "3 by 4" => u8, u8),
+///     Err(AocError::InvalidInput(_))
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```should_panic
+/// # use aoc::prelude::*;
+/// let _ = scan!("{}x{}", "3x4" => u8);
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern: expr, $input: expr => $($t: ty),+ $(,)?) => {
+        $crate::parse::scan_fields($pattern, $input).and_then(|fields| {
+            let expected = [$(stringify!($t)),+].len();
+            assert_eq!(
+                fields.len(),
+                expected,
+                "the scan pattern has {} placeholders but {} types were given",
+                fields.len(),
+                expected
+            );
+            let mut fields = fields.into_iter();
+
+            Ok(($($crate::parse::scan_field::<$t>(fields.next().unwrap())?,)+))
+        })
+    };
+}
+
+/// A block of whitespace-separated `key:value` fields, such as a record spread over
+/// several lines of input, from which values can be extracted and validated.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # #![feature(assert_matches)]
+/// # use std::assert_matches::assert_matches;
+/// # use aoc::prelude::*;
+/// use aoc::parse::RecordBlock;
+///
+/// let record = RecordBlock::parse("ecl:gry pid:860033327\nbyr:1937 hgt:183cm").unwrap();
+/// assert_eq!(record.len(), 4);
+/// assert_eq!(record.get("ecl"), Some("gry"));
+/// assert_eq!(record.get("cid"), None);
+/// assert!(record.has_all(&["ecl", "pid", "byr"]));
+/// assert!(!record.has_all(&["ecl", "cid"]));
+///
+/// assert_eq!(record.get_parse::<u32>("byr"), Ok(1937));
+/// assert_eq!(record.get_parse_within("byr", 1920..=2002), Ok(1937));
+/// assert_eq!(
+///     record.get_validate("hgt", |v| v.ends_with("cm")),
+///     Ok("183cm")
+/// );
+///
+/// assert_matches!(record.get_parse::<u32>("ecl"), Err(AocError::InvalidInput(_)));
+/// assert_matches!(
+///     record.get_parse_within("byr", 1940..=2002),
+///     Err(AocError::InvalidInput(_))
+/// );
+/// assert_matches!(record.require("cid"), Err(AocError::InvalidInput(_)));
+/// assert_matches!(
+///     record.require_all(&["pid", "cid"]),
+///     Err(AocError::InvalidInput(_))
+/// );
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::parse::RecordBlock;
+///
+/// // A token without a colon
+/// assert!(RecordBlock::parse("ecl:gry pid").is_err());
+/// // A repeated key
+/// assert!(RecordBlock::parse("ecl:gry ecl:amb").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordBlock<'a> {
+    /// The value of each key.
+    fields: HashMap<&'a str, &'a str>,
+}
+impl<'a> RecordBlock<'a> {
+    /// Parses a block from `input` text, in which the fields are separated by any
+    /// whitespace, including newlines.
+    ///
+    /// Returns [`AocError::InvalidInput`] if a field has no colon or a key is repeated.
+    pub fn parse(input: &'a str) -> AocResult<Self> {
+        let mut fields = HashMap::default();
+        for token in input.split_whitespace() {
+            let (key, value) = token.split_once(':').ok_or_else(|| {
+                AocError::InvalidInput(format!("The field '{token}' has no colon").into())
+            })?;
+            if fields.insert(key, value).is_some() {
+                return Err(AocError::InvalidInput(
+                    format!("The key '{key}' appears more than once").into(),
+                ));
+            }
+        }
+        Ok(Self { fields })
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns whether there are no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns an [`Iterator`] over the keys, in no particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.fields.keys().copied()
+    }
+
+    /// Returns the value of a `key`, if the block has it.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.fields.get(key).copied()
+    }
+
+    /// Returns whether the block has every one of some `keys`.
+    pub fn has_all(&self, keys: &[&str]) -> bool {
+        keys.iter().all(|key| self.fields.contains_key(key))
+    }
+
+    /// Returns the value of a `key`.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the block does not have the key.
+    pub fn require(&self, key: &str) -> AocResult<&'a str> {
+        self.get(key).ok_or_else(|| {
+            AocError::InvalidInput(format!("The required field '{key}' is missing").into())
+        })
+    }
+
+    /// Checks that the block has every one of some `keys`.
+    ///
+    /// Returns [`AocError::InvalidInput`] for the first key that the block does not have.
+    pub fn require_all(&self, keys: &[&str]) -> AocResult<()> {
+        keys.iter()
+            .try_for_each(|key| self.require(key).map(|_| ()))
+    }
+
+    /// Returns the value of a `key` parsed as a type.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the block does not have the key or the
+    /// value cannot be parsed.
+    pub fn get_parse<T: FromStr>(&self, key: &str) -> AocResult<T> {
+        scan_field(self.require(key)?)
+    }
+
+    /// Returns the value of a `key` parsed as a type, which must be in a `range`.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the block does not have the key, the
+    /// value cannot be parsed, or it is outside the range.
+    pub fn get_parse_within<T: FromStr + PartialOrd + fmt::Debug>(
+        &self,
+        key: &str,
+        range: RangeInclusive<T>,
+    ) -> AocResult<T> {
+        let value = self.get_parse(key)?;
+        if range.contains(&value) {
+            Ok(value)
+        } else {
+            Err(AocError::InvalidInput(
+                format!("The field '{key}' value {value:?} is not in the range {range:?}").into(),
+            ))
+        }
+    }
+
+    /// Returns the value of a `key` that passes a `validate` check.
+    ///
+    /// Returns [`AocError::InvalidInput`] if the block does not have the key or the
+    /// value does not pass the check.
+    pub fn get_validate(
+        &self,
+        key: &str,
+        validate: impl FnOnce(&str) -> bool,
+    ) -> AocResult<&'a str> {
+        let value = self.require(key)?;
+        if validate(value) {
+            Ok(value)
+        } else {
+            Err(AocError::InvalidInput(
+                format!("The field '{key}' has the invalid value '{value}'").into(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Engines for kinds of puzzles that recur across problems with different details.
+//!
+//! - [`keys_doors`] collects every key in a maze in which doors need their keys, with
+//!   one or more robots.
+//! - [`token_sort`] moves typed tokens around a small graph of spaces to their home
+//!   spaces at the least cost, such as amphipods sorting themselves into rooms.
+
+pub mod keys_doors;
+pub mod token_sort;
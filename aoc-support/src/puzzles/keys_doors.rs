@@ -0,0 +1,345 @@
+//! Mazes in which robots must collect every key, where each door can only be passed once
+//! its key has been collected.
+//!
+//! A [`KeyMaze`] is created from a [`Grid`] of [`Tile`]s, which can be parsed from the
+//! usual characters of these puzzles.
+//! The shortest [`Route`] from each starting position and key to every other key is
+//! found up front, along with the doors that block it, so that the search for the fewest
+//! steps only needs to consider which key each robot goes to next.
+//! The search is a Dijkstra search over the positions of the robots and the set of keys
+//! collected so far, which is a bit mask.
+
+use crate::{
+    collections::HashMap,
+    error::{AocError, AocResult},
+    grid::{Grid, GridPoint},
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    str::FromStr,
+};
+
+/// The maximum number of keys in a [`KeyMaze`], which is the number of bits in a set of
+/// keys.
+pub const MAX_KEYS: usize = 32;
+
+/// An element of a maze [`Grid`], which can be parsed from characters.
+///
+/// Walls are `#`, open spaces are `.`, starting positions are `@`, keys are lowercase
+/// letters, and doors are uppercase letters, where the door `A` is opened by the key `a`.
+/// Keys and doors are numbered from zero in alphabetical order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    /// A wall, which cannot be passed.
+    Wall,
+    /// An open space.
+    Open,
+    /// The starting position of a robot, which is otherwise an open space.
+    Start,
+    /// A key with a number.
+    Key(u8),
+    /// A door with the number of its key.
+    Door(u8),
+}
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '#' => Self::Wall,
+            '.' => Self::Open,
+            '@' => Self::Start,
+            'a'..='z' => Self::Key(value as u8 - b'a'),
+            'A'..='Z' => Self::Door(value as u8 - b'A'),
+            _ => return Err(()),
+        })
+    }
+}
+
+/// A place in a [`KeyMaze`] at which a robot can be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Location {
+    /// The starting position of the robot with an index.
+    Start(usize),
+    /// The position of the key with a number.
+    Key(u8),
+}
+
+/// The shortest route from a [`Location`] in a [`KeyMaze`] to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Route {
+    /// The number of the key at the end of the route.
+    pub key: u8,
+    /// The number of steps along the route.
+    pub distance: u64,
+    /// The set of keys whose doors are on the route, all of which must be collected
+    /// before the route can be taken.
+    pub doors: u32,
+    /// The set of other keys on the route, which are collected along the way.
+    pub keys: u32,
+}
+
+/// A maze of keys and doors, along with the routes between its starting positions and
+/// keys.
+///
+/// Each starting position has its own robot, and only one robot moves at a time.
+/// The routes are the shortest paths, so the doors and keys on a route are those on one
+/// of the shortest paths, which is exact for mazes in which there is only one path
+/// between any two positions, as is the case in these puzzles.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::puzzles::keys_doors::{KeyMaze, Location, Route};
+/// use std::str::FromStr;
+///
+/// let maze = KeyMaze::from_str(
+///     "#########
+/// #b.A.@.a#
+/// #########",
+/// )
+/// .unwrap();
+/// assert_eq!(maze.num_robots(), 1);
+/// assert_eq!(maze.num_keys(), 2);
+/// assert_eq!(
+///     maze.routes(Location::Key(0)),
+///     &[Route {
+///         key: 1,
+///         distance: 6,
+///         doors: 0b1,
+///         keys: 0,
+///     }],
+/// );
+/// assert_eq!(maze.min_steps(), Ok(8));
+///
+/// let maze = KeyMaze::from_str(
+///     "########################
+/// #f.D.E.e.C.b.A.@.a.B.c.#
+/// ######################.#
+/// #d.....................#
+/// ########################",
+/// )
+/// .unwrap();
+/// assert_eq!(maze.min_steps(), Ok(86));
+/// ```
+///
+/// Multiple robots, which are in separate parts of the maze:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::puzzles::keys_doors::KeyMaze;
+/// use std::str::FromStr;
+///
+/// let maze = KeyMaze::from_str(
+///     "#######
+/// #a.#Cd#
+/// ##@#@##
+/// #######
+/// ##@#@##
+/// #cB#Ab#
+/// #######",
+/// )
+/// .unwrap();
+/// assert_eq!(maze.num_robots(), 4);
+/// assert_eq!(maze.min_steps(), Ok(8));
+///
+/// let maze = KeyMaze::from_str(
+///     "###############
+/// #d.ABC.#.....a#
+/// ######@#@######
+/// ###############
+/// ######@#@######
+/// #b.....#.....c#
+/// ###############",
+/// )
+/// .unwrap();
+/// assert_eq!(maze.min_steps(), Ok(24));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::puzzles::keys_doors::KeyMaze;
+/// use std::str::FromStr;
+///
+/// // There is no starting position
+/// assert!(KeyMaze::from_str("#a.b#").is_err());
+/// // There are two of the same key
+/// assert!(KeyMaze::from_str("#a@a#").is_err());
+/// // The door is closed for good since its key is missing
+/// assert_eq!(
+///     KeyMaze::from_str("#a@B.b#").unwrap().min_steps(),
+///     Err(AocError::NoSolution),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyMaze {
+    /// The routes from the starting position of each robot.
+    starts: Vec<Vec<Route>>,
+    /// The routes from each key, which are empty for keys that are not in the maze.
+    keys: Vec<Vec<Route>>,
+    /// The set of all the keys in the maze.
+    all_keys: u32,
+}
+impl KeyMaze {
+    /// Creates a maze from a `grid` of tiles, finding the routes from every starting
+    /// position and key.
+    ///
+    /// Returns [`AocError::InvalidInput`] if there are no starting positions, a key
+    /// appears more than once, or a key number is too large, see [`MAX_KEYS`].
+    pub fn new<U>(grid: &Grid<Tile, U>) -> AocResult<Self> {
+        let mut starts = Vec::new();
+        let mut keys: Vec<Option<GridPoint<U>>> = vec![None; MAX_KEYS];
+        for point in grid.all_points() {
+            match grid.get(&point) {
+                Tile::Start => starts.push(point),
+                Tile::Key(key) | Tile::Door(key) if usize::from(*key) >= MAX_KEYS => {
+                    return Err(AocError::InvalidInput(
+                        format!("The key number {key} is too large").into(),
+                    ));
+                }
+                Tile::Key(key) => {
+                    if keys[usize::from(*key)].replace(point).is_some() {
+                        return Err(AocError::InvalidInput(
+                            format!("The key number {key} appears more than once").into(),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if starts.is_empty() {
+            return Err(AocError::InvalidInput(
+                "There are no starting positions".into(),
+            ));
+        }
+
+        Ok(Self {
+            starts: starts.iter().map(|p| Self::find_routes(grid, p)).collect(),
+            all_keys: keys
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| p.is_some())
+                .fold(0, |set, (key, _)| set | (1 << key)),
+            keys: keys
+                .iter()
+                .map(|p| match p {
+                    Some(p) => Self::find_routes(grid, p),
+                    None => Vec::new(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the shortest routes from a `start` point to every key that can be reached,
+    /// searching breadth first.
+    fn find_routes<U>(grid: &Grid<Tile, U>, start: &GridPoint<U>) -> Vec<Route> {
+        let mut routes = Vec::new();
+        let mut seen = Grid::<bool, U>::default(*grid.size());
+        seen.set(start, true);
+        let mut queue = VecDeque::from([(*start, 0, 0, 0)]);
+        while let Some((point, distance, doors, keys)) = queue.pop_front() {
+            for next in grid.neighbor_points(&point, false, false) {
+                if *seen.get(&next) {
+                    continue;
+                }
+                seen.set(&next, true);
+
+                let (mut doors, mut keys) = (doors, keys);
+                match grid.get(&next) {
+                    Tile::Wall => continue,
+                    Tile::Door(key) => doors |= 1 << key,
+                    Tile::Key(key) => {
+                        routes.push(Route {
+                            key: *key,
+                            distance: distance + 1,
+                            doors,
+                            keys,
+                        });
+                        keys |= 1 << key;
+                    }
+                    Tile::Open | Tile::Start => {}
+                }
+                queue.push_back((next, distance + 1, doors, keys));
+            }
+        }
+        routes
+    }
+
+    /// Returns the number of robots, which is the number of starting positions.
+    pub fn num_robots(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Returns the number of keys in the maze.
+    pub fn num_keys(&self) -> usize {
+        self.all_keys.count_ones().try_into().unwrap()
+    }
+
+    /// Returns the shortest routes from a `location` to every key that can be reached
+    /// from it, in the order in which the keys are reached.
+    ///
+    /// # Panics
+    /// This will panic if there is no robot with the index of a starting position or the
+    /// number of a key is too large, see [`MAX_KEYS`].
+    pub fn routes(&self, location: Location) -> &[Route] {
+        match location {
+            Location::Start(robot) => &self.starts[robot],
+            Location::Key(key) => &self.keys[usize::from(key)],
+        }
+    }
+
+    /// Returns the fewest total steps for the robots to collect every key.
+    ///
+    /// Routes that pass other keys that have not been collected are not taken, since
+    /// collecting those keys first is just as short.
+    /// Returns [`AocError::NoSolution`] if some keys can never be reached.
+    pub fn min_steps(&self) -> AocResult<u64> {
+        let start = (0..self.num_robots())
+            .map(Location::Start)
+            .collect::<Vec<_>>();
+        let mut best = HashMap::default();
+        best.insert((start.clone(), 0), 0);
+        let mut queue = BinaryHeap::from([Reverse((0, 0, start))]);
+
+        while let Some(Reverse((distance, collected, locations))) = queue.pop() {
+            if collected == self.all_keys {
+                return Ok(distance);
+            }
+            if best[&(locations.clone(), collected)] < distance {
+                continue;
+            }
+
+            for (robot, location) in locations.iter().enumerate() {
+                for route in self.routes(*location) {
+                    if collected & (1 << route.key) != 0
+                        || route.doors & !collected != 0
+                        || route.keys & !collected != 0
+                    {
+                        continue;
+                    }
+
+                    let mut next = locations.clone();
+                    next[robot] = Location::Key(route.key);
+                    let collected = collected | (1 << route.key);
+                    let distance = distance + route.distance;
+                    let known = best.entry((next.clone(), collected)).or_insert(u64::MAX);
+                    if distance < *known {
+                        *known = distance;
+                        queue.push(Reverse((distance, collected, next)));
+                    }
+                }
+            }
+        }
+
+        Err(AocError::NoSolution)
+    }
+}
+impl FromStr for KeyMaze {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(&Grid::from_str(s)?)
+    }
+}
@@ -0,0 +1,436 @@
+//! Puzzles in which typed tokens move between the spaces of a small graph until every
+//! home space holds a token of its type, at the least total cost.
+//!
+//! A [`TokenSort`] puzzle is defined by a [`SpaceGraph`], the home spaces of each type
+//! of token, the cost for each type of token to move a unit of distance, and a rule
+//! that decides which moves are allowed.
+//! Tokens can only pass through spaces that are not occupied, and each move takes a
+//! token along the shortest such path, so that the rule only needs to decide where
+//! tokens may stop, given the [`TokenState`] of all the tokens.
+//! The puzzle is solved with a [`BestCostTreeNode`] search.
+
+use crate::{
+    collections::HashMap,
+    error::AocResult,
+    tree_search::{ApplyNodeAction, BestCostChild, BestCostTreeNode, Metric},
+};
+use derive_more::Add;
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+/// An undirected graph of spaces, which are numbered from zero, with a distance along
+/// each edge.
+#[derive(Debug, Clone)]
+pub struct SpaceGraph {
+    /// The neighboring spaces of each space, along with the distances to them.
+    neighbors: Vec<Vec<(usize, u64)>>,
+}
+impl SpaceGraph {
+    /// Creates a graph of some number of spaces without any edges.
+    pub fn new(len: usize) -> Self {
+        Self {
+            neighbors: vec![Vec::new(); len],
+        }
+    }
+
+    /// Returns the number of spaces.
+    pub fn len(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    /// Returns whether there are no spaces.
+    pub fn is_empty(&self) -> bool {
+        self.neighbors.is_empty()
+    }
+
+    /// Adds an edge between the spaces `a` and `b` with some `distance`.
+    ///
+    /// # Panics
+    /// This will panic if either space is out of bounds.
+    pub fn add_edge(&mut self, a: usize, b: usize, distance: u64) {
+        assert!(
+            a < self.len() && b < self.len(),
+            "the edge from {a} to {b} is out of bounds"
+        );
+        self.neighbors[a].push((b, distance));
+        self.neighbors[b].push((a, distance));
+    }
+
+    /// Returns the shortest distance from a `start` space to every space, only passing
+    /// through spaces that are `open`.
+    ///
+    /// The distance to a space is `None` if it cannot be reached in this way.
+    /// The `start` space itself does not need to be open.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::puzzles::token_sort::SpaceGraph;
+    ///
+    /// let mut graph = SpaceGraph::new(4);
+    /// graph.add_edge(0, 1, 2);
+    /// graph.add_edge(1, 2, 3);
+    /// graph.add_edge(0, 2, 6);
+    ///
+    /// assert_eq!(graph.distances(0, |_| true), vec![Some(0), Some(2), Some(5), None]);
+    /// assert_eq!(graph.distances(0, |s| s != 1), vec![Some(0), None, Some(6), None]);
+    /// ```
+    pub fn distances(&self, start: usize, open: impl Fn(usize) -> bool) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((distance, space))) = queue.pop() {
+            if distances[space].is_some() {
+                continue;
+            }
+            distances[space] = Some(distance);
+            for (next, edge) in self.neighbors[space].iter() {
+                if distances[*next].is_none() && open(*next) {
+                    queue.push(Reverse((distance + edge, *next)));
+                }
+            }
+        }
+        distances
+    }
+}
+
+/// A move of a token from one space to another, which the rule of a [`TokenSort`]
+/// puzzle allows or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMove<T> {
+    /// The type of the token.
+    pub token: T,
+    /// The space from which the token moves.
+    pub from: usize,
+    /// The space to which the token moves.
+    pub to: usize,
+    /// The length of the shortest path between the spaces through unoccupied spaces.
+    pub distance: u64,
+}
+
+/// The home spaces of a [`TokenSort`] puzzle.
+#[derive(Debug, Clone)]
+struct Homes<T> {
+    /// The type of token whose home each space is, if any.
+    home_of: Vec<Option<T>>,
+    /// The home spaces of each type of token, from the entrance inwards.
+    spaces: HashMap<T, Vec<usize>>,
+}
+
+/// The spaces occupied by the tokens of a [`TokenSort`] puzzle, which is passed to its
+/// rule along with each move to be checked.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenState<'a, T> {
+    /// The home spaces of the puzzle.
+    homes: &'a Homes<T>,
+    /// The token occupying each space, if any.
+    occupants: &'a [Option<T>],
+}
+impl<'a, T: Copy + Eq + Hash> TokenState<'a, T> {
+    /// Returns the token occupying a `space`, if any.
+    pub fn occupant(&self, space: usize) -> Option<T> {
+        self.occupants[space]
+    }
+
+    /// Returns the type of token for which a `space` is a home, if any.
+    pub fn home_of(&self, space: usize) -> Option<T> {
+        self.homes.home_of[space]
+    }
+
+    /// Returns the home spaces of a type of `token`, in the order in which they were
+    /// given to the puzzle.
+    pub fn home_spaces(&self, token: T) -> &'a [usize] {
+        self.homes
+            .spaces
+            .get(&token)
+            .map(|s| s.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether every home space of a type of `token` is either unoccupied or
+    /// occupied by a token of that type.
+    pub fn is_settled(&self, token: T) -> bool {
+        self.home_spaces(token)
+            .iter()
+            .all(|s| self.occupant(*s).is_none_or(|o| o == token))
+    }
+
+    /// Returns the last unoccupied home space of a type of `token`, which is the
+    /// innermost one when the home spaces are given from the entrance inwards.
+    pub fn innermost_free_home(&self, token: T) -> Option<usize> {
+        self.home_spaces(token)
+            .iter()
+            .rev()
+            .find(|s| self.occupant(**s).is_none())
+            .copied()
+    }
+}
+
+/// A puzzle in which typed tokens move on a [`SpaceGraph`] to their home spaces.
+///
+/// The `R` type is the rule that decides whether a [`TokenMove`] is allowed given the
+/// [`TokenState`] before the move.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::puzzles::token_sort::{SpaceGraph, TokenSort};
+///
+/// // A corridor of three spaces, with a pocket off the middle space
+/// let mut graph = SpaceGraph::new(4);
+/// graph.add_edge(0, 1, 1);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(1, 3, 1);
+///
+/// // Tokens may only leave a home of another type for a space that is not a home, or
+/// // go to their own home, so that no arrangement can repeat.
+/// let puzzle = TokenSort::new(
+///     graph.clone(),
+///     [('A', vec![2]), ('B', vec![0])],
+///     |token| if token == 'A' { 1 } else { 10 },
+///     |state, mv| {
+///         state.home_of(mv.to) == Some(mv.token)
+///             || (state.home_of(mv.from).is_some_and(|h| h != mv.token)
+///                 && state.home_of(mv.to).is_none())
+///     },
+/// );
+///
+/// // The cheap token steps into the pocket to let the expensive token past
+/// assert_eq!(puzzle.solve([(0, 'A'), (2, 'B')]), Ok(24));
+/// assert_eq!(puzzle.solve([(2, 'A'), (0, 'B')]), Ok(0));
+/// ```
+///
+/// Invalid usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::puzzles::token_sort::{SpaceGraph, TokenSort};
+///
+/// // Without the pocket the tokens cannot pass each other
+/// let mut graph = SpaceGraph::new(3);
+/// graph.add_edge(0, 1, 1);
+/// graph.add_edge(1, 2, 1);
+///
+/// let puzzle = TokenSort::new(
+///     graph,
+///     [('A', vec![2]), ('B', vec![0])],
+///     |_| 1,
+///     |state, mv| {
+///         state.home_of(mv.to) == Some(mv.token)
+///             || (state.home_of(mv.from).is_some_and(|h| h != mv.token)
+///                 && state.home_of(mv.to).is_none())
+///     },
+/// );
+///
+/// assert_eq!(puzzle.solve([(0, 'A'), (2, 'B')]), Err(AocError::NoSolution));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenSort<T, R> {
+    /// The graph of spaces.
+    graph: SpaceGraph,
+    /// The home spaces.
+    homes: Homes<T>,
+    /// The shortest distance between every pair of spaces when no space is occupied.
+    distances: Vec<Vec<Option<u64>>>,
+    /// The cost for each type of token to move a unit of distance.
+    step_cost: fn(T) -> u64,
+    /// The rule that decides whether a move is allowed.
+    allowed: R,
+}
+impl<T, R> TokenSort<T, R>
+where
+    T: Copy + Eq + Hash,
+    R: Fn(&TokenState<T>, &TokenMove<T>) -> bool,
+{
+    /// Creates a puzzle on a `graph` with the `homes` of each type of token, the
+    /// `step_cost` for each type of token to move a unit of distance, and a rule that
+    /// decides whether a move is `allowed`.
+    ///
+    /// The home spaces of each type of token should be given from the entrance inwards
+    /// for [`TokenState::innermost_free_home`] to be meaningful.
+    ///
+    /// # Panics
+    /// This will panic if a home space is out of bounds or is a home for more than one
+    /// type of token.
+    pub fn new(
+        graph: SpaceGraph,
+        homes: impl IntoIterator<Item = (T, Vec<usize>)>,
+        step_cost: fn(T) -> u64,
+        allowed: R,
+    ) -> Self {
+        let spaces: HashMap<_, _> = homes.into_iter().collect();
+        let mut home_of = vec![None; graph.len()];
+        for (token, token_spaces) in spaces.iter() {
+            for space in token_spaces {
+                assert!(
+                    home_of[*space].replace(*token).is_none(),
+                    "space {space} is a home for more than one type of token"
+                );
+            }
+        }
+        let distances = (0..graph.len())
+            .map(|space| graph.distances(space, |_| true))
+            .collect();
+
+        Self {
+            graph,
+            homes: Homes { home_of, spaces },
+            distances,
+            step_cost,
+            allowed,
+        }
+    }
+
+    /// Returns the least total cost to move the `tokens`, each of which is given with
+    /// the space it starts in, until every home space holds a token of its type.
+    ///
+    /// The rule must ensure that no arrangement of the tokens can be repeated, for
+    /// example by limiting how often each token can move, because the search does not
+    /// detect cycles.
+    /// Returns [`AocError::NoSolution`](crate::error::AocError::NoSolution) if the tokens
+    /// cannot all be moved home.
+    ///
+    /// # Panics
+    /// This will panic if a space is out of bounds or holds more than one token.
+    pub fn solve(&self, tokens: impl IntoIterator<Item = (usize, T)>) -> AocResult<u64> {
+        let mut occupants = vec![None; self.graph.len()];
+        for (space, token) in tokens {
+            assert!(
+                occupants[space].replace(token).is_none(),
+                "space {space} holds more than one token"
+            );
+        }
+
+        Arrangement {
+            puzzle: self,
+            occupants,
+        }
+        .traverse_tree()
+        .map(|c| c.0)
+    }
+}
+
+/// The total or relative cost to move tokens.
+#[derive(Debug, Clone, Copy, Default, Add)]
+struct Cost(u64);
+impl Metric for Cost {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+/// An arrangement of the tokens of a [`TokenSort`] puzzle, which is a node of the
+/// search for its solution.
+struct Arrangement<'a, T, R> {
+    /// The puzzle.
+    puzzle: &'a TokenSort<T, R>,
+    /// The token occupying each space, if any.
+    occupants: Vec<Option<T>>,
+}
+impl<T: Clone, R> Clone for Arrangement<'_, T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            puzzle: self.puzzle,
+            occupants: self.occupants.clone(),
+        }
+    }
+}
+impl<T: PartialEq, R> PartialEq for Arrangement<'_, T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.occupants == other.occupants
+    }
+}
+impl<T: Eq, R> Eq for Arrangement<'_, T, R> {}
+impl<T: Hash, R> Hash for Arrangement<'_, T, R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.occupants.hash(state);
+    }
+}
+impl<T, R> BestCostTreeNode for Arrangement<'_, T, R>
+where
+    T: Copy + Eq + Hash,
+    R: Fn(&TokenState<T>, &TokenMove<T>) -> bool,
+{
+    type Metric = Cost;
+
+    fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>> {
+        let puzzle = self.puzzle;
+        if puzzle
+            .homes
+            .home_of
+            .iter()
+            .zip(self.occupants.iter())
+            .all(|(home, occupant)| home.is_none() || home == occupant)
+        {
+            return ApplyNodeAction::Stop(true);
+        }
+
+        let state = TokenState {
+            homes: &puzzle.homes,
+            occupants: &self.occupants,
+        };
+        let mut children = Vec::new();
+        for (from, token) in self
+            .occupants
+            .iter()
+            .enumerate()
+            .filter_map(|(space, occupant)| occupant.map(|t| (space, t)))
+        {
+            let distances = puzzle
+                .graph
+                .distances(from, |space| self.occupants[space].is_none());
+            for (to, distance) in distances
+                .into_iter()
+                .enumerate()
+                .filter_map(|(space, distance)| distance.map(|d| (space, d)))
+                .filter(|(space, _)| *space != from)
+            {
+                let mv = TokenMove {
+                    token,
+                    from,
+                    to,
+                    distance,
+                };
+                if (puzzle.allowed)(&state, &mv) {
+                    let mut occupants = self.occupants.clone();
+                    occupants[from] = None;
+                    occupants[to] = Some(token);
+                    children.push(BestCostChild::new(
+                        Self { puzzle, occupants },
+                        Cost((puzzle.step_cost)(token) * distance),
+                    ));
+                }
+            }
+        }
+
+        if children.is_empty() {
+            ApplyNodeAction::Stop(false)
+        } else {
+            ApplyNodeAction::Continue(children)
+        }
+    }
+
+    fn lower_bound_remaining(&self) -> Option<Self::Metric> {
+        // Every token away from home must at least move to its nearest home space
+        let puzzle = self.puzzle;
+        Some(Cost(
+            self.occupants
+                .iter()
+                .enumerate()
+                .filter_map(|(space, occupant)| {
+                    let token = (*occupant)?;
+                    if puzzle.homes.home_of[space] == Some(token) {
+                        return None;
+                    }
+                    puzzle
+                        .homes
+                        .spaces
+                        .get(&token)?
+                        .iter()
+                        .filter_map(|home| puzzle.distances[space][*home])
+                        .min()
+                        .map(|d| (puzzle.step_cost)(token) * d)
+                })
+                .sum(),
+        ))
+    }
+}
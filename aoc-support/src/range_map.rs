@@ -0,0 +1,223 @@
+//! Mapping of disjoint ranges of integers to values.
+//!
+//! Some problems map numbers through piecewise functions, such as offsets that apply to
+//! ranges of numbers, in several layers.
+//! When whole ranges of numbers need to be mapped, the ranges are split at the boundaries
+//! of the pieces so that each segment can be mapped at once, see
+//! [`RangeMap::transform_ranges`].
+//! Mapping ranges through each layer in turn is then a fold over the layers.
+
+use num::Integer;
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// A map of disjoint inclusive ranges of keys to values.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::range_map::RangeMap;
+///
+/// let mut map = RangeMap::new();
+/// map.insert(0..=9, 'a');
+/// map.insert(20..=29, 'b');
+/// assert_eq!(map.get(&5), Some(&'a'));
+/// assert_eq!(map.get(&15), None);
+/// assert_eq!(map.get(&20), Some(&'b'));
+///
+/// // Overlapped parts of existing ranges are replaced
+/// map.insert(5..=24, 'c');
+/// assert_eq!(
+///     map.iter().collect::<Vec<_>>(),
+///     vec![(0..=4, &'a'), (5..=24, &'c'), (25..=29, &'b')],
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<K, V> {
+    /// The entries, in which the key is the start of each range and the value is the end
+    /// of the range along with its value.
+    entries: BTreeMap<K, (K, V)>,
+}
+impl<K, V> Default for RangeMap<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+impl<K: Integer + Copy, V> RangeMap<K, V> {
+    /// Creates a new empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of ranges in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no ranges in the map.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maps a `range` of keys to a `value`, replacing the values of any parts of existing
+    /// ranges that it overlaps.
+    ///
+    /// The parts of overlapped ranges outside of the new range keep their values, so that
+    /// the ranges stay disjoint.
+    /// Nothing is inserted for an empty range.
+    pub fn insert(&mut self, range: RangeInclusive<K>, value: V)
+    where
+        V: Clone,
+    {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = (*range.start(), *range.end());
+
+        // Remove the overlapping ranges, keeping their parts outside of the new range
+        let overlapping: Vec<_> = self
+            .entries
+            .range(..=end)
+            .rev()
+            .take_while(|(_, (e, _))| *e >= start)
+            .map(|(s, _)| *s)
+            .collect();
+        for s in overlapping {
+            let (e, v) = self.entries.remove(&s).unwrap();
+            if s < start {
+                self.entries.insert(s, (start - K::one(), v.clone()));
+            }
+            if e > end {
+                self.entries.insert(end + K::one(), (e, v));
+            }
+        }
+
+        self.entries.insert(start, (end, value));
+    }
+
+    /// Returns the range containing a `key` along with its value, or `None` if no range
+    /// contains the key.
+    pub fn get_range(&self, key: &K) -> Option<(RangeInclusive<K>, &V)> {
+        self.entries
+            .range(..=*key)
+            .next_back()
+            .filter(|(_, (e, _))| e >= key)
+            .map(|(s, (e, v))| (*s..=*e, v))
+    }
+
+    /// Returns the value of the range containing a `key`, or `None` if no range contains
+    /// the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_range(key).map(|(_, v)| v)
+    }
+
+    /// Returns an [`Iterator`] over the ranges and their values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (RangeInclusive<K>, &V)> {
+        self.entries.iter().map(|(s, (e, v))| (*s..=*e, v))
+    }
+
+    /// Splits a `range` of keys at the boundaries of the ranges in the map, returning the
+    /// segments in order along with the value of the range containing each segment, or
+    /// `None` for segments that are not in any range.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::range_map::RangeMap;
+    ///
+    /// let map: RangeMap<_, _> = [(50..=97, 2), (98..=99, -48)].into_iter().collect();
+    /// assert_eq!(
+    ///     map.segments(&(45..=99)),
+    ///     vec![(45..=49, None), (50..=97, Some(&2)), (98..=99, Some(&-48))],
+    /// );
+    /// assert_eq!(map.segments(&(60..=70)), vec![(60..=70, Some(&2))]);
+    /// assert_eq!(map.segments(&(10..=20)), vec![(10..=20, None)]);
+    /// assert_eq!(map.segments(&(10..=0)), vec![]);
+    /// ```
+    pub fn segments(&self, range: &RangeInclusive<K>) -> Vec<(RangeInclusive<K>, Option<&V>)> {
+        let mut segments = Vec::new();
+        if range.is_empty() {
+            return segments;
+        }
+        let (mut start, end) = (*range.start(), *range.end());
+
+        // Begin with the range containing the start, if there is one
+        let first = self
+            .get_range(&start)
+            .map(|(r, _)| *r.start())
+            .unwrap_or(start);
+        for (s, (e, v)) in self.entries.range(first..=end) {
+            if *s > start {
+                segments.push((start..=*s - K::one(), None));
+                start = *s;
+            }
+
+            let segment_end = *e.min(&end);
+            segments.push((start..=segment_end, Some(v)));
+            if segment_end == end {
+                return segments;
+            }
+            start = segment_end + K::one();
+        }
+
+        segments.push((start..=end, None));
+        segments
+    }
+
+    /// Splits each of some `ranges` of keys into segments, see [`RangeMap::segments`], and
+    /// applies a `transform` to each segment and the value of the range containing it,
+    /// returning the transformed segments of all the ranges in order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::range_map::RangeMap;
+    /// use std::ops::RangeInclusive;
+    ///
+    /// // Layers of maps that offset the numbers in each range, like the almanac of 2023 day 5
+    /// let layers: Vec<RangeMap<i64, i64>> = vec![
+    ///     [(98..=99, -48), (50..=97, 2)].into_iter().collect(),
+    ///     [(15..=51, -15), (52..=53, -15), (0..=14, 39)]
+    ///         .into_iter()
+    ///         .collect(),
+    /// ];
+    /// let shift = |range: RangeInclusive<i64>, offset: Option<&i64>| {
+    ///     let offset = offset.copied().unwrap_or(0);
+    ///     range.start() + offset..=range.end() + offset
+    /// };
+    ///
+    /// assert_eq!(
+    ///     layers[0].transform_ranges([45..=99], shift),
+    ///     vec![45..=49, 52..=99, 50..=51],
+    /// );
+    ///
+    /// let ranges = layers.iter().fold(vec![79..=92, 55..=67, 45..=49], |ranges, layer| {
+    ///     layer.transform_ranges(ranges, shift)
+    /// });
+    /// assert_eq!(ranges, vec![81..=94, 57..=69, 30..=34]);
+    /// ```
+    pub fn transform_ranges<R>(
+        &self,
+        ranges: impl IntoIterator<Item = RangeInclusive<K>>,
+        mut transform: impl FnMut(RangeInclusive<K>, Option<&V>) -> R,
+    ) -> Vec<R> {
+        ranges
+            .into_iter()
+            .flat_map(|range| self.segments(&range))
+            .map(|(segment, value)| transform(segment, value))
+            .collect()
+    }
+}
+impl<K: Integer + Copy, V: Clone> FromIterator<(RangeInclusive<K>, V)> for RangeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (RangeInclusive<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
@@ -0,0 +1,200 @@
+//! Recording of simulation frames to files, for example to make animations.
+//!
+//! Recording is started with [`start_recording`], which the main program does when
+//! passed the `--record` option, after which solutions can record frames of their
+//! simulations with [`record_frame`] or [`record_grid`].
+//! Each frame is written to its own numbered text file in the recording directory.
+//! When the `gif` feature is enabled, grid frames can also be recorded using
+//! `record_grid_image` as the frames of an animated GIF.
+//!
+//! Recording frames does nothing unless recording has been started, so solutions can
+//! record frames unconditionally at little cost.
+
+use crate::{
+    error::{AocError, AocResult},
+    grid::Grid,
+};
+use std::{fs, path::PathBuf, sync::Mutex, time::Duration};
+
+/// The name of the animated GIF file in the recording directory.
+#[cfg(feature = "gif")]
+pub const GIF_FILE_NAME: &str = "animation.gif";
+
+/// The options for recording frames.
+#[derive(Debug, Clone)]
+pub struct RecordOptions {
+    /// The directory in which to write the frame files, which is created if needed.
+    pub dir: PathBuf,
+    /// The time for which each frame is shown in an animation.
+    pub frame_delay: Duration,
+    /// The width and height in pixels of each grid element in an animation.
+    pub scale: u16,
+}
+
+/// The state of an active recording.
+struct Recorder {
+    /// The recording options.
+    options: RecordOptions,
+    /// The number of text frames written so far.
+    text_frames: usize,
+    /// The animated GIF encoder, which is created with the first image frame.
+    #[cfg(feature = "gif")]
+    gif: Option<gif::Encoder<fs::File>>,
+}
+impl Recorder {
+    /// Writes a text `frame` to the next numbered file.
+    fn write_text(&mut self, frame: &str) -> AocResult<()> {
+        self.text_frames += 1;
+        let path = self
+            .options
+            .dir
+            .join(format!("frame_{:05}.txt", self.text_frames));
+        fs::write(path, frame).map_err(|e| AocError::Other(e.to_string().into()))
+    }
+
+    /// Writes the next image frame of the animated GIF, with the color of each
+    /// element of the `grid` given by `color`.
+    #[cfg(feature = "gif")]
+    fn write_image<T, U>(
+        &mut self,
+        grid: &Grid<T, U>,
+        color: impl Fn(&T) -> [u8; 3],
+    ) -> AocResult<()> {
+        let map_err = |e: gif::EncodingError| AocError::Other(e.to_string().into());
+        let scale = usize::from(self.options.scale.max(1));
+        let size = grid.size();
+        let (width, height) = (size.width * scale, size.height * scale);
+        let dimension = |n: usize| {
+            u16::try_from(n).map_err(|_| AocError::Other("Grid is too large for a GIF".into()))
+        };
+        let (width, height) = (dimension(width)?, dimension(height)?);
+
+        if self.gif.is_none() {
+            let file = fs::File::create(self.options.dir.join(GIF_FILE_NAME))
+                .map_err(|e| AocError::Other(e.to_string().into()))?;
+            let mut encoder = gif::Encoder::new(file, width, height, &[]).map_err(map_err)?;
+            encoder.set_repeat(gif::Repeat::Infinite).map_err(map_err)?;
+            self.gif = Some(encoder);
+        }
+
+        // Each grid element becomes a square of pixels
+        let mut pixels = Vec::with_capacity(usize::from(width) * usize::from(height) * 3);
+        for row in grid.rows_iter() {
+            let row_pixels = row
+                .iter()
+                .flat_map(|t| std::iter::repeat(color(t)).take(scale))
+                .flatten()
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row_pixels);
+            }
+        }
+
+        let mut frame = gif::Frame::from_rgb(width, height, &pixels);
+        frame.delay = (self.options.frame_delay.as_millis() / 10)
+            .try_into()
+            .unwrap_or(u16::MAX);
+        self.gif
+            .as_mut()
+            .unwrap()
+            .write_frame(&frame)
+            .map_err(map_err)
+    }
+}
+
+/// The active recording, if any.
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Runs a function with the active recorder, if there is one.
+///
+/// This is an internal function that stops recording if the function fails, logging
+/// a warning, since there is no good way for a solution to handle such errors.
+fn with_recorder(f: impl FnOnce(&mut Recorder) -> AocResult<()>) {
+    let mut recorder = RECORDER.lock().unwrap();
+    if let Some(active) = recorder.as_mut() {
+        if let Err(e) = f(active) {
+            tracing::warn!("Stopping recording due to error: {e}");
+            *recorder = None;
+        }
+    }
+}
+
+/// Starts recording frames with some recording `options`, replacing any active recording.
+///
+/// Returns an error if the recording directory cannot be created.
+pub fn start_recording(options: RecordOptions) -> AocResult<()> {
+    fs::create_dir_all(&options.dir).map_err(|e| AocError::Other(e.to_string().into()))?;
+    *RECORDER.lock().unwrap() = Some(Recorder {
+        options,
+        text_frames: 0,
+        #[cfg(feature = "gif")]
+        gif: None,
+    });
+    Ok(())
+}
+
+/// Finishes the active recording, if any, ensuring that all files are complete.
+pub fn finish_recording() {
+    *RECORDER.lock().unwrap() = None;
+}
+
+/// Returns the recording directory if recording is active.
+pub fn recording_dir() -> Option<PathBuf> {
+    RECORDER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|r| r.options.dir.clone())
+}
+
+/// Records a text `frame` if recording is active.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::record::{finish_recording, record_frame, start_recording, RecordOptions};
+/// use std::time::Duration;
+///
+/// let dir = std::env::temp_dir().join("aoc_record_frame_example");
+/// start_recording(RecordOptions {
+///     dir: dir.clone(),
+///     frame_delay: Duration::from_millis(100),
+///     scale: 1,
+/// })
+/// .unwrap();
+/// record_frame("first");
+/// record_frame("second");
+/// finish_recording();
+///
+/// assert_eq!(std::fs::read_to_string(dir.join("frame_00002.txt")).unwrap(), "second");
+///
+/// // This does nothing since recording has finished
+/// record_frame("third");
+/// assert!(!dir.join("frame_00003.txt").exists());
+/// ```
+pub fn record_frame(frame: &str) {
+    with_recorder(|r| r.write_text(frame))
+}
+
+/// Records a `grid` as a text frame if recording is active, with each element
+/// of the grid being shown as the character given by `glyph`.
+pub fn record_grid<T, U>(grid: &Grid<T, U>, glyph: impl Fn(&T) -> char) {
+    with_recorder(|r| {
+        let text = grid
+            .rows_iter()
+            .map(|row| row.iter().map(&glyph).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        r.write_text(&text)
+    })
+}
+
+/// Records a `grid` as the next frame of the animated GIF if recording is active,
+/// with each element of the grid being shown in the RGB color given by `color`.
+///
+/// The animation is sized for the first frame, so all grids should be the same size.
+#[cfg(feature = "gif")]
+pub fn record_grid_image<T, U>(grid: &Grid<T, U>, color: impl Fn(&T) -> [u8; 3]) {
+    with_recorder(|r| r.write_image(grid, color))
+}
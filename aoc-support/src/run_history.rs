@@ -0,0 +1,84 @@
+//! The log of the run times of solutions, from which the most recent run time of each
+//! solution is shown in the list of solutions.
+//!
+//! The `bench` subcommand of the main program appends the mean total run time of the
+//! benchmarked solution to the [`RUN_HISTORY_FILE`], one line per benchmark with the
+//! year, the day, and the run time in seconds, separated by spaces.
+//! ```text
+//! 2022 12 0.0041
+//! 2021 23 1.87
+//! ```
+
+use crate::{
+    collections::HashMap,
+    solution::{Day, Year},
+};
+use anyhow::Context;
+use std::{fs, io::Write, time::Duration};
+
+/// The file to which the run times of solutions are appended.
+pub const RUN_HISTORY_FILE: &str = "run_history.log";
+
+/// The most recent run time of each solution recorded in a run history log.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::run_history::RunHistory;
+/// use aoc::solution::Year;
+/// use std::time::Duration;
+///
+/// let history = RunHistory::parse("2022 12 0.5\n2022 12 0.25\n2021 23 2\nnot a run");
+/// let year = Year::new(2022).unwrap();
+///
+/// assert_eq!(history.latest(year, 12), Some(Duration::from_millis(250)));
+/// assert_eq!(history.latest(year, 13), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RunHistory {
+    /// The most recent run time for each year and day.
+    latest: HashMap<(Year, u8), Duration>,
+}
+impl RunHistory {
+    /// Parses the history from the text of a log, in which later lines supersede
+    /// earlier ones and lines that are not valid are ignored.
+    pub fn parse(s: &str) -> Self {
+        let parse_line = |line: &str| {
+            let mut fields = line.split_whitespace();
+            let year = Year::new(fields.next()?.parse().ok()?).ok()?;
+            let day = Day::new(fields.next()?.parse().ok()?).ok()?;
+            let time = Duration::try_from_secs_f64(fields.next()?.parse().ok()?).ok()?;
+            fields.next().is_none().then_some(((year, day.get()), time))
+        };
+
+        Self {
+            latest: s.lines().filter_map(parse_line).collect(),
+        }
+    }
+
+    /// Loads the history from the [`RUN_HISTORY_FILE`], which is empty if the file
+    /// cannot be read, for example because no solution has been run yet.
+    pub fn load() -> Self {
+        fs::read_to_string(RUN_HISTORY_FILE)
+            .map(|s| Self::parse(&s))
+            .unwrap_or_default()
+    }
+
+    /// Returns the most recent run time of the solution for a `day` in some `year`.
+    pub fn latest(&self, year: Year, day: u8) -> Option<Duration> {
+        self.latest.get(&(year, day)).copied()
+    }
+
+    /// Appends a run `time` of the solution for a `day` in some `year` to the
+    /// [`RUN_HISTORY_FILE`].
+    pub fn record(year: Year, day: Day, time: Duration) -> anyhow::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(RUN_HISTORY_FILE)
+            .with_context(|| format!("Could not open {RUN_HISTORY_FILE}"))?;
+        writeln!(file, "{year} {day} {}", time.as_secs_f64())
+            .with_context(|| format!("Could not write to {RUN_HISTORY_FILE}"))
+    }
+}
@@ -0,0 +1,123 @@
+//! Reusable buffers for the temporary collections built in every step of a simulation.
+//!
+//! Simulations often collect temporary values in every step, such as the frontier of
+//! a breadth first search or the candidate moves of each participant, which would
+//! otherwise be allocated anew each time.
+//! A [`Pool`] instead hands out [`Buffer`]s that return to the pool when dropped, keeping
+//! their capacity, so that after the first few steps the inner loops no longer allocate.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// A pool of reusable [`Vec`] buffers.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::scratch::Pool;
+///
+/// let pool = Pool::new();
+/// let mut buffer = pool.take();
+/// buffer.extend([1, 2, 3]);
+/// let capacity = buffer.capacity();
+/// assert_eq!(pool.idle(), 0);
+/// drop(buffer);
+/// assert_eq!(pool.idle(), 1);
+///
+/// // The buffer is reused, and is empty but keeps its capacity
+/// let buffer = pool.take();
+/// assert!(buffer.is_empty());
+/// assert_eq!(buffer.capacity(), capacity);
+/// assert_eq!(pool.idle(), 0);
+/// ```
+///
+/// Alternating buffers for the frontier of a breadth first search:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::scratch::Pool;
+///
+/// let pool = Pool::new();
+/// let mut frontier = pool.take();
+/// frontier.push(1);
+/// for _ in 0..4 {
+///     let mut next = pool.take();
+///     next.extend(frontier.iter().flat_map(|n| [2 * n, 2 * n + 1]));
+///     frontier = next;
+/// }
+/// assert_eq!(frontier.len(), 16);
+/// assert_eq!(pool.idle(), 1);
+/// ```
+pub struct Pool<T> {
+    /// The buffers that are not in use, which are all empty.
+    idle: RefCell<Vec<Vec<T>>>,
+}
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Self {
+            idle: RefCell::new(Vec::new()),
+        }
+    }
+}
+impl<T> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool").field("idle", &self.idle()).finish()
+    }
+}
+impl<T> Pool<T> {
+    /// Creates a new pool with no buffers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes an empty buffer from the pool, which is a new buffer if none are idle.
+    ///
+    /// The buffer is cleared and returned to the pool when it is dropped.
+    pub fn take(&self) -> Buffer<'_, T> {
+        Buffer {
+            pool: self,
+            buffer: self.idle.borrow_mut().pop().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the number of buffers in the pool that are not in use.
+    pub fn idle(&self) -> usize {
+        self.idle.borrow().len()
+    }
+}
+
+/// A buffer taken from a [`Pool`], which dereferences to a [`Vec`] and is returned to
+/// the pool when dropped.
+pub struct Buffer<'a, T> {
+    /// The pool to which the buffer is returned.
+    pool: &'a Pool<T>,
+    /// The buffer itself.
+    buffer: Vec<T>,
+}
+impl<T> Deref for Buffer<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+impl<T> DerefMut for Buffer<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Buffer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.buffer, f)
+    }
+}
+impl<T> Drop for Buffer<'_, T> {
+    fn drop(&mut self) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        self.pool.idle.borrow_mut().push(buffer);
+    }
+}
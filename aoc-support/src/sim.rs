@@ -0,0 +1,6 @@
+//! Simulations of things that move around in discrete ticks.
+//!
+//! - [`agents`] moves several agents on a grid one at a time in each tick, detecting
+//!   and resolving the collisions between them, such as carts on tracks.
+
+pub mod agents;
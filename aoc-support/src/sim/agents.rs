@@ -0,0 +1,241 @@
+//! Agents that move on a grid in order during each tick and may collide with each other.
+//!
+//! The [`AgentRules`] of a problem define how each agent moves and what happens when
+//! two agents end up at the same point.
+//! An [`Agents`] simulation is an [`Evolver`], so it can be advanced tick by tick with
+//! [`Evolver::evolve`] or [`Evolver::evolutions`].
+//! In each tick the agents move one at a time in reading order of their positions at the
+//! start of the tick, so that an agent can run into another that has not yet moved.
+//! Collisions are checked after every single move, and every point at which a collision
+//! occurred is recorded in order, see [`Agents::collisions`].
+
+use crate::{collections::HashMap, evolver::Evolver, grid::GridPoint};
+use itertools::Itertools;
+
+/// What happens when an agent moves to a point that is occupied by another agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Collision {
+    /// Both agents are removed.
+    RemoveBoth,
+    /// The agent that moved is removed.
+    RemoveMoving,
+    /// The agent that was already at the point is removed.
+    RemoveOther,
+    /// The agent that moved stays where it was instead, but is otherwise unchanged.
+    Block,
+}
+
+/// The rules for how the agents of an [`Agents`] simulation move and collide.
+pub trait AgentRules {
+    /// The type of the agents, which usually includes their position and heading.
+    type Agent: Clone;
+
+    /// Returns the current position of an `agent`.
+    fn position(&self, agent: &Self::Agent) -> GridPoint;
+
+    /// Returns the `agent` after it takes its move for one tick.
+    fn step(&self, agent: &Self::Agent) -> Self::Agent;
+
+    /// Determines what happens when a `moving` agent moves to the point that an `other`
+    /// agent occupies.
+    ///
+    /// By default, both agents are removed.
+    fn collide(&self, _moving: &Self::Agent, _other: &Self::Agent) -> Collision {
+        Collision::RemoveBoth
+    }
+}
+
+/// A simulation of agents that move on a grid according to some [`AgentRules`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::direction::Direction4;
+/// use aoc::sim::agents::{AgentRules, Agents};
+/// use itertools::Itertools;
+///
+/// struct Walkers;
+/// impl AgentRules for Walkers {
+///     type Agent = (GridPoint, Direction4);
+///
+///     fn position(&self, agent: &Self::Agent) -> GridPoint {
+///         agent.0
+///     }
+///
+///     fn step(&self, agent: &Self::Agent) -> Self::Agent {
+///         (agent.0.offset(agent.1).unwrap(), agent.1)
+///     }
+/// }
+///
+/// let agents = Agents::new(
+///     &Walkers,
+///     [
+///         (GridPoint::new(0, 0), Direction4::Right),
+///         (GridPoint::new(4, 0), Direction4::Left),
+///         (GridPoint::new(2, 3), Direction4::Up),
+///     ],
+/// );
+/// let after_two = agents.evolutions().nth(1).unwrap();
+/// assert_eq!(after_two.collisions(), &[GridPoint::new(2, 0)]);
+/// assert_eq!(
+///     after_two.agents().collect_vec(),
+///     vec![&(GridPoint::new(2, 1), Direction4::Up)],
+/// );
+///
+/// // Agents move in reading order, so the left agent runs into the other before it moves
+/// let agents = Agents::new(
+///     &Walkers,
+///     [
+///         (GridPoint::new(1, 1), Direction4::Left),
+///         (GridPoint::new(0, 1), Direction4::Right),
+///     ],
+/// );
+/// let next = agents.evolve();
+/// assert!(next.is_empty());
+/// assert_eq!(next.collisions(), &[GridPoint::new(1, 1)]);
+/// ```
+pub struct Agents<'a, R: AgentRules> {
+    /// The rules for the agents.
+    rules: &'a R,
+    /// The agents, which are `None` once they are removed so that the index of each
+    /// agent never changes.
+    agents: Vec<Option<R::Agent>>,
+    /// The index of the agent at each occupied point.
+    occupied: HashMap<GridPoint, usize>,
+    /// The points at which collisions have occurred, in order.
+    collisions: Vec<GridPoint>,
+}
+impl<R: AgentRules> Clone for Agents<'_, R> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules,
+            agents: self.agents.clone(),
+            occupied: self.occupied.clone(),
+            collisions: self.collisions.clone(),
+        }
+    }
+}
+impl<'a, R: AgentRules> Agents<'a, R> {
+    /// Creates a new simulation of some `agents` that follow some `rules`.
+    ///
+    /// If more than one agent starts at the same point, these collide in order as if
+    /// each moved there, except that an agent that would be blocked is not added.
+    pub fn new(rules: &'a R, agents: impl IntoIterator<Item = R::Agent>) -> Self {
+        let mut simulation = Self {
+            rules,
+            agents: Vec::new(),
+            occupied: HashMap::default(),
+            collisions: Vec::new(),
+        };
+        for agent in agents {
+            simulation.agents.push(None);
+            let idx = simulation.agents.len() - 1;
+            simulation.place(idx, agent, None);
+        }
+        simulation
+    }
+
+    /// Returns an [`Iterator`] over the agents that have not been removed.
+    pub fn agents(&self) -> impl Iterator<Item = &R::Agent> {
+        self.agents.iter().flatten()
+    }
+
+    /// Returns the number of agents that have not been removed.
+    pub fn len(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// Returns whether every agent has been removed.
+    pub fn is_empty(&self) -> bool {
+        self.occupied.is_empty()
+    }
+
+    /// Returns the points at which collisions have occurred, in the order that they
+    /// occurred.
+    pub fn collisions(&self) -> &[GridPoint] {
+        &self.collisions
+    }
+
+    /// Removes the agent with an index, returning it if it was present.
+    ///
+    /// This is an internal function of [`Agents`].
+    fn remove(&mut self, idx: usize) -> Option<R::Agent> {
+        let agent = self.agents[idx].take()?;
+        self.occupied.remove(&self.rules.position(&agent));
+        Some(agent)
+    }
+
+    /// Puts an `agent` with an index at its position, which must not be occupied.
+    ///
+    /// This is an internal function of [`Agents`].
+    fn occupy(&mut self, idx: usize, agent: R::Agent) {
+        self.occupied.insert(self.rules.position(&agent), idx);
+        self.agents[idx] = Some(agent);
+    }
+
+    /// Places an `agent` with an index, which is not currently present, at its position,
+    /// resolving any collision with an agent already there.
+    ///
+    /// If the agent is blocked, it is put back in its `previous` state, if any.
+    ///
+    /// This is an internal function of [`Agents`].
+    fn place(&mut self, idx: usize, agent: R::Agent, previous: Option<R::Agent>) {
+        let point = self.rules.position(&agent);
+        let Some(other_idx) = self.occupied.get(&point).copied() else {
+            self.occupy(idx, agent);
+            return;
+        };
+
+        let other = self.agents[other_idx].as_ref().unwrap();
+        match self.rules.collide(&agent, other) {
+            Collision::Block => {
+                if let Some(previous) = previous {
+                    self.occupy(idx, previous);
+                }
+                return;
+            }
+            Collision::RemoveBoth => {
+                self.remove(other_idx);
+            }
+            Collision::RemoveMoving => {}
+            Collision::RemoveOther => {
+                self.remove(other_idx);
+                self.occupy(idx, agent);
+            }
+        }
+        self.collisions.push(point);
+    }
+}
+impl<R: AgentRules> Evolver<Option<R::Agent>> for Agents<'_, R> {
+    type Point = usize;
+
+    fn next_default(other: &Self) -> Self {
+        other.clone()
+    }
+
+    fn set_element(&mut self, point: &Self::Point, value: Option<R::Agent>) {
+        // Agents that were removed earlier in the tick do not move
+        let Some(agent) = value else {
+            return;
+        };
+        if let Some(previous) = self.remove(*point) {
+            self.place(*point, agent, Some(previous));
+        }
+    }
+
+    fn next_cell(&self, point: &Self::Point) -> Option<R::Agent> {
+        self.agents[*point]
+            .as_ref()
+            .map(|agent| self.rules.step(agent))
+    }
+
+    fn next_iter(&self) -> impl Iterator<Item = Self::Point> {
+        self.agents
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, agent)| agent.as_ref().map(|a| (idx, self.rules.position(a))))
+            .sorted_unstable_by_key(|(_, point)| (point.y, point.x))
+            .map(|(idx, _)| idx)
+    }
+}
@@ -0,0 +1,391 @@
+//! Spatial indexes for sparse sets of points in 2D.
+//!
+//! Some problems involve a small number of points spread over a range far too large
+//! to represent with a [`Grid`](crate::grid::Grid), for example beacons or stars.
+//! A [`QuadTree`] stores such points so that those within a box or the point nearest
+//! to some other point can be found without checking every point.
+//!
+//! There is also [`convergence`], which finds when moving points come closest
+//! together, as when a message appears in the sky when stars converge.
+
+use crate::{extension::euclid::BoxInclusive, grid::AnyGridPoint, grid::GridSpace};
+use euclid::Box2D;
+
+/// The number of points that a leaf node can hold before it is split into quadrants.
+const LEAF_CAPACITY: usize = 8;
+
+/// A square region of a [`QuadTree`] whose side length is a power of two.
+#[derive(Clone, Copy)]
+struct Region<U> {
+    /// The minimum corner of the region, which is included in the region.
+    min: AnyGridPoint<U>,
+    /// The side length of the region.
+    size: isize,
+}
+impl<U> Region<U> {
+    /// Returns whether a `point` is in the region.
+    fn contains(&self, point: &AnyGridPoint<U>) -> bool {
+        (self.min.x..self.min.x + self.size).contains(&point.x)
+            && (self.min.y..self.min.y + self.size).contains(&point.y)
+    }
+
+    /// Returns the index of the quadrant that contains a `point` within the region.
+    ///
+    /// The quadrants are in row-major order.
+    fn quadrant(&self, point: &AnyGridPoint<U>) -> usize {
+        let half = self.size / 2;
+        usize::from(point.x >= self.min.x + half) + 2 * usize::from(point.y >= self.min.y + half)
+    }
+
+    /// Returns the region of one of the quadrants, see [`Region::quadrant`].
+    fn child(&self, quadrant: usize) -> Self {
+        let half = self.size / 2;
+        Self {
+            min: AnyGridPoint::new(
+                self.min.x + half * isize::from(quadrant % 2 == 1),
+                self.min.y + half * isize::from(quadrant >= 2),
+            ),
+            size: half,
+        }
+    }
+
+    /// Returns the region as a box.
+    fn as_box(&self) -> Box2D<isize, U> {
+        Box2D::new(
+            self.min,
+            AnyGridPoint::new(self.min.x + self.size, self.min.y + self.size),
+        )
+    }
+
+    /// Returns the square of the Euclidean distance from a `point` to the nearest
+    /// point in the region, which is zero if the `point` is in the region.
+    fn distance_squared(&self, point: &AnyGridPoint<U>) -> isize {
+        let axis = |p: isize, min: isize| {
+            let nearest = p.clamp(min, min + self.size - 1);
+            (p - nearest).pow(2)
+        };
+        axis(point.x, self.min.x) + axis(point.y, self.min.y)
+    }
+}
+
+/// A node of a [`QuadTree`].
+#[derive(Clone)]
+enum Node<U> {
+    /// A leaf node with the points in its region.
+    Leaf(Vec<AnyGridPoint<U>>),
+    /// A branch node with child nodes for each quadrant of its region.
+    Branch(Box<[Node<U>; 4]>),
+}
+impl<U> Node<U> {
+    /// Returns a new branch node with empty children.
+    fn empty_branch() -> Self {
+        Self::Branch(Box::new([
+            Self::Leaf(Vec::new()),
+            Self::Leaf(Vec::new()),
+            Self::Leaf(Vec::new()),
+            Self::Leaf(Vec::new()),
+        ]))
+    }
+
+    /// Inserts a `point` into this node, which has some `region`.
+    fn insert(&mut self, region: &Region<U>, point: AnyGridPoint<U>) {
+        match self {
+            Node::Leaf(points) => {
+                points.push(point);
+                if points.len() > LEAF_CAPACITY && region.size > 1 {
+                    let points = std::mem::take(points);
+                    *self = Self::empty_branch();
+                    for point in points {
+                        self.insert(region, point);
+                    }
+                }
+            }
+            Node::Branch(children) => {
+                let quadrant = region.quadrant(&point);
+                children[quadrant].insert(&region.child(quadrant), point);
+            }
+        }
+    }
+
+    /// Adds the points of this node, which has some `region`, that are within an `area`
+    /// to a list of `found` points.
+    fn query(&self, region: &Region<U>, area: &Box2D<isize, U>, found: &mut Vec<AnyGridPoint<U>>) {
+        if !region.as_box().intersects(area) {
+            return;
+        }
+
+        match self {
+            Node::Leaf(points) => found.extend(points.iter().filter(|p| area.contains(**p))),
+            Node::Branch(children) => {
+                for (quadrant, child) in children.iter().enumerate() {
+                    child.query(&region.child(quadrant), area, found);
+                }
+            }
+        }
+    }
+
+    /// Updates the `best` point found so far and the square of its distance to a `point`
+    /// with any closer point of this node, which has some `region`.
+    fn nearest(
+        &self,
+        region: &Region<U>,
+        point: &AnyGridPoint<U>,
+        best: &mut Option<(isize, AnyGridPoint<U>)>,
+    ) {
+        match self {
+            Node::Leaf(points) => {
+                for p in points {
+                    let distance = (*p - *point).square_length();
+                    if best.map(|(d, _)| distance < d).unwrap_or(true) {
+                        *best = Some((distance, *p));
+                    }
+                }
+            }
+            Node::Branch(children) => {
+                // Search the closest quadrants first so that others can be skipped
+                let mut quadrants = (0..4)
+                    .map(|q| {
+                        let child = region.child(q);
+                        (child.distance_squared(point), q, child)
+                    })
+                    .collect::<Vec<_>>();
+                quadrants.sort_by_key(|(d, q, _)| (*d, *q));
+
+                for (distance, quadrant, child) in quadrants {
+                    if best.map(|(d, _)| distance < d).unwrap_or(true) {
+                        children[quadrant].nearest(&child, point, best);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A region quadtree of points with integer coordinates, which grows as needed to
+/// contain all of the points inserted.
+///
+/// The same point can be inserted more than once, in which case it is stored once for
+/// each insertion.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::spatial::QuadTree;
+/// use euclid::Box2D;
+///
+/// let mut tree: QuadTree = [(1, 2), (-40, 7), (1000, -3), (5, 5), (2, 2)]
+///     .into_iter()
+///     .map(|(x, y)| AnyGridPoint::new(x, y))
+///     .collect();
+/// tree.insert(AnyGridPoint::new(3, 4));
+/// tree.extend((0..20).map(|x| AnyGridPoint::new(10 * x, 100)));
+/// assert_eq!(tree.len(), 26);
+///
+/// let mut found = tree.query(&Box2D::new_inclusive(
+///     AnyGridPoint::new(0, 0),
+///     AnyGridPoint::new(3, 4),
+/// ));
+/// found.sort_by_key(|p| (p.x, p.y));
+/// assert_eq!(
+///     found,
+///     vec![
+///         AnyGridPoint::new(1, 2),
+///         AnyGridPoint::new(2, 2),
+///         AnyGridPoint::new(3, 4),
+///     ],
+/// );
+///
+/// assert_eq!(
+///     tree.nearest(&AnyGridPoint::new(900, 0)),
+///     Some(AnyGridPoint::new(1000, -3)),
+/// );
+/// assert_eq!(
+///     tree.nearest(&AnyGridPoint::new(-100, -100)),
+///     Some(AnyGridPoint::new(-40, 7)),
+/// );
+/// assert_eq!(
+///     tree.bounding_box(),
+///     Some(Box2D::new_inclusive(
+///         AnyGridPoint::new(-40, -3),
+///         AnyGridPoint::new(1000, 100),
+///     )),
+/// );
+/// assert_eq!(QuadTree::<GridSpace>::default().nearest(&AnyGridPoint::origin()), None);
+/// ```
+#[derive(Clone)]
+pub struct QuadTree<U = GridSpace> {
+    /// The root node and its region, if any points have been inserted.
+    root: Option<(Region<U>, Node<U>)>,
+    /// The number of points inserted.
+    len: usize,
+    /// The inclusive minimum and maximum corners of the bounding box of all the points,
+    /// if any points have been inserted.
+    bounds: Option<(AnyGridPoint<U>, AnyGridPoint<U>)>,
+}
+impl<U> Default for QuadTree<U> {
+    fn default() -> Self {
+        Self {
+            root: None,
+            len: 0,
+            bounds: None,
+        }
+    }
+}
+impl<U> QuadTree<U> {
+    /// Returns the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no points in the tree.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a `point` into the tree.
+    pub fn insert(&mut self, point: AnyGridPoint<U>) {
+        self.len += 1;
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(point), max.max(point)),
+            None => (point, point),
+        });
+
+        let (mut region, mut root) = self.root.take().unwrap_or_else(|| {
+            (
+                Region {
+                    min: point,
+                    size: 1,
+                },
+                Node::Leaf(Vec::new()),
+            )
+        });
+
+        // Double the size of the root region towards the point until it contains it
+        while !region.contains(&point) {
+            let grown = Region {
+                min: AnyGridPoint::new(
+                    if point.x < region.min.x {
+                        region.min.x - region.size
+                    } else {
+                        region.min.x
+                    },
+                    if point.y < region.min.y {
+                        region.min.y - region.size
+                    } else {
+                        region.min.y
+                    },
+                ),
+                size: 2 * region.size,
+            };
+            let mut branch = Node::empty_branch();
+            if let Node::Branch(children) = &mut branch {
+                children[grown.quadrant(&region.min)] = root;
+            }
+            (region, root) = (grown, branch);
+        }
+
+        root.insert(&region, point);
+        self.root = Some((region, root));
+    }
+
+    /// Returns all of the points in the tree that are within an `area`.
+    ///
+    /// The points are in no particular order.
+    pub fn query(&self, area: &Box2D<isize, U>) -> Vec<AnyGridPoint<U>> {
+        let mut found = Vec::new();
+        if let Some((region, root)) = &self.root {
+            root.query(region, area, &mut found);
+        }
+        found
+    }
+
+    /// Returns the point in the tree that is nearest to a `point` by Euclidean distance,
+    /// or `None` if the tree is empty.
+    ///
+    /// If several points are equally near then any one of these is returned.
+    pub fn nearest(&self, point: &AnyGridPoint<U>) -> Option<AnyGridPoint<U>> {
+        let (region, root) = self.root.as_ref()?;
+        let mut best = None;
+        root.nearest(region, point, &mut best);
+        best.map(|(_, p)| p)
+    }
+
+    /// Returns the smallest box that contains all of the points in the tree, or `None`
+    /// if the tree is empty.
+    pub fn bounding_box(&self) -> Option<Box2D<isize, U>> {
+        self.bounds.map(|(min, max)| Box2D::new_inclusive(min, max))
+    }
+}
+impl<U> FromIterator<AnyGridPoint<U>> for QuadTree<U> {
+    fn from_iter<T: IntoIterator<Item = AnyGridPoint<U>>>(iter: T) -> Self {
+        let mut tree = Self::default();
+        tree.extend(iter);
+        tree
+    }
+}
+impl<U> Extend<AnyGridPoint<U>> for QuadTree<U> {
+    fn extend<T: IntoIterator<Item = AnyGridPoint<U>>>(&mut self, iter: T) {
+        for point in iter {
+            self.insert(point);
+        }
+    }
+}
+
+/// Finds the frame in which some moving points are most closely converged, where each
+/// of the `frames` are the positions of the points at successive times.
+///
+/// The points are most closely converged when the area of their bounding box is
+/// smallest, so the frames are only consumed until the area starts to increase, which
+/// means the `frames` can be an infinite [`Iterator`].
+/// Returns the index of this frame and the bounding box of its points, or `None` if
+/// there are no frames with any points.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::spatial::convergence;
+/// use euclid::Box2D;
+///
+/// // Two points that approach and then pass each other
+/// let frames = (0..).map(|t: isize| {
+///     [
+///         AnyGridPoint::new(-10 + 2 * t, 0),
+///         AnyGridPoint::new(10 - 2 * t, 1),
+///     ]
+/// });
+///
+/// assert_eq!(
+///     convergence(frames),
+///     Some((
+///         5,
+///         Box2D::new_inclusive(AnyGridPoint::new(0, 0), AnyGridPoint::new(0, 1)),
+///     )),
+/// );
+/// ```
+pub fn convergence<U, P: IntoIterator<Item = AnyGridPoint<U>>>(
+    frames: impl IntoIterator<Item = P>,
+) -> Option<(usize, Box2D<isize, U>)> {
+    let mut best: Option<(usize, Box2D<isize, U>)> = None;
+
+    for (idx, frame) in frames.into_iter().enumerate() {
+        let Some((min, max)) = frame.into_iter().fold(None, |bounds, point| {
+            Some(match bounds {
+                Some((min, max)) => (AnyGridPoint::min(min, point), max.max(point)),
+                None => (point, point),
+            })
+        }) else {
+            continue;
+        };
+        let bounds = Box2D::new_inclusive(min, max);
+
+        match &best {
+            Some((_, b)) if bounds.area() > b.area() => break,
+            Some((_, b)) if bounds.area() == b.area() => {}
+            _ => best = Some((idx, bounds)),
+        }
+    }
+
+    best
+}
@@ -0,0 +1,1470 @@
+//! Potentially exhaustive search of a tree structure.
+//!
+//! Provides traits that can be implemented by tree nodes to enable recursive searches
+//! of the tree. The different traits have different goals and provide different results when
+//! searching the tree. Tree search capability is added by implementing one of the node
+//! traits on tree node items, which can then spawn child nodes into which the search
+//! algorithm will recurse.
+//!
+//! Examples of problems amenable to tree structures including one or multiplayer game trees,
+//! optimally solving a problem with a particular goal using a brute force search, etc.
+//! Refer to AOC problem solutions that utilize this module for more examples.
+//!
+//! The searches are:
+//! - [`GlobalStateTreeNode`], which visits nodes depth-first while updating a single
+//!   global state, and so can implement any kind of search.
+//! - [`BestCostTreeNode`], which finds the best total cost to a successful terminal node,
+//!   with memoization of repeated nodes and optional pruning using a lower bound on
+//!   the remaining cost and dominance between nodes.
+//! - [`LeastStepsTreeNode`], which is an adapter over a [`BestCostTreeNode`] search
+//!   for when every step has the same cost.
+//! - [`ReverseSearchNode`], which searches backwards from the goal to the start, either
+//!   exhaustively or greedily with random restarts.
+//!
+//! There are also helpers for the pruning rules common to searches over which producers
+//! of resources to build, namely [`producer_useful`], [`build_deadline`] and
+//! [`optimistic_total`].
+//!
+//! Nodes that each use some of a fixed set of items, such as the pieces that remain to be
+//! placed, can refer to the items in a [`SharedPool`] with a [`PoolSubset`], which is
+//! much cheaper to clone than the items themselves.
+//!
+//! The nodes explored by a search can be recorded with a [`SearchRecorder`] and exported
+//! as a Graphviz DOT graph, which helps when debugging a search.
+
+use crate::{
+    collections::{HashMap, HashSet},
+    error::{AocError, AocResult},
+    math::SplitMix,
+};
+use derive_more::{Add, From};
+use derive_new::new;
+use std::{
+    fmt::{self, Debug, Write},
+    fs,
+    rc::Rc,
+};
+
+/// Action to take by a tree search algorithm after processing a particular node.
+pub enum NodeAction<N> {
+    /// This is a terminal node, so do not recurse.
+    Stop,
+    /// The is a terminal node, and the search should immediately stop.
+    Complete,
+    /// Recurse to one or more child nodes.
+    Continue(Vec<N>),
+}
+
+/// A pool of reusable buffers for child nodes, which is provided to nodes by some
+/// tree searches.
+///
+/// Deep searches can otherwise allocate and free many millions of child lists.
+/// The search returns each buffer to the pool after it has recursed into all
+/// of its children, so that nodes that create their children using the pool rarely
+/// need to allocate.
+pub struct ChildPool<N> {
+    /// The empty buffers available for reuse.
+    buffers: Vec<Vec<N>>,
+}
+impl<N> Default for ChildPool<N> {
+    fn default() -> Self {
+        Self {
+            buffers: Vec::new(),
+        }
+    }
+}
+impl<N> ChildPool<N> {
+    /// Returns an empty buffer for child nodes, reusing a previous buffer if one is available.
+    pub fn buffer(&mut self) -> Vec<N> {
+        self.buffers.pop().unwrap_or_default()
+    }
+
+    /// Collects child nodes into a buffer from the pool.
+    pub fn collect(&mut self, children: impl IntoIterator<Item = N>) -> Vec<N> {
+        let mut buffer = self.buffer();
+        buffer.extend(children);
+        buffer
+    }
+
+    /// Returns a buffer to the pool for reuse.
+    fn recycle(&mut self, mut buffer: Vec<N>) {
+        buffer.clear();
+        self.buffers.push(buffer);
+    }
+}
+
+/// The index of an item in a [`SharedPool`].
+pub type PoolIndex = u16;
+
+/// A fixed set of items that is shared between the nodes of a search, which refer to
+/// the items by their [`PoolIndex`], usually with a [`PoolSubset`].
+///
+/// Cloning the pool only clones a reference to the items.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::SharedPool;
+/// use itertools::Itertools;
+///
+/// let pool = SharedPool::new(["red", "green", "blue"]);
+/// assert_eq!(pool.len(), 3);
+/// assert_eq!(pool[1], "green");
+///
+/// // Subsets share their indices until they are changed
+/// let all = pool.all();
+/// let mut remaining = all.clone();
+/// assert!(remaining.remove(0));
+/// assert!(!remaining.remove(0));
+/// assert_eq!(remaining.iter().map(|i| pool[i]).collect_vec(), vec!["green", "blue"]);
+/// assert_eq!(all.len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct SharedPool<T> {
+    /// The shared items.
+    items: Rc<[T]>,
+}
+impl<T> Clone for SharedPool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+impl<T> SharedPool<T> {
+    /// Creates a new pool of some `items`.
+    ///
+    /// # Panics
+    /// This will panic if there are more items than can be indexed by a [`PoolIndex`].
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        let items: Rc<[T]> = items.into_iter().collect();
+        assert!(
+            items.len() <= usize::from(PoolIndex::MAX) + 1,
+            "a pool cannot have {} items",
+            items.len()
+        );
+        Self { items }
+    }
+
+    /// Returns the number of items in the pool.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether there are no items in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns a subset containing every item in the pool.
+    pub fn all(&self) -> PoolSubset {
+        PoolSubset {
+            indices: Rc::new((0..self.items.len()).map(|i| i as PoolIndex).collect()),
+        }
+    }
+}
+impl<T> std::ops::Index<PoolIndex> for SharedPool<T> {
+    type Output = T;
+
+    fn index(&self, index: PoolIndex) -> &Self::Output {
+        &self.items[usize::from(index)]
+    }
+}
+
+/// A subset of the items in a [`SharedPool`], in order, as their indices.
+///
+/// The indices are copied on write, so that cloning a subset does not allocate until
+/// either copy is changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PoolSubset {
+    /// The indices of the items in the subset.
+    indices: Rc<Vec<PoolIndex>>,
+}
+impl PoolSubset {
+    /// Returns the number of items in the subset.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns whether there are no items in the subset.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns an [`Iterator`] over the indices of the items in the subset.
+    pub fn iter(&self) -> impl Iterator<Item = PoolIndex> + '_ {
+        self.indices.iter().copied()
+    }
+
+    /// Removes the item with some pool `index` from the subset, returning whether it
+    /// was in the subset.
+    pub fn remove(&mut self, index: PoolIndex) -> bool {
+        match self.indices.iter().position(|i| *i == index) {
+            Some(position) => {
+                Rc::make_mut(&mut self.indices).remove(position);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The ID of a node recorded by a [`SearchRecorder`], which is the order in which it
+/// was visited.
+pub type RecordedNodeId = usize;
+
+/// Records the nodes visited by a tree search and the edges between them, which can be
+/// exported as a [Graphviz](https://graphviz.org/) DOT graph.
+///
+/// Nodes are labeled with their [`Debug`] representations, and the edges of searches with
+/// costs are labeled with the cost from parent to child.
+/// Nodes whose sub-trees were already searched are dotted, and nodes that were pruned are
+/// dashed.
+/// Only the first nodes visited up to a maximum number are recorded, since searches can
+/// visit far too many nodes to view.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::{ApplyNodeAction, LeastStepsTreeNode, SearchRecorder};
+///
+/// // Counting up to a number by adding one or two
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Count(u8);
+/// impl LeastStepsTreeNode for Count {
+///     fn recurse_action(&mut self) -> ApplyNodeAction<Self> {
+///         if self.0 >= 3 {
+///             return ApplyNodeAction::Stop(self.0 == 3);
+///         }
+///         ApplyNodeAction::Continue(vec![Count(self.0 + 1), Count(self.0 + 2)])
+///     }
+/// }
+///
+/// let mut recorder = SearchRecorder::new(100);
+/// assert_eq!(Count(0).traverse_tree_recorded(&mut recorder), Ok(2));
+/// assert_eq!(recorder.len(), 7);
+/// assert!(!recorder.is_capped());
+///
+/// let dot = recorder.to_dot();
+/// assert!(dot.starts_with("digraph search {"));
+/// assert!(dot.contains("0 [label=\"Count(0)\"];"));
+/// assert!(dot.contains("0 -> 1 [label=\"1\"];"));
+///
+/// // Only some of the nodes are recorded for larger searches
+/// let mut recorder = SearchRecorder::new(3);
+/// Count(0).traverse_tree_recorded(&mut recorder).unwrap();
+/// assert_eq!(recorder.len(), 3);
+/// assert!(recorder.is_capped());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SearchRecorder {
+    /// The maximum number of nodes to record.
+    max_nodes: usize,
+    /// The label and the style, if any, of each recorded node.
+    nodes: Vec<(String, Option<&'static str>)>,
+    /// The parent, child, and label, if any, of each recorded edge.
+    edges: Vec<(RecordedNodeId, RecordedNodeId, Option<String>)>,
+    /// Whether any nodes were not recorded because the maximum was reached.
+    capped: bool,
+}
+impl SearchRecorder {
+    /// The environment variable that, when set to a path, enables recording with
+    /// [`SearchRecorder::from_env`].
+    pub const PATH_VAR: &'static str = "AOC_SEARCH_DOT";
+
+    /// The number of nodes recorded by a recorder from [`SearchRecorder::from_env`].
+    pub const ENV_MAX_NODES: usize = 1000;
+
+    /// Creates a new recorder that records at most `max_nodes` nodes.
+    pub fn new(max_nodes: usize) -> Self {
+        Self {
+            max_nodes,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            capped: false,
+        }
+    }
+
+    /// Creates a new recorder along with the path to which to save the DOT graph, but
+    /// only if the [`SearchRecorder::PATH_VAR`] environment variable is set.
+    ///
+    /// This allows recording to be toggled without changing any code, by passing the
+    /// recorder to a search when it is set.
+    pub fn from_env() -> Option<(Self, String)> {
+        std::env::var(Self::PATH_VAR)
+            .ok()
+            .filter(|p| !p.trim().is_empty())
+            .map(|path| (Self::new(Self::ENV_MAX_NODES), path))
+    }
+
+    /// Returns the number of recorded nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether no nodes have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns whether any visited nodes were not recorded because the maximum number
+    /// of nodes was reached.
+    pub fn is_capped(&self) -> bool {
+        self.capped
+    }
+
+    /// Records a visited node with a `label` as a child of a `parent` node, if any,
+    /// where the edge from the parent has an `edge_label`, if any.
+    ///
+    /// Returns the ID of the recorded node, or `None` if the maximum number of nodes
+    /// has been reached or the parent was not recorded.
+    fn add_node(
+        &mut self,
+        parent: Option<RecordedNodeId>,
+        is_root: bool,
+        label: String,
+        edge_label: Option<String>,
+    ) -> Option<RecordedNodeId> {
+        if parent.is_none() && !is_root {
+            return None;
+        }
+        if self.nodes.len() >= self.max_nodes {
+            self.capped = true;
+            return None;
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push((label, None));
+        if let Some(parent) = parent {
+            self.edges.push((parent, id, edge_label));
+        }
+        Some(id)
+    }
+
+    /// Sets the DOT `style` of a recorded node, if it was recorded.
+    fn set_style(&mut self, id: Option<RecordedNodeId>, style: &'static str) {
+        if let Some(id) = id {
+            self.nodes[id].1 = Some(style);
+        }
+    }
+
+    /// Returns the recorded search as a Graphviz DOT graph.
+    pub fn to_dot(&self) -> String {
+        /// This is an internal function of [`SearchRecorder::to_dot`] that escapes a label
+        /// for a quoted DOT string.
+        fn escape(label: &str) -> String {
+            label
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+        }
+
+        let mut dot = String::from("digraph search {\n    node [shape=box];\n");
+        for (id, (label, style)) in self.nodes.iter().enumerate() {
+            write!(dot, "    {id} [label=\"{}\"", escape(label)).unwrap();
+            if let Some(style) = style {
+                write!(dot, ", style={style}").unwrap();
+            }
+            dot.push_str("];\n");
+        }
+        for (parent, child, label) in self.edges.iter() {
+            write!(dot, "    {parent} -> {child}").unwrap();
+            if let Some(label) = label {
+                write!(dot, " [label=\"{}\"]", escape(label)).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        if self.capped {
+            dot.push_str("    capped [label=\"more nodes not recorded\", shape=plaintext];\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Saves the recorded search as a Graphviz DOT graph to a file at `path`.
+    pub fn save_dot(&self, path: &str) -> AocResult<()> {
+        fs::write(path, self.to_dot())
+            .map_err(|e| AocError::Other(format!("Could not write {path}: {e}").into()))
+    }
+}
+
+/// A [`SearchRecorder`] along with how to label the nodes and edge costs of a search.
+struct Recording<'a, N, C> {
+    /// The recorder.
+    recorder: &'a mut SearchRecorder,
+    /// Returns the label of a node.
+    node_label: fn(&N) -> String,
+    /// Returns the label of an edge cost.
+    cost_label: fn(&C) -> String,
+}
+impl<'a, N: Debug, C: Debug> Recording<'a, N, C> {
+    /// Creates a new recording into a `recorder` using [`Debug`] labels.
+    fn new(recorder: &'a mut SearchRecorder) -> Self {
+        Self {
+            recorder,
+            node_label: |n| format!("{n:?}"),
+            cost_label: |c| format!("{c:?}"),
+        }
+    }
+}
+
+/// Records a visited `node` as a child of a `parent` with some edge `cost`, if there is
+/// a `recording`, see [`SearchRecorder::add_node`].
+fn record_node<N, C>(
+    recording: &mut Option<Recording<N, C>>,
+    parent: Option<RecordedNodeId>,
+    is_root: bool,
+    node: &N,
+    cost: Option<&C>,
+) -> Option<RecordedNodeId> {
+    let recording = recording.as_mut()?;
+    let label = (recording.node_label)(node);
+    let edge_label = cost.map(recording.cost_label);
+    recording
+        .recorder
+        .add_node(parent, is_root, label, edge_label)
+}
+
+/// Sets the DOT `style` of a recorded node, if there is a `recording`.
+fn style_node<N, C>(
+    recording: &mut Option<Recording<N, C>>,
+    id: Option<RecordedNodeId>,
+    style: &'static str,
+) {
+    if let Some(recording) = recording {
+        recording.recorder.set_style(id, style);
+    }
+}
+
+/// Implemented by a tree node, for which the tree search runs until stopped by a
+/// node or the entire tree is searched.
+///
+/// The nodes have access to a single, mutable global state.
+///
+/// # Examples
+/// For examples of the usage of this tree search method, see the
+/// [2020 day 20 problem](../../advent_of_code/aoc_2020/day_20/solution/struct.TileMap.html)
+/// or the
+/// [2021 day 12 problem](../../advent_of_code/aoc_2021/day_12/solution/struct.PathTip.html).
+pub trait GlobalStateTreeNode: Sized {
+    /// The type of the global state.
+    type GlobalState;
+
+    /// Determines the action to take by the search algorithm from the current node.
+    ///
+    /// Child nodes for [`NodeAction::Continue`] should ideally be collected into a
+    /// buffer obtained from the `pool`, but this is not required.
+    fn recurse_action(
+        self,
+        global_state: &mut Self::GlobalState,
+        pool: &mut ChildPool<Self>,
+    ) -> NodeAction<Self>;
+
+    /// Searches the tree until the whole tree is searched, or a node stops the search
+    /// by returning [`NodeAction::Complete`].
+    ///
+    /// The initial global state is passed in and the final state after the search is returned.
+    ///
+    /// # Panics
+    /// This will panic if any node returns an empty array of children.
+    fn traverse_tree(self, initial_state: Self::GlobalState) -> Self::GlobalState {
+        traverse_global_state(self, initial_state, None)
+    }
+
+    /// Searches the tree like [`GlobalStateTreeNode::traverse_tree`], while recording the
+    /// visited nodes with a `recorder`.
+    ///
+    /// # Panics
+    /// This will panic if any node returns an empty array of children.
+    fn traverse_tree_recorded(
+        self,
+        initial_state: Self::GlobalState,
+        recorder: &mut SearchRecorder,
+    ) -> Self::GlobalState
+    where
+        Self: Debug,
+    {
+        traverse_global_state(self, initial_state, Some(Recording::new(recorder)))
+    }
+}
+
+/// Searches the tree of a [`GlobalStateTreeNode`] from a `root` node, see
+/// [`GlobalStateTreeNode::traverse_tree`], with an optional `recording` of the search.
+fn traverse_global_state<N: GlobalStateTreeNode>(
+    root: N,
+    mut initial_state: N::GlobalState,
+    mut recording: Option<Recording<N, ()>>,
+) -> N::GlobalState {
+    /// This is an internal recursive function of [`traverse_global_state`].
+    ///
+    /// Recursive performs the tree search.
+    /// The return value is whether to terminate the search immediately.
+    fn rec_traverse<N: GlobalStateTreeNode>(
+        global_state: &mut N::GlobalState,
+        pool: &mut ChildPool<N>,
+        recording: &mut Option<Recording<N, ()>>,
+        current_node: N,
+        id: Option<RecordedNodeId>,
+    ) -> bool {
+        match current_node.recurse_action(global_state, pool) {
+            NodeAction::Stop => false,
+            NodeAction::Continue(mut children) => {
+                if children.is_empty() {
+                    panic!("node returned an empty child list");
+                }
+
+                for child in children.drain(..) {
+                    let child_id = record_node(recording, id, false, &child, None);
+                    if rec_traverse(global_state, pool, recording, child, child_id) {
+                        return true;
+                    }
+                }
+                pool.recycle(children);
+                false
+            }
+            NodeAction::Complete => true,
+        }
+    }
+
+    let id = record_node(&mut recording, None, true, &root, None);
+    rec_traverse(
+        &mut initial_state,
+        &mut ChildPool::default(),
+        &mut recording,
+        root,
+        id,
+    );
+    initial_state
+}
+
+/// A metric, primarily for use with a [`BestCostTreeNode`] tree search, but can be used
+/// more generally.
+///
+/// Typically metrics are numeric, representing a cost to be minimized.
+pub trait Metric: Sized {
+    /// Returns whether this metric is better than some `other` metric.
+    fn is_better(&self, other: &Self) -> bool;
+
+    /// Sets this metric value to some other metric if the other is better.
+    fn update_if_better(&mut self, other: Self) {
+        if other.is_better(self) {
+            *self = other;
+        }
+    }
+}
+impl<T: Metric> Metric for Option<T> {
+    fn is_better(&self, other: &Self) -> bool {
+        match self {
+            Some(s) => match other {
+                Some(o) => s.is_better(o),
+                None => true,
+            },
+            None => false,
+        }
+    }
+}
+
+/// Action to take by a tree search algorithm after processing a particular node.
+pub enum ApplyNodeAction<C> {
+    /// This is a terminal node, and whether this node/path should should count or not.
+    Stop(bool),
+    /// This is a terminal node and the search should be immediately stopped, and whether this
+    /// node/path should should count or not.
+    Complete(bool),
+    /// Recurse to one or more child nodes.
+    Continue(Vec<C>),
+}
+
+/// State that stores a singular solution.
+pub struct BasicSolutionState<T> {
+    /// The current solution, if one has been set.
+    solution: Option<T>,
+}
+impl<T> Default for BasicSolutionState<T> {
+    fn default() -> Self {
+        Self { solution: None }
+    }
+}
+impl<T> BasicSolutionState<T> {
+    /// Sets the solution.
+    pub fn set_solution(&mut self, sol: T) {
+        self.solution = Some(sol);
+    }
+
+    /// Returns the current solution, or [`AocError::NoSolution`] if none has been set.
+    pub fn solution(self) -> AocResult<T> {
+        self.solution.ok_or(AocError::NoSolution)
+    }
+}
+
+/// The global state for a [`BestCostTreeNode`] search.
+struct BestCostState<'a, N: BestCostTreeNode> {
+    /// The overall best cost, if one has been set.
+    best_cost: Option<N::Metric>,
+    /// Optimization table where the key is the canonical form of a node, and the value is
+    /// the best cost of the node's sub-tree, that is, the best cost if starting at the node.
+    node_best_costs: HashMap<N::Key, Option<N::Metric>>,
+    /// The recording of the search, if it is being recorded.
+    recording: Option<Recording<'a, N, N::Metric>>,
+}
+impl<N: BestCostTreeNode> BestCostState<'_, N> {
+    /// Updates the overall best cost if `other` is better.
+    pub fn update_if_better(&mut self, other: N::Metric) {
+        self.best_cost.update_if_better(Some(other));
+    }
+}
+
+/// A child node to which to recurse for a [`BestCostTreeNode`].
+#[derive(new)]
+pub struct BestCostChild<N: BestCostTreeNode> {
+    /// The child node to which to recurse.
+    node: N,
+    /// The cost to go from the parent node to this child.
+    cost: N::Metric,
+}
+
+/// A tree node in a [`BestCostTreeNode`] search.
+struct BestCostNode<N: BestCostTreeNode> {
+    /// The actual [`BestCostTreeNode`].
+    node: N,
+    /// The total cost to get to get to this node from the root.
+    cumulative_cost: N::Metric,
+    /// The ID of the node in the recording of the search, if it was recorded.
+    id: Option<RecordedNodeId>,
+}
+
+/// Implemented by a tree node, for which the tree search optimizes some [`Metric`].
+///
+/// Each transition from parent to child has an associated relative cost. Each path
+/// from the root node to each successful terminal node then has a total cost. It
+/// is this total cost that the tree search will optimize over the entire tree.
+///
+/// # Examples
+/// For examples of the usage of this tree search method, see the
+/// [2015 day 22 problem](../../advent_of_code/aoc_2015/day_22/solution/struct.Characters.html)
+/// or the [`token_sort`](crate::puzzles::token_sort) puzzle engine.
+pub trait BestCostTreeNode: Sized + Clone + Eq + PartialEq + std::hash::Hash {
+    /// The cost type, the default value should be initial or zero cost.
+    ///
+    /// Costs are added in the order of the path from the root, so the addition need not be
+    /// commutative, which allows for metrics that carry the path itself.
+    type Metric: Metric + Clone + Default + std::ops::Add<Output = Self::Metric>;
+
+    /// The key of the best cost table of [`BestCostTreeNode::traverse_tree`], see
+    /// [`BestCostTreeNode::canonical_key`], which by default is the node itself.
+    ///
+    /// Converting a node into its key must give its canonical key, so that
+    /// [`BestCostTreeNode::canonical_key`] need not be implemented when this is not the
+    /// default.
+    type Key: std::hash::Hash + Eq + From<Self> = Self;
+
+    /// Determines the action to take by the algorithm from the current node.
+    fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>>;
+
+    /// Returns an optimistic bound on the cost from this node to any successful terminal node,
+    /// or `None` if no such bound is known, which is the default.
+    ///
+    /// The bound must be admissible, that is, no path from this node can have a cost that is
+    /// better than the bound.
+    /// During the search, a node is pruned if its cumulative cost plus this bound is not better
+    /// than the best cost found so far.
+    fn lower_bound_remaining(&self) -> Option<Self::Metric> {
+        None
+    }
+
+    /// Returns whether this node dominates some `other` node, meaning that no path from the
+    /// `other` node can have a better cost than the best path from this node.
+    ///
+    /// When a node returns children, any child dominated by a sibling with a cost that is at
+    /// least as good is dropped. By default no node dominates any other.
+    fn dominates(&self, _other: &Self) -> bool {
+        false
+    }
+
+    /// Returns the canonical key of this node, which is used in place of the node itself
+    /// as the key of the best cost table of [`BestCostTreeNode::traverse_tree`].
+    ///
+    /// Nodes that are structurally equivalent, for example by a symmetry of the problem,
+    /// can have the same canonical key so that the sub-tree of only one of them is
+    /// searched.
+    /// Equivalent nodes must have sub-trees with the same best cost.
+    /// By default this converts a clone of the node into a [`BestCostTreeNode::Key`], so
+    /// that every node is its own key unless the key type is overridden, and this can be
+    /// implemented to determine a compact key without the clone.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::tree_search::BestCostTreeNode;
+    ///
+    /// // Floors of microchip and generator pairs, whose elements are interchangeable.
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Facility {
+    ///     elevator: u8,
+    ///     pairs: Vec<(u8, u8)>,
+    /// }
+    ///
+    /// // The pairs are sorted so that states only differing in which elements
+    /// // are where share best cost table entries.
+    /// #[derive(Debug, PartialEq, Eq, Hash)]
+    /// struct FacilityKey {
+    ///     elevator: u8,
+    ///     pairs: Vec<(u8, u8)>,
+    /// }
+    /// impl From<Facility> for FacilityKey {
+    ///     fn from(mut facility: Facility) -> Self {
+    ///         facility.pairs.sort();
+    ///         Self {
+    ///             elevator: facility.elevator,
+    ///             pairs: facility.pairs,
+    ///         }
+    ///     }
+    /// }
+    /// # use aoc::tree_search::{ApplyNodeAction, BestCostChild};
+    /// # #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    /// # struct Steps(u32);
+    /// # impl aoc::tree_search::Metric for Steps {
+    /// #     fn is_better(&self, other: &Self) -> bool {
+    /// #         self.0 < other.0
+    /// #     }
+    /// # }
+    /// # impl std::ops::Add for Steps {
+    /// #     type Output = Self;
+    /// #     fn add(self, other: Self) -> Self {
+    /// #         Self(self.0 + other.0)
+    /// #     }
+    /// # }
+    ///
+    /// impl BestCostTreeNode for Facility {
+    ///     # type Metric = Steps;
+    ///     type Key = FacilityKey;
+    ///     # fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>> {
+    ///     #     ApplyNodeAction::Stop(true)
+    ///     # }
+    ///
+    ///     // The key can also be determined directly, without first cloning the node
+    ///     fn canonical_key(&self) -> FacilityKey {
+    ///         let mut pairs = self.pairs.clone();
+    ///         pairs.sort();
+    ///         FacilityKey {
+    ///             elevator: self.elevator,
+    ///             pairs,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let a = Facility {
+    ///     elevator: 0,
+    ///     pairs: vec![(1, 0), (0, 2)],
+    /// };
+    /// let b = Facility {
+    ///     elevator: 0,
+    ///     pairs: vec![(0, 2), (1, 0)],
+    /// };
+    /// assert_ne!(a, b);
+    /// assert_eq!(a.canonical_key(), b.canonical_key());
+    /// assert_eq!(a.canonical_key(), FacilityKey::from(b));
+    /// ```
+    fn canonical_key(&self) -> Self::Key {
+        self.clone().into()
+    }
+
+    /// Searches the tree to find the optimal [`Metric`] cost, which is returned if one was found.
+    ///
+    /// The algorithm includes the optimization of keeping a best cost table for each node, which
+    /// stores the best cost if one were to start at that node, that is, the best cost of its
+    /// sub-tree.
+    /// This obviates the need to recurse past each node more than once, which can vastly reduce
+    /// the algorithm execution time.
+    /// Nodes are also pruned using [`BestCostTreeNode::lower_bound_remaining`] and
+    /// [`BestCostTreeNode::dominates`] if these are implemented.
+    ///
+    /// # Panics
+    /// This will panic if any node returns an empty array of children.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::tree_search::{ApplyNodeAction, BestCostChild, BestCostTreeNode, Metric};
+    ///
+    /// // The rooms visited, where fewer rooms are better, with ties broken alphabetically.
+    /// #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    /// struct Route(Vec<char>);
+    /// impl Metric for Route {
+    ///     fn is_better(&self, other: &Self) -> bool {
+    ///         (self.0.len(), &self.0) < (other.0.len(), &other.0)
+    ///     }
+    /// }
+    /// impl std::ops::Add for Route {
+    ///     type Output = Self;
+    ///
+    ///     fn add(mut self, other: Self) -> Self {
+    ///         self.0.extend(other.0);
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Room(char);
+    /// impl BestCostTreeNode for Room {
+    ///     type Metric = Route;
+    ///
+    ///     fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>> {
+    ///         let doors = match self.0 {
+    ///             'a' => "cbe",
+    ///             'b' | 'c' => "d",
+    ///             'e' => "f",
+    ///             'f' => "d",
+    ///             _ => return ApplyNodeAction::Stop(true),
+    ///         };
+    ///         ApplyNodeAction::Continue(
+    ///             doors
+    ///                 .chars()
+    ///                 .map(|c| BestCostChild::new(Room(c), Route(vec![c])))
+    ///                 .collect(),
+    ///         )
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Room('a').traverse_tree().unwrap(), Route(vec!['b', 'd']));
+    /// ```
+    fn traverse_tree(self) -> AocResult<Self::Metric> {
+        traverse_best_cost(self, None)
+    }
+
+    /// Searches the tree like [`BestCostTreeNode::traverse_tree`], while recording the
+    /// visited nodes and the costs between them with a `recorder`.
+    ///
+    /// # Panics
+    /// This will panic if any node returns an empty array of children.
+    fn traverse_tree_recorded(self, recorder: &mut SearchRecorder) -> AocResult<Self::Metric>
+    where
+        Self: Debug,
+        Self::Metric: Debug,
+    {
+        traverse_best_cost(self, Some(Recording::new(recorder)))
+    }
+
+    /// Searches the tree to find the optimal [`Metric`] cost along with every node that lies
+    /// on at least one path with this cost, which are returned if a solution was found.
+    ///
+    /// Like [`BestCostTreeNode::traverse_tree`], the best cost of each node's sub-tree is
+    /// stored so that each sub-tree is only searched once.
+    /// However, nodes are never pruned using [`BestCostTreeNode::lower_bound_remaining`] or
+    /// [`BestCostTreeNode::dominates`] since this could drop paths that tie the best cost,
+    /// and [`ApplyNodeAction::Complete`] is treated the same as [`ApplyNodeAction::Stop`]
+    /// since the whole tree must be searched.
+    ///
+    /// # Panics
+    /// This will panic if any node returns an empty array of children.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use aoc::prelude::*;
+    /// use aoc::tree_search::{ApplyNodeAction, BestCostChild, BestCostTreeNode, Metric};
+    ///
+    /// #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    /// struct Cost(u32);
+    /// impl Metric for Cost {
+    ///     fn is_better(&self, other: &Self) -> bool {
+    ///         self.0 < other.0
+    ///     }
+    /// }
+    /// impl std::ops::Add for Cost {
+    ///     type Output = Self;
+    ///
+    ///     fn add(self, other: Self) -> Self {
+    ///         Self(self.0 + other.0)
+    ///     }
+    /// }
+    ///
+    /// // Moving right or down through a grid of costs from the upper left to the lower right
+    /// const COSTS: [[u32; 3]; 3] = [[0, 1, 1], [1, 5, 1], [1, 1, 1]];
+    ///
+    /// #[derive(Clone, PartialEq, Eq, Hash)]
+    /// struct Position(usize, usize);
+    /// impl BestCostTreeNode for Position {
+    ///     type Metric = Cost;
+    ///
+    ///     fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>> {
+    ///         if (self.0, self.1) == (2, 2) {
+    ///             return ApplyNodeAction::Stop(true);
+    ///         }
+    ///         ApplyNodeAction::Continue(
+    ///             [(self.0 + 1, self.1), (self.0, self.1 + 1)]
+    ///                 .into_iter()
+    ///                 .filter(|(x, y)| *x < 3 && *y < 3)
+    ///                 .map(|(x, y)| BestCostChild::new(Position(x, y), Cost(COSTS[y][x])))
+    ///                 .collect(),
+    ///         )
+    ///     }
+    /// }
+    ///
+    /// let (best, nodes) = Position(0, 0).traverse_tree_all_optimal().unwrap();
+    /// assert_eq!(best, Cost(4));
+    ///
+    /// // The paths on both sides of the expensive center are optimal
+    /// assert_eq!(nodes.len(), 8);
+    /// assert!(!nodes.contains(&Position(1, 1)));
+    /// ```
+    fn traverse_tree_all_optimal(self) -> AocResult<(Self::Metric, HashSet<Self>)> {
+        /// Optimization table where the key is a node, and the value is the best cost of the
+        /// node's sub-tree along with the children through which the sub-tree's optimal
+        /// paths pass.
+        type OptimalTable<N> = HashMap<N, (Option<<N as BestCostTreeNode>::Metric>, Vec<N>)>;
+
+        /// This is an internal recursive function of
+        /// [`BestCostTreeNode::traverse_tree_all_optimal`].
+        ///
+        /// Recursively finds the best cost of the sub-tree of a node.
+        fn rec_traverse<N: BestCostTreeNode>(
+            table: &mut OptimalTable<N>,
+            node: &N,
+        ) -> Option<N::Metric> {
+            if let Some((best_cost, _)) = table.get(node) {
+                return best_cost.clone();
+            }
+
+            let (best_cost, optimal_children) = match node.clone().recurse_action() {
+                ApplyNodeAction::Stop(apply) | ApplyNodeAction::Complete(apply) => {
+                    (apply.then_some(N::Metric::default()), Vec::new())
+                }
+                ApplyNodeAction::Continue(children) => {
+                    if children.is_empty() {
+                        panic!("node returned an empty child list");
+                    }
+
+                    let mut best_cost: Option<N::Metric> = None;
+                    let mut optimal_children = Vec::new();
+                    for child in children {
+                        let Some(cost) = rec_traverse(table, &child.node).map(|c| child.cost + c)
+                        else {
+                            continue;
+                        };
+
+                        match &best_cost {
+                            Some(best) if best.is_better(&cost) => {}
+                            Some(best) if !cost.is_better(best) => {
+                                optimal_children.push(child.node)
+                            }
+                            _ => {
+                                best_cost = Some(cost);
+                                optimal_children = vec![child.node];
+                            }
+                        }
+                    }
+                    (best_cost, optimal_children)
+                }
+            };
+
+            table.insert(node.clone(), (best_cost.clone(), optimal_children));
+            best_cost
+        }
+
+        let mut table = HashMap::default();
+        let best_cost = rec_traverse(&mut table, &self).ok_or(AocError::NoSolution)?;
+
+        // Collect the nodes on optimal paths starting from the root
+        let mut optimal_nodes = HashSet::default();
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            if !optimal_nodes.contains(&node) {
+                stack.extend(table[&node].1.iter().cloned());
+                optimal_nodes.insert(node);
+            }
+        }
+
+        Ok((best_cost, optimal_nodes))
+    }
+}
+
+/// Searches the tree of a [`BestCostTreeNode`] from a `root` node, see
+/// [`BestCostTreeNode::traverse_tree`], with an optional `recording` of the search.
+fn traverse_best_cost<N: BestCostTreeNode>(
+    root: N,
+    recording: Option<Recording<N, N::Metric>>,
+) -> AocResult<N::Metric> {
+    /// A return value from the the recursive tree search function.
+    struct BestCostReturn<N: BestCostTreeNode> {
+        /// Whether to immediately terminate the search.
+        complete: bool,
+        /// The best cost of the sub-tree below the current node, if there is a valid path
+        /// to a successful terminal node.
+        best_cost: Option<N::Metric>,
+        /// Whether the best cost is exact, which is not the case if any part of the sub-tree
+        /// was pruned, in which case it cannot be stored in the best cost table.
+        exact: bool,
+    }
+
+    /// This is an internal function of [`traverse_best_cost`] that drops
+    /// any children that are dominated by a sibling.
+    fn drop_dominated<N: BestCostTreeNode>(
+        children: Vec<BestCostChild<N>>,
+    ) -> Vec<BestCostChild<N>> {
+        let dominated = |i: usize, j: usize| {
+            let (a, b) = (&children[i], &children[j]);
+            a.node.dominates(&b.node) && !b.cost.is_better(&a.cost)
+        };
+
+        // Keep the first of any children that dominate each other
+        let keep = (0..children.len())
+            .map(|j| {
+                !(0..children.len())
+                    .any(|i| i != j && dominated(i, j) && (i < j || !dominated(j, i)))
+            })
+            .collect::<Vec<_>>();
+        children
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(child, keep)| keep.then_some(child))
+            .collect()
+    }
+
+    /// This is an internal recursive function of [`traverse_best_cost`].
+    ///
+    /// Recursive performs the tree search.
+    fn rec_traverse<N: BestCostTreeNode>(
+        best_cost_state: &mut BestCostState<N>,
+        mut current_node: BestCostNode<N>,
+    ) -> BestCostReturn<N> {
+        // If we already know the best cost to add for this node and its sub-tree, then exit early
+        let key = current_node.node.canonical_key();
+        if let Some(bc) = best_cost_state.node_best_costs.get(&key).cloned() {
+            style_node(&mut best_cost_state.recording, current_node.id, "dotted");
+            if let Some(best_cost) = &bc {
+                best_cost_state.update_if_better(current_node.cumulative_cost + best_cost.clone());
+            }
+            return BestCostReturn {
+                complete: false,
+                best_cost: bc,
+                exact: true,
+            };
+        }
+
+        // Prune this node if it cannot possibly improve on the best cost
+        if let (Some(best), Some(bound)) = (
+            &best_cost_state.best_cost,
+            current_node.node.lower_bound_remaining(),
+        ) {
+            if !(current_node.cumulative_cost.clone() + bound).is_better(best) {
+                style_node(&mut best_cost_state.recording, current_node.id, "dashed");
+                return BestCostReturn {
+                    complete: false,
+                    best_cost: None,
+                    exact: false,
+                };
+            }
+        }
+
+        let bc_return = match current_node.node.recurse_action() {
+            ApplyNodeAction::Stop(apply) => {
+                if apply {
+                    best_cost_state.update_if_better(current_node.cumulative_cost);
+                }
+                BestCostReturn {
+                    complete: false,
+                    best_cost: apply.then_some(N::Metric::default()),
+                    exact: true,
+                }
+            }
+            ApplyNodeAction::Complete(apply) => {
+                if apply {
+                    best_cost_state.update_if_better(current_node.cumulative_cost);
+                }
+                BestCostReturn {
+                    complete: true,
+                    best_cost: apply.then_some(N::Metric::default()),
+                    exact: true,
+                }
+            }
+            ApplyNodeAction::Continue(children) => {
+                if children.is_empty() {
+                    panic!("node returned an empty child list");
+                }
+
+                let mut best_cost = None;
+                let mut exact = true;
+
+                for child in drop_dominated(children) {
+                    let child_cost = child.cost;
+                    let id = record_node(
+                        &mut best_cost_state.recording,
+                        current_node.id,
+                        false,
+                        &child.node,
+                        Some(&child_cost),
+                    );
+                    let mut bc_return = rec_traverse(
+                        best_cost_state,
+                        BestCostNode {
+                            node: child.node,
+                            cumulative_cost: current_node.cumulative_cost.clone()
+                                + child_cost.clone(),
+                            id,
+                        },
+                    );
+
+                    bc_return.best_cost = bc_return.best_cost.map(|c| child_cost + c);
+
+                    if bc_return.complete {
+                        return bc_return;
+                    }
+
+                    best_cost.update_if_better(bc_return.best_cost);
+                    exact &= bc_return.exact;
+                }
+
+                BestCostReturn {
+                    complete: false,
+                    best_cost,
+                    exact,
+                }
+            }
+        };
+
+        // Update the best cost node optimization table
+        if bc_return.exact {
+            best_cost_state
+                .node_best_costs
+                .insert(key, bc_return.best_cost);
+        }
+        bc_return
+    }
+
+    let mut initial_state = BestCostState {
+        best_cost: None,
+        node_best_costs: HashMap::default(),
+        recording,
+    };
+    let id = record_node(&mut initial_state.recording, None, true, &root, None);
+    rec_traverse(
+        &mut initial_state,
+        BestCostNode {
+            node: root,
+            cumulative_cost: N::Metric::default(),
+            id,
+        },
+    );
+    initial_state.best_cost.ok_or(AocError::NoSolution)
+}
+
+/// A [`Metric`] that counts steps between node.
+#[derive(Clone, Copy, Default, Add, From)]
+struct Step(usize);
+impl Debug for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl Metric for Step {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+/// A tree node wrapper in a [`LeastStepsTreeNode`] search.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct LeastStepsNode<N: LeastStepsTreeNode>(N);
+impl<N: LeastStepsTreeNode + Debug> Debug for LeastStepsNode<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl<N: LeastStepsTreeNode> BestCostTreeNode for LeastStepsNode<N> {
+    type Metric = Step;
+
+    fn recurse_action(&mut self) -> ApplyNodeAction<BestCostChild<Self>> {
+        match self.0.recurse_action() {
+            ApplyNodeAction::Stop(a) => ApplyNodeAction::Stop(a),
+            ApplyNodeAction::Complete(a) => ApplyNodeAction::Complete(a),
+            ApplyNodeAction::Continue(v) => ApplyNodeAction::Continue(
+                v.into_iter()
+                    .map(|node| BestCostChild {
+                        node: Self(node),
+                        cost: 1.into(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    type Key = Self;
+
+    fn canonical_key(&self) -> Self {
+        Self(self.0.canonical_key())
+    }
+}
+
+/// Implemented by a tree node, for which the tree search finds the least number
+/// of steps to a successful terminal node.
+///
+/// # Examples
+/// For an example of the usage of this tree search method, see the
+/// [`reverse_least_steps`] search, which is implemented with it.
+pub trait LeastStepsTreeNode: Sized + Clone + Eq + PartialEq + std::hash::Hash {
+    /// Determines the action to take by the search algorithm from the current node.
+    fn recurse_action(&mut self) -> ApplyNodeAction<Self>;
+
+    /// Returns the canonical form of this node, so that structurally equivalent nodes
+    /// are only searched once, see [`BestCostTreeNode::canonical_key`].
+    ///
+    /// By default every node is its own canonical form.
+    fn canonical_key(&self) -> Self {
+        self.clone()
+    }
+
+    /// Searches the tree until the whole tree is searched, or a node stops the search
+    /// by returning [`ApplyNodeAction::Complete`].
+    ///
+    /// Returns the least number of steps to a successful terminal node, or
+    /// [`AocError::NoSolution`] if no successful terminal nodes were encountered.
+    fn traverse_tree(self) -> AocResult<usize> {
+        LeastStepsNode(self).traverse_tree().map(|s| s.0)
+    }
+
+    /// Searches the tree like [`LeastStepsTreeNode::traverse_tree`], while recording the
+    /// visited nodes with a `recorder`.
+    fn traverse_tree_recorded(self, recorder: &mut SearchRecorder) -> AocResult<usize>
+    where
+        Self: Debug,
+    {
+        LeastStepsNode(self)
+            .traverse_tree_recorded(recorder)
+            .map(|s| s.0)
+    }
+}
+
+/// Implemented by a state of a problem that is searched backwards, from the goal state
+/// to the start state.
+///
+/// This is worth doing when each state can be reached from far fewer states than it
+/// leads to, so that the branching factor of the reverse search is much lower, such as
+/// when reducing a molecule back to the single element from which it was made.
+/// The search is then [`reverse_least_steps`] for the exhaustive search, or
+/// [`greedy_reduction`] for a quick greedy search of problems in which every successful
+/// path has the same number of steps.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::{greedy_reduction, reverse_least_steps, ReverseSearchNode};
+///
+/// // Numbers that can be doubled or incremented
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// struct Number(u64);
+/// impl ReverseSearchNode for Number {
+///     fn predecessors(&self) -> Vec<Self> {
+///         let mut predecessors = vec![];
+///         if self.0 % 2 == 0 && self.0 > 0 {
+///             predecessors.push(Number(self.0 / 2));
+///         }
+///         if self.0 > 0 {
+///             predecessors.push(Number(self.0 - 1));
+///         }
+///         predecessors
+///     }
+///
+///     fn remaining_estimate(&self) -> usize {
+///         self.0.try_into().unwrap()
+///     }
+/// }
+///
+/// assert_eq!(reverse_least_steps(Number(1), Number(10)), Ok(4));
+/// assert_eq!(greedy_reduction(&Number(0), Number(6), 10, 0), Ok(4));
+/// ```
+pub trait ReverseSearchNode: Sized + Clone + Eq + std::hash::Hash {
+    /// Returns the states from which a single step leads to this state.
+    fn predecessors(&self) -> Vec<Self>;
+
+    /// Returns an estimate of how far this state is from the start state, by which the
+    /// predecessors are greedily ordered so that the closest are searched first.
+    ///
+    /// By default every state is estimated to be as close as any other.
+    fn remaining_estimate(&self) -> usize {
+        0
+    }
+}
+
+/// A tree node that searches the states of a [`ReverseSearchNode`] backwards.
+#[derive(Clone)]
+struct Reversed<N> {
+    /// The current state.
+    state: N,
+    /// The start state, which is the goal of the reverse search.
+    start: Rc<N>,
+}
+impl<N: PartialEq> PartialEq for Reversed<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+impl<N: Eq> Eq for Reversed<N> {}
+impl<N: std::hash::Hash> std::hash::Hash for Reversed<N> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.state.hash(state);
+    }
+}
+impl<N: ReverseSearchNode> LeastStepsTreeNode for Reversed<N> {
+    fn recurse_action(&mut self) -> ApplyNodeAction<Self> {
+        if self.state == *self.start {
+            return ApplyNodeAction::Stop(true);
+        }
+
+        let mut predecessors = self.state.predecessors();
+        if predecessors.is_empty() {
+            return ApplyNodeAction::Stop(false);
+        }
+        predecessors.sort_by_cached_key(N::remaining_estimate);
+        ApplyNodeAction::Continue(
+            predecessors
+                .into_iter()
+                .map(|state| Self {
+                    state,
+                    start: self.start.clone(),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Searches backwards from a `goal` state to a `start` state, see [`ReverseSearchNode`].
+///
+/// Returns the least number of steps from the `start` to the `goal`, or
+/// [`AocError::NoSolution`] if the `goal` cannot be reached.
+pub fn reverse_least_steps<N: ReverseSearchNode>(start: N, goal: N) -> AocResult<usize> {
+    Reversed {
+        state: goal,
+        start: Rc::new(start),
+    }
+    .traverse_tree()
+}
+
+/// Reduces a `goal` state greedily back to a `start` state, see [`ReverseSearchNode`],
+/// restarting with randomly broken ties whenever a reduction reaches a dead end.
+///
+/// Each reduction repeatedly steps to the predecessor with the least
+/// [`ReverseSearchNode::remaining_estimate`] that it has not yet visited, breaking any
+/// ties randomly from the `seed` after the first reduction.
+/// This is much faster than an exhaustive search, but only finds the least number of
+/// steps for problems in which every path from the `start` to the `goal` has the same
+/// number of steps.
+///
+/// Returns the number of steps of the first successful reduction, or
+/// [`AocError::NoSolution`] if none of the `attempts` succeed.
+pub fn greedy_reduction<N: ReverseSearchNode>(
+    start: &N,
+    goal: N,
+    attempts: usize,
+    seed: u64,
+) -> AocResult<usize> {
+    let mut rng = SplitMix::new(seed);
+    for attempt in 0..attempts {
+        let mut visited = HashSet::default();
+        let mut current = goal.clone();
+        let mut steps = 0;
+        while current != *start {
+            let mut predecessors = current.predecessors();
+            predecessors.retain(|p| !visited.contains(p));
+            if attempt > 0 {
+                rng.shuffle(&mut predecessors);
+            }
+            let Some(next) = predecessors.into_iter().min_by_key(N::remaining_estimate) else {
+                break;
+            };
+
+            visited.insert(current);
+            current = next;
+            steps += 1;
+        }
+
+        if current == *start {
+            return Ok(steps);
+        }
+    }
+
+    Err(AocError::NoSolution)
+}
+
+/// Determines whether building another producer of a resource can still be useful in a
+/// search over which producers to build, where at most one item can be bought each step.
+///
+/// This is a common pruning rule for resource production searches.
+/// At most `max_spend` of the resource can be spent in a single step, so once the
+/// current `producers` and `stock` can cover this spending on each of the `steps_left`,
+/// more producers would never help.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::producer_useful;
+///
+/// // There are already enough producers to afford anything every step
+/// assert!(!producer_useful(4, 0, 4, 10));
+/// // The stock will cover the most that can be spent
+/// assert!(!producer_useful(2, 22, 4, 10));
+/// assert!(producer_useful(2, 21, 4, 10));
+/// ```
+pub fn producer_useful(
+    producers: usize,
+    stock: usize,
+    max_spend: usize,
+    steps_left: usize,
+) -> bool {
+    // The resource produced on the last step can no longer be spent
+    producers < max_spend
+        && stock + producers * steps_left.saturating_sub(1) < max_spend * steps_left
+}
+
+/// Returns the last step, counting from zero, at which a producer can start to be built
+/// so that it can still contribute to the objective within `time_allowed` steps, or
+/// `None` if there is no such step.
+///
+/// This is a common pruning rule for resource production searches, where building an
+/// item takes one step, after which it produces one resource each step.
+/// The number of `stages` is the length of the shortest chain of producers from this one
+/// to the objective, including itself, for example one for a producer of the objective
+/// itself, and two for a producer of a resource that is spent to build those.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::build_deadline;
+///
+/// assert_eq!(build_deadline(24, 1), Some(22));
+/// assert_eq!(build_deadline(24, 2), Some(20));
+/// assert_eq!(build_deadline(3, 2), None);
+/// ```
+pub fn build_deadline(time_allowed: usize, stages: usize) -> Option<usize> {
+    time_allowed.checked_sub(2 * stages)
+}
+
+/// Returns an upper bound on the amount of the objective resource that can be produced
+/// by the end of a resource production search, when it is possible to build a new
+/// producer of it on every one of the `steps_left`.
+///
+/// The bound starts with the current `total` and `producers` of the objective.
+/// This is a common pruning rule for resource production searches, in which a node can
+/// be stopped when this bound cannot beat the best total found so far.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::tree_search::optimistic_total;
+///
+/// assert_eq!(optimistic_total(0, 0, 0), 0);
+/// assert_eq!(optimistic_total(3, 2, 1), 5);
+/// // The new producers add 0, 1, 2, and 3
+/// assert_eq!(optimistic_total(3, 2, 4), 3 + 8 + 6);
+/// ```
+pub fn optimistic_total(total: usize, producers: usize, steps_left: usize) -> usize {
+    total + producers * steps_left + steps_left * steps_left.saturating_sub(1) / 2
+}
@@ -0,0 +1,646 @@
+//! Virtual machines that execute programs of instructions, and an interactive debugger for them.
+//!
+//! Several problems involve simulating a simple computer running a program given as the
+//! input. Implementing the [`Machine`] trait for such a computer allows it to be run
+//! with [`run`], which just executes the program until the machine halts unless
+//! debugging has been enabled with [`start_debugging`], as the main program does when
+//! passed the `--debug` option.
+//!
+//! When debugging, the [`Debugger`] pauses before the first instruction and reads
+//! commands from the terminal, supporting single-stepping, breakpoints on instruction
+//! indices or register conditions, watchpoints on registers or memory, and dumps of
+//! the registers and memory.
+//! Enter `help` at the debugger prompt for a list of commands.
+//!
+//! Some programs spend nearly all of their time in small loops, for example
+//! multiplying by repeated addition. These can be sped up by rewriting the program
+//! with [`optimize`] before running it, using peephole [`Pass`]es that replace
+//! recognized loops with composite instructions.
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Implemented by a virtual machine that runs a program one instruction at a time.
+pub trait Machine {
+    /// The instruction type of the program.
+    type Instruction: fmt::Debug;
+
+    /// Returns the program being executed.
+    fn program(&self) -> &[Self::Instruction];
+
+    /// Returns the index of the next instruction to execute, or `None` if the machine
+    /// has halted.
+    fn instruction_pointer(&self) -> Option<usize>;
+
+    /// Executes the next instruction.
+    ///
+    /// This is only called when the machine has not halted.
+    fn step(&mut self);
+
+    /// Returns the names and values of all of the registers.
+    ///
+    /// Registers with a word size that is not that of a primitive type can be
+    /// [`Wrapping`](crate::math::Wrapping) integers, which convert to [`i64`].
+    fn registers(&self) -> Vec<(&'static str, i64)>;
+
+    /// Returns the memory of the machine, if it has any beyond its registers.
+    fn memory(&self) -> &[i64] {
+        &[]
+    }
+}
+
+/// Whether [`run`] should run machines in the [`Debugger`].
+static DEBUGGING: AtomicBool = AtomicBool::new(false);
+
+/// Enables debugging of machines run with [`run`] from the terminal.
+pub fn start_debugging() {
+    DEBUGGING.store(true, Ordering::Relaxed);
+}
+
+/// Runs a `machine` until it halts, in the [`Debugger`] if debugging is enabled.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::vm::{run, Machine};
+///
+/// struct Counter {
+///     total: i64,
+///     next: usize,
+/// }
+/// impl Machine for Counter {
+///     type Instruction = i64;
+///
+///     fn program(&self) -> &[i64] {
+///         &[1, 2, 3]
+///     }
+///
+///     fn instruction_pointer(&self) -> Option<usize> {
+///         (self.next < self.program().len()).then_some(self.next)
+///     }
+///
+///     fn step(&mut self) {
+///         self.total += self.program()[self.next];
+///         self.next += 1;
+///     }
+///
+///     fn registers(&self) -> Vec<(&'static str, i64)> {
+///         vec![("total", self.total)]
+///     }
+/// }
+///
+/// let mut machine = Counter { total: 0, next: 0 };
+/// run(&mut machine);
+/// assert_eq!(machine.total, 6);
+/// ```
+pub fn run<M: Machine>(machine: &mut M) {
+    if DEBUGGING.load(Ordering::Relaxed) {
+        let result = Debugger::default().run_with(machine, io::stdin().lock(), io::stdout());
+        if let Err(e) = result {
+            eprintln!("Stopping debugging due to error: {e}");
+        }
+    }
+
+    while machine.instruction_pointer().is_some() {
+        machine.step();
+    }
+}
+
+/// A comparison operator in a conditional breakpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// Equal to, `==`.
+    Equal,
+    /// Not equal to, `!=`.
+    NotEqual,
+    /// Less than, `<`.
+    Less,
+    /// Less than or equal to, `<=`.
+    LessOrEqual,
+    /// Greater than, `>`.
+    Greater,
+    /// Greater than or equal to, `>=`.
+    GreaterOrEqual,
+}
+impl Comparison {
+    /// Returns whether the comparison holds between `left` and `right`.
+    pub fn holds(&self, left: i64, right: i64) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        })
+    }
+}
+impl TryFrom<&str> for Comparison {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "==" => Ok(Self::Equal),
+            "!=" => Ok(Self::NotEqual),
+            "<" => Ok(Self::Less),
+            "<=" => Ok(Self::LessOrEqual),
+            ">" => Ok(Self::Greater),
+            ">=" => Ok(Self::GreaterOrEqual),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A condition before executing an instruction at which the [`Debugger`] pauses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// The instruction with this index is next.
+    Instruction(usize),
+    /// A register compares to a value.
+    Register {
+        /// The name of the register.
+        name: String,
+        /// How the register compares to the value.
+        comparison: Comparison,
+        /// The value.
+        value: i64,
+    },
+}
+impl Breakpoint {
+    /// Returns whether the breakpoint is hit by the current state of a `machine`.
+    fn is_hit<M: Machine>(&self, machine: &M) -> bool {
+        match self {
+            Breakpoint::Instruction(index) => machine.instruction_pointer() == Some(*index),
+            Breakpoint::Register {
+                name,
+                comparison,
+                value,
+            } => register(machine, name).is_some_and(|r| comparison.holds(r, *value)),
+        }
+    }
+}
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Instruction(index) => write!(f, "instruction {index}"),
+            Breakpoint::Register {
+                name,
+                comparison,
+                value,
+            } => write!(f, "{name} {comparison} {value}"),
+        }
+    }
+}
+
+/// A location whose value the [`Debugger`] watches, pausing when it changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Watchpoint {
+    /// A register with this name.
+    Register(String),
+    /// The memory at this address.
+    Memory(usize),
+}
+impl Watchpoint {
+    /// Returns the current value at the watched location of a `machine`, if it exists.
+    fn value<M: Machine>(&self, machine: &M) -> Option<i64> {
+        match self {
+            Watchpoint::Register(name) => register(machine, name),
+            Watchpoint::Memory(address) => machine.memory().get(*address).copied(),
+        }
+    }
+}
+impl fmt::Display for Watchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Watchpoint::Register(name) => write!(f, "{name}"),
+            Watchpoint::Memory(address) => write!(f, "@{address}"),
+        }
+    }
+}
+
+/// Returns the value of a register of a `machine` by its `name`, if it exists.
+fn register<M: Machine>(machine: &M, name: &str) -> Option<i64> {
+    machine
+        .registers()
+        .into_iter()
+        .find_map(|(n, v)| (n == name).then_some(v))
+}
+
+/// The help text listing the debugger commands.
+const HELP: &str = "\
+Commands:
+  step [N], s [N]        Execute the next N instructions, one by default
+  continue, c            Run until a breakpoint or watchpoint is hit
+  break N, b N           Break before executing instruction N
+  break R OP V, b R OP V Break when register R compares to value V, with OP being
+                         one of ==, !=, <, <=, >, or >=
+  watch R, w R           Break when register R changes
+  watch @A, w @A         Break when the memory at address A changes
+  delete, d              Delete all breakpoints and watchpoints
+  info, i                List the breakpoints and watchpoints
+  registers, r           Show the register values
+  memory A [N], m A [N]  Show N memory values starting at address A, eight by default
+  list, l                Show the instructions around the next instruction
+  quit, q                Stop debugging and run the machine to completion
+  help, h                Show this help";
+
+/// What the debugger should do after a command.
+enum Resume {
+    /// Read another command.
+    Prompt,
+    /// Execute some number of instructions and then pause.
+    Steps(usize),
+    /// Execute until something is hit.
+    Continue,
+    /// Stop debugging.
+    Quit,
+}
+
+/// An interactive debugger for a [`Machine`].
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::vm::{Debugger, Machine};
+///
+/// /// Adds each instruction to the total register.
+/// struct Counter {
+///     total: i64,
+///     next: usize,
+/// }
+/// impl Machine for Counter {
+///     type Instruction = i64;
+///
+///     fn program(&self) -> &[i64] {
+///         &[5, 10, 20, 40]
+///     }
+///
+///     fn instruction_pointer(&self) -> Option<usize> {
+///         (self.next < self.program().len()).then_some(self.next)
+///     }
+///
+///     fn step(&mut self) {
+///         self.total += self.program()[self.next];
+///         self.next += 1;
+///     }
+///
+///     fn registers(&self) -> Vec<(&'static str, i64)> {
+///         vec![("total", self.total)]
+///     }
+/// }
+///
+/// let mut machine = Counter { total: 0, next: 0 };
+/// let commands = "s\nb total > 10\nc\nr\nd\nc\n";
+/// let mut output = Vec::new();
+/// Debugger::default()
+///     .run_with(&mut machine, commands.as_bytes(), &mut output)
+///     .unwrap();
+/// let output = String::from_utf8(output).unwrap();
+///
+/// assert_eq!(machine.total, 75);
+/// assert!(output.contains("Breakpoint hit: total > 10"));
+/// assert!(output.contains("total = 15"));
+/// assert!(output.ends_with("Machine halted\n"));
+/// ```
+#[derive(Debug, Default)]
+pub struct Debugger {
+    /// The active breakpoints.
+    breakpoints: Vec<Breakpoint>,
+    /// The active watchpoints.
+    watchpoints: Vec<Watchpoint>,
+}
+impl Debugger {
+    /// Adds a `breakpoint`.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    /// Adds a `watchpoint`.
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.watchpoints.push(watchpoint);
+    }
+
+    /// Runs a `machine` in the debugger until it halts or debugging is quit, reading
+    /// commands from the `input` and writing to the `output`.
+    ///
+    /// The debugger pauses before the first instruction, and debugging is quit when
+    /// the `input` ends.
+    /// Returns an error if reading or writing fails.
+    pub fn run_with<M: Machine>(
+        &mut self,
+        machine: &mut M,
+        mut input: impl BufRead,
+        mut output: impl Write,
+    ) -> io::Result<()> {
+        let mut paused = true;
+        let mut steps_left = None;
+
+        while let Some(pointer) = machine.instruction_pointer() {
+            if paused {
+                show_instruction(machine, pointer, &mut output)?;
+                match self.prompt(machine, &mut input, &mut output)? {
+                    Resume::Prompt => unreachable!(),
+                    Resume::Steps(n) => steps_left = Some(n.max(1)),
+                    Resume::Continue => steps_left = None,
+                    Resume::Quit => return Ok(()),
+                }
+            }
+
+            let watched = self
+                .watchpoints
+                .iter()
+                .map(|w| w.value(machine))
+                .collect::<Vec<_>>();
+            machine.step();
+            paused = false;
+
+            if let Some(n) = steps_left.as_mut() {
+                *n -= 1;
+                paused |= *n == 0;
+            }
+            for (watchpoint, old) in self.watchpoints.iter().zip(watched) {
+                let new = watchpoint.value(machine);
+                if new != old {
+                    writeln!(
+                        output,
+                        "Watchpoint {watchpoint} changed: {} -> {}",
+                        display_value(old),
+                        display_value(new),
+                    )?;
+                    paused = true;
+                }
+            }
+            if machine.instruction_pointer().is_some() {
+                for breakpoint in self.breakpoints.iter().filter(|b| b.is_hit(machine)) {
+                    writeln!(output, "Breakpoint hit: {breakpoint}")?;
+                    paused = true;
+                }
+            }
+        }
+
+        writeln!(output, "Machine halted")
+    }
+
+    /// Reads and performs commands until one resumes execution.
+    ///
+    /// This is an internal function of [`Debugger::run_with`].
+    fn prompt<M: Machine>(
+        &mut self,
+        machine: &M,
+        input: &mut impl BufRead,
+        output: &mut impl Write,
+    ) -> io::Result<Resume> {
+        loop {
+            write!(output, "(debug) ")?;
+            output.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                writeln!(output)?;
+                return Ok(Resume::Quit);
+            }
+
+            match self.command(line.trim(), machine, output) {
+                Ok(Resume::Prompt) => {}
+                Ok(resume) => return Ok(resume),
+                Err(CommandError::Io(e)) => return Err(e),
+                Err(CommandError::Invalid(message)) => {
+                    writeln!(output, "{message}, enter 'help' for a list of commands")?
+                }
+            }
+        }
+    }
+
+    /// Performs a single command.
+    ///
+    /// This is an internal function of [`Debugger::run_with`].
+    fn command<M: Machine>(
+        &mut self,
+        command: &str,
+        machine: &M,
+        output: &mut impl Write,
+    ) -> Result<Resume, CommandError> {
+        let words = command.split_whitespace().collect::<Vec<_>>();
+        let number = |word: &str| {
+            word.parse::<usize>()
+                .map_err(|_| CommandError::Invalid(format!("Invalid number '{word}'")))
+        };
+
+        match words.as_slice() {
+            [] => {}
+            ["step" | "s"] => return Ok(Resume::Steps(1)),
+            ["step" | "s", n] => return Ok(Resume::Steps(number(n)?)),
+            ["continue" | "c"] => return Ok(Resume::Continue),
+            ["quit" | "q"] => return Ok(Resume::Quit),
+            ["break" | "b", index] => self.add_breakpoint(Breakpoint::Instruction(number(index)?)),
+            ["break" | "b", name, comparison, value] => {
+                let comparison = Comparison::try_from(*comparison).map_err(|_| {
+                    CommandError::Invalid(format!("Invalid comparison '{comparison}'"))
+                })?;
+                let value = value
+                    .parse()
+                    .map_err(|_| CommandError::Invalid(format!("Invalid value '{value}'")))?;
+                self.add_breakpoint(Breakpoint::Register {
+                    name: name.to_string(),
+                    comparison,
+                    value,
+                });
+            }
+            ["watch" | "w", location] => match location.strip_prefix('@') {
+                Some(address) => self.add_watchpoint(Watchpoint::Memory(number(address)?)),
+                None => self.add_watchpoint(Watchpoint::Register(location.to_string())),
+            },
+            ["delete" | "d"] => {
+                self.breakpoints.clear();
+                self.watchpoints.clear();
+            }
+            ["info" | "i"] => {
+                for breakpoint in self.breakpoints.iter() {
+                    writeln!(output, "Breakpoint: {breakpoint}")?;
+                }
+                for watchpoint in self.watchpoints.iter() {
+                    writeln!(output, "Watchpoint: {watchpoint}")?;
+                }
+            }
+            ["registers" | "r"] => {
+                for (name, value) in machine.registers() {
+                    writeln!(output, "{name} = {value}")?;
+                }
+            }
+            ["memory" | "m", address] => show_memory(machine, number(address)?, 8, output)?,
+            ["memory" | "m", address, n] => {
+                show_memory(machine, number(address)?, number(n)?, output)?
+            }
+            ["list" | "l"] => {
+                let pointer = machine.instruction_pointer().unwrap();
+                for index in pointer.saturating_sub(3)..(pointer + 4).min(machine.program().len()) {
+                    let marker = if index == pointer { "=>" } else { "  " };
+                    writeln!(
+                        output,
+                        "{marker} {index:>4}: {:?}",
+                        machine.program()[index]
+                    )?;
+                }
+            }
+            ["help" | "h"] => writeln!(output, "{HELP}")?,
+            _ => {
+                return Err(CommandError::Invalid(format!(
+                    "Unknown command '{command}'"
+                )))
+            }
+        }
+
+        Ok(Resume::Prompt)
+    }
+}
+
+/// An error performing a debugger command.
+enum CommandError {
+    /// Writing the output failed.
+    Io(io::Error),
+    /// The command was not valid, with a message describing why.
+    Invalid(String),
+}
+impl From<io::Error> for CommandError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// Writes the next instruction of a `machine`, which has some instruction `pointer`.
+fn show_instruction<M: Machine>(
+    machine: &M,
+    pointer: usize,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(output, "=> {pointer:>4}: {:?}", machine.program()[pointer])
+}
+
+/// Writes `n` memory values of a `machine` starting at an `address`.
+fn show_memory<M: Machine>(
+    machine: &M,
+    address: usize,
+    n: usize,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    let memory = machine.memory();
+    let end = address.saturating_add(n).min(memory.len());
+    if address >= end {
+        return writeln!(output, "No memory at address {address}");
+    }
+    for (offset, value) in memory[address..end].iter().enumerate() {
+        writeln!(output, "@{} = {value}", address + offset)?;
+    }
+    Ok(())
+}
+
+/// Returns a watched value for display.
+fn display_value(value: Option<i64>) -> String {
+    value.map_or_else(|| "none".to_string(), |v| v.to_string())
+}
+
+/// A peephole optimization pass that rewrites windows of consecutive instructions.
+///
+/// Each solution defines the passes for its own instruction set.
+#[derive(Debug)]
+pub struct Pass<I> {
+    /// The number of instructions in each window.
+    pub len: usize,
+    /// Returns the replacement for a window if it matches the pattern of the pass.
+    ///
+    /// The replacement must have the same number of instructions as the window so
+    /// that jumps elsewhere in the program are unaffected. Any instructions after the
+    /// composite instruction are typically no-ops that are skipped by it.
+    pub rewrite: fn(&[I]) -> Option<Vec<I>>,
+}
+
+/// Returns a `program` optimized by some peephole optimization `passes`, which are
+/// applied in order.
+///
+/// Each pass scans the program from the start, and after a window is rewritten the
+/// scan continues after the window so that replacements do not overlap.
+///
+/// # Panics
+/// This will panic if a pass replaces a window with a different number of instructions.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::vm::{optimize, Pass};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// enum Instruction {
+///     Increment(char),
+///     Decrement(char),
+///     JumpIfNotZero(char, i32),
+///     /// Adds the second register to the first, and clears the second.
+///     Add(char, char),
+///     NoOp,
+/// }
+/// use Instruction::*;
+///
+/// const PASSES: &[Pass<Instruction>] = &[Pass {
+///     len: 3,
+///     rewrite: |window| match window {
+///         [Increment(a), Decrement(b), JumpIfNotZero(c, -2)] if b == c => {
+///             Some(vec![Add(*a, *b), NoOp, NoOp])
+///         }
+///         _ => None,
+///     },
+/// }];
+///
+/// let program = vec![
+///     Increment('a'),
+///     Increment('a'),
+///     Decrement('b'),
+///     JumpIfNotZero('b', -2),
+///     Decrement('c'),
+/// ];
+///
+/// assert_eq!(
+///     optimize(&program, PASSES),
+///     vec![Increment('a'), Add('a', 'b'), NoOp, NoOp, Decrement('c')],
+/// );
+/// ```
+pub fn optimize<I: Clone>(program: &[I], passes: &[Pass<I>]) -> Vec<I> {
+    let mut program = program.to_vec();
+
+    for pass in passes.iter().filter(|p| p.len > 0) {
+        let mut index = 0;
+        while index + pass.len <= program.len() {
+            let window = index..index + pass.len;
+            match (pass.rewrite)(&program[window.clone()]) {
+                Some(replacement) => {
+                    assert_eq!(
+                        replacement.len(),
+                        pass.len,
+                        "an optimization pass changed the number of instructions"
+                    );
+                    program.splice(window, replacement);
+                    index += pass.len;
+                }
+                None => index += 1,
+            }
+        }
+    }
+
+    program
+}
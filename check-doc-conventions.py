@@ -411,7 +411,11 @@ def source_files() -> str:
     """
     Generator over all Rust source file paths.
     """
-    src_dirs = ("src", os.path.join("aoc_derive", "src"))
+    src_dirs = (
+        "src",
+        os.path.join("aoc-support", "src"),
+        os.path.join("aoc_derive", "src"),
+    )
 
     for src_dir in src_dirs:
         for (dir, tmp, fnames) in os.walk(src_dir):
@@ -425,7 +429,7 @@ if args.lints:
         lint.describe()
 else:
     # Check every file for every lint
-    # for source_path in ["src/aoc/iter.rs"]:
+    # for source_path in ["aoc-support/src/iter.rs"]:
     for source_path in source_files():
         source_file = SourceFile(source_path)
 
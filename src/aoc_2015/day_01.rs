@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Signed;
 
     solution_tests! {
         example {
@@ -44,13 +43,12 @@ mod tests {
         }
         example {
             input = ")";
-            answers = &[None, Some(Signed(1))];
+            answers = answers![skip, s 1];
         }
         example {
             input = "()())";
-            answers = &[None, Some(Signed(5))];
+            answers = answers![skip, s 5];
         }
-        actual_answers = signed![74, 1795];
     }
 }
 
@@ -115,7 +113,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 1,
     name: "Not Quite Lisp",
-    preprocessor: Some(|input| Ok(Box::new(Directions::from_str(input)?).into())),
+    preprocessor: Some(parsable_preprocessor::<Directions>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -14,7 +14,6 @@ mod tests {
             input = "1x1x10";
             answers = unsigned![43, 14];
         }
-        actual_answers = unsigned![1588178, 3783758];
     }
 }
 
@@ -111,7 +110,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 2,
     name: "I Was Told There Would Be No Math",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Presents>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Presents>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
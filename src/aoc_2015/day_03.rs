@@ -3,16 +3,15 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
             input = ">";
-            answers = &[Some(Unsigned(2)), None];
+            answers = answers![u 2, skip];
         }
         example {
             input = "^v";
-            answers = &[None, Some(Unsigned(3))];
+            answers = answers![skip, u 3];
         }
         example {
             input = "^>v<";
@@ -22,7 +21,6 @@ mod tests {
             input = "^v^v^v^v^v";
             answers = unsigned![2, 11];
         }
-        actual_answers = unsigned![2565, 2639];
     }
 }
 
@@ -149,31 +147,26 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 3,
     name: "Perfectly Spherical Houses in a Vacuum",
-    preprocessor: Some(|input| Ok(Box::new(Directions::from_str(input)?).into())),
+    preprocessor: Some(parsable_preprocessor::<Directions>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
-            Ok(Answer::Unsigned(
-                input
-                    .expect_data::<Directions>()?
-                    .visited_houses::<PartOne>()
-                    .len()
-                    .try_into()
-                    .unwrap(),
-            ))
+            Ok(input
+                .expect_data::<Directions>()?
+                .visited_houses::<PartOne>()
+                .len()
+                .into())
         },
         // Part two
         |input| {
             // Process
-            Ok(Answer::Unsigned(
-                input
-                    .expect_data::<Directions>()?
-                    .visited_houses::<PartTwo>()
-                    .len()
-                    .try_into()
-                    .unwrap(),
-            ))
+            Ok(input
+                .expect_data::<Directions>()?
+                .visited_houses::<PartTwo>()
+                .len()
+                .into())
         },
     ],
 };
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -12,16 +11,15 @@ aaa
 jchzalrnumimnmhp
 haegwjzuvuyypxyu
 dvszwmarrgswjxmb";
-            answers = &[Some(Unsigned(2)), None];
+            answers = answers![u 2, skip];
         }
         example {
             input = "qjhvhtzxzqqjkmpb
 xxyxx
 uurcxstgmygtbstg
 ieodomkazucvgmuy";
-            answers = &[None, Some(Unsigned(2))];
+            answers = answers![skip, u 2];
         }
-        actual_answers = unsigned![236, 51];
     }
 }
 
@@ -66,7 +64,7 @@ mod solution {
     impl Nice<PartTwo> for &str {
         fn is_nice(&self) -> bool {
             // Pair of letters appearing twice but not overlapping
-            (0..self.len() - 3).any(|i| self[i + 2..].contains(&self[i..i + 2]))
+            (0..self.len() - 1).any(|i| self.find_all(&self[i..i + 2]).count() >= 2)
 	    &&
         // Repeating letter with one in between
         self.chars().tuple_windows().any(|(a, _, c)| a == c)
@@ -81,6 +79,7 @@ pub const SOLUTION: Solution = Solution {
     day: 5,
     name: "Doesn't He Have Intern-Elves For This?",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,21 +3,19 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
             input = "turn on 0,0 through 999,999
 toggle 0,0 through 999,0
 turn off 499,499 through 500,500";
-            answers = &[Some(Unsigned(998996)), None];
+            answers = answers![u 998996, skip];
         }
         example {
             input = "turn on 0,0 through 0,0
     toggle 0,0 through 999,999";
-            answers = &[None, Some(Unsigned(2000001))];
+            answers = answers![skip, u 2000001];
         }
-        actual_answers = unsigned![543903, 14687245];
     }
 }
 
@@ -206,6 +204,7 @@ pub const SOLUTION: Solution = Solution {
     day: 6,
     name: "Probably a Fire Hazard",
     preprocessor: Some(|input| Ok(Box::new(Instruction::gather(input.lines())?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -217,9 +216,7 @@ pub const SOLUTION: Solution = Solution {
             //println!("{:?}", light_grid);
 
             // Process
-            Ok(Answer::Unsigned(
-                light_grid.number_lit().try_into().unwrap(),
-            ))
+            Ok(light_grid.number_lit().into())
         },
         // Part two
         |input| {
@@ -17,7 +17,6 @@ NOT y -> i
 f -> a";
             answers = unsigned![492];
         }
-        actual_answers = unsigned![46065, 14134];
     }
 }
 
@@ -270,6 +269,7 @@ pub const SOLUTION: Solution = Solution {
     name: "Some Assembly Required",
     // NOTE: Circuit keeps references to input, so we cannot use a pre-processor.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -12,7 +12,6 @@ mod tests {
 \"\\x27\"";
             answers = unsigned![12, 19];
         }
-        actual_answers = unsigned![1333, 2046];
     }
 }
 
@@ -106,6 +105,7 @@ pub const SOLUTION: Solution = Solution {
     name: "Matchsticks",
     // NOTE: List keeps references to input, so we cannot use a pre-processor.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -113,11 +113,7 @@ pub const SOLUTION: Solution = Solution {
             let list = List::from_str(input.expect_input()?);
 
             // Process
-            Ok(Answer::Unsigned(
-                (list.literal_size() - list.escaped_size()?)
-                    .try_into()
-                    .unwrap(),
-            ))
+            Ok((list.literal_size() - list.escaped_size()?).into())
         },
         // Part two
         |input| {
@@ -125,11 +121,7 @@ pub const SOLUTION: Solution = Solution {
             let list = List::from_str(input.expect_input()?);
 
             // Process
-            Ok(Answer::Unsigned(
-                (list.encoded_size() - list.literal_size())
-                    .try_into()
-                    .unwrap(),
-            ))
+            Ok((list.encoded_size() - list.literal_size()).into())
         },
     ],
 };
@@ -11,7 +11,6 @@ London to Belfast = 518
 Dublin to Belfast = 141";
             answers = unsigned![605, 982];
         }
-        actual_answers = unsigned![251, 898];
     }
 }
 
@@ -140,6 +139,7 @@ pub const SOLUTION: Solution = Solution {
     name: "All in a Single Night",
     // NOTE: Problem keeps references to input, so we cannot use a pre-processor.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
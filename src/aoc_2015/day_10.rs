@@ -11,9 +11,8 @@ mod tests {
         }
         expensive_example {
             input = "1";
-            answers = &[None, Some(Answer::Unsigned(1166642))];
+            answers = answers![skip, u 1166642];
         }
-        actual_answers = unsigned![492982, 6989950];
     }
 }
 
@@ -61,6 +60,7 @@ pub const SOLUTION: Solution = Solution {
     name: "Elves Look, Elves Say",
     // NOTE: Sequence is an iterator so needs mutated, so we just parse it in each part.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
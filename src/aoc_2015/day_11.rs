@@ -13,7 +13,6 @@ mod tests {
             input = "ghijklmn";
             answers = string!["ghjaabcc"];
         }
-        actual_answers = string!["hxbxxyzz", "hxcaabcc"];
     }
 }
 
@@ -100,6 +99,7 @@ pub const SOLUTION: Solution = Solution {
     day: 11,
     name: "Corporate Policy",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -20,7 +20,6 @@ David would lose 7 happiness units by sitting next to Bob.
 David would gain 41 happiness units by sitting next to Carol.";
             answers = signed![330, 286];
         }
-        actual_answers = signed![664, 640];
     }
 }
 
@@ -176,6 +175,7 @@ pub const SOLUTION: Solution = Solution {
     name: "Knights of the Dinner Table",
     // NOTE: Problem keeps references to input, so we cannot use a pre-processor.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -10,7 +10,6 @@ mod tests {
     Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
             answers = unsigned![2660, 1564];
         }
-        actual_answers = unsigned![2655, 1059];
     }
 }
 
@@ -135,6 +134,7 @@ pub const SOLUTION: Solution = Solution {
     day: 14,
     name: "Reindeer Olympics",
     preprocessor: Some(|input| Ok(Box::new(Race::from_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -10,13 +10,14 @@ mod tests {
     Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
             answers = unsigned![62842880, 57600000];
         }
-        actual_answers = unsigned![222870, 117936];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
+    use aoc::optimize::maximize_composition;
+    use itertools::Itertools;
     use nom::{
         bytes::complete::{tag, take_until},
         combinator::map,
@@ -24,57 +25,12 @@ mod solution {
     };
     use std::{
         convert::TryInto,
-        iter::Step,
         ops::{Add, Mul, RangeInclusive},
         str::FromStr,
     };
 
-    #[cfg(test)]
-    mod tests {
-        use super::SumPermutations;
-
-        #[test]
-        fn sum_permutations() {
-            let perms: Vec<Vec<u8>> = SumPermutations::new(6, 3).collect();
-
-            assert_eq!(
-                perms,
-                vec![
-                    vec![0, 0, 6],
-                    vec![0, 1, 5],
-                    vec![0, 2, 4],
-                    vec![0, 3, 3],
-                    vec![0, 4, 2],
-                    vec![0, 5, 1],
-                    vec![0, 6, 0],
-                    vec![1, 0, 5],
-                    vec![1, 1, 4],
-                    vec![1, 2, 3],
-                    vec![1, 3, 2],
-                    vec![1, 4, 1],
-                    vec![1, 5, 0],
-                    vec![2, 0, 4],
-                    vec![2, 1, 3],
-                    vec![2, 2, 2],
-                    vec![2, 3, 1],
-                    vec![2, 4, 0],
-                    vec![3, 0, 3],
-                    vec![3, 1, 2],
-                    vec![3, 2, 1],
-                    vec![3, 3, 0],
-                    vec![4, 0, 2],
-                    vec![4, 1, 1],
-                    vec![4, 2, 0],
-                    vec![5, 0, 1],
-                    vec![5, 1, 0],
-                    vec![6, 0, 0],
-                ]
-            );
-        }
-    }
-
     /// A cookie ingredient with its properties, which can be parsed from text input.
-    #[derive(Debug)]
+    #[derive(Debug, Default)]
     pub struct Ingredient {
         /// How well the cookie absorbs milk.
         capacity: i64,
@@ -158,81 +114,11 @@ mod solution {
         }
     }
 
-    /// Recursive [`Iterator`] over all permutations of some number of numeric values that sum to a constant.
-    struct SumPermutations<T> {
-        /// Number to which the permutations must sum.
-        sum: T,
-        /// Number of elements in each permutation.
-        number: usize,
-        /// Iterator that goes through the possible first elements.
-        first_iter: RangeInclusive<T>,
-        /// Current first element.
-        first: T,
-        /// Iterator over the sum permutations for the remaining elements after the first one.
-        rest_iter: Box<dyn Iterator<Item = Vec<T>>>,
-    }
-    impl<T: Copy + Step + num::Num + 'static> SumPermutations<T> {
-        /// Creates a new permutation [`Iterator`].
-        fn new(sum: T, number: usize) -> Self {
-            let mut first_iter = T::zero()..=sum;
-            let first = first_iter.next().unwrap();
-
-            Self {
-                sum,
-                number,
-                first_iter,
-                first,
-                rest_iter: Self::new_rest_iter(sum - first, number - 1),
-            }
-        }
-
-        /// Creates a new iterator for the rest of the elements, which may be
-        /// recursive or a single-permutation iterator if only a single element
-        /// is needed.
-        fn new_rest_iter(sum: T, number: usize) -> Box<dyn Iterator<Item = Vec<T>>> {
-            if number > 1 {
-                Box::new(Self::new(sum, number))
-            } else {
-                Box::new([vec![sum]].into_iter())
-            }
-        }
-    }
-    impl<T> Iterator for SumPermutations<T>
-    where
-        T: Copy + Step + num::Num + 'static,
-    {
-        type Item = Vec<T>;
-
-        fn next(&mut self) -> Option<Self::Item> {
-            match self.rest_iter.next() {
-                Some(mut perm) => {
-                    // There is another permutation for the rest, so just prepend the current first value
-                    perm.insert(0, self.first);
-                    Some(perm)
-                }
-                None => {
-                    // There are no more permutations for the rest.
-                    match self.first_iter.next() {
-                        Some(first) => {
-                            // We have more permutations for the next first value
-                            self.first = first;
-                            self.rest_iter = Self::new_rest_iter(self.sum - first, self.number - 1);
-                            self.next()
-                        }
-                        None => {
-                            // We are all done, nothing else
-                            None
-                        }
-                    }
-                }
-            }
-        }
-    }
-
     /// Behavior specific to a particular problem part.
     pub trait Part {
-        /// Determines if a cookie with particular total ingredients is valid to consider for the part.
-        fn valid_recipe(_ingredient: &Ingredient) -> bool {
+        /// Determines if a cookie whose calories are somewhere in a range could be valid
+        /// to consider for the part.
+        fn calories_possible(_calories: RangeInclusive<i64>) -> bool {
             true
         }
     }
@@ -244,8 +130,8 @@ mod solution {
     /// Behavior for part two.
     pub struct PartTwo;
     impl Part for PartTwo {
-        fn valid_recipe(ingredient: &Ingredient) -> bool {
-            ingredient.calories == 500
+        fn calories_possible(calories: RangeInclusive<i64>) -> bool {
+            calories.contains(&500)
         }
     }
 
@@ -265,21 +151,42 @@ mod solution {
         }
     }
     impl Problem {
+        /// Determines the total ingredient of a cookie with some amounts of each ingredient.
+        fn total(&self, amounts: &[i64]) -> Ingredient {
+            amounts
+                .iter()
+                .zip(self.ingredients.iter())
+                .map(|(a, ing)| ing * *a)
+                .fold(Ingredient::default(), |a, b| a + b)
+        }
+
         /// Determines score of the highest scoring cookie possible.
         pub fn best_recipe<P: Part>(&self) -> u64 {
-            SumPermutations::new(100, self.ingredients.len())
-                .map(|amounts| {
-                    amounts
-                        .into_iter()
-                        .zip(self.ingredients.iter())
-                        .map(|(a, ing)| ing * a)
-                        .reduce(|a, b| a + b)
-                        .unwrap()
-                })
-                .filter(|ing| P::valid_recipe(ing))
-                .map(|ing| ing.score())
-                .max()
-                .unwrap()
+            let (min_calories, max_calories) = self
+                .ingredients
+                .iter()
+                .map(|ing| ing.calories)
+                .minmax()
+                .into_option()
+                .unwrap();
+
+            maximize_composition(
+                100,
+                self.ingredients.len(),
+                |amounts, remaining| {
+                    // Skip if the calories cannot be valid no matter the remaining amounts
+                    let calories = self.total(amounts).calories;
+                    !P::calories_possible(
+                        calories + remaining * min_calories..=calories + remaining * max_calories,
+                    )
+                },
+                |amounts| {
+                    let total = self.total(amounts);
+                    P::calories_possible(total.calories..=total.calories).then(|| total.score())
+                },
+            )
+            .unwrap()
+            .1
         }
     }
 }
@@ -290,7 +197,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 15,
     name: "Science for Hungry People",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Problem>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Problem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
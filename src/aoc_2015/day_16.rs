@@ -5,7 +5,6 @@ mod tests {
     use aoc::prelude_test::*;
 
     solution_tests! {
-        actual_answers = unsigned![40, 241];
     }
 }
 
@@ -143,5 +142,6 @@ pub const SOLUTION: Solution = Solution {
     day: 16,
     name: "Aunt Sue",
     preprocessor: None,
+    validate: None,
     solvers: &[solve::<PartOne>, solve::<PartTwo>],
 };
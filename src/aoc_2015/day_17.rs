@@ -13,7 +13,6 @@ mod tests {
 5";
             answers = unsigned![0, 0];
         }
-        actual_answers = unsigned![654, 57];
     }
 }
 
@@ -59,19 +58,17 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 17,
     name: "No Such Thing as Too Much",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Problem>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Problem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
-            Ok(Answer::Unsigned(
-                input
-                    .expect_data::<Problem>()?
-                    .combinations(150)
-                    .count()
-                    .try_into()
-                    .unwrap(),
-            ))
+            Ok(input
+                .expect_data::<Problem>()?
+                .combinations(150)
+                .count()
+                .into_answer())
         },
         // Part two
         |input| {
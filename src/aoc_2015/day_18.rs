@@ -16,7 +16,6 @@ mod tests {
 ####..";
             answers = unsigned![4, 7];
         }
-        actual_answers = unsigned![814, 924];
     }
 }
 
@@ -138,6 +137,7 @@ pub const SOLUTION: Solution = Solution {
     day: 18,
     name: "Like a GIF For Your Yard",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
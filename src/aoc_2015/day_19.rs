@@ -4,7 +4,6 @@ use std::{collections::HashSet, str::FromStr};
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -31,7 +30,7 @@ H => OH
 O => HH
 
 HOH";
-            answers = &[None, Some(Unsigned(3))];
+            answers = answers![skip, u 3];
         }
         example {
             input = "e => H
@@ -41,9 +40,8 @@ H => OH
 O => HH
 
 HOHOHO";
-            answers = &[None, Some(Unsigned(6))];
+            answers = answers![skip, u 6];
         }
-        actual_answers = unsigned![576, 207];
     }
 }
 
@@ -60,7 +58,7 @@ mod solution {
     use super::*;
     use aoc::{
         parse::trim,
-        tree_search::{ApplyNodeAction, LeastStepsTreeNode},
+        tree_search::{greedy_reduction, ReverseSearchNode},
     };
     use derive_new::new;
     use nom::{
@@ -103,9 +101,12 @@ mod solution {
         }
     }
 
-    /// A molecule, which is a node in the tree search.
+    /// The number of greedy reductions to attempt before giving up.
+    const REDUCTION_ATTEMPTS: usize = 1000;
+
+    /// A molecule, which is a state in the search.
     ///
-    /// The tree search works backwards, from the molecule we are trying to make
+    /// The search works backwards, from the molecule we are trying to make
     /// to the starting molecule.
     #[derive(Clone)]
     struct Molecule<'a> {
@@ -122,15 +123,24 @@ mod solution {
         }
     }
     impl<'a> Molecule<'a> {
-        /// Returns the initial molecule, which is the molecule we want to make, for a given
-        /// `start_molecule` and molecule making `machine`.
-        fn start(start_molecule: &'static str, machine: &'a Machine) -> Self {
+        /// Returns the molecule we want to make, for a given `start_molecule` and
+        /// molecule making `machine`.
+        fn medicine(start_molecule: &'static str, machine: &'a Machine) -> Self {
             Molecule {
                 machine,
                 current: machine.medicine.to_string(),
                 target: start_molecule,
             }
         }
+
+        /// Returns the `start_molecule` itself for a molecule making `machine`.
+        fn start(start_molecule: &'static str, machine: &'a Machine) -> Self {
+            Molecule {
+                machine,
+                current: start_molecule.to_string(),
+                target: start_molecule,
+            }
+        }
     }
     impl PartialEq for Molecule<'_> {
         fn eq(&self, other: &Self) -> bool {
@@ -143,30 +153,31 @@ mod solution {
             self.current.hash(state);
         }
     }
-    impl LeastStepsTreeNode for Molecule<'_> {
-        fn recurse_action(&mut self) -> ApplyNodeAction<Self> {
-            if self.current == self.target {
-                return ApplyNodeAction::Complete(true);
-            } else if self.current.contains(self.target) {
+    impl ReverseSearchNode for Molecule<'_> {
+        fn predecessors(&self) -> Vec<Self> {
+            if self.current != self.target && self.current.contains(self.target) {
                 // An assumption here is that the target string is not a part
                 // of any replacement to string, i.e. it cannot be further transformed.
                 // Thus, if it is in any non-equal string, this branch can be abandoned.
-                return ApplyNodeAction::Complete(false);
+                return Vec::new();
             }
 
-            // All replacements in the current string
-            ApplyNodeAction::Continue(
-                self.machine
-                    .replacements
-                    .iter()
-                    .flat_map(|rep| self.current.individual_replacements(&rep.to, &rep.from))
-                    .map(|rep| Self {
-                        machine: self.machine,
-                        current: rep,
-                        target: self.target,
-                    })
-                    .collect(),
-            )
+            // All reverse replacements in the current string
+            self.machine
+                .replacements
+                .iter()
+                .flat_map(|rep| self.current.individual_replacements(&rep.to, &rep.from))
+                .map(|rep| Self {
+                    machine: self.machine,
+                    current: rep,
+                    target: self.target,
+                })
+                .collect()
+        }
+
+        fn remaining_estimate(&self) -> usize {
+            // Shorter molecules are greedily preferred
+            self.current.len()
         }
     }
 
@@ -224,10 +235,6 @@ mod solution {
                 medicine = medicine.replace(&meta_rep.from, &meta_rep.to);
             }
 
-            // Now make the search greedy
-            replacements.sort_unstable_by_key(|r| r.to.len());
-            replacements.reverse();
-
             Ok(Machine {
                 replacements,
                 medicine,
@@ -244,10 +251,17 @@ mod solution {
 
         /// Counts the number of replacement steps required to create a target molecule
         /// from a starting molecule.
+        ///
+        /// This relies on every way of making the target molecule taking the same
+        /// number of steps, which is the case for the actual input.
         pub fn number_of_steps(&self, starting_molecule: &'static str) -> AocResult<u64> {
-            Molecule::start(starting_molecule, self)
-                .traverse_tree()
-                .map(|s| s.try_into().unwrap())
+            greedy_reduction(
+                &Molecule::start(starting_molecule, self),
+                Molecule::medicine(starting_molecule, self),
+                REDUCTION_ATTEMPTS,
+                0,
+            )
+            .map(|s| s.try_into().unwrap())
         }
     }
 }
@@ -258,7 +272,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 19,
     name: "Medicine for Rudolph",
-    preprocessor: Some(|input| Ok(Box::new(Machine::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Machine>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -9,7 +9,6 @@ mod tests {
             input = "5000";
             answers = unsigned![180, 168];
         }
-        actual_answers = unsigned![786240, 831600];
     }
 }
 
@@ -85,6 +84,7 @@ pub const SOLUTION: Solution = Solution {
     day: 20,
     name: "Infinite Elves and Infinite Houses",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
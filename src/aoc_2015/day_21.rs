@@ -11,7 +11,6 @@ Damage: 3
 Armor: 3";
             answers = unsigned![23, 33];
         }
-        actual_answers = unsigned![111, 188];
     }
 }
 
@@ -258,6 +257,7 @@ pub const SOLUTION: Solution = Solution {
     day: 21,
     name: "RPG Simulator 20XX",
     preprocessor: Some(|input| Ok(Box::new(Problem::new(Character::from_str(input)?)).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
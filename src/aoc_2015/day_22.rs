@@ -10,7 +10,6 @@ mod tests {
     Damage: 9";
             answers = unsigned![734, 754];
         }
-        actual_answers = unsigned![1269, 1309];
     }
 }
 
@@ -397,6 +396,7 @@ pub const SOLUTION: Solution = Solution {
         ))
         .into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -14,13 +14,15 @@ tpl b
 inc b";
             answers = unsigned![4, 10];
         }
-        actual_answers = unsigned![170, 247];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
-    use aoc::parse::trim;
+    use aoc::{
+        parse::trim,
+        vm::{run, Machine},
+    };
     use num::Integer;
 
     use super::*;
@@ -55,7 +57,7 @@ mod solution {
 
     /// Possible instructions of the computer, which can be parsed from text input.
     #[derive(Debug)]
-    enum Instruction {
+    pub enum Instruction {
         /// The `hlf` instruction operating on register.
         Half(Register),
         /// The `tpl` instruction operating on a register.
@@ -180,21 +182,46 @@ mod solution {
     impl Program {
         /// Executes the program/instructions given a starting state, returning
         /// the final state after completion.
-        pub fn execute(&self, mut state: State) -> State {
-            loop {
-                if state.program_counter < 0 {
-                    break;
-                }
-                let pc: usize = state.program_counter.try_into().unwrap();
-                if pc >= self.instructions.len() {
-                    break;
-                }
-                //println!("Executing: {:?}", self.instructions[pc]);
-                self.instructions[pc].execute(&mut state);
-                //println!("State {:?}", state);
-            }
+        pub fn execute(&self, state: State) -> State {
+            let mut computer = Computer {
+                program: self,
+                state,
+            };
+            run(&mut computer);
+            computer.state
+        }
+    }
+
+    /// The computer running a program, which can be debugged.
+    struct Computer<'a> {
+        /// The program being run.
+        program: &'a Program,
+        /// The current state of the computer.
+        state: State,
+    }
+    impl Machine for Computer<'_> {
+        type Instruction = Instruction;
+
+        fn program(&self) -> &[Self::Instruction] {
+            &self.program.instructions
+        }
+
+        fn instruction_pointer(&self) -> Option<usize> {
+            usize::try_from(self.state.program_counter)
+                .ok()
+                .filter(|pc| *pc < self.program.instructions.len())
+        }
+
+        fn step(&mut self) {
+            let pc = self.instruction_pointer().unwrap();
+            self.program.instructions[pc].execute(&mut self.state);
+        }
 
-            state
+        fn registers(&self) -> Vec<(&'static str, i64)> {
+            [("a", Register::A), ("b", Register::B)]
+                .into_iter()
+                .map(|(name, r)| (name, self.state.registers[&r].try_into().unwrap_or(i64::MAX)))
+                .collect()
         }
     }
 }
@@ -205,7 +232,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 23,
     name: "Opening the Turing Lock",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Program>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Program>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -18,7 +18,6 @@ mod tests {
 11";
             answers = unsigned![99, 44];
         }
-        actual_answers = unsigned![10723906903, 74850409];
     }
 }
 
@@ -136,6 +135,7 @@ pub const SOLUTION: Solution = Solution {
     day: 24,
     name: "It Hangs in the Balance",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
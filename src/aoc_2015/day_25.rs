@@ -9,7 +9,6 @@ mod tests {
             input = "To continue, please consult the code grid in the manual.  Enter the code at row 6, column 6.";
             answers = unsigned![27995004];
         }
-        actual_answers = unsigned![19980801];
     }
 }
 
@@ -79,6 +78,7 @@ pub const SOLUTION: Solution = Solution {
     day: 25,
     name: "Let It Snow",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -2,6 +2,7 @@ use aoc::prelude::*;
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use aoc::prelude_test::*;
 
     solution_tests! {
@@ -11,7 +12,16 @@ mod tests {
 2-9 c: ccccccccc";
             answers = unsigned![2, 1];
         }
-        actual_answers = unsigned![378, 280];
+    }
+
+    #[test]
+    fn invalid_position() {
+        assert_eq!(
+            SOLUTION.preprocess("1-3 a: abcde\n2-9 c: ccccc").err(),
+            Some(AocError::InvalidInput(
+                "position 9 is not valid for the password 'ccccc'".into()
+            )),
+        );
     }
 }
 
@@ -90,7 +100,7 @@ mod solution {
         }
 
         fn validate(&self, password: &str) -> bool {
-            // Just going to naively assume that the string is long
+            // The validation function ensures that the string is long
             // enough to contain both characters.
             let check =
                 |position: usize| password.chars().iterations(position).unwrap() == self.character;
@@ -131,6 +141,22 @@ mod solution {
             self.policy.validate(self.password)
         }
     }
+    impl Password<'_, PartTwoPolicy> {
+        /// Checks that both policy positions are valid positions in the password,
+        /// which is assumed by [`PartTwoPolicy`].
+        pub fn check_positions(&self) -> AocResult<()> {
+            let len = self.password.chars().count();
+            for position in self.policy.positions {
+                ensure!(
+                    (1..=len).contains(&position),
+                    "position {} is not valid for the password '{}'",
+                    position,
+                    self.password
+                );
+            }
+            Ok(())
+        }
+    }
 
     /// Solves a part of the problem by reading in policies and passwords and counting those that are valid.
     pub fn solve<P: PasswordPolicy>(input: &SolverInput) -> AocResult<Answer> {
@@ -151,5 +177,11 @@ pub const SOLUTION: Solution = Solution {
     day: 2,
     name: "Password Philosophy",
     preprocessor: None,
+    validate: Some(|input| {
+        for password in Password::<PartTwoPolicy>::gather(input.expect_input()?.lines())? {
+            password.check_positions()?;
+        }
+        Ok(())
+    }),
     solvers: &[solve::<PartOnePolicy>, solve::<PartTwoPolicy>],
 };
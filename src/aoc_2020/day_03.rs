@@ -20,7 +20,6 @@ mod tests {
 ";
             answers = unsigned![7, 336];
         }
-        actual_answers = unsigned![225, 1115775000];
     }
 }
 
@@ -94,6 +93,7 @@ pub const SOLUTION: Solution = Solution {
     day: 3,
     name: "Toboggan Trajectory",
     preprocessor: Some(|input| Ok(Box::new(Map::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -20,7 +19,7 @@ hgt:179cm
 
 hcl:#cfa07d eyr:2025 pid:166559648
 iyr:2011 ecl:brn hgt:59in";
-            answers = &[Some(Unsigned(2)), None];
+            answers = answers![u 2, skip];
         }
         example {
             input = "eyr:1972 cid:100
@@ -36,7 +35,7 @@ ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
 hgt:59cm ecl:zzz
 eyr:2038 hcl:74454a iyr:2023
 pid:3556412378 byr:2007";
-            answers = &[None, Some(Unsigned(0))];
+            answers = answers![skip, u 0];
         }
         example {
             input = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
@@ -51,198 +50,101 @@ pid:545766238 ecl:hzl
 eyr:2022
 
 iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
-            answers = &[None, Some(Unsigned(4))];
+            answers = answers![skip, u 4];
         }
-        actual_answers = unsigned![202, 137];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
+    use aoc::parse::RecordBlock;
     use nom::{
         branch::alt,
-        bytes::complete::{is_not, tag, take_until, take_while_m_n},
-        character::complete::{line_ending, space1},
-        combinator::{all_consuming, map},
-        multi::separated_list1,
-        sequence::{pair, preceded, separated_pair},
+        bytes::complete::{tag, take_while_m_n},
+        combinator::all_consuming,
+        sequence::{pair, preceded},
     };
-    use std::collections::HashMap;
-    use strum::IntoEnumIterator;
-    use strum_macros::{EnumIter, EnumString};
 
-    /// Possible passport fields, which can be parsed from text input.
-    #[derive(PartialEq, Eq, Hash, Debug, EnumString, EnumIter)]
-    pub enum PassportField {
-        /// Birth year.
-        #[strum(serialize = "byr")]
-        BirthYear,
-        /// Issue year.
-        #[strum(serialize = "iyr")]
-        IssueYear,
-        /// Exppiration year.
-        #[strum(serialize = "eyr")]
-        ExpirationYear,
-        /// Person's height.
-        #[strum(serialize = "hgt")]
-        Height,
-        /// Person's hair color.
-        #[strum(serialize = "hcl")]
-        HairColor,
-        /// Person's eye color.
-        #[strum(serialize = "ecl")]
-        EyeColor,
-        /// Passport ID.
-        #[strum(serialize = "pid")]
-        PassportId,
-        /// Country ID of the passport.
-        #[strum(serialize = "cid")]
-        CountryId,
-    }
-    impl PassportField {
-        /// Returns whether a field value is valid or not given the field type.
-        fn validate(&self, value: &str) -> bool {
-            use PassportField::*;
-            match self {
-                BirthYear => match value.parse::<u32>() {
-                    Ok(b) => (1920..=2002).contains(&b),
-                    Err(_) => false,
-                },
-                IssueYear => match value.parse::<u32>() {
-                    Ok(b) => (2010..=2020).contains(&b),
-                    Err(_) => false,
-                },
-                ExpirationYear => match value.parse::<u32>() {
-                    Ok(b) => (2020..=2030).contains(&b),
-                    Err(_) => false,
-                },
-                Height => {
-                    let res: NomParseResult<&str, (u32, &str)> = all_consuming(pair(
-                        nom::character::complete::u32,
-                        alt((tag("cm"), tag("in"))),
-                    ))(value);
-                    match res {
-                        Ok((_, (h, u))) => match u {
-                            "cm" => (150..=193).contains(&h),
-                            _ => (59..=76).contains(&h),
-                        },
-                        Err(_) => false,
-                    }
-                }
-                HairColor => {
-                    let res: NomParseResult<&str, &str> = all_consuming(preceded(
-                        tag("#"),
-                        take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit()),
-                    ))(value);
-                    res.is_ok()
-                }
-                EyeColor => {
-                    let res: NomParseResult<&str, &str> = all_consuming(alt((
-                        tag("amb"),
-                        tag("blu"),
-                        tag("brn"),
-                        tag("gry"),
-                        tag("grn"),
-                        tag("hzl"),
-                        tag("oth"),
-                    )))(value);
-                    res.is_ok()
-                }
-                PassportId => {
-                    let res: NomParseResult<&str, &str> =
-                        all_consuming(take_while_m_n(9, 9, |c: char| c.is_ascii_digit()))(value);
-                    res.is_ok()
-                }
-                CountryId => true,
-            }
-        }
-    }
-    impl Parsable<'_> for PassportField {
-        fn parser(input: &str) -> NomParseResult<&str, Self>
-        where
-            Self: Sized,
-        {
-            map(take_until(":"), |s: &str| {
-                s.parse()
-                    .unwrap_or_else(|_| panic!("Unknown passport field: {s}"))
-            })(input)
-        }
-    }
-
-    /// Hash map from a passport field to its value.
-    type PassportFieldMap = HashMap<PassportField, String>;
+    /// The fields that a passport must have, which excludes the optional country ID.
+    const REQUIRED_FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
 
     /// Behavior for a particular part of the problem.
     pub trait Part {
         /// Validates whether a passport is valid.
-        fn validate(field_map: &PassportFieldMap) -> bool;
+        fn validate(passport: &RecordBlock) -> bool;
     }
 
     /// Behavior for part one.
     pub struct PartOne {}
     impl Part for PartOne {
-        fn validate(field_map: &PassportFieldMap) -> bool {
-            PassportField::iter()
-                .all(|field| field_map.contains_key(&field) || field == PassportField::CountryId)
+        fn validate(passport: &RecordBlock) -> bool {
+            passport.has_all(REQUIRED_FIELDS)
         }
     }
 
     /// Behavior for part two.
     pub struct PartTwo {}
-    impl Part for PartTwo {
-        fn validate(field_map: &PassportFieldMap) -> bool {
-            PassportField::iter().all(|field| match field_map.get(&field) {
-                Some(v) => field.validate(v),
-                None => field == PassportField::CountryId,
-            })
-        }
-    }
-
-    /// A passport with its fields, which can be parsed from text input.
-    struct Passport {
-        /// Map from fields to their values.
-        field_map: PassportFieldMap,
-    }
-    impl Parsable<'_> for Passport {
-        fn parser(input: &str) -> NomParseResult<&str, Self>
-        where
-            Self: Sized,
-        {
-            map(
-                separated_list1(
-                    alt((space1, line_ending)),
-                    separated_pair(PassportField::parser, tag(":"), is_not(" \t\n\r")),
-                ),
-                |vec| Self {
-                    field_map: vec.into_iter().map(|(k, v)| (k, v.to_string())).collect(),
-                },
-            )(input)
+    impl PartTwo {
+        /// Checks that the passport has every required field with a valid value.
+        ///
+        /// This is an internal function of [`PartTwo::validate`].
+        fn check(passport: &RecordBlock) -> AocResult<()> {
+            passport.get_parse_within::<u32>("byr", 1920..=2002)?;
+            passport.get_parse_within::<u32>("iyr", 2010..=2020)?;
+            passport.get_parse_within::<u32>("eyr", 2020..=2030)?;
+            passport.get_validate("hgt", |value| {
+                let res: NomParseResult<&str, (u32, &str)> = all_consuming(pair(
+                    nom::character::complete::u32,
+                    alt((tag("cm"), tag("in"))),
+                ))(value);
+                match res {
+                    Ok((_, (h, "cm"))) => (150..=193).contains(&h),
+                    Ok((_, (h, _))) => (59..=76).contains(&h),
+                    Err(_) => false,
+                }
+            })?;
+            passport.get_validate("hcl", |value| {
+                let res: NomParseResult<&str, &str> = all_consuming(preceded(
+                    tag("#"),
+                    take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit()),
+                ))(value);
+                res.is_ok()
+            })?;
+            passport.get_validate("ecl", |value| {
+                ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value)
+            })?;
+            passport.get_validate("pid", |value| {
+                value.len() == 9 && value.chars().all(|c| c.is_ascii_digit())
+            })?;
+            Ok(())
         }
     }
-    impl Passport {
-        /// Validates the passport for a particular part of the problem.
-        pub fn validate<P: Part>(&self) -> bool {
-            P::validate(&self.field_map)
+    impl Part for PartTwo {
+        fn validate(passport: &RecordBlock) -> bool {
+            Self::check(passport).is_ok()
         }
     }
 
     /// List of passports, which can be parsed from text input.
-    pub struct PassportList {
+    pub struct PassportList<'a> {
         /// List of passports.
-        passports: Vec<Passport>,
+        passports: Vec<RecordBlock<'a>>,
     }
-    impl PassportList {
+    impl<'a> PassportList<'a> {
         /// Parses the list from text input.
-        pub fn from_str(input: &str) -> AocResult<Self> {
+        pub fn from_str(input: &'a str) -> AocResult<Self> {
             Ok(Self {
-                passports: Passport::gather(input.split("\n\n"))?,
+                passports: input
+                    .split("\n\n")
+                    .map(RecordBlock::parse)
+                    .collect::<AocResult<_>>()?,
             })
         }
 
         /// Counts the number of passports in the list that are valid for a particular part of the problem.
         pub fn count_valid<P: Part>(&self) -> u64 {
-            self.passports.iter().filter_count(|p| p.validate::<P>())
+            self.passports.iter().filter_count(|p| P::validate(p))
         }
     }
 }
@@ -253,21 +155,20 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 4,
     name: "Passport Processing",
-    preprocessor: Some(|input| Ok(Box::new(PassportList::from_str(input)?).into())),
+    preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Processing
-            Ok(input
-                .expect_data::<PassportList>()?
+            Ok(PassportList::from_str(input.expect_input()?)?
                 .count_valid::<PartOne>()
                 .into())
         },
         // Part two
         |input| {
             // Processing
-            Ok(input
-                .expect_data::<PassportList>()?
+            Ok(PassportList::from_str(input.expect_input()?)?
                 .count_valid::<PartTwo>()
                 .into())
         },
@@ -3,16 +3,14 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
             input = "BFFFBBFRRR
 FFFBBBFRRR
 BBFFBBFRLL";
-            answers = &[Some(Unsigned(820)), None];
+            answers = answers![u 820, skip];
         }
-        actual_answers = unsigned![970, 587];
     }
 }
 
@@ -92,6 +90,7 @@ pub const SOLUTION: Solution = Solution {
         })
         .into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
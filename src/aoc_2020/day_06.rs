@@ -24,7 +24,6 @@ b
 ";
             answers = unsigned![11, 6];
         }
-        actual_answers = unsigned![6335, 3392];
     }
 }
 
@@ -107,6 +106,7 @@ pub const SOLUTION: Solution = Solution {
     preprocessor: Some(|input| {
         Ok(Box::new(input.split("\n\n").map(Group::from).collect::<Vec<Group>>()).into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -4,7 +4,6 @@ use std::str::FromStr;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -27,9 +26,8 @@ dark yellow bags contain 2 dark green bags.
 dark green bags contain 2 dark blue bags.
 dark blue bags contain 2 dark violet bags.
 dark violet bags contain no other bags.";
-            answers = &[None, Some(Unsigned(126))];
+            answers = answers![skip, u 126];
         }
-        actual_answers = unsigned![316, 11310];
     }
 }
 
@@ -212,6 +210,7 @@ pub const SOLUTION: Solution = Solution {
 
         Ok(Box::new((bag_rules, id)).into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -17,7 +17,6 @@ jmp -4
 acc +6";
             answers = unsigned![5, 8];
         }
-        actual_answers = unsigned![1087, 780];
     }
 }
 
@@ -204,7 +203,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 8,
     name: "Handheld Halting",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Program>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Program>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -29,7 +29,6 @@ mod tests {
 576";
             answers = unsigned![127, 62];
         }
-        actual_answers = unsigned![542529149, 75678618];
     }
 }
 
@@ -137,7 +136,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 9,
     name: "Encoding Error",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<XmasPacket>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<XmasPacket>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
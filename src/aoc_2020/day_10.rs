@@ -55,7 +55,6 @@ mod tests {
 3";
             answers = unsigned![220, 19208];
         }
-        actual_answers = unsigned![2100, 16198260678656];
     }
 }
 
@@ -217,7 +216,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 10,
     name: "Adapter Array",
-    preprocessor: Some(|input| Ok(Box::new(AdapterSet::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<AdapterSet>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -1,5 +1,4 @@
 use aoc::prelude::*;
-use std::cell::RefCell;
 
 #[cfg(test)]
 mod tests {
@@ -19,17 +18,16 @@ L.LLLLLL.L
 L.LLLLL.LL";
             answers = unsigned![37, 26];
         }
-        actual_answers = unsigned![2483, 2285];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use derive_new::new;
-    use euclid::Vector2D;
-    use itertools::iproduct;
-    use std::{collections::HashSet, fmt::Display, hash::Hash, rc::Rc};
+    use aoc::{
+        automaton::{generations, stabilize},
+        record::record_grid,
+    };
 
     /// State of a single seat in the waiting room.
     #[derive(Clone, Copy, Hash, PartialEq, Eq, Default)]
@@ -61,171 +59,78 @@ mod solution {
         }
     }
 
-    /// The termination status of a waiting room simulation.
-    pub enum TerminationStatus<T> {
-        /// The simulation becomes stable (i.e. unchanging) with the final state.
-        Stable(T),
-        /// The simulation evolves forever, becoming periodic at some point with the first repeated state.
-        Periodic(T),
-    }
-    impl<T: Display> Display for TerminationStatus<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let (s, a) = match self {
-                TerminationStatus::Stable(a) => ("Stable", a),
-                TerminationStatus::Periodic(a) => ("Infinite", a),
-            };
-            write!(f, "{s}:\n{a}")
-        }
-    }
-    impl TerminationStatus<Rc<Area>> {
-        /// Checks the simulation result to ensure that its stable.
-        ///
-        /// The `Ok` return variant contains the number of occupied seats.
-        pub fn check(&self) -> AocResult<u64> {
-            match self {
-                TerminationStatus::Stable(a) => Ok(a.count_occupied()),
-                TerminationStatus::Periodic(_) => Err(AocError::Process(
-                    "Simulation did not reach a steady state".into(),
-                )),
-            }
-        }
-    }
+    /// Behavior specific to one particular part of the problem.
+    pub trait Part {
+        /// The minimum number of seats seen that are occupied in order for a seat to vacate.
+        const MIN_NEEDED_TO_VACATE: u8;
 
-    /// Part of the problem.
-    ///
-    /// This must be an enum because the behavior of the [`Evolver`] methods depend
-    /// on the part so it cannot be a generic parameter (since the trait method
-    /// has no generic parameters).
-    #[derive(Clone, Copy, Hash, PartialEq, Eq)]
-    pub enum Part {
-        /// Part one.
-        PartOne,
-        /// Part two.
-        PartTwo,
+        /// Returns the number of occupied seats seen from a given seat.
+        fn point_occupied(grid: &Grid<Seat>, point: &GridPoint) -> u8;
     }
-    impl Part {
-        /// Returns the minimum number of adjacent seats that are occupied in order for a seat to vacate.
-        fn min_needed_to_vacate(&self) -> u8 {
-            match self {
-                Part::PartOne => 4,
-                Part::PartTwo => 5,
-            }
-        }
 
-        /// Returns the number of occupied seats for a given seat for the part.
-        fn point_occupied(&self, area: &Area, point: &GridPoint) -> u8 {
-            let grid = &area.grid;
-            match self {
-                // Just look at the eight adjacent seats.
-                Part::PartOne => grid
-                    .neighbor_points(point, true, false)
-                    .filter_count(|point| *grid.get(point) == Seat::Occupied),
-                // Look for the first seat in the eight directions in our line of sight.
-                Part::PartTwo => iproduct!(-1isize..=1, -1isize..=1)
-                    .map(|(dx, dy)| Vector2D::new(dx, dy))
-                    .filter(|dp| *dp != Vector2D::zero())
-                    .filter_count(|dp| {
-                        let mut i: isize = 1;
-                        loop {
-                            let point = point.to_isize();
+    /// Behavior for part one.
+    pub struct PartOne;
+    impl Part for PartOne {
+        const MIN_NEEDED_TO_VACATE: u8 = 4;
 
-                            match grid.bounded_point(&(point + *dp * i)) {
-                                Some(p) => match grid.get(&p) {
-                                    Seat::Occupied => break true,
-                                    Seat::Empty => break false,
-                                    Seat::Floor => (),
-                                },
-                                None => break false,
-                            }
+        fn point_occupied(grid: &Grid<Seat>, point: &GridPoint) -> u8 {
+            // Just look at the eight adjacent seats.
+            grid.neighbor_points(point, true, false)
+                .filter_count(|point| *grid.get(point) == Seat::Occupied)
+        }
+    }
 
-                            i += 1;
-                        }
-                    }),
-            }
+    /// Behavior for part two.
+    pub struct PartTwo;
+    impl Part for PartTwo {
+        const MIN_NEEDED_TO_VACATE: u8 = 5;
+
+        fn point_occupied(grid: &Grid<Seat>, point: &GridPoint) -> u8 {
+            // Look for the first seat in the eight directions in our line of sight.
+            AnyGridPoint::zero()
+                .all_neighbor_points(true, false)
+                .filter_count(|dp| {
+                    grid.first_visible(point, dp.to_vector(), |s| *s != Seat::Floor)
+                        .is_some_and(|p| *grid.get(&p) == Seat::Occupied)
+                })
         }
     }
 
     /// The waiting room seating area, which can be parsed from text input.
-    #[derive(Clone, Hash, PartialEq, Eq, new)]
     pub struct Area {
-        /// The part for whose rules we want to simulate.
-        part: Part,
         /// The grid of seats.
         grid: Grid<Seat>,
     }
     impl From<Grid<Seat>> for Area {
         fn from(value: Grid<Seat>) -> Self {
-            Self {
-                part: Part::PartOne,
-                grid: value,
-            }
+            Self { grid: value }
         }
     }
-    impl Evolver<Seat> for Area {
-        type Point = GridPoint;
-
-        fn next_default(other: &Self) -> Self {
-            Area::new(other.part, Grid::default(*other.grid.size()))
-        }
-
-        fn set_element(&mut self, point: &Self::Point, value: Seat) {
-            self.grid.set(point, value)
-        }
-
-        fn next_cell(&self, point: &Self::Point) -> Seat {
-            let occupied = self.part.point_occupied(self, point);
-            let orig = *self.grid.get(point);
+    impl Area {
+        /// Returns the next state of the seat at a `point` according to the rules of a
+        /// part of the problem.
+        fn next_seat<P: Part>(grid: &Grid<Seat>, point: &GridPoint) -> Seat {
+            let orig = *grid.get(point);
             match orig {
-                Seat::Empty => {
-                    if occupied == 0 {
-                        Seat::Occupied
-                    } else {
-                        orig
-                    }
-                }
-                Seat::Occupied => {
-                    if occupied >= self.part.min_needed_to_vacate() {
-                        Seat::Empty
-                    } else {
-                        orig
-                    }
+                Seat::Empty if P::point_occupied(grid, point) == 0 => Seat::Occupied,
+                Seat::Occupied if P::point_occupied(grid, point) >= P::MIN_NEEDED_TO_VACATE => {
+                    Seat::Empty
                 }
                 _ => orig,
             }
         }
 
-        fn next_iter(&self) -> impl Iterator<Item = Self::Point> {
-            self.grid.all_points()
-        }
-    }
-    impl Area {
-        /// Sets the problem at to use.
-        pub fn set_part(&mut self, part: Part) {
-            self.part = part;
-        }
+        /// Runs the simulation for a part of the problem until it becomes stable, and
+        /// returns the number of occupied seats.
+        pub fn simulate<P: Part>(&self) -> AocResult<u64> {
+            let (grid, _) = stabilize(
+                generations(self.grid.clone(), Self::next_seat::<P>)
+                    .inspect(|grid| record_grid(grid, |s| s.into())),
+            )?;
 
-        /// Counts the number of occupied seats.
-        fn count_occupied(&self) -> u64 {
-            self.grid
+            Ok(grid
                 .all_values()
-                .filter_count(|seat| matches!(seat, Seat::Occupied))
-        }
-
-        /// Runs the simulation and returns the termination status containing the final state.
-        pub fn simulate(&self) -> TerminationStatus<Rc<Self>> {
-            let mut prior_states: HashSet<Rc<Self>> = HashSet::new();
-            let mut last_state = prior_states.get_or_insert(Rc::new(self.clone()));
-            for state in self.evolutions() {
-                //println!("{:?}", state);
-                if state == *last_state {
-                    return TerminationStatus::Stable(state);
-                }
-                if prior_states.contains(&state) {
-                    return TerminationStatus::Periodic(state);
-                }
-                last_state = prior_states.get_or_insert(state);
-            }
-            panic!("Somehow the evolver iterator ended!")
+                .filter_count(|seat| **seat == Seat::Occupied))
         }
     }
 }
@@ -236,25 +141,18 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 11,
     name: "Seating System",
-    preprocessor: Some(|input| Ok(Box::new(RefCell::new(Area::from_grid_str(input)?)).into())),
+    preprocessor: Some(|input| Ok(Box::new(Area::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
-            // Generation
-            let area = input.expect_data::<RefCell<Area>>()?;
-            area.borrow_mut().set_part(Part::PartOne);
-
             // Process
-            Ok(area.borrow().simulate().check()?.into())
+            Ok(input.expect_data::<Area>()?.simulate::<PartOne>()?.into())
         },
         // Part two
         |input| {
-            // Generation
-            let area = input.expect_data::<RefCell<Area>>()?;
-            area.borrow_mut().set_part(Part::PartTwo);
-
             // Process
-            Ok(area.borrow().simulate().check()?.into())
+            Ok(input.expect_data::<Area>()?.simulate::<PartTwo>()?.into())
         },
     ],
 };
@@ -18,7 +18,6 @@ R90
 F11";
             answers = unsigned![25, 286];
         }
-        actual_answers = unsigned![2228, 42908];
     }
 }
 
@@ -150,7 +149,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 12,
     name: "Rain Risk",
-    preprocessor: Some(|input| Ok(Box::new(NavigationInstructions::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<NavigationInstructions>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
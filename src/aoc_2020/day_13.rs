@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -14,24 +13,23 @@ mod tests {
         example {
             input = "0
     67,7,59,61";
-            answers = &[None, Some(Unsigned(754018))];
+            answers = answers![skip, u 754018];
         }
         example {
             input = "0
     67,x,7,59,61";
-            answers = &[None, Some(Unsigned(779210))];
+            answers = answers![skip, u 779210];
         }
         example {
             input = "0
     67,7,x,59,61";
-            answers = &[None, Some(Unsigned(1261476))];
+            answers = answers![skip, u 1261476];
         }
         example {
             input = "0
     1789,37,47,1889";
-            answers = &[None, Some(Unsigned(1202161486))];
+            answers = answers![skip, u 1202161486];
         }
-        actual_answers = unsigned![1895, 840493039281088];
     }
 }
 
@@ -144,7 +142,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 13,
     name: "Shuttle Search",
-    preprocessor: Some(|input| Ok(Box::new(Schedule::from_str(input)?).into())),
+    preprocessor: Some(parsable_preprocessor::<Schedule>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -11,16 +10,15 @@ mod tests {
 mem[8] = 11
 mem[7] = 101
 mem[8] = 0";
-            answers = &[Some(Unsigned(165)), None];
+            answers = answers![u 165, skip];
         }
         example {
             input = "mask = 000000000000000000000000000000X1001X
 mem[42] = 100
 mask = 00000000000000000000000000000000X0XX
 mem[26] = 1";
-            answers = &[None, Some(Unsigned(208))];
+            answers = answers![skip, u 208];
         }
-        actual_answers = unsigned![9967721333886, 4355897790573];
     }
 }
 
@@ -264,6 +262,7 @@ pub const SOLUTION: Solution = Solution {
         let program: Program = input.parse()?;
         Ok(Box::new(program).into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -33,7 +33,6 @@ mod tests {
             input = "3,1,2";
             answers = unsigned![1836, 362];
         }
-        actual_answers = unsigned![1428, 3718541];
     }
 }
 
@@ -113,6 +112,7 @@ pub const SOLUTION: Solution = Solution {
     day: 15,
     name: "Rambunctious Recitation",
     preprocessor: Some(|input| Ok(Box::new(Game::from_str(input.trim())?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
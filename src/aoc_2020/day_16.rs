@@ -4,7 +4,6 @@ use std::str::FromStr;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -21,7 +20,7 @@ nearby tickets:
 55,2,20
 38,6,12";
             // Solution: row, class, seat
-            answers = &[Some(Unsigned(71)), None];
+            answers = answers![u 71, skip];
         }
         example {
             input = "class: 0-1 or 4-19
@@ -36,9 +35,8 @@ nearby tickets:
 15,1,5
 5,14,9";
             // Solution: row, class, seat
-            answers = &[None, Some(Unsigned(1))];
+            answers = answers![skip, u 1];
         }
-        actual_answers = unsigned![29851, 3029180675981];
     }
 }
 
@@ -298,7 +296,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 16,
     name: "Ticket Translation",
-    preprocessor: Some(|input| Ok(Box::new(Problem::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Problem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -11,7 +11,6 @@ mod tests {
 ###";
             answers = unsigned![112, 848];
         }
-        actual_answers = unsigned![386, 2276];
     }
 }
 
@@ -198,6 +197,7 @@ pub const SOLUTION: Solution = Solution {
     day: 17,
     name: "Conway Cubes",
     preprocessor: Some(|input| Ok(Box::new(Slice::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -19,7 +18,7 @@ bababa
 abbbab
 aaabbb
 aaaabbb";
-            answers = &[Some(Unsigned(2)), None];
+            answers = answers![u 2, skip];
         }
         example {
             input = "42: 9 14 | 10 1
@@ -71,7 +70,6 @@ babaaabbbaaabaababbaabababaaab
 aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba";
             answers = unsigned![3, 12];
         }
-        actual_answers = unsigned![149, 332];
     }
 }
 
@@ -283,6 +281,7 @@ pub const SOLUTION: Solution = Solution {
     day: 19,
     name: "Monster Messages",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
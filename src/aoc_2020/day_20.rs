@@ -8,123 +8,20 @@ mod tests {
 
     solution_tests! {
         example {
-            input = "Tile 2311:
-..##.#..#.
-##..#.....
-#...##..#.
-####.#...#
-##.##.###.
-##...#.###
-.#.#.#..##
-..#....#..
-###...#.#.
-..###..###
-
-Tile 1951:
-#.##...##.
-#.####...#
-.....#..##
-#...######
-.##.#....#
-.###.#####
-###.##.##.
-.###....#.
-..#.#..#.#
-#...##.#..
-
-Tile 1171:
-####...##.
-#..##.#..#
-##.#..#.#.
-.###.####.
-..###.####
-.##....##.
-.#...####.
-#.##.####.
-####..#...
-.....##...
-
-Tile 1427:
-###.##.#..
-.#..#.##..
-.#.##.#..#
-#.#.#.##.#
-....#...##
-...##..##.
-...#.#####
-.#.####.#.
-..#..###.#
-..##.#..#.
-
-Tile 1489:
-##.#.#....
-..##...#..
-.##..##...
-..#...#...
-#####...#.
-#..#.#.#.#
-...#.#.#..
-##.#...##.
-..##.##.##
-###.##.#..
-
-Tile 2473:
-#....####.
-#..#.##...
-#.##..#...
-######.#.#
-.#...#.#.#
-.#########
-.###.#..#.
-########.#
-##...##.#.
-..###.#.#.
-
-Tile 2971:
-..#.#....#
-#...###...
-#.#.###...
-##.##..#..
-.#####..##
-.#..####.#
-#..#.#..#.
-..####.###
-..#.#.###.
-...#.#.#.#
-
-Tile 2729:
-...#.#.#.#
-####.#....
-..#.#.....
-....#..#.#
-.##..##.#.
-.#.####...
-####.#.#..
-##.####...
-##..#.##..
-#.##...##.
-
-Tile 3079:
-#.#.#####.
-.#..######
-..#.......
-######....
-####.#..#.
-.#...#.##.
-#.#####.##
-..#.###...
-..#.......
-..#.###...";
+            input_file = "day_20_1.txt";
             answers = unsigned![20899048083289, 273];
         }
-        actual_answers = unsigned![83775126454273, 1993];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use aoc::tree_search::{BasicSolutionState, GlobalStateTreeNode, NodeAction};
+    use aoc::cache::Cacheable;
+    use aoc::tree_search::{
+        BasicSolutionState, ChildPool, GlobalStateTreeNode, NodeAction, PoolIndex, PoolSubset,
+        SharedPool,
+    };
     use derive_more::{Deref, From, Into};
     use derive_new::new;
     use enum_map::{enum_map, Enum, EnumMap};
@@ -136,7 +33,6 @@ mod solution {
         sequence::{delimited, pair},
         Finish,
     };
-    use std::rc::Rc;
     use std::{cmp::Ordering, fmt};
     use strum_macros::{Display, EnumIter};
 
@@ -323,17 +219,13 @@ mod solution {
             .filter(|point| {
                 let sub_image = self
                     .pixels
-                    .sub_grid(&Box2D::from_origin_and_size(*point, *image_size));
+                    .view(&Box2D::from_origin_and_size(*point, *image_size));
 
-                // It is very strange to me that we need to use assign variable here, but we get a compiler
-                // error if we do not.
-                let keep = image
+                image
                     .pixels
                     .all_values()
                     .zip(sub_image.all_values())
-                    .all(|(pi, ps)| !**pi || **ps);
-
-                keep
+                    .all(|(pi, ps)| !**pi || **ps)
             })
             .collect()
         }
@@ -556,8 +448,8 @@ mod solution {
     /// A slot for a tile in a square image formed by tiles.
     #[derive(Clone)]
     struct TileSlot {
-        /// The tile in this slot.
-        tile: Rc<Tile>,
+        /// The index of the tile in this slot in the pool of tiles.
+        tile: PoolIndex,
         /// How the tile must be transformed to fit in the overall image.
         transform: Transform,
     }
@@ -565,8 +457,10 @@ mod solution {
     /// A square map of tiles formed into a larger image.
     #[derive(Clone)]
     pub struct TileMap {
+        /// The pool of all the tiles.
+        tiles: SharedPool<Tile>,
         /// Remaining tiles that need to be placed.
-        remaining: Vec<Rc<Tile>>,
+        remaining: PoolSubset,
         /// The square grid of tile slots, which may be empty.
         slots: Grid<Option<TileSlot>>,
         /// Current tile that needs to be placed when solving.
@@ -580,7 +474,7 @@ mod solution {
                     "{}",
                     row.iter()
                         .map(|slot| match slot {
-                            Some(t) => format!("{} {}", t.tile.id, t.transform),
+                            Some(t) => format!("{} {}", self.tiles[t.tile].id, t.transform),
                             None => "-".to_string(),
                         })
                         .join(" | "),
@@ -594,8 +488,10 @@ mod solution {
         /// needing to be placed.
         fn new(tile_set: TileSet) -> Self {
             let size = tile_set.size;
+            let tiles = SharedPool::new(tile_set.tiles);
             TileMap {
-                remaining: tile_set.tiles.into_iter().map(Rc::new).collect(),
+                remaining: tiles.all(),
+                tiles,
                 slots: Grid::default(size2(size, size)),
                 placement_tile: GridPoint::origin(),
             }
@@ -607,7 +503,7 @@ mod solution {
         }
 
         /// Sets a tile in the map.
-        fn set(&mut self, point: &GridPoint, tile: Rc<Tile>, transform: Transform) {
+        fn set(&mut self, point: &GridPoint, tile: PoolIndex, transform: Transform) {
             self.slots.set(point, Some(TileSlot { tile, transform }));
         }
 
@@ -616,6 +512,12 @@ mod solution {
             self.slots.get(point).as_ref()
         }
 
+        /// Returns the tile and its transform at a point in the map, if one is placed there.
+        fn get_tile(&self, point: &GridPoint) -> Option<(&Tile, Transform)> {
+            self.get(point)
+                .map(|slot| (&self.tiles[slot.tile], slot.transform))
+        }
+
         /// Verifies that the map is filled.
         fn verify_filled(&self) -> AocResult<()> {
             if self.slots.all_values().any(|s| s.is_none()) {
@@ -639,7 +541,7 @@ mod solution {
                     row.iter()
                         .map(|slot| {
                             let slot = slot.as_ref().unwrap();
-                            slot.tile.image.transformed(slot.transform)
+                            self.tiles[slot.tile].image.transformed(slot.transform)
                         })
                         .reduce(|left, right| left.adjoin_right(&right))
                         .unwrap()
@@ -648,6 +550,20 @@ mod solution {
                 .unwrap())
         }
 
+        /// Returns the result of assembling this map if it has been filled, which is
+        /// all that is needed to answer both parts.
+        pub fn assembly(&self) -> AocResult<Assembly> {
+            Ok(Assembly {
+                corner_id_product: self.corner_id_product()?,
+                rows: self
+                    .stitched_image()?
+                    .pixels
+                    .rows_iter()
+                    .map(|row| row.iter().map(|p| **p).collect())
+                    .collect(),
+            })
+        }
+
         /// Verifies that the map has been filled and, if so, returns
         /// the product of the four corner tile IDs.
         pub fn corner_id_product(&self) -> AocResult<u64> {
@@ -655,7 +571,7 @@ mod solution {
 
             let size = self.size();
             Ok(iproduct!([0, size - 1], [0, size - 1])
-                .map(|(x, y)| self.get(&point2(x, y)).unwrap().tile.id)
+                .map(|(x, y)| self.get_tile(&point2(x, y)).unwrap().0.id)
                 .product::<u64>())
         }
     }
@@ -663,64 +579,70 @@ mod solution {
     impl GlobalStateTreeNode for TileMap {
         type GlobalState = BasicSolutionState<Self>;
 
-        fn recurse_action(self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            self,
+            global_state: &mut Self::GlobalState,
+            pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             if self.remaining.is_empty() {
                 global_state.set_solution(self.clone());
                 return NodeAction::Complete;
             }
-            //println!("Have :\n {:?}", map);
+            tracing::trace!("Have:\n{self:?}");
 
             let (x, y) = (self.placement_tile.x, self.placement_tile.y);
 
-            let children: Vec<Self> = self
-                .remaining
-                .iter()
-                .enumerate()
-                .cartesian_product(Transform::iter())
-                .filter_map(|((tile_idx, tile), transform)| {
-                    /*println!(
-                        "Trying tile {} with transform {} at ({}, {})",
-                        tile.id, transform, x, y
-                    );*/
-                    let mut fits = true;
-                    // Do we need to match to the right side of the tile to the left?
-                    if x > 0 {
-                        let left_slot = self.get(&point2(x - 1, y)).unwrap();
-                        if tile.get_edge(Edge::Left, transform)
-                            != left_slot.tile.get_edge(Edge::Right, left_slot.transform)
-                        {
-                            fits = false;
+            let children = pool.collect(
+                self.remaining
+                    .iter()
+                    .cartesian_product(Transform::iter())
+                    .filter_map(|(tile_idx, transform)| {
+                        let tile = &self.tiles[tile_idx];
+                        tracing::trace!(
+                            "Trying tile {} with transform {transform} at ({x}, {y})",
+                            tile.id
+                        );
+                        let mut fits = true;
+                        // Do we need to match to the right side of the tile to the left?
+                        if x > 0 {
+                            let (left_tile, left_transform) =
+                                self.get_tile(&point2(x - 1, y)).unwrap();
+                            if tile.get_edge(Edge::Left, transform)
+                                != left_tile.get_edge(Edge::Right, left_transform)
+                            {
+                                fits = false;
+                            }
                         }
-                    }
-                    // Do we need to match the top side of the tile with the bottom
-                    // side of the tile above?
-                    if y > 0 {
-                        let above_slot = self.get(&point2(x, y - 1)).unwrap();
-                        if tile.get_edge(Edge::Top, transform)
-                            != above_slot.tile.get_edge(Edge::Bottom, above_slot.transform)
-                        {
-                            fits = false;
+                        // Do we need to match the top side of the tile with the bottom
+                        // side of the tile above?
+                        if y > 0 {
+                            let (above_tile, above_transform) =
+                                self.get_tile(&point2(x, y - 1)).unwrap();
+                            if tile.get_edge(Edge::Top, transform)
+                                != above_tile.get_edge(Edge::Bottom, above_transform)
+                            {
+                                fits = false;
+                            }
                         }
-                    }
 
-                    if fits {
-                        // The tile fits, so place it and work on the next tile
-                        //println!("It fit!");
-                        let mut map = self.clone();
-                        map.set(&point2(x, y), tile.clone(), transform);
-                        map.remaining.remove(tile_idx);
-                        let (x, y) = if x == map.size() - 1 {
-                            (0, y + 1)
+                        if fits {
+                            // The tile fits, so place it and work on the next tile
+                            tracing::trace!("It fit!");
+                            let mut map = self.clone();
+                            map.set(&point2(x, y), tile_idx, transform);
+                            map.remaining.remove(tile_idx);
+                            let (x, y) = if x == map.size() - 1 {
+                                (0, y + 1)
+                            } else {
+                                (x + 1, y)
+                            };
+                            map.placement_tile = point2(x, y);
+                            Some(map)
                         } else {
-                            (x + 1, y)
-                        };
-                        map.placement_tile = point2(x, y);
-                        Some(map)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+                            None
+                        }
+                    }),
+            );
 
             if children.is_empty() {
                 NodeAction::Stop
@@ -730,6 +652,31 @@ mod solution {
         }
     }
 
+    /// The result of assembling the tiles into a complete map, which can be cached since
+    /// placing the tiles is expensive.
+    #[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Assembly {
+        /// The product of the four corner tile IDs.
+        pub corner_id_product: u64,
+        /// The rows of pixels of the stitched image.
+        rows: Vec<Vec<bool>>,
+    }
+    impl Cacheable for Assembly {
+        const VERSION: u32 = 1;
+    }
+    impl Assembly {
+        /// Returns the stitched image.
+        pub fn stitched_image(&self) -> AocResult<Image> {
+            Grid::from_data(
+                self.rows
+                    .iter()
+                    .map(|row| row.iter().map(|p| Pixel::from(*p)).collect())
+                    .collect(),
+            )
+            .map(Image::from)
+        }
+    }
+
     /// Solver for the problem, which can be parsed from text input.
     #[derive(Debug)]
     pub struct Solver {
@@ -764,18 +711,21 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 20,
     name: "Jurassic Jigsaw",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Solver>()?.solve()?).into())),
+    preprocessor: Some(|input| {
+        aoc::cache::cached(input, |input| input.parse::<Solver>()?.solve()?.assembly())
+    }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
-            Ok(input.expect_data::<TileMap>()?.corner_id_product()?.into())
+            Ok(input.expect_data::<Assembly>()?.corner_id_product.into())
         },
         // Part two
         |input| {
             // Process
             Ok(input
-                .expect_data::<TileMap>()?
+                .expect_data::<Assembly>()?
                 .stitched_image()?
                 .find_and_subtract_sea_monster()?
                 .count_set_pixels()
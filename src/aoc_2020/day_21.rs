@@ -15,7 +15,6 @@ sqjhc fvjkl (contains soy)
 sqjhc mxmxvkd sbzzf (contains fish)";
             answers = answers![Unsigned(5), String("mxmxvkd,sqjhc,fvjkl".into())];
         }
-        actual_answers = answers![Unsigned(2287), String("fntg,gtqfrp,xlvrggj,rlsr,xpbxbv,jtjtrd,fvjkp,zhszc".into())];
     }
 }
 
@@ -238,6 +237,7 @@ pub const SOLUTION: Solution = Solution {
     day: 21,
     name: "Allergen Assessment",
     preprocessor: Some(|input| Ok(Box::new(input.parse::<Problem>()?.partial_solve()).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -31,9 +30,8 @@ Player 2:
 2
 29
 14";
-            answers = &[None, Some(Unsigned(105))];
+            answers = answers![skip, u 105];
         }
-        actual_answers = unsigned![33561, 34594];
     }
 }
 
@@ -151,7 +149,7 @@ mod solution {
             let mut history = HashSet::new();
 
             loop {
-                //println!("Game: {:?}", game);
+                tracing::trace!("Game: {game:?}");
 
                 if history.contains(&game) {
                     break game.player1;
@@ -165,11 +163,11 @@ mod solution {
 
                 let c1 = game.player1.draw().unwrap();
                 let c2 = game.player2.draw().unwrap();
-                //println!("Player 1 drew: {}, Player 2 drew: {}", c1, c2);
+                tracing::trace!("Player 1 drew: {c1}, Player 2 drew: {c2}");
                 let s1 = game.player1.len();
                 let s2 = game.player2.len();
                 if s1 >= c1.into() && s2 >= c2.into() {
-                    //println!("Starting sub-game:");
+                    tracing::trace!("Starting sub-game");
                     let sub_game = game.make_new(
                         &game.player1.cards[(s1 - Into::<usize>::into(c1))..],
                         &game.player2.cards[(s2 - Into::<usize>::into(c2))..],
@@ -251,7 +249,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 22,
     name: "Crab Combat",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Game>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Game>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
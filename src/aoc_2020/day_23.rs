@@ -3,18 +3,16 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
             input = "389125467";
-            answers = &[Some(Unsigned(67384529)), None];
+            answers = answers![u 67384529, skip];
         }
         expensive_example {
             input = "389125467";
-            answers = &[None, Some(Unsigned(149245887792))];
+            answers = answers![skip, u 149245887792];
         }
-        actual_answers = unsigned![98645732, 689500518476];
     }
 }
 
@@ -320,6 +318,7 @@ pub const SOLUTION: Solution = Solution {
     day: 23,
     name: "Crab Cups",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
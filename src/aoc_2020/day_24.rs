@@ -28,7 +28,6 @@ neswnwewnwnwseenwseesewsenwsweewe
 wseweeenwnesenwwwswnew";
             answers = unsigned![10, 2208];
         }
-        actual_answers = unsigned![354, 3608];
     }
 }
 
@@ -253,7 +252,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 24,
     name: "Lobby Layout",
-    preprocessor: Some(|input| Ok(Box::new(input.parse::<Floor>()?).into())),
+    preprocessor: Some(from_str_preprocessor::<Floor>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -11,7 +11,6 @@ mod tests {
     17807724";
             answers = unsigned![14897079];
         }
-        actual_answers = unsigned![6421487];
     }
 }
 
@@ -108,6 +107,7 @@ pub const SOLUTION: Solution = Solution {
     day: 25,
     name: "Combo Breaker",
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
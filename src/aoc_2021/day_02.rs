@@ -15,7 +15,6 @@ down 8
 forward 2";
             answers = signed![150, 900];
         }
-        actual_answers = signed![2102357, 2101031224];
     }
 }
 
@@ -146,7 +145,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 2,
     name: "Dive!",
-    preprocessor: Some(|input| Ok(Box::new(Course::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Course>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -21,7 +21,6 @@ mod tests {
 01010";
             answers = unsigned![198, 230];
         }
-        actual_answers = unsigned![3320834, 4481199];
     }
 }
 
@@ -182,7 +181,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 3,
     name: "Binary Diagnostic",
-    preprocessor: Some(|input| Ok(Box::new(Report::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Report>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -28,7 +28,6 @@ mod tests {
  2  0 12  3  7";
             answers = unsigned![4512, 1924];
         }
-        actual_answers = unsigned![12796, 18063];
     }
 }
 
@@ -203,7 +202,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 4,
     name: "Giant Squid",
-    preprocessor: Some(|input| Ok(Box::new(BingoGame::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<BingoGame>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -19,7 +19,6 @@ mod tests {
 5,5 -> 8,2";
             answers = unsigned![5, 12];
         }
-        actual_answers = unsigned![5835, 17013];
     }
 }
 
@@ -240,7 +239,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 5,
     name: "Hydrothermal Venture",
-    preprocessor: Some(|input| Ok(Box::new(Vents::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Vents>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
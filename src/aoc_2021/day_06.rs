@@ -11,7 +11,6 @@ mod tests {
             input = "3,4,3,1,2";
             answers = unsigned![5934, 26984457539];
         }
-        actual_answers = unsigned![380612, 1710166656900];
     }
 }
 
@@ -79,6 +78,7 @@ pub const SOLUTION: Solution = Solution {
     name: "Lanternfish",
     // NOTE: Simulation is an iterator so needs mutated, so we just parse it in each part.
     preprocessor: None,
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -10,19 +10,21 @@ mod tests {
             input = "16,1,2,0,4,2,7,1,2,14";
             answers = unsigned![37, 168];
         }
-        actual_answers = unsigned![341534, 93397632];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use itertools::{Itertools, MinMaxResult};
 
     /// Behavior specific to one particular part of the problem.
     pub trait Part {
         /// Returns the amount of fuel used for a given horizontal distance traveled.
         fn fuel_used(dist: u64) -> u64;
+
+        /// Returns positions among which the optimal alignment position is found, given the
+        /// non-empty crab `positions`.
+        fn candidates(positions: &[u64]) -> Vec<u64>;
     }
 
     /// Behavior for part one.
@@ -31,6 +33,11 @@ mod solution {
         fn fuel_used(dist: u64) -> u64 {
             dist
         }
+
+        fn candidates(positions: &[u64]) -> Vec<u64> {
+            // The total distance is minimized at the median
+            positions.iter().copied().median().into_iter().collect()
+        }
     }
 
     /// Behavior for part two.
@@ -39,6 +46,12 @@ mod solution {
         fn fuel_used(dist: u64) -> u64 {
             dist * (dist + 1) / 2
         }
+
+        fn candidates(positions: &[u64]) -> Vec<u64> {
+            // The total fuel is minimized within half a position of the mean
+            let mean = positions.iter().copied().mean().unwrap();
+            vec![mean.floor() as u64, mean.ceil() as u64]
+        }
     }
 
     /// Collection of crab submarines, which can be parsed from text input.
@@ -60,19 +73,20 @@ mod solution {
         /// horizontal position, and returns the amount of fuel needed for this
         /// based on the fuel usage requirements for the [`Part`].
         pub fn align<P: Part>(&self) -> AocResult<u64> {
-            match self.positions.iter().minmax() {
-                MinMaxResult::MinMax(min, max) => Ok(((*min)..=(*max))
-                    .map(|p| {
-                        self.positions
-                            .iter()
-                            .map(|x| P::fuel_used(x.abs_diff(p)))
-                            .sum()
-                    })
-                    .min()
-                    .unwrap()),
-                MinMaxResult::OneElement(v) => Ok(*v),
-                _ => Err(AocError::Process("Data empty!".into())),
+            if self.positions.is_empty() {
+                return Err(AocError::Process("Data empty!".into()));
             }
+
+            Ok(P::candidates(&self.positions)
+                .into_iter()
+                .map(|p| {
+                    self.positions
+                        .iter()
+                        .map(|x| P::fuel_used(x.abs_diff(p)))
+                        .sum()
+                })
+                .min()
+                .unwrap())
         }
     }
 }
@@ -83,7 +97,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 7,
     name: "The Treachery of Whales",
-    preprocessor: Some(|input| Ok(Box::new(CrabSubs::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<CrabSubs>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
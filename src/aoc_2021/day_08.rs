@@ -22,7 +22,6 @@ egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
             answers = unsigned![26, 61229];
         }
-        actual_answers = unsigned![330, 1010472];
     }
 }
 
@@ -270,6 +269,7 @@ pub const SOLUTION: Solution = Solution {
         )
         .into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -1,6 +1,5 @@
 use aoc::prelude::*;
 use itertools::Itertools;
-use std::collections::HashSet;
 
 #[cfg(test)]
 mod tests {
@@ -15,67 +14,15 @@ mod tests {
 9899965678";
             answers = unsigned![15, 1134];
         }
-        actual_answers = unsigned![512, 1600104];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use aoc::grid::Digit;
-    use euclid::vec2;
 
     use super::*;
 
-    /// Basin in the cave floor surrounding a low point.
-    pub struct Basin<'a> {
-        /// The floor map in which the basin resides.
-        floor_map: &'a FloorMap,
-        /// The low point in the map for this basin.
-        low_point: GridPoint,
-    }
-    impl Basin<'_> {
-        /// Returns the height of the low point.
-        pub fn low_height(&self) -> u8 {
-            **self.floor_map.grid.get(&self.low_point)
-        }
-
-        /// Returns the size of the basin.
-        pub fn size(&self) -> u64 {
-            /// This is a recursive internal function of [`Basin::size`] that finds the size of a region
-            /// given any point in the region.
-            fn region_size(
-                grid: &Grid<Digit>,
-                point: GridPoint,
-                points: &mut HashSet<GridPoint>,
-            ) -> u64 {
-                // Base cases
-                if **grid.get(&point) == 9 || points.contains(&point) {
-                    return 0;
-                }
-
-                let mut reg_size = 1;
-                let size = grid.size();
-                points.insert(point);
-                if point.x > 0 {
-                    reg_size += region_size(grid, point - vec2(1, 0), points);
-                }
-                if point.x < size.width - 1 {
-                    reg_size += region_size(grid, point + vec2(1, 0), points);
-                }
-                if point.y > 0 {
-                    reg_size += region_size(grid, point - vec2(0, 1), points);
-                }
-                if point.y < size.height - 1 {
-                    reg_size += region_size(grid, point + vec2(0, 1), points);
-                }
-
-                reg_size
-            }
-
-            region_size(&self.floor_map.grid, self.low_point, &mut HashSet::new())
-        }
-    }
-
     /// Height map of the cave floor, which can parsed from text input.
     pub struct FloorMap {
         /// Grid of the heights.
@@ -87,20 +34,14 @@ mod solution {
         }
     }
     impl FloorMap {
-        /// Returns an [`Iterator`] over all of the basins on the cave floor.
-        pub fn basins(&self) -> impl Iterator<Item = Basin> {
-            self.grid
-                .all_points()
-                .filter(|point| {
-                    let height = self.grid.get(point);
-                    self.grid
-                        .neighbor_points(point, false, false)
-                        .all(|p| height < self.grid.get(&p))
-                })
-                .map(|low_point| Basin {
-                    floor_map: self,
-                    low_point,
-                })
+        /// Returns an [`Iterator`] over the heights of the low points of the basins.
+        pub fn low_heights(&self) -> impl Iterator<Item = u8> + '_ {
+            self.grid.local_minima().map(|p| **self.grid.get(&p))
+        }
+
+        /// Returns the sizes of all of the basins on the cave floor.
+        pub fn basin_sizes(&self) -> Vec<usize> {
+            self.grid.watershed_basins(9).sizes
         }
     }
 }
@@ -112,14 +53,15 @@ pub const SOLUTION: Solution = Solution {
     day: 9,
     name: "Smoke Basin",
     preprocessor: Some(|input| Ok(Box::new(FloorMap::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
             Ok(input
                 .expect_data::<FloorMap>()?
-                .basins()
-                .map(|basin| u64::from(basin.low_height() + 1))
+                .low_heights()
+                .map(|height| u64::from(height + 1))
                 .sum::<u64>()
                 .into())
         },
@@ -128,8 +70,9 @@ pub const SOLUTION: Solution = Solution {
             // Process
             Ok(input
                 .expect_data::<FloorMap>()?
-                .basins()
-                .map(|basin| basin.size())
+                .basin_sizes()
+                .into_iter()
+                .map(|size| u64::try_from(size).unwrap())
                 .sorted()
                 .rev()
                 .take(3)
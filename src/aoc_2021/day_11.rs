@@ -26,7 +26,6 @@ mod tests {
 5283751526";
             answers = unsigned![1656, 195];
         }
-            actual_answers = unsigned![1644, 229];
     }
 }
 
@@ -121,6 +120,7 @@ pub const SOLUTION: Solution = Solution {
     day: 11,
     name: "Dumbo Octopus",
     preprocessor: Some(|input| Ok(Box::new(Octopi::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
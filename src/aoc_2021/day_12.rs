@@ -50,14 +50,13 @@ pj-fs
 start-RW";
             answers = unsigned![226, 3509];
         }
-        actual_answers = unsigned![4011, 108035];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use aoc::tree_search::{GlobalStateTreeNode, NodeAction};
+    use aoc::tree_search::{ChildPool, GlobalStateTreeNode, NodeAction};
     use infinitable::Infinitable;
     use nom::{
         bytes::complete::tag, character::complete::alphanumeric1, combinator::map,
@@ -251,7 +250,11 @@ mod solution {
     impl<'a> GlobalStateTreeNode for PathTip<'a> {
         type GlobalState = Paths<'a>;
 
-        fn recurse_action(self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            self,
+            global_state: &mut Self::GlobalState,
+            _pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             let cave = self.graph.node_weight(self.tip).unwrap();
 
             if cave.cave_type == CaveType::End {
@@ -299,7 +302,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 12,
     name: "Passage Pathing",
-    preprocessor: Some(|input| Ok(Box::new(CaveSystem::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<CaveSystem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -28,9 +28,8 @@ mod tests {
 
 fold along y=7
 fold along x=5";
-            answers = unsigned![17, 16];
+            answers = answers![u 17, skip];
         }
-        actual_answers = unsigned![592, 94];
     }
 }
 
@@ -38,124 +37,26 @@ fold along x=5";
 mod solution {
     use super::*;
     use aoc::{
-        grid::{AnyGridPoint, StdBool},
+        geometry::{fold_letters, reflect_points, Fold},
         parse::trim,
     };
-    use derive_more::{AsRef, Deref};
-    use euclid::Point2D;
-    use itertools::Itertools;
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{multispace1, one_of},
-        combinator::map,
-        sequence::{preceded, separated_pair},
-    };
-    use std::{collections::HashSet, fmt::Debug, rc::Rc};
+    use nom::{bytes::complete::tag, combinator::map, sequence::separated_pair};
 
     /// A dot location on the transparent page, which can be parsed from text input.
-    #[derive(Deref, AsRef, PartialEq, Eq, Hash, Clone)]
     struct Dot(AnyGridPoint);
     impl Parsable<'_> for Dot {
         fn parser(input: &str) -> NomParseResult<&str, Self> {
             map(
-                separated_pair(
-                    nom::character::complete::i32,
-                    trim(false, tag(",")),
-                    nom::character::complete::i32,
-                ),
-                |(x, y)| Self(Point2D::new(x, y).to_isize()),
-            )(input)
-        }
-    }
-    impl Dot {
-        /// Creates a new dot based on its coordinates on the page.
-        fn new(x: isize, y: isize) -> Self {
-            Self(AnyGridPoint::new(x, y))
-        }
-    }
-
-    /// A transparent page, which can be parsed from text input.
-    #[derive(Clone)]
-    pub struct Page {
-        /// The set of dots on the page.
-        dots: HashSet<Dot>,
-    }
-    impl FromStr for Page {
-        type Err = AocError;
-
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-            Ok(Page {
-                dots: Dot::gather(s.lines())?.into_iter().collect(),
-            })
-        }
-    }
-    impl Debug for Page {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let points = self.dots.iter().map(|d| **d).collect_vec();
-            write!(f, "{:?}", Grid::<StdBool>::from_coordinates(points.iter()))
-        }
-    }
-    impl Page {
-        /// Folds the page and returns the new folded page.
-        fn fold(&self, fold: &Fold) -> Self {
-            let mut dots = HashSet::new();
-            match fold {
-                Fold::Vertical(fx) => {
-                    for dot in self.dots.iter().map(Dot::as_ref) {
-                        dots.insert(Dot::new(
-                            if dot.x <= *fx { dot.x } else { 2 * fx - dot.x },
-                            dot.y,
-                        ));
-                    }
-                }
-                Fold::Horizontal(fy) => {
-                    for dot in self.dots.iter().map(Dot::as_ref) {
-                        dots.insert(Dot::new(
-                            dot.x,
-                            if dot.y <= *fy { dot.y } else { 2 * fy - dot.y },
-                        ));
-                    }
-                }
-            }
-            Self { dots }
-        }
-
-        /// Returns the number of dots on the page.
-        pub fn num_dots(&self) -> usize {
-            self.dots.len()
-        }
-    }
-
-    /// A way and location to fold a page, which can be parsed from text input.
-    #[derive(Clone, Copy)]
-    enum Fold {
-        /// Vertical fold at the `x` coordinate of the vertical line.
-        Vertical(isize),
-        /// Horizontal fold at the `y` coordinate of the horizontal line.
-        Horizontal(isize),
-    }
-    impl Parsable<'_> for Fold {
-        fn parser(input: &str) -> NomParseResult<&str, Self> {
-            map(
-                preceded(
-                    preceded(tag("fold along"), multispace1),
-                    separated_pair(one_of("xy"), tag("="), nom::character::complete::u32),
-                ),
-                |(dir, val)| {
-                    let val = val.try_into().unwrap();
-                    match dir {
-                        'x' => Self::Vertical(val),
-                        _ => Self::Horizontal(val),
-                    }
-                },
+                separated_pair(usize::parser, trim(false, tag(",")), usize::parser),
+                |(x, y)| Self(GridPoint::new(x, y).to_isize()),
             )(input)
         }
     }
 
     /// The problem definition, which can be parsed from text input.
     pub struct Problem {
-        /// The initial page from the manual.
-        page: Page,
+        /// The dots on the initial page from the manual.
+        dots: Box<[AnyGridPoint]>,
         /// The ordered list of folds to arrive at the final pattern of dots.
         folds: Box<[Fold]>,
     }
@@ -166,38 +67,27 @@ mod solution {
             let sections = s.sections(2)?;
 
             Ok(Self {
-                page: Page::from_str(sections[0])?,
+                dots: Dot::gather(sections[0].lines())?
+                    .into_iter()
+                    .map(|d| d.0)
+                    .collect(),
                 folds: Fold::gather(sections[1].lines())?.into_boxed_slice(),
             })
         }
     }
     impl Problem {
-        /// Applies the complete sequence of folds.
-        ///
-        /// Returns an [`Iterator`] of pages after each fold is made.
-        pub fn apply_folds(&self) -> FoldedPages {
-            FoldedPages {
-                page: Rc::new(self.page.clone()),
-                folds: self.folds.iter(),
-            }
+        /// Returns the number of dots that are visible after only the first fold.
+        pub fn first_fold_dots(&self) -> AocResult<usize> {
+            let fold = self
+                .folds
+                .first()
+                .ok_or(AocError::InvalidInput("There are no folds".into()))?;
+            Ok(reflect_points(self.dots.iter().copied(), *fold).len())
         }
-    }
-
-    /// [`Iterator`] over the pages after each fold.
-    pub struct FoldedPages<'a> {
-        /// The current page.
-        page: Rc<Page>,
-        /// [`Iterator`] over the actual folds.
-        folds: std::slice::Iter<'a, Fold>,
-    }
-    impl Iterator for FoldedPages<'_> {
-        type Item = Rc<Page>;
 
-        fn next(&mut self) -> Option<Self::Item> {
-            self.folds.next().map(|fold| {
-                self.page = Rc::new(self.page.fold(fold));
-                self.page.clone()
-            })
+        /// Returns the code, which is the letters drawn by the dots after all the folds.
+        pub fn code(&self) -> AocResult<String> {
+            fold_letters(self.dots.iter().copied(), &self.folds)
         }
     }
 }
@@ -208,7 +98,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 13,
     name: "Transparent Origami",
-    preprocessor: Some(|input| Ok(Box::new(Problem::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Problem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -216,10 +107,7 @@ pub const SOLUTION: Solution = Solution {
             Ok(Answer::Unsigned(
                 input
                     .expect_data::<Problem>()?
-                    .apply_folds()
-                    .next()
-                    .unwrap()
-                    .num_dots()
+                    .first_fold_dots()?
                     .try_into()
                     .unwrap(),
             ))
@@ -227,19 +115,7 @@ pub const SOLUTION: Solution = Solution {
         // Part two
         |input| {
             // Process
-            let last_page = input
-                .expect_data::<Problem>()?
-                .apply_folds()
-                .last()
-                .unwrap();
-
-            // This requires looking at letters in the folded image,
-            // which cannot really be done in automated way easily.
-            println!("Part two folded image:\n");
-            println!("{last_page:?}");
-            println!("Part two actual answer: JGAJEFKU\n");
-
-            Ok(Answer::Unsigned(last_page.num_dots().try_into().unwrap()))
+            Ok(input.expect_data::<Problem>()?.code()?.into())
         },
     ],
 };
@@ -4,70 +4,68 @@ use std::str::FromStr;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
             input = "D2FE28";
-            answers = &[Some(Unsigned(6)), None];
+            answers = answers![u 6, skip];
         }
         example {
             input = "38006F45291200";
-            answers = &[Some(Unsigned(9)), None];
+            answers = answers![u 9, skip];
         }
         example {
             input = "EE00D40C823060";
-            answers = &[Some(Unsigned(14)), None];
+            answers = answers![u 14, skip];
         }
         example {
             input = "8A004A801A8002F478";
-            answers = &[Some(Unsigned(16)), None];
+            answers = answers![u 16, skip];
         }
         example {
             input = "620080001611562C8802118E34";
-            answers = &[Some(Unsigned(12)), None];
+            answers = answers![u 12, skip];
         }
         example {
             input = "C0015000016115A2E0802F182340";
-            answers = &[Some(Unsigned(23)), None];
+            answers = answers![u 23, skip];
         }
         example {
             input = "A0016C880162017C3686B18A3D4780";
-            answers = &[Some(Unsigned(31)), None];
+            answers = answers![u 31, skip];
         }
         example {
             input = "C200B40A82";
-            answers = &[None, Some(Unsigned(3))];
+            answers = answers![skip, u 3];
         }
         example {
             input = "04005AC33890";
-            answers = &[None, Some(Unsigned(54))];
+            answers = answers![skip, u 54];
         }
         example {
             input = "880086C3E88112";
-            answers = &[None, Some(Unsigned(7))];
+            answers = answers![skip, u 7];
         }
         example {
             input = "CE00C43D881120";
-            answers = &[None, Some(Unsigned(9))];
+            answers = answers![skip, u 9];
         }
         example {
             input = "D8005AC2A8F0";
-            answers = &[None, Some(Unsigned(1))];
+            answers = answers![skip, u 1];
         }
         example {
             input = "F600BC2D8F";
-            answers = &[None, Some(Unsigned(0))];
+            answers = answers![skip, u 0];
         }
         example {
             input = "9C005AC2F8F0";
-            answers = &[None, Some(Unsigned(0))];
+            answers = answers![skip, u 0];
         }
         example {
             input = "9C0141080250320F1802104A08";
-            answers = &[None, Some(Unsigned(1))];
+            answers = answers![skip, u 1];
         }
-        actual_answers = unsigned![963, 1549026292886];
     }
 }
 
@@ -335,7 +333,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 16,
     name: "Packet Decoder",
-    preprocessor: Some(|input| Ok(Box::new(Packet::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Packet>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -9,7 +9,6 @@ mod tests {
             input = "target area: x=20..30, y=-10..-5";
             answers = unsigned![45, 112];
         }
-        actual_answers = unsigned![3003, 940];
     }
 }
 
@@ -138,7 +137,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 17,
     name: "Trick Shot",
-    preprocessor: Some(|input| Ok(Box::new(TargetArea::from_str(input)?).into())),
+    preprocessor: Some(parsable_preprocessor::<TargetArea>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -19,7 +19,6 @@ mod tests {
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
             answers = unsigned![4140, 3993];
         }
-        actual_answers = unsigned![4207, 4635];
     }
 }
 
@@ -243,6 +242,7 @@ pub const SOLUTION: Solution = Solution {
     day: 18,
     name: "Snailfish",
     preprocessor: Some(|input| Ok(Box::new(SnailfishNumber::gather(input.lines())?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
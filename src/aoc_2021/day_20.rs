@@ -22,7 +22,6 @@ mod tests {
 ..###";
             answers = unsigned![35, 3351];
         }
-        actual_answers = unsigned![5361, 16826];
     }
 }
 
@@ -165,7 +164,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 20,
     name: "Trench Map",
-    preprocessor: Some(|input| Ok(Box::new(Image::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Image>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -15,9 +15,8 @@ Player 2 starting position: 8";
         }
         expensive_example {
             input = INPUT;
-            answers = &[None, Some(Answer::Unsigned(444356092776315))];
+            answers = answers![skip, u 444356092776315];
         }
-        actual_answers = unsigned![864900, 575111835924670];
     }
 }
 
@@ -26,7 +25,7 @@ mod solution {
     use super::*;
     use aoc::{
         parse::field_line_parser,
-        tree_search::{GlobalStateTreeNode, NodeAction},
+        tree_search::{ChildPool, GlobalStateTreeNode, NodeAction},
     };
     use bare_metal_modulo::{MNum, OffsetNumC};
     use derive_new::new;
@@ -211,7 +210,11 @@ mod solution {
     impl GlobalStateTreeNode for GameNode {
         type GlobalState = GameGlobalState;
 
-        fn recurse_action(self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            self,
+            global_state: &mut Self::GlobalState,
+            _pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             if self.win() {
                 global_state.num_universes_wins[self.turn] += self.num_universes;
                 return NodeAction::Stop;
@@ -246,7 +249,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 21,
     name: "Dirac Dice",
-    preprocessor: Some(|input| Ok(Box::new(Game::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Game>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -3,7 +3,6 @@ use aoc::prelude::*;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::Unsigned;
 
     solution_tests! {
         example {
@@ -99,9 +98,8 @@ off x=-27365..46395,y=31009..98017,z=15428..76570
 off x=-70369..-16548,y=22648..78696,z=-1892..86821
 on x=-53470..21291,y=-120233..-33476,z=-44150..38147
 off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
-            answers = &[None, Some(Unsigned(2758514936282235))];
+            answers = answers![skip, u 2758514936282235];
         }
-        actual_answers = unsigned![546724, 1346544039176841];
     }
 }
 
@@ -305,6 +303,7 @@ pub const SOLUTION: Solution = Solution {
         )
         .into())
     }),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -9,12 +9,9 @@ mod tests {
     #[test]
     #[ignore]
     fn actual() {
-        assert_eq!(
-            &SOLUTION
-                .run_and_print(super::super::YEAR_SOLUTIONS.year)
-                .unwrap(),
-            unsigned![92967699949891, 91411143612181]
-        );
+        let year = super::super::YEAR_SOLUTIONS.year;
+        let answers = SOLUTION.run_and_print(year, None, &Default::default()).unwrap();
+        aoc::answer_db::verify_answers(year, SOLUTION.day, &answers).unwrap();
     }
 
     #[test]
@@ -404,7 +401,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 24,
     name: "Arithmetic Logic Unit",
-    preprocessor: Some(|input| Ok(Box::new(Program::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Program>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
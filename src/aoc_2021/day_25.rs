@@ -17,14 +17,13 @@ v.v..>>v.v
 ....v..v.>";
         answers = unsigned![58];
     }
-    actual_answers = unsigned![523];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use aoc::grid::GridSpace;
+    use aoc::grid::{GridSpace, TorusGrid};
     use euclid::Vector2D;
     use std::{fmt, rc::Rc};
 
@@ -72,15 +71,15 @@ mod solution {
         }
     }
 
-    /// The state of a trench, which is a [`Grid`] of [`Location`]s.
+    /// The state of a trench, which is a [`TorusGrid`] of [`Location`]s.
     #[derive(Clone, PartialEq, Eq)]
     pub struct Trench {
         /// Grid of trench locations.
-        grid: Grid<Location>,
+        grid: TorusGrid<Location>,
     }
     impl From<Grid<Location>> for Trench {
         fn from(value: Grid<Location>) -> Self {
-            Trench { grid: value }
+            Trench { grid: value.into() }
         }
     }
     impl fmt::Debug for Trench {
@@ -95,13 +94,10 @@ mod solution {
             &'a self,
             location: &'a Location,
         ) -> impl Iterator<Item = AnyGridPoint> + 'a {
-            self.grid.all_points().filter_map(move |point| {
-                if self.grid.get(&point) == location {
-                    Some(point.to_isize())
-                } else {
-                    None
-                }
-            })
+            self.grid
+                .all_points()
+                .map(|point| point.to_isize())
+                .filter(move |point| self.grid.get(point) == location)
         }
 
         /// Determines and returns the next trench state.
@@ -116,14 +112,10 @@ mod solution {
                 let check_trench = new_trench.clone();
 
                 for point in self.specific_points(&cucumber) {
-                    let moved_point =
-                        (point + direction).wrapped_grid_point(new_trench.grid.size());
+                    let moved_point = point + direction;
                     // Move if the adjacent space is free
                     if !check_trench.grid.get(&moved_point).occupied() {
-                        new_trench.grid.set(
-                            &point.wrapped_grid_point(new_trench.grid.size()),
-                            Location::Empty,
-                        );
+                        new_trench.grid.set(&point, Location::Empty);
                         new_trench.grid.set(&moved_point, cucumber);
                     }
                 }
@@ -183,6 +175,7 @@ pub const SOLUTION: Solution = Solution {
     day: 25,
     name: "Sea Cucumber",
     preprocessor: Some(|input| Ok(Box::new(Trench::from_grid_str(input)?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
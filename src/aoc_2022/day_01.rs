@@ -22,7 +22,6 @@ mod tests {
 10000";
             answers = unsigned![24000, 45000];
         }
-        actual_answers = unsigned![72718, 213089];
     }
 }
 
@@ -60,6 +59,7 @@ pub const SOLUTION: Solution = Solution {
     day: 1,
     name: "Calorie Counting",
     preprocessor: Some(|input| Ok(Box::new(Elf::gather(input.split("\n\n"))?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
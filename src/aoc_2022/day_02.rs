@@ -12,7 +12,6 @@ B X
 C Z";
             answers = unsigned![15, 12];
         }
-        actual_answers = unsigned![13809, 12316];
     }
 }
 
@@ -202,7 +201,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 2,
     name: "Rock Paper Scissors",
-    preprocessor: Some(|input| Ok(Box::new(Game::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Game>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -16,7 +16,6 @@ ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
             answers = unsigned![157, 70];
         }
-        actual_answers = unsigned![7691, 2508];
     }
 }
 
@@ -177,7 +176,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 3,
     name: "Rucksack Reorganization",
-    preprocessor: Some(|input| Ok(Box::new(PackSet::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<PackSet>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
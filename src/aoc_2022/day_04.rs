@@ -14,7 +14,6 @@ mod tests {
 2-6,4-8";
             answers = unsigned![2, 4];
         }
-        actual_answers = unsigned![595, 952];
     }
 }
 
@@ -66,6 +65,7 @@ pub const SOLUTION: Solution = Solution {
     day: 4,
     name: "Camp Cleanup",
     preprocessor: Some(|input| Ok(Box::new(Assignment::gather(input.lines())?).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -19,7 +19,6 @@ move 2 from 2 to 1
 move 1 from 1 to 2";
             answers = string!["CMZ", "MCD"];
         }
-        actual_answers = string!["VGBBJCRMN", "LBBVJBRMH"];
     }
 }
 
@@ -263,7 +262,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 5,
     name: "Supply Stack",
-    preprocessor: Some(|input| Ok(Box::new(Problem::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Problem>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
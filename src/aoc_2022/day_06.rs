@@ -25,14 +25,12 @@ mod tests {
             input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
             answers = unsigned![11, 26];
         }
-        actual_answers = unsigned![1804, 2508];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use std::collections::HashSet;
 
     /// The data stream sent from the elves to the device.
     pub struct Datastream {
@@ -50,15 +48,12 @@ mod solution {
         /// Returns the index of the last character sent (where the first characteris 1, not 0)
         /// such that the last `size` characters are all distinct.
         fn distinct_window(&self, size: usize) -> AocResult<usize> {
-            for (idx, chunk) in self.buffer.windows(size).enumerate() {
-                let set: HashSet<char> = HashSet::from_iter(chunk.iter().copied());
-                if set.len() == size {
-                    // We want the index of the last character, not the first
-                    return Ok(idx + size);
-                }
-            }
-
-            Err(AocError::NoSolution)
+            self.buffer
+                .iter()
+                .first_distinct_window(size)
+                // We want the index of the last character, not the first
+                .map(|idx| idx + size)
+                .ok_or(AocError::NoSolution)
         }
 
         /// Returns the index of the last character sent such that the
@@ -88,6 +83,7 @@ pub const SOLUTION: Solution = Solution {
     day: 6,
     name: "Tuning Trouble",
     preprocessor: Some(|input| Ok(Box::new(Datastream::from(input)).into())),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -32,7 +32,6 @@ $ ls
 7214296 k";
             answers = unsigned![95437, 24933642];
         }
-        actual_answers = unsigned![1583951, 214171];
     }
 }
 
@@ -327,7 +326,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 7,
     name: "No Space Left On Device",
-    preprocessor: Some(|input| Ok(Box::new(Directory::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Directory>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -14,7 +14,6 @@ mod tests {
 35390";
             answers = unsigned![21, 8];
         }
-        actual_answers = unsigned![1782, 474606];
     }
 }
 
@@ -22,30 +21,6 @@ mod tests {
 mod solution {
     use super::*;
     use aoc::grid::Digit;
-    use strum::IntoEnumIterator;
-    use strum_macros::EnumIter;
-
-    /// A cardinal direction to look relative to a tree in the patch.
-    #[derive(Debug, Clone, Copy, EnumIter)]
-    enum CardinalDirection {
-        /// To the left of the tree.
-        Left,
-        /// To the right of the tree.
-        Right,
-        /// Above the tree.
-        Up,
-        /// Below the tree.
-        Down,
-    }
-
-    /// A look direction.
-    #[derive(Debug, Clone, Copy)]
-    enum LookDirection {
-        /// In from outside the patch.
-        In,
-        /// Out from a tree.
-        Out,
-    }
 
     /// The patch of trees with which we are concerned.
     pub struct TreePatch {
@@ -62,112 +37,15 @@ mod solution {
         }
     }
     impl TreePatch {
-        /// Returns a list of the trees seen relative to a particular `tree`, in order.
-        ///
-        /// The direction to look is `cardinal_dir`, but this has a different
-        /// meaning depending on the `look_dir`.
-        /// If looking in from outside the patch the look from position is in the
-        /// `cardinal_dir` from the tree.
-        /// As an example, if `cardinal_dir` is [`CardinalDirection::Left`], then we
-        /// look right in at the tree from the outside to the left of the patch.
-        /// The order of the trees will be reversed whether looking in or looking out.
-        /// We include the `tree` itself in the list if `include_tree` is `true`, otherwise
-        /// it is not included.
-        fn trees_seen(
-            &self,
-            tree: &GridPoint,
-            cardinal_dir: CardinalDirection,
-            look_dir: LookDirection,
-            include_self: bool,
-        ) -> Vec<Digit> {
-            // Get the trees seen, including ours
-            match cardinal_dir {
-                CardinalDirection::Left => {
-                    let mut v: Vec<_> = self
-                        .grid
-                        .row_iter(tree.y)
-                        .copied()
-                        .take(tree.x + if include_self { 1 } else { 0 })
-                        .collect();
-
-                    match look_dir {
-                        LookDirection::In => v,
-                        LookDirection::Out => {
-                            v.reverse();
-                            v
-                        }
-                    }
-                }
-                CardinalDirection::Right => {
-                    let mut v: Vec<_> = self
-                        .grid
-                        .row_iter(tree.y)
-                        .skip(tree.x + if include_self { 0 } else { 1 })
-                        .copied()
-                        .collect();
-
-                    match look_dir {
-                        LookDirection::In => {
-                            v.reverse();
-                            v
-                        }
-                        LookDirection::Out => v,
-                    }
-                }
-                CardinalDirection::Up => {
-                    let mut v: Vec<_> = self
-                        .grid
-                        .column_iter(tree.x)
-                        .take(tree.y + if include_self { 1 } else { 0 })
-                        .copied()
-                        .collect();
-
-                    match look_dir {
-                        LookDirection::In => v,
-                        LookDirection::Out => {
-                            v.reverse();
-                            v
-                        }
-                    }
-                }
-                CardinalDirection::Down => {
-                    let mut v: Vec<_> = self
-                        .grid
-                        .column_iter(tree.x)
-                        .skip(tree.y + if include_self { 0 } else { 1 })
-                        .copied()
-                        .collect();
-
-                    match look_dir {
-                        LookDirection::In => {
-                            v.reverse();
-                            v
-                        }
-                        LookDirection::Out => v,
-                    }
-                }
-            }
-        }
-
         /// Returns whether a particular `tree` is visible from outside the patch.
         ///
-        /// Refer to [`TreePatch::trees_seen`] for a discussion of the meaning of the
-        /// `dir`.
-        fn visible(&self, dir: CardinalDirection, tree: &GridPoint) -> bool {
-            let mut trees = self.trees_seen(tree, dir, LookDirection::In, true);
-
-            let mut visible = true;
-            let mut max_height = trees.remove(0);
-            for tree in trees {
-                if tree > max_height {
-                    max_height = tree;
-                    visible = true;
-                } else {
-                    visible = false;
-                }
-            }
-
-            visible
+        /// This is when every tree between it and the edge of the patch in some
+        /// direction is shorter.
+        fn visible(&self, tree: &GridPoint) -> bool {
+            let height = self.grid.get(tree);
+            self.grid
+                .sightlines(tree)
+                .any(|(_, trees)| trees.into_iter().all(|t| t < height))
         }
 
         /// Calculates the number of trees that are visible from outside the patch
@@ -175,32 +53,25 @@ mod solution {
         pub fn num_visible(&self) -> u64 {
             self.grid
                 .all_points()
-                .filter_count(|tree| CardinalDirection::iter().any(|dir| self.visible(dir, tree)))
-        }
-
-        /// Returns how many other trees we can see from a potential tree house built
-        /// on a particular `tree`.
-        ///
-        /// This is when looking out in a given `dir` from the potential tree house.
-        fn viewing_distance(&self, dir: CardinalDirection, tree: &GridPoint) -> u64 {
-            let height = *self.grid.get(tree);
-            let trees = self.trees_seen(tree, dir, LookDirection::Out, false);
-
-            let mut count = 0;
-            for tree in trees.into_iter() {
-                count += 1;
-                if tree >= height {
-                    break;
-                }
-            }
-
-            count
+                .filter_count(|tree| self.visible(tree))
         }
 
         /// Calculates the scenic score for a particular `tree`.
+        ///
+        /// This is the product of the number of trees that can be seen looking out in
+        /// each direction from a potential tree house built on the `tree`, where the
+        /// view is blocked by the first tree that is at least as tall.
         fn scenic_score(&self, tree: &GridPoint) -> u64 {
-            CardinalDirection::iter()
-                .map(|dir| self.viewing_distance(dir, tree))
+            let height = self.grid.get(tree);
+            self.grid
+                .sightlines(tree)
+                .map(|(_, trees)| {
+                    let seen = match trees.iter().position(|t| *t >= height) {
+                        Some(i) => i + 1,
+                        None => trees.len(),
+                    };
+                    u64::try_from(seen).unwrap()
+                })
                 .product()
         }
 
@@ -222,7 +93,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 8,
     name: "Treetop Tree House",
-    preprocessor: Some(|input| Ok(Box::new(TreePatch::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<TreePatch>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -4,165 +4,29 @@ use std::str::FromStr;
 #[cfg(test)]
 mod tests {
     use aoc::prelude_test::*;
-    use Answer::{Signed, Unsigned};
+    use Answer::Signed;
 
     solution_tests! {
         example {
             input = "noop
 addx 3
 addx -5";
-            answers = &[Some(Signed(0)), None];
+            answers = answers![s 0, skip];
         }
         example {
-            input = "addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop";
-            answers = answers![Signed(13140), Unsigned(124)];
+            input_file = "day_10_2.txt";
+            answers = answers![
+                Signed(13140),
+                Answer::from(
+                    "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+                )
+            ];
         }
-        actual_answers = answers![Signed(17940), Unsigned(92)];
     }
 }
 
@@ -337,7 +201,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 10,
     name: "Cathode-Ray Tube",
-    preprocessor: Some(|input| Ok(Box::new(Program::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Program>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -354,16 +219,10 @@ pub const SOLUTION: Solution = Solution {
         },
         // Part two
         |input| {
-            let pixels = render_crt(input.expect_data::<Program>()?);
-
-            // This requires looking at letters in the folded image,
-            // which cannot really be done in automated way easily.
-            println!("Part two image:\n");
-            println!("{pixels:?}");
-            println!("Part two actual answer: ZCBAJFJZ\n");
-
             // Process
-            Ok(Answer::Unsigned(pixels.all_values().filter_count(|p| ***p)))
+            Ok(Answer::from_grid_ocr(&render_crt(
+                input.expect_data::<Program>()?,
+            )))
         },
     ],
 };
@@ -36,7 +36,6 @@ Monkey 3:
     If false: throw to monkey 1";
             answers = unsigned![10605, 2713310158];
         }
-        actual_answers = unsigned![58794, 20151213744];
     }
 }
 
@@ -368,7 +367,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 11,
     name: "Monkey in the Middle",
-    preprocessor: Some(|input| Ok(Box::new(Monkeys::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Monkeys>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
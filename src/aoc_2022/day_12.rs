@@ -14,17 +14,15 @@ acctuvwj
 abdefghi";
             answers = unsigned![31, 29];
         }
-        actual_answers = unsigned![440, 439];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use petgraph::{algo::dijkstra, graph::NodeIndex, Graph};
 
     /// A square in in the height map.
-    #[derive(Clone)]
+    #[derive(Clone, PartialEq, Eq)]
     enum Square {
         /// The designated start space.
         Start,
@@ -67,14 +65,12 @@ mod solution {
 
     /// The entire height map.
     pub struct HeightMap {
-        /// The graph representing the height map.
-        ///
-        /// The nodes are the spaces and the edges represent directed paths between adjacent spaces.
-        graph: Graph<Square, ()>,
-        /// The node index for the designated start space.
-        start: NodeIndex,
-        /// The node index for the designated end space.
-        end: NodeIndex,
+        /// The grid of squares.
+        grid: Grid<Square>,
+        /// The designated start space.
+        start: GridPoint,
+        /// The designated end space.
+        end: GridPoint,
     }
     impl FromStr for HeightMap {
         type Err = AocError;
@@ -84,42 +80,30 @@ mod solution {
             let grid = Grid::from_str(s)?;
 
             let start = grid
-                .all_points()
-                .find(|p| matches!(grid.get(p), Square::Start))
+                .position_of(&Square::Start)
                 .ok_or(AocError::InvalidInput("No start cell!".into()))?;
             let end = grid
-                .all_points()
-                .find(|p| matches!(grid.get(p), Square::End))
+                .position_of(&Square::End)
                 .ok_or(AocError::InvalidInput("No end cell!".into()))?;
 
-            // Transform the height map into a graph.
-            let (graph, node_grid) =
-                grid.as_graph(false, |p, np| (np.height() <= p.height() + 1).then_some(()));
-
-            Ok(Self {
-                graph,
-                start: *node_grid.get(&start),
-                end: *node_grid.get(&end),
-            })
+            Ok(Self { grid, start, end })
         }
     }
     impl HeightMap {
-        /// Uses [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm) to determine
-        /// the shortest path length from the `start` square to the designated end square.
-        ///
-        /// The shortest path length is returned, or [`None``] if there is no path from `start` to the end.
-        fn fewest_steps(&self, start: NodeIndex) -> Option<u64> {
-            let map = dijkstra(&self.graph, start, Some(self.end), |_| 1);
-
-            map.get(&self.end).copied()
+        /// Returns whether it is possible to step from one square to an adjacent one, which
+        /// is the case if the new square is at most one higher.
+        fn can_step(from: &Square, to: &Square) -> bool {
+            to.height() <= from.height() + 1
         }
 
         /// Determines the shortest path length from the designated start space to the designated
         /// end space.
         ///
         /// Returns an error if there is no complete path at all.
-        pub fn fewest_steps_from_start(&self) -> AocResult<u64> {
-            self.fewest_steps(self.start).ok_or(AocError::NoSolution)
+        pub fn fewest_steps_from_start(&self) -> AocResult<usize> {
+            self.grid
+                .climbing_shortest_path(&self.start, &self.end, Self::can_step)
+                .ok_or(AocError::NoSolution)
         }
 
         /// Determines the shortest among all the shortest path lengths from every lowest square,
@@ -127,17 +111,9 @@ mod solution {
         ///
         /// Note that this of course includes the designated start square.
         /// Returns an error if none of the lowest squares have a complete path to the end square at all.
-        pub fn fewest_steps_from_lowest(&self) -> AocResult<u64> {
-            self.graph
-                .node_indices()
-                .filter_map(|ni| {
-                    if self.graph.node_weight(ni).unwrap().height() == 0 {
-                        self.fewest_steps(ni)
-                    } else {
-                        None
-                    }
-                })
-                .min()
+        pub fn fewest_steps_from_lowest(&self) -> AocResult<usize> {
+            self.grid
+                .climbing_shortest_path_from_any(|s| s.height() == 0, &self.end, Self::can_step)
                 .ok_or(AocError::NoSolution)
         }
     }
@@ -149,7 +125,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 12,
     name: "Hill Climbing Algorithm",
-    preprocessor: Some(|input| Ok(Box::new(HeightMap::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<HeightMap>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
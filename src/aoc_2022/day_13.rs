@@ -32,7 +32,6 @@ mod tests {
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
             answers = unsigned![13, 140];
         }
-        actual_answers = unsigned![5605, 24969];
     }
 }
 
@@ -182,7 +181,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 13,
     name: "Distress Signal",
-    preprocessor: Some(|input| Ok(Box::new(PacketPairs::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<PacketPairs>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
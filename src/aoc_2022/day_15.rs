@@ -28,7 +28,6 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3
 20";
             answers = unsigned![26, 56000011];
         }
-        actual_answers = unsigned![5100463, 11557863040754];
     }
 }
 
@@ -37,11 +36,12 @@ mod solution {
     use std::{collections::HashSet, str::FromStr};
 
     use super::*;
-    use aoc::parse::trim;
+    use aoc::{
+        geometry::{Diamond, DiamondCoverage},
+        parse::trim,
+    };
     use derive_more::Deref;
     use euclid::Point2D;
-    use gcollections::ops::{Bounded, Cardinality, Difference, Empty, IsEmpty};
-    use interval::{ops::Range, Interval, IntervalSet};
     use nom::{
         bytes::complete::tag,
         combinator::map,
@@ -88,18 +88,13 @@ mod solution {
         }
     }
     impl SensorReport {
-        /// Returns the interval that the sensor covers for a given row, which may be empty.
+        /// Returns the diamond that the sensor covers.
         ///
         /// This is based on the (Manhattan) distance of the sensor to the nearest beacon.
-        pub fn coverage_row(&self, row: isize) -> Interval<isize> {
-            let distance = (self.nearest_beacon - self.sensor).manhattan_len();
-
-            let dy = (self.sensor.y - row).abs();
-            if dy > distance {
-                Interval::empty()
-            } else {
-                let dx = distance - dy;
-                Interval::new(self.sensor.x - dx, self.sensor.x + dx)
+        pub fn coverage(&self) -> Diamond {
+            Diamond {
+                center: self.sensor,
+                radius: (self.nearest_beacon - self.sensor).manhattan_len(),
             }
         }
     }
@@ -109,6 +104,8 @@ mod solution {
     pub struct SensorReports {
         /// The sensor reports.
         reports: Vec<SensorReport>,
+        /// The coverage of all of the sensors.
+        coverage: DiamondCoverage,
         /// The row for which to determine the number of positions where a beacon
         /// cannot be (part one).
         row: isize,
@@ -130,36 +127,19 @@ mod solution {
                 })
             }
 
+            let reports = SensorReport::gather(secs[0].lines())?;
             Ok(Self {
-                reports: SensorReport::gather(secs[0].lines())?,
+                coverage: DiamondCoverage::new(reports.iter().map(SensorReport::coverage)),
+                reports,
                 row: parse_number("part one row", secs[1])?,
                 limit: parse_number("part two x/y limit", secs[2])?,
             })
         }
     }
     impl SensorReports {
-        /// Returns an interval set that includes the coverage for all sensors for a
-        /// particular `row`, which could be empty.
-        fn row_coverage(&self, row: isize) -> IntervalSet<isize> {
-            // Build the intervals on the row
-            let mut row_ints = IntervalSet::empty();
-            row_ints.extend(self.reports.iter().filter_map(|sr| {
-                let int = sr.coverage_row(row);
-                if int.is_empty() {
-                    None
-                } else {
-                    Some(int)
-                }
-            }));
-
-            row_ints
-        }
-
         /// Returns the number of positions where the beacon cannot be in the row
         /// provided as part of the input (part one).
         pub fn row_no_beacon_positions(&self) -> u64 {
-            let row_ints = self.row_coverage(self.row);
-
             // Unique beacons on the row
             let beacons = self
                 .reports
@@ -167,7 +147,9 @@ mod solution {
                 .filter_map(|sr| (sr.nearest_beacon.y == self.row).then_some(sr.nearest_beacon))
                 .collect::<HashSet<_>>();
 
-            (row_ints.size() - beacons.len()).try_into().unwrap()
+            (self.coverage.covered_len(self.row) - beacons.len())
+                .try_into()
+                .unwrap()
         }
 
         /// Locates the distress beacon position, searching within the limited space
@@ -176,22 +158,13 @@ mod solution {
         /// Returns the tuning frequency based on the distress beacon coordinates
         /// (part two).
         pub fn distress_beacon_tuning_frequency(&self) -> AocResult<u64> {
-            let mut whole_row = IntervalSet::empty();
-            whole_row.extend_one(Interval::new(0, self.limit));
-
-            // Go through every row looking for a gap in coverage
-            for row in 0..=self.limit {
-                let positions = whole_row.clone().difference(&self.row_coverage(row));
-                if let Some(int) = positions.into_iter().next()
-                    && !int.is_empty()
-                {
-                    // We have a position where the beacon can be!
-                    let col = int.lower();
-
-                    return Ok((col * 4000000 + row).try_into().unwrap());
-                }
-            }
-            Err(AocError::NoSolution)
+            // Look for a gap in coverage, where the beacon must be
+            let point = self
+                .coverage
+                .uncovered_point(0..=self.limit, 0..=self.limit)
+                .ok_or(AocError::NoSolution)?;
+
+            Ok((point.x * 4000000 + point.y).try_into().unwrap())
         }
     }
 }
@@ -202,7 +175,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 15,
     name: "Beacon Exclusion Zone",
-    preprocessor: Some(|input| Ok(Box::new(SensorReports::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<SensorReports>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -19,7 +19,6 @@ Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
             answers = unsigned![1651, 1707];
         }
-        actual_answers = unsigned![1940, 2469];
     }
 }
 
@@ -28,7 +27,7 @@ mod solution {
     use super::*;
     use aoc::{
         parse::trim,
-        tree_search::{GlobalStateTreeNode, Metric, NodeAction},
+        tree_search::{ChildPool, GlobalStateTreeNode, Metric, NodeAction},
     };
     use derive_more::From;
     use derive_new::new;
@@ -286,7 +285,11 @@ mod solution {
     impl<'a> GlobalStateTreeNode for SearchNode<'a> {
         type GlobalState = SearchState<'a>;
 
-        fn recurse_action(mut self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            mut self,
+            global_state: &mut Self::GlobalState,
+            _pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             // Open the current valves
             self.you
                 .open_current_valve(global_state, &mut self.closed_valves);
@@ -560,7 +563,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 16,
     name: "Proboscidea Volcanium",
-    preprocessor: Some(|input| Ok(Box::new(Volcano::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Volcano>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -10,23 +10,25 @@ mod tests {
             input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
             answers = unsigned![3068, 1514285714288];
         }
-        actual_answers = unsigned![3184, 1577077363915];
     }
 }
 
 /// Contains solution implementation items.
 mod solution {
     use super::*;
-    use aoc::grid::StdBool;
+    use aoc::{
+        direction::Direction4,
+        evolver::extrapolate,
+        grid::{check_placement, Collision, EdgePolicies, EdgePolicy, StdBool},
+    };
     use circular_buffer::CircularBuffer;
     use derive_new::new;
     use euclid::{point2, size2, vec2, Box2D, Length, Point2D, Size2D, Vector2D};
     use gat_lending_iterator::LendingIterator;
     use itertools::Itertools;
-    use num::{integer::lcm, Integer};
     use std::collections::HashSet;
-    use strum::{EnumCount, IntoEnumIterator};
-    use strum_macros::{EnumCount, EnumIter};
+    use strum::IntoEnumIterator;
+    use strum_macros::EnumIter;
 
     /// Rock shape constants.
     mod rock_shapes {
@@ -120,7 +122,7 @@ mod solution {
     }
 
     /// The different rock shapes.
-    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, EnumCount)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, EnumIter)]
     enum RockShape {
         /// Horizontal line.
         /// ```[text]
@@ -183,7 +185,7 @@ mod solution {
     }
 
     /// A rock located in the chamber.
-    #[derive(Debug, Clone, PartialEq, Eq, new)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, new)]
     struct Rock {
         /// The shape of the rock.
         rock_shape: RockShape,
@@ -205,13 +207,14 @@ mod solution {
             Box2D::from_origin_and_size(self.lower_left, self.rock_shape.size().cast_unit())
         }
 
-        /// Returns whether or not `other` collides with this rock.
-        pub fn collides(&self, other: &Self) -> bool {
-            if self.bounding_box().intersects(&other.bounding_box()) {
-                !self.points().is_disjoint(&other.points())
-            } else {
-                false
-            }
+        /// Returns whether or not a `point` is part of this rock.
+        pub fn contains(&self, point: &Point<ChamberRelativeSpace>) -> bool {
+            self.bounding_box().contains(*point)
+                && self
+                    .rock_shape
+                    .points()
+                    .iter()
+                    .any(|p| self.lower_left + p.to_vector().cast_unit() == *point)
         }
     }
     impl std::ops::Add<Vector<ChamberRelativeSpace>> for &Rock {
@@ -239,7 +242,7 @@ mod solution {
     }
 
     /// The circular chamber buffer.
-    #[derive(Clone, Default, Eq)]
+    #[derive(Clone, Default)]
     struct ChamberBuffer {
         /// The buffer of fallen rocks currently in the chamber.
         ///
@@ -258,14 +261,18 @@ mod solution {
         /// needed to search for truly period cycles (part two).
         last_jet_direction_idx: usize,
     }
-    impl PartialEq for ChamberBuffer {
-        fn eq(&self, other: &Self) -> bool {
-            self.fallen_rocks == other.fallen_rocks
-                && self.last_rock_shape == other.last_rock_shape
-                && self.last_jet_direction_idx == other.last_jet_direction_idx
-        }
-    }
     impl ChamberBuffer {
+        /// Returns the fingerprint of the chamber buffer, which is the same for buffers
+        /// from which the same rocks will fall in the same way, regardless of the floor
+        /// height.
+        pub fn fingerprint(&self) -> (Vec<Rock>, RockShape, usize) {
+            (
+                self.fallen_rocks.iter().cloned().collect(),
+                self.last_rock_shape,
+                self.last_jet_direction_idx,
+            )
+        }
+
         /// Returns the absolute height of the tower of rocks currently in the chamber.
         pub fn tower_height(&self) -> Length<u64, ChamberAbsoluteSpace> {
             self.floor_height + self.tower_height.cast_unit().try_cast().unwrap()
@@ -273,25 +280,31 @@ mod solution {
 
         /// Checks a rock to see how it sits in the current chamber.
         pub fn check_rock(&self, rock: &Rock) -> CheckRock {
-            let point = rock.lower_left;
-
-            if point.y < 0 {
-                CheckRock::FallOutBottom
-            } else {
-                let rock_box = rock.bounding_box();
-                let chamber_box = Box2D::new(
-                    point2(0, rock_box.min.y),
-                    point2(CHAMBER_WIDTH, rock_box.max.y),
-                );
-                if !chamber_box.contains_box(&rock_box) {
-                    CheckRock::OutOfBounds
-                } else if rock.lower_left.y <= self.tower_height.0
-                    && self.fallen_rocks.iter().any(|r| rock.collides(r))
-                {
-                    CheckRock::RockCollision
-                } else {
-                    CheckRock::Good
-                }
+            // The chamber is upside down in grid terms, so its floor is the top edge,
+            // and rocks can be anywhere above the tower.
+            let size = size2(
+                CHAMBER_WIDTH.try_into().unwrap(),
+                self.tower_height.0.try_into().unwrap(),
+            );
+            let edges = EdgePolicies {
+                bottom: EdgePolicy::Ignore,
+                ..Default::default()
+            };
+
+            match check_placement(
+                &size,
+                rock.rock_shape.points(),
+                &rock.lower_left,
+                &edges,
+                |p| {
+                    let point = p.to_isize();
+                    self.fallen_rocks.iter().any(|r| r.contains(&point))
+                },
+            ) {
+                Ok(()) => CheckRock::Good,
+                Err(Collision::Edge(Direction4::Up)) => CheckRock::FallOutBottom,
+                Err(Collision::Edge(_)) => CheckRock::OutOfBounds,
+                Err(Collision::Occupied(_)) => CheckRock::RockCollision,
             }
         }
 
@@ -359,7 +372,8 @@ mod solution {
         }
     }
     impl LendingIterator for ChamberSimulation<'_> {
-        type Item<'a> = &'a ChamberBuffer
+        type Item<'a>
+            = &'a ChamberBuffer
         where
             Self: 'a;
 
@@ -441,40 +455,14 @@ mod solution {
         /// If `num_rocks` is sufficiently large, a cycle in the chamber buffer is identified
         /// and used to determine the tower height without having to directly simulate `num_rocks`.
         /// This is an optimization necessary to compute part two in a reasonable amount of time.
-        pub fn tower_height(&self, num_rocks: usize) -> u64 {
+        pub fn tower_height(&self, num_rocks: usize) -> AocResult<u64> {
             let mut simulation = ChamberSimulation::new(&self.jet_directions);
-            let lcm = lcm(RockShape::COUNT, self.jet_directions.len());
-
-            if num_rocks > lcm {
-                // In this case we look for cycles to apply a remainder to reduce the compute time
-                let base_chamber_state = simulation.iterations(lcm).unwrap().clone();
-                let base_height = base_chamber_state.tower_height();
-
-                // We need to store the heights relative to the base height at each step in the cycle
-                let mut relative_heights = Vec::new();
-                relative_heights.push(Length::new(0));
-
-                // NOTE: Due to some strange limitation of the borrow checker we cannot  simply
-                // use for_each as follows:
-                //simulation.take(2 * lcm).for_each(|_| println!("here"));
-                let cycle_len = loop {
-                    if let Some(cr) = simulation.next() {
-                        relative_heights.push(cr.tower_height() - base_height);
-                        if *cr == base_chamber_state {
-                            break relative_heights.len() - 1;
-                        }
-                    }
-                };
+            let chambers = std::iter::once(ChamberBuffer::default())
+                .chain(std::iter::from_fn(|| simulation.next().cloned()));
 
-                let (num_cycles, rem) = (num_rocks - lcm).div_rem(&cycle_len);
-
-                base_height.0
-                    + relative_heights.last().unwrap().0 * u64::try_from(num_cycles).unwrap()
-                    + relative_heights[rem].0
-            } else {
-                // Directly simulate
-                simulation.iterations(num_rocks).unwrap().tower_height().0
-            }
+            extrapolate(chambers, num_rocks, ChamberBuffer::fingerprint, |c| {
+                c.tower_height().0
+            })
         }
     }
 }
@@ -485,19 +473,20 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 17,
     name: "Pyroclastic Flow",
-    preprocessor: Some(|input| Ok(Box::new(Chamber::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Chamber>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
-            Ok(input.expect_data::<Chamber>()?.tower_height(2022).into())
+            Ok(input.expect_data::<Chamber>()?.tower_height(2022)?.into())
         },
         // Part two
         |input| {
             // Process
             Ok(input
                 .expect_data::<Chamber>()?
-                .tower_height(1000000000000)
+                .tower_height(1000000000000)?
                 .into())
         },
     ],
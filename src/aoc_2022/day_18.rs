@@ -22,7 +22,6 @@ mod tests {
 2,3,5";
             answers = unsigned![64, 58];
         }
-        actual_answers = unsigned![3470, 1986];
     }
 }
 
@@ -31,7 +30,7 @@ mod solution {
     use super::*;
     use aoc::{
         parse::trim,
-        tree_search::{GlobalStateTreeNode, NodeAction},
+        tree_search::{ChildPool, GlobalStateTreeNode, NodeAction},
     };
     use derive_new::new;
     use euclid::default::{Box3D, Point3D, Vector3D};
@@ -198,7 +197,11 @@ mod solution {
     impl<'a> GlobalStateTreeNode for RegionSearchNode<'a> {
         type GlobalState = RegionSearchState<'a>;
 
-        fn recurse_action(self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            self,
+            global_state: &mut Self::GlobalState,
+            _pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             // Add to the current region
             global_state.current_region.insert(self.cube);
 
@@ -261,7 +264,8 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 18,
     name: "Boiling Boulders",
-    preprocessor: Some(|input| Ok(Box::new(Droplet::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<Droplet>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
@@ -15,9 +15,8 @@ Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsid
         }
         expensive_example {
             input = EXAMPLE_INPUT;
-            answers = &[None, Some(Answer::Unsigned(3472))];
+            answers = answers![skip, u 3472];
         }
-        actual_answers = unsigned![1294, 13640];
     }
 }
 
@@ -25,14 +24,17 @@ Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsid
 mod solution {
     use super::*;
     use aoc::{
+        iter::par_map_solve,
         parse::trim,
-        tree_search::{GlobalStateTreeNode, Metric, NodeAction},
+        tree_search::{
+            build_deadline, optimistic_total, producer_useful, ChildPool, GlobalStateTreeNode,
+            Metric, NodeAction,
+        },
     };
     use derive_more::{Add, Deref, DerefMut, From};
     use derive_new::new;
     use infinitable::Infinitable;
     use itertools::Itertools;
-    use maplit::hashmap;
     use multiset::HashMultiSet;
     use nom::{
         branch::alt,
@@ -42,7 +44,6 @@ mod solution {
         multi::{many_m_n, separated_list1},
         sequence::{delimited, pair, separated_pair, terminated},
     };
-    use num::rational::Ratio;
     use std::{collections::HashMap, hash::Hash};
     use strum::IntoEnumIterator;
     use strum_macros::EnumIter;
@@ -72,6 +73,17 @@ mod solution {
             ))(input)
         }
     }
+    impl Material {
+        /// Returns the length of the shortest chain of robots from a robot of this type to
+        /// a geode-cracking robot, including both, see [`build_deadline`].
+        fn stages(&self) -> usize {
+            match self {
+                Self::Geode => 1,
+                Self::Ore | Self::Obsidian => 2,
+                Self::Clay => 3,
+            }
+        }
+    }
 
     /// A build cost for for only one material, which can be parsed from text input.
     #[derive(Debug)]
@@ -169,45 +181,60 @@ mod solution {
         time_allowed: usize,
         /// The largest number of geodes that have been cracked so far for any recipe.
         most_geodes_cracked: GeodesCracked,
-        /// The maximum number of robots that we would ever need to support building
-        /// each robot type in a single minute.
-        max_robots_needed: RobotInventory,
-        /// For each robot type, the maximum number of minutes after which we would no
-        /// longer want to build that type of robot as doing so would not be able to
-        /// impact the number of geodes cracked by the time we must stop.
-        max_build_time: HashMap<Material, usize>,
+        /// The most of each material that can be spent in a single minute, which is the
+        /// most that building any one robot costs.
+        max_spend: MaterialInventory,
+        /// For each robot type, the last minute at which we would want to start building
+        /// that type of robot, after which doing so would not be able to impact the number
+        /// of geodes cracked by the time we must stop.
+        build_deadlines: HashMap<Material, Option<usize>>,
     }
     impl SearchState {
         /// Creates a new search state for a particular `blueprint` and `time_allowed` in minutes.
         pub fn new(blueprint: &Blueprint, time_allowed: usize) -> Self {
-            let mut max_robots_needed = RobotInventory::default();
-            for rt in Material::iter() {
+            let mut max_spend = MaterialInventory::default();
+            for material in Material::iter() {
                 let max_cost = blueprint
                     .robot_costs
                     .values()
-                    .map(|c| c.count_of(&rt))
+                    .map(|c| c.count_of(&material))
                     .max()
                     .unwrap();
-
-                max_robots_needed
-                    .insert_times(rt, if max_cost > 0 { max_cost } else { time_allowed })
+                max_spend.insert_times(material, max_cost);
             }
 
-            let max_value = |t| (Ratio::new(t, 24) * time_allowed).ceil().to_integer();
-            let max_build_time = hashmap! [
-                Material::Ore => max_value(14),
-                Material::Clay => max_value(16),
-                Material::Obsidian => max_value(20),
-                Material::Geode => max_value(22),
-            ];
-
             Self {
                 time_allowed,
                 most_geodes_cracked: GeodesCracked(0),
-                max_robots_needed,
-                max_build_time,
+                max_spend,
+                build_deadlines: Material::iter()
+                    .map(|rt| (rt, build_deadline(time_allowed, rt.stages())))
+                    .collect(),
             }
         }
+
+        /// Determines whether it is worth building a robot of some type, which will start
+        /// to be built at the `start` minute, given the current state of the `time_tracker`.
+        fn should_build(
+            &self,
+            time_tracker: &TimeTracker,
+            robot_type: Material,
+            start: usize,
+        ) -> bool {
+            // Beyond a certain time, building a robot of a given type cannot help
+            let in_time = self.build_deadlines[&robot_type].is_some_and(|d| start <= d);
+
+            // There is an upper limit on how many robots of a given type are useful, except
+            // for those cracking geodes
+            in_time
+                && (robot_type == Material::Geode
+                    || producer_useful(
+                        time_tracker.robots.count_of(&robot_type),
+                        time_tracker.materials.count_of(&robot_type),
+                        self.max_spend.count_of(&robot_type),
+                        self.time_allowed - time_tracker.elapsed_time,
+                    ))
+        }
     }
 
     /// The next robot to build.
@@ -252,7 +279,11 @@ mod solution {
     impl GlobalStateTreeNode for SearchNode<'_> {
         type GlobalState = SearchState;
 
-        fn recurse_action(mut self, global_state: &mut Self::GlobalState) -> NodeAction<Self> {
+        fn recurse_action(
+            mut self,
+            global_state: &mut Self::GlobalState,
+            pool: &mut ChildPool<Self>,
+        ) -> NodeAction<Self> {
             // Wait to build our current robot
             if let Some(ref ttb) = self.to_build_next {
                 if let Err(gc) = self
@@ -265,24 +296,32 @@ mod solution {
                 }
             }
 
-            // See which robots we can and should build next
-            let children = Material::iter()
-                .filter_map(|to_build| {
-                    if let Infinitable::Finite(t) = self.time_tracker.time_to_build_robot(&to_build)
-                    // Do not build anything after the time is up.
-                    && self.time_tracker.elapsed_time + t < global_state.time_allowed
-                    // There is an upper limit on how many robots we need of a given type.
-                    && self.time_tracker.robots.count_of(&to_build)
-                        < global_state.max_robots_needed.count_of(&to_build)
-                    // Beyond a certain time, we should not be building a robot of a given type.
-                    && self.time_tracker.elapsed_time + t <= global_state.max_build_time[&to_build]
-                    {
-                        Some(self.duplicate(ToBuildNext::new(t, to_build)))
-                    } else {
-                        None
-                    }
-                })
-                .collect_vec();
+            // Stop if we cannot possibly crack more geodes than the best recipe so far
+            let tracker = &self.time_tracker;
+            if optimistic_total(
+                tracker.geodes_cracked().0,
+                tracker.robots.count_of(&Material::Geode),
+                global_state.time_allowed - tracker.elapsed_time,
+            ) <= global_state.most_geodes_cracked.0
+            {
+                return NodeAction::Stop;
+            }
+
+            // See which robots we can and should build next, trying geode-cracking robots
+            // first so that good recipes are found early for pruning
+            let children = pool.collect(Material::iter().rev().filter_map(|to_build| {
+                if let Infinitable::Finite(t) = self.time_tracker.time_to_build_robot(&to_build)
+                    && global_state.should_build(
+                        &self.time_tracker,
+                        to_build,
+                        self.time_tracker.elapsed_time + t,
+                    )
+                {
+                    Some(self.duplicate(ToBuildNext::new(t, to_build)))
+                } else {
+                    None
+                }
+            }));
 
             // If we have nothing to build, then we can just run out the clock
             if children.is_empty() {
@@ -559,23 +598,23 @@ mod solution {
         /// each blueprint (part one).
         ///
         /// Here 24 minutes are allowed in total.
-        pub fn sum_of_quality_levels(&self) -> u64 {
-            self.blueprints
-                .iter()
-                .map(|b| u64::from(b.num) * b.largest_geodes_cracked(24))
-                .sum()
+        pub fn sum_of_quality_levels(&self) -> AocResult<u64> {
+            Ok(par_map_solve(&self.blueprints, |b| {
+                Ok(u64::from(b.num) * b.largest_geodes_cracked(24))
+            })?
+            .into_iter()
+            .sum())
         }
 
         /// Returns the product of the most geodes that can be cracked for each of the first three
         /// blueprints (part two).
         ///
         /// Here 32 minutes are allowed in total.
-        pub fn product_of_most_geodes(&self) -> u64 {
-            self.blueprints
-                .iter()
-                .take(3)
-                .map(|b| b.largest_geodes_cracked(32))
-                .product()
+        pub fn product_of_most_geodes(&self) -> AocResult<u64> {
+            let blueprints = &self.blueprints[..self.blueprints.len().min(3)];
+            Ok(par_map_solve(blueprints, |b| Ok(b.largest_geodes_cracked(32)))?
+                .into_iter()
+                .product())
         }
     }
 }
@@ -586,14 +625,15 @@ use solution::*;
 pub const SOLUTION: Solution = Solution {
     day: 19,
     name: "Not Enough Minerals",
-    preprocessor: Some(|input| Ok(Box::new(RobotFactory::from_str(input)?).into())),
+    preprocessor: Some(from_str_preprocessor::<RobotFactory>),
+    validate: None,
     solvers: &[
         // Part one
         |input| {
             // Process
             Ok(input
                 .expect_data::<RobotFactory>()?
-                .sum_of_quality_levels()
+                .sum_of_quality_levels()?
                 .into())
         },
         // Part two
@@ -601,7 +641,7 @@ pub const SOLUTION: Solution = Solution {
             // Process
             Ok(input
                 .expect_data::<RobotFactory>()?
-                .product_of_most_geodes()
+                .product_of_most_geodes()?
                 .into())
         },
     ],
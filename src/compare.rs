@@ -0,0 +1,210 @@
+//! Comparison of the run times of solutions in the working tree with those at another git
+//! revision.
+//!
+//! The other revision is checked out in a temporary git worktree, and both are built in
+//! release mode with cargo.
+//! The solutions are then verified and benchmarked by running each build with the
+//! `verify` and `bench --bench-json` subcommands, so the other revision must support these.
+
+use anyhow::{bail, Context};
+use aoc::solution::{format_duration, Year};
+use colored::Colorize;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+/// The name of the program built by cargo.
+const PROGRAM: &str = "advent-of-code";
+
+/// The directory within the target directory of the working tree in which the other
+/// revision is built, so that its dependencies only need to be built once.
+const TARGET_SUBDIR: &str = "compare";
+
+/// Runs a `command`, returning its standard output, or an error with its standard error
+/// if it does not succeed.
+fn run_command(command: &mut Command) -> anyhow::Result<String> {
+    let output = command
+        .output()
+        .with_context(|| format!("Could not run {command:?}"))?;
+    if !output.status.success() {
+        bail!(
+            "{command:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// A git worktree in a temporary directory, which is removed when dropped.
+struct Worktree {
+    /// The directory of the worktree.
+    dir: PathBuf,
+}
+impl Worktree {
+    /// Checks out a git `revision` in a new worktree.
+    fn new(revision: &str) -> anyhow::Result<Self> {
+        let dir = std::env::temp_dir().join(format!("aoc-compare-{}", std::process::id()));
+        run_command(
+            Command::new("git")
+                .args(["worktree", "add", "--detach"])
+                .arg(&dir)
+                .arg(revision),
+        )
+        .with_context(|| format!("Could not check out {revision}"))?;
+        Ok(Self { dir })
+    }
+}
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        if let Err(e) = run_command(
+            Command::new("git")
+                .args(["worktree", "remove", "--force"])
+                .arg(&self.dir),
+        ) {
+            eprintln!("Could not remove the worktree: {e}");
+        }
+    }
+}
+
+/// The program built from a revision, which is run in the directory of the revision so
+/// that it uses the inputs and answers of that revision.
+struct Build {
+    /// The path of the program.
+    program: PathBuf,
+    /// The directory of the revision.
+    dir: PathBuf,
+}
+impl Build {
+    /// Builds the program of the revision in a `dir` in release mode, with the build
+    /// files in a `target_dir`.
+    fn new(dir: &Path, target_dir: &Path) -> anyhow::Result<Self> {
+        run_command(
+            Command::new("cargo")
+                .args(["build", "--release", "--quiet", "--target-dir"])
+                .arg(target_dir)
+                .current_dir(dir),
+        )
+        .with_context(|| format!("Could not build {}", dir.display()))?;
+        Ok(Self {
+            program: target_dir.join("release").join(PROGRAM),
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    /// Returns a command to run the program with some `args` for the problem of a `day`
+    /// in some `year`.
+    fn command(&self, args: &[&str], year: Year, day: u8) -> Command {
+        let mut command = Command::new(&self.program);
+        command
+            .arg(args[0])
+            .arg(year.to_string())
+            .arg(day.to_string())
+            .args(&args[1..])
+            .current_dir(&self.dir);
+        command
+    }
+
+    /// Returns whether the answers of the solution for a `day` in some `year` match the
+    /// known answers.
+    fn verify(&self, year: Year, day: u8) -> bool {
+        run_command(&mut self.command(&["verify"], year, day)).is_ok()
+    }
+
+    /// Benchmarks the solution for a `day` in some `year` with some number of `runs`,
+    /// returning the mean total run time.
+    fn bench(&self, year: Year, day: u8, runs: u32) -> anyhow::Result<Duration> {
+        let runs = runs.to_string();
+        let report: serde_json::Value = serde_json::from_str(&run_command(&mut self.command(
+            &["bench", "--runs", &runs, "--bench-json"],
+            year,
+            day,
+        ))?)?;
+        let mean = report["total"]["mean"]
+            .as_f64()
+            .context("The benchmark report has no mean total run time")?;
+        Ok(Duration::try_from_secs_f64(mean)?)
+    }
+}
+
+/// Compares the run times of some solutions in the working tree with those at another git
+/// `revision`, printing the change in the mean total run time of each.
+///
+/// The `solutions` are the year, day, and title of each, and each is benchmarked with
+/// some number of `runs`.
+/// Solutions that cannot be run at either revision, for example because they do not
+/// exist at the other revision, are reported and skipped.
+/// The working tree must be the root of the repository.
+pub fn compare(
+    revision: &str,
+    solutions: impl IntoIterator<Item = (Year, u8, String)>,
+    runs: u32,
+) -> anyhow::Result<()> {
+    let root = std::env::current_dir()?;
+    let target_dir = root.join("target");
+    let worktree = Worktree::new(revision)?;
+
+    println!("Building the working tree and {revision}...");
+    let current = Build::new(&root, &target_dir)?;
+    let other = Build::new(&worktree.dir, &target_dir.join(TARGET_SUBDIR))?;
+
+    let (mut total_current, mut total_other) = (Duration::ZERO, Duration::ZERO);
+    for (year, day, title) in solutions {
+        let times = other
+            .bench(year, day, runs)
+            .and_then(|o| Ok((o, current.bench(year, day, runs)?)));
+        let (time_other, time_current) = match times {
+            Ok(times) => times,
+            Err(e) => {
+                println!("Year {year} {title}: {}", format!("FAILED: {e}").red());
+                continue;
+            }
+        };
+        total_current += time_current;
+        total_other += time_other;
+
+        let mut status = String::new();
+        for (build, name) in [(&other, revision), (&current, "the working tree")] {
+            if !build.verify(year, day) {
+                status.push_str(&format!(" (answers do not match in {name})"));
+            }
+        }
+        println!(
+            "Year {year} {title}: {} -> {} {}{}",
+            format_duration(time_other),
+            format_duration(time_current),
+            change(time_other, time_current),
+            status.red(),
+        );
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Total: {} -> {} {}",
+            format_duration(total_other),
+            format_duration(total_current),
+            change(total_other, total_current),
+        )
+        .bold()
+    );
+    Ok(())
+}
+
+/// Returns the relative change from the `before` time to the `after` time, colored green
+/// if it is faster and red if it is slower.
+fn change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return String::new();
+    }
+    let percent = 100.0 * (after.as_secs_f64() / before.as_secs_f64() - 1.0);
+    let text = format!("({percent:+.1}%)");
+    if after < before {
+        text.green().to_string()
+    } else if after > before {
+        text.red().to_string()
+    } else {
+        text
+    }
+}
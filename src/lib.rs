@@ -0,0 +1,79 @@
+//! These are my solutions to the [Advent of Code](https://adventofcode.com/) problems in Rust.
+//!
+//! I started this to help me learn Rust, but now the problems are just fun to solve and also help keep my Rust skills sharp!
+//! All the code is documented to some extent, including general utilities in the [`aoc`] module
+//! that are used in multiple solutions.
+//!
+//! The solutions can be run from other programs with [`run`], in addition to the
+//! `advent-of-code` command line interface.
+//!
+//! Also see the LaTeX notes for problems that required more analysis or warranted further explanation.
+//! The document is in the `notes` directory and includes a `Makefile`.
+#![feature(hash_set_entry)]
+#![feature(type_alias_impl_trait)]
+#![feature(let_chains)]
+#![feature(step_trait)]
+#![feature(ascii_char)]
+#![feature(ascii_char_variants)]
+#![feature(iter_array_chunks)]
+#![feature(impl_trait_in_assoc_type)]
+#![feature(extend_one)]
+#![warn(missing_docs)]
+#![warn(clippy::missing_docs_in_private_items)]
+
+mod aoc_2015;
+mod aoc_2020;
+mod aoc_2021;
+mod aoc_2022;
+
+use aoc::{
+    error::{AocError, AocResult},
+    solution::{Answer, Day, Solution, Year, YearSolutions},
+};
+
+/// All of the implemented solutions.
+pub const ALL_YEAR_SOLUTIONS: &[&YearSolutions] = &[
+    &aoc_2015::YEAR_SOLUTIONS,
+    &aoc_2020::YEAR_SOLUTIONS,
+    &aoc_2021::YEAR_SOLUTIONS,
+    &aoc_2022::YEAR_SOLUTIONS,
+];
+
+/// Gets the solution of the problem for a `year` and `day` or produces errors if it is not
+/// implemented.
+pub fn get_solution(year: Year, day: Day) -> AocResult<&'static Solution> {
+    ALL_YEAR_SOLUTIONS
+        .iter()
+        .find(|ys| ys.year == year)
+        .ok_or(AocError::NoYear(year))?
+        .get_day(day)
+        .ok_or(AocError::NoDay(day))
+}
+
+/// Runs the solution of the problem for a `year` and `day` on some `input`, returning the
+/// answers to all of its parts in order.
+///
+/// Nothing is printed, so this is how other programs, such as benchmarks, can run the
+/// solutions.
+/// Returns [`AocError::NoYear`] or [`AocError::NoDay`] if the problem is not solved, or
+/// the error of the solution if it fails on the input.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use aoc::prelude::*;
+/// use aoc::solution::{Day, Year};
+///
+/// let answers = advent_of_code::run(Year::new(2022).unwrap(), Day::new(1).unwrap(), "1\n2\n\n4");
+/// assert_eq!(answers, Ok(vec![Answer::Unsigned(4), Answer::Unsigned(7)]));
+///
+/// assert!(advent_of_code::run(Year::new(2016).unwrap(), Day::new(1).unwrap(), "").is_err());
+/// ```
+pub fn run(year: Year, day: Day, input: &str) -> AocResult<Vec<Answer>> {
+    Ok(get_solution(year, day)?
+        .run(input, None)?
+        .answers()
+        .into_iter()
+        .flatten()
+        .collect())
+}
@@ -1,93 +1,567 @@
-//! These are my solutions to the [Advent of Code](https://adventofcode.com/) problems in Rust.
-//!
-//! I started this to help me learn Rust, but now the problems are just fun to solve and also help keep my Rust skills sharp!
-//! All the code is documented to some extent, including general utilities in the [`aoc`] module
-//! that are used in multiple solutions.
-//!
-//! Also see the LaTeX notes for problems that required more analysis or warranted further explanation.
-//! The document is in the `notes` directory and includes a `Makefile`.
-#![feature(hash_set_entry)]
-#![feature(type_alias_impl_trait)]
-#![feature(let_chains)]
-#![feature(step_trait)]
-#![feature(ascii_char)]
-#![feature(ascii_char_variants)]
-#![feature(iter_array_chunks)]
-#![feature(impl_trait_in_assoc_type)]
-#![feature(extend_one)]
+//! The command line interface to the solutions in the [`advent_of_code`] library, which
+//! runs and verifies them, and helps with creating new ones.
 #![warn(missing_docs)]
 #![warn(clippy::missing_docs_in_private_items)]
 
-mod aoc_2015;
-mod aoc_2020;
-mod aoc_2021;
-mod aoc_2022;
+mod compare;
+mod scaffold;
+mod scrub;
+mod site;
 
-use aoc::error::AocError;
-use clap::Parser;
+use advent_of_code::{get_solution, ALL_YEAR_SOLUTIONS};
+use anyhow::Context;
+use aoc::{
+    answer_db::{verify_answers, AnswerDb, ANSWERS_FILE},
+    error::AocError,
+    record::{finish_recording, start_recording, RecordOptions},
+    run_history::RunHistory,
+    solution::{format_duration, Day, Part, TimingTiers, Year},
+    vm::start_debugging,
+};
+use clap::{Args, Parser, Subcommand};
 use colored::Colorize;
 use itertools::Itertools;
+use std::{
+    collections::hash_map::RandomState,
+    fs,
+    hash::BuildHasher,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+use tracing::Level;
 
 /// Run the Advent of Code solution for a particular year and day.
+///
+/// Solutions can be run without the `run` subcommand for backward compatibility.
 #[derive(Parser)]
-#[command(name = "Advent of Code Solutions", author, version)]
-struct Args {
+#[command(
+    name = "Advent of Code Solutions",
+    author,
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Options to run a solution when no subcommand is given.
+    #[command(flatten)]
+    run: RunArgs,
+    /// Show log messages from the solutions on standard error, with more detail each
+    /// time this is given.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+/// The subcommands.
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a particular year and day.
+    Run(RunArgs),
     /// List the implemented solutions.
+    List,
+    /// Verify the answers of solutions against the known answers in the answer manifest.
+    Verify(VerifyArgs),
+    /// Run the solution for a particular year and day several times and report the run times.
+    Bench(BenchArgs),
+    /// Create the skeleton of a new solution for a particular year and day.
+    New(NewArgs),
+    /// Download the input for a particular year and day from the website.
+    Fetch(FetchArgs),
+    /// Submit the answer to a part of a particular year and day to the website.
+    Submit(SubmitArgs),
+    /// Compare the run times of solutions in the working tree with those at another git revision.
+    Compare(CompareArgs),
+    /// Write a copy of the input for a particular year and day with its numbers and labels
+    /// randomized, which can be shared.
+    Scrub(ScrubArgs),
+}
+
+/// Identifies the problem of a particular year and day.
+#[derive(Args)]
+struct ProblemArgs {
+    /// Year of the problem.
+    #[arg(name = "YEAR", required = true)]
+    year: Option<Year>,
+    /// Day of the problem (1-25).
+    #[arg(name = "DAY", required = true)]
+    day: Option<Day>,
+}
+impl ProblemArgs {
+    /// Returns the year and day, which are always set when parsed as required.
+    fn get(&self) -> (Year, Day) {
+        (self.year.unwrap(), self.day.unwrap())
+    }
+}
+
+/// Thresholds for color-coding run times.
+#[derive(Args)]
+struct TimingArgs {
+    /// Run times in milliseconds below which are shown as fast.
+    #[arg(long, default_value_t = 10)]
+    fast_ms: u64,
+    /// Run times in milliseconds below which are shown as moderate, with slower times
+    /// shown as slow.
+    #[arg(long, default_value_t = 1000)]
+    slow_ms: u64,
+}
+impl TimingArgs {
+    /// Returns the timing tiers.
+    fn tiers(&self) -> TimingTiers {
+        TimingTiers {
+            fast: Duration::from_millis(self.fast_ms),
+            slow: Duration::from_millis(self.slow_ms),
+        }
+    }
+}
+
+/// Options for the `run` subcommand.
+#[derive(Args)]
+struct RunArgs {
+    /// The problem to run.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// Run only this part of the problem (1-2).
     #[arg(short, long)]
-    list: bool,
-    /// Year of the problem solution to run.
-    #[arg(name = "YEAR", required_unless_present("list"))]
-    year: Option<u16>,
-    /// Day of the problem solution to run (1-25).
-    #[arg(name = "DAY", required_unless_present("list"))]
-    day: Option<u8>,
+    part: Option<Part>,
+    /// Thresholds for color-coding run times.
+    #[command(flatten)]
+    timing: TimingArgs,
+    /// Record the frames of solutions that support it to files in this directory.
+    #[arg(long, value_name = "DIR")]
+    record: Option<PathBuf>,
+    /// Time in milliseconds for which each recorded frame is shown in animations.
+    #[arg(long, default_value_t = 100)]
+    frame_delay_ms: u64,
+    /// Size in pixels of each grid element in recorded animations.
+    #[arg(long, default_value_t = 4)]
+    frame_scale: u16,
+    /// Verify the answers to every part against the known answers in the answer manifest.
+    #[arg(long, conflicts_with = "part")]
+    verify: bool,
+    /// Run solutions that use virtual machines in an interactive debugger.
+    #[arg(long)]
+    debug: bool,
 }
 
-/// Runs the program, of course.
-fn main() -> anyhow::Result<()> {
-    // Parse command line arguments
-    let cli = Args::parse();
+/// Options for the `verify` subcommand.
+#[derive(Args)]
+struct VerifyArgs {
+    /// Verify only the solutions of this year.
+    #[arg(name = "YEAR")]
+    year: Option<Year>,
+    /// Verify only the solution of this day of the year (1-25).
+    #[arg(name = "DAY", requires = "YEAR")]
+    day: Option<Day>,
+}
+
+/// Options for the `bench` subcommand.
+#[derive(Args)]
+struct BenchArgs {
+    /// The problem to run.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// Run only this part of the problem (1-2).
+    #[arg(short, long)]
+    part: Option<Part>,
+    /// The number of times to run the solution.
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Thresholds for color-coding run times.
+    #[command(flatten)]
+    timing: TimingArgs,
+    /// Print the report as JSON, with times in seconds, instead of as text.
+    #[arg(long)]
+    bench_json: bool,
+}
+
+/// Statistics of the run times of one phase of a solution over several runs.
+struct TimeStats {
+    /// The name of the phase.
+    phase: String,
+    /// The mean run time.
+    mean: Duration,
+    /// The minimum run time.
+    min: Duration,
+    /// The maximum run time.
+    max: Duration,
+}
+impl TimeStats {
+    /// Computes the statistics for a `phase` from its run `times`.
+    fn new(phase: String, times: Vec<Duration>) -> Self {
+        Self {
+            phase,
+            mean: times.iter().sum::<Duration>() / u32::try_from(times.len()).unwrap(),
+            min: *times.iter().min().unwrap(),
+            max: *times.iter().max().unwrap(),
+        }
+    }
+}
+
+/// Options for the `new` subcommand.
+#[derive(Args)]
+struct NewArgs {
+    /// The problem to create the solution for.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// The name of the problem.
+    #[arg(name = "NAME")]
+    name: String,
+}
+
+/// Options for the `fetch` subcommand.
+#[derive(Args)]
+struct FetchArgs {
+    /// The problem to download the input for.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// Download the input even if the input file already exists.
+    #[arg(long)]
+    overwrite: bool,
+}
+
+/// Options for the `submit` subcommand.
+#[derive(Args)]
+struct SubmitArgs {
+    /// The problem to submit the answer to.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// The part of the problem to submit the answer to (1-2).
+    #[arg(name = "PART")]
+    part: Part,
+}
+
+/// Options for the `compare` subcommand.
+#[derive(Args)]
+struct CompareArgs {
+    /// The git revision to compare with, such as a branch, tag, or commit.
+    #[arg(name = "REF")]
+    revision: String,
+    /// Compare only the solutions of this year.
+    #[arg(name = "YEAR")]
+    year: Option<Year>,
+    /// Compare only the solution of this day of the year (1-25).
+    #[arg(name = "DAY", requires = "YEAR")]
+    day: Option<Day>,
+    /// The number of times to run each solution at each revision.
+    #[arg(short, long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+}
+
+/// Options for the `scrub` subcommand.
+#[derive(Args)]
+struct ScrubArgs {
+    /// The problem to scrub the input of.
+    #[command(flatten)]
+    problem: ProblemArgs,
+    /// The seed of the randomization, which is random if not given.
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Write the scrubbed input to this file instead of printing it.
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+/// Runs a solution and prints the answers, see [`RunArgs`].
+fn run(args: RunArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let solution = get_solution(year, day)?;
+
+    if let Some(dir) = args.record {
+        start_recording(RecordOptions {
+            dir,
+            frame_delay: Duration::from_millis(args.frame_delay_ms),
+            scale: args.frame_scale,
+        })?;
+    }
+    if args.debug {
+        start_debugging();
+    }
+    let result = solution.run_and_print(year, args.part, &args.timing.tiers());
+    finish_recording();
+    let answers = result?;
+
+    if args.verify {
+        verify_answers(year, solution.day, &answers)?;
+        println!("{}", format!("Answers match {ANSWERS_FILE}").green());
+    }
+
+    Ok(())
+}
+
+/// Lists all the implemented solutions, along with their known answers and most recent
+/// run times.
+fn list() {
+    let history = RunHistory::load();
+    let answers = AnswerDb::load().ok();
+    println!(
+        "{}",
+        ALL_YEAR_SOLUTIONS
+            .iter()
+            .map(|year_solutions| {
+                let year = year_solutions.year;
+                format!(
+                    "{}\n{}",
+                    format!("{year}").bold().underline(),
+                    year_solutions.solution_list(&history, answers),
+                )
+            })
+            .join("\n\n")
+    );
+}
+
+/// Verifies the answers of the selected solutions, see [`VerifyArgs`], continuing after
+/// any that fail.
+fn verify(args: VerifyArgs) -> anyhow::Result<()> {
+    let mut failures = 0;
+    for year_solutions in ALL_YEAR_SOLUTIONS
+        .iter()
+        .filter(|ys| args.year.is_none_or(|y| ys.year == y))
+    {
+        let year = year_solutions.year;
+        for solution in year_solutions
+            .solutions
+            .iter()
+            .filter(|s| args.day.is_none_or(|d| s.day == d.get()))
+        {
+            let result = solution.read_input(year).and_then(|input| {
+                let answers = solution.run(&input, None)?.answers();
+                Ok(verify_answers(year, solution.day, &answers)?)
+            });
+            let status = match result {
+                Ok(()) => "ok".green(),
+                Err(e) => {
+                    failures += 1;
+                    format!("FAILED: {e}").red()
+                }
+            };
+            println!("Year {year} {}: {status}", solution.title());
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} solutions do not match {ANSWERS_FILE}");
+    }
+    println!("{}", format!("All answers match {ANSWERS_FILE}").green());
+    Ok(())
+}
+
+/// Runs a solution several times and prints statistics of the run times, see [`BenchArgs`].
+///
+/// The preprocessing, which includes parsing the input, is reported separately from
+/// solving each part.
+/// When all the parts are run, the mean total run time is appended to the run history.
+fn bench(args: BenchArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let solution = get_solution(year, day)?;
+    let tiers = args.timing.tiers();
 
-    let all_year_solutions = vec![
-        &aoc_2015::YEAR_SOLUTIONS,
-        &aoc_2020::YEAR_SOLUTIONS,
-        &aoc_2021::YEAR_SOLUTIONS,
-        &aoc_2022::YEAR_SOLUTIONS,
-    ];
+    let input = solution.read_input(year)?;
+    let runs = (0..args.runs)
+        .map(|_| solution.run(&input, args.part))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    if cli.list {
-        // List all implemented solutions
+    let mut stats = Vec::new();
+    if solution.preprocessor.is_some() || solution.validate.is_some() {
+        stats.push(TimeStats::new(
+            "Preprocessing".to_string(),
+            runs.iter().map(|r| r.preprocess_time).collect(),
+        ));
+    }
+    for (idx, part) in Part::all().take(solution.solvers.len()).enumerate() {
+        if args.part.is_some_and(|p| p != part) {
+            continue;
+        }
+        stats.push(TimeStats::new(
+            format!("Part {}", part.name()),
+            runs.iter()
+                .map(|r| r.parts[idx].as_ref().unwrap().1)
+                .collect(),
+        ));
+    }
+    let total = TimeStats::new(
+        "Total".to_string(),
+        runs.iter().map(|r| r.total_time()).collect(),
+    );
+    if args.part.is_none() {
+        RunHistory::record(year, day, total.mean)?;
+    }
+
+    if args.bench_json {
+        let phase_json = |s: &TimeStats| {
+            serde_json::json!({
+                "phase": s.phase,
+                "mean": s.mean.as_secs_f64(),
+                "min": s.min.as_secs_f64(),
+                "max": s.max.as_secs_f64(),
+            })
+        };
+        let report = serde_json::json!({
+            "year": year.get(),
+            "day": day.get(),
+            "name": solution.name,
+            "runs": args.runs,
+            "phases": stats.iter().map(phase_json).collect::<Vec<_>>(),
+            "total": phase_json(&total),
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Year {year} {} ({} runs)", solution.title(), args.runs).yellow()
+    );
+    for s in stats.iter().chain([&total]) {
+        let share = if s.phase == total.phase || total.mean.is_zero() {
+            String::new()
+        } else {
+            format!(
+                " ({:.1}% of total)",
+                100.0 * s.mean.as_secs_f64() / total.mean.as_secs_f64()
+            )
+        };
         println!(
-            "{}",
-            all_year_solutions
-                .into_iter()
-                .map(|year_solutions| {
-                    let year = year_solutions.year;
-                    format!(
-                        "{}\n{}",
-                        format!("{year}").bold().underline(),
-                        year_solutions.solution_list(),
-                    )
-                })
-                .join("\n\n")
+            "{}: mean {}, min {}, max {}{share}",
+            s.phase,
+            tiers.colorize(s.mean),
+            format_duration(s.min),
+            format_duration(s.max),
         );
+    }
+
+    Ok(())
+}
+
+/// Creates the skeleton of a new solution, see [`NewArgs`].
+fn new(args: NewArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let path = scaffold::new_solution(year, day, &args.name)?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+/// Downloads the input of a problem, see [`FetchArgs`].
+fn fetch(args: FetchArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let path = PathBuf::from(format!("input/{year}/day_{:02}.txt", day.get()));
+    if site::fetch_input(year, day, &path, args.overwrite)? {
+        println!("Downloaded {}", path.display());
     } else {
-        // Get solution or produce errors if it is not implemented
-        let year = cli.year.unwrap();
-        let day = cli.day.unwrap();
-        let year_solutions = all_year_solutions
-            .iter()
-            .find(|ys| ys.year == year)
-            .ok_or(AocError::NoYear(year))?;
-        let day_range = 1..=25;
-        if !day_range.contains(&day) {
-            return Err(AocError::DayRange(day, day_range).into());
-        }
-        let solution = year_solutions.get_day(day).ok_or(AocError::NoDay(day))?;
+        println!("{} already exists", path.display());
+    }
+    Ok(())
+}
+
+/// Runs a solution and submits the answer to a part, see [`SubmitArgs`].
+fn submit(args: SubmitArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let solution = get_solution(year, day)?;
+
+    let answer = solution
+        .run_and_print(year, Some(args.part), &TimingTiers::default())?
+        .into_iter()
+        .nth(args.part.index())
+        .flatten()
+        .ok_or_else(|| {
+            AocError::Process(format!("There is no solver for part {}", args.part).into())
+        })?;
+    let response = site::submit_answer(year, day, args.part, &answer)?;
+    if response.correct {
+        println!("{}", response.message.green());
+    } else {
+        println!("{}", response.message.red());
+    }
+    Ok(())
+}
+
+/// Compares the run times of the selected solutions with those at another revision, see
+/// [`CompareArgs`].
+fn compare(args: CompareArgs) -> anyhow::Result<()> {
+    let solutions = ALL_YEAR_SOLUTIONS
+        .iter()
+        .filter(|ys| args.year.is_none_or(|y| ys.year == y))
+        .flat_map(|ys| {
+            ys.solutions
+                .iter()
+                .filter(|s| args.day.is_none_or(|d| s.day == d.get()))
+                .map(|s| (ys.year, s.day, s.title()))
+        });
+    compare::compare(&args.revision, solutions, args.runs)
+}
+
+/// Scrubs the input of a problem, see [`ScrubArgs`], after checking that the solution can
+/// still parse the scrubbed input.
+///
+/// The input is parsed by preprocessing it, or by solving it if the solution has no
+/// preprocessor, since the solvers then parse the input themselves.
+fn scrub(args: ScrubArgs) -> anyhow::Result<()> {
+    let (year, day) = args.problem.get();
+    let solution = get_solution(year, day)?;
+    let seed = args
+        .seed
+        .unwrap_or_else(|| RandomState::new().hash_one(SystemTime::now()));
 
-        // Run the solution
-        solution.run_and_print(year_solutions.year)?;
+    let scrubbed = scrub::scrub(
+        &solution.read_input(year)?,
+        scrub::solution_keywords(year, day)?,
+        seed,
+    )?;
+    let failed =
+        || format!("The input scrubbed with seed {seed} cannot be parsed, try another seed");
+    if solution.preprocessor.is_none() {
+        solution.run(&scrubbed, None).with_context(failed)?;
+        eprintln!("{}", "The scrubbed input can be solved".green());
+    } else {
+        solution.preprocess(&scrubbed).with_context(failed)?;
+        eprintln!("{}", "The scrubbed input can be preprocessed".green());
     }
 
+    match args.output {
+        Some(path) => {
+            fs::write(&path, scrubbed)
+                .with_context(|| format!("Could not write {}", path.display()))?;
+            eprintln!("Wrote {} with seed {seed}", path.display());
+        }
+        None => {
+            print!("{scrubbed}");
+            eprintln!("Scrubbed with seed {seed}");
+        }
+    }
     Ok(())
 }
+
+/// Runs the program, of course.
+fn main() -> anyhow::Result<()> {
+    // Parse command line arguments
+    let cli = Cli::parse();
+
+    // Only warnings are logged by default
+    tracing_subscriber::fmt()
+        .with_max_level(match cli.verbose {
+            0 => Level::WARN,
+            1 => Level::INFO,
+            2 => Level::DEBUG,
+            _ => Level::TRACE,
+        })
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+
+    match cli.command {
+        None => run(cli.run),
+        Some(Command::Run(args)) => run(args),
+        Some(Command::List) => {
+            list();
+            Ok(())
+        }
+        Some(Command::Verify(args)) => verify(args),
+        Some(Command::Bench(args)) => bench(args),
+        Some(Command::New(args)) => new(args),
+        Some(Command::Fetch(args)) => fetch(args),
+        Some(Command::Submit(args)) => submit(args),
+        Some(Command::Compare(args)) => compare(args),
+        Some(Command::Scrub(args)) => scrub(args),
+    }
+}
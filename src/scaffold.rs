@@ -0,0 +1,100 @@
+//! Creation of the skeleton of a new solution for a day's problem.
+
+use anyhow::{bail, Context};
+use aoc::solution::{Day, Year};
+use std::{fs, path::PathBuf};
+
+/// The template of a new solution module, in which `{day}` and `{name}` are replaced
+/// with the day and name of the problem.
+const TEMPLATE: &str = r#"use aoc::solution_prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use aoc::prelude_test::*;
+
+    solution_tests! {
+        example {
+            input = "";
+            answers = unsigned![0];
+        }
+    }
+}
+
+/// Contains solution implementation items.
+mod solution {
+    use super::*;
+}
+
+use solution::*;
+
+/// Solution struct.
+pub const SOLUTION: Solution = Solution {
+    day: {day},
+    name: "{name}",
+    preprocessor: None,
+    validate: None,
+    solvers: &[
+        // Part one
+        |input| {
+            // Process
+            Ok(0u64.into())
+        },
+    ],
+};
+"#;
+
+/// Creates the module for a new solution to the problem of a `day` in some `year` with
+/// a `name`, and adds it to the days of the solutions module for the year.
+///
+/// The solutions module for the year must already exist.
+/// Returns the path of the new module.
+pub fn new_solution(year: Year, day: Day, name: &str) -> anyhow::Result<PathBuf> {
+    let year_dir = PathBuf::from(format!("src/aoc_{year}"));
+    let mod_path = year_dir.join("mod.rs");
+    let day_mod = format!("day_{:02}", day.get());
+    let day_path = year_dir.join(format!("{day_mod}.rs"));
+
+    let year_mod = fs::read_to_string(&mod_path).with_context(|| {
+        format!(
+            "Could not read {}, the solutions module for year {year} needs to be created first",
+            mod_path.display()
+        )
+    })?;
+    if day_path.exists() {
+        bail!("The solution module {} already exists", day_path.display());
+    }
+
+    // Add the day to the list of days, keeping them in order
+    let Some((head, rest)) = year_mod.split_once("days = [") else {
+        bail!("Could not find the list of days in {}", mod_path.display());
+    };
+    let Some((days, tail)) = rest.split_once(']') else {
+        bail!(
+            "Could not find the end of the list of days in {}",
+            mod_path.display()
+        );
+    };
+    let mut days: Vec<_> = days
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .collect();
+    days.push(&day_mod);
+    days.sort();
+    let days: String = days
+        .into_iter()
+        .map(|d| format!("\n        {d},"))
+        .collect();
+
+    fs::write(
+        &day_path,
+        TEMPLATE
+            .replace("{day}", &day.to_string())
+            .replace("{name}", name),
+    )
+    .with_context(|| format!("Could not write {}", day_path.display()))?;
+    fs::write(&mod_path, format!("{head}days = [{days}\n    ]{tail}"))
+        .with_context(|| format!("Could not write {}", mod_path.display()))?;
+
+    Ok(day_path)
+}
@@ -0,0 +1,165 @@
+//! Scrubbing of problem inputs so that they can be shared, for example to reproduce a bug
+//! in the support crate.
+//!
+//! The website asks that inputs are not published, so every number and label in an input
+//! is replaced with a random one of the same form, while keeping the rest of the input.
+//! Replacements are consistent, so that the same number or label is always replaced with
+//! the same thing and different ones are replaced with different things.
+//! Words that appear in the source code of the solution are kept, since these are likely
+//! to be keywords that the solution parses, rather than labels.
+
+use anyhow::{bail, Context};
+use aoc::{
+    math::SplitMix,
+    solution::{Day, Year},
+};
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+/// The number of random replacements to try for each number or label before giving up
+/// on finding one that has not already been used.
+const MAX_TRIES: usize = 1000;
+
+/// Returns the words in the source code of the solution to the problem of a `day` in
+/// some `year`, which includes any keywords that the solution parses.
+pub fn solution_keywords(year: Year, day: Day) -> anyhow::Result<HashSet<String>> {
+    let path = format!("src/aoc_{year}/day_{:02}.rs", day.get());
+    let source = fs::read_to_string(&path)
+        .with_context(|| format!("Could not read the solution module {path}"))?;
+    Ok(source
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// The kind of a run of characters in an input.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// A number, with only digits.
+    Number,
+    /// A word, with only letters.
+    Word,
+    /// Anything else, which is kept.
+    Other,
+}
+impl Kind {
+    /// Returns the kind of a character.
+    fn of(c: char) -> Self {
+        if c.is_ascii_digit() {
+            Self::Number
+        } else if c.is_ascii_alphabetic() {
+            Self::Word
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Replaces numbers and words with random ones of the same form.
+struct Scrubber {
+    /// The random number generator.
+    rng: SplitMix,
+    /// The words to keep.
+    keywords: HashSet<String>,
+    /// The digits in the input, from which the digits of replacements are chosen.
+    digits: Vec<char>,
+    /// The lowercase letters in the input, from which the lowercase letters of
+    /// replacements are chosen.
+    lowercase: Vec<char>,
+    /// The uppercase letters in the input, from which the uppercase letters of
+    /// replacements are chosen.
+    uppercase: Vec<char>,
+    /// The replacement of each number or word that has been replaced.
+    replacements: HashMap<String, String>,
+    /// The replacements that have been used.
+    used: HashSet<String>,
+}
+impl Scrubber {
+    /// Returns a random replacement for a `token` with the same kind of character for each
+    /// character, without adding a leading zero to a number.
+    fn random_like(&mut self, token: &str) -> String {
+        let mut replacement = String::with_capacity(token.len());
+        for (idx, c) in token.chars().enumerate() {
+            let alphabet = if c.is_ascii_digit() {
+                &self.digits
+            } else if c.is_ascii_uppercase() {
+                &self.uppercase
+            } else {
+                &self.lowercase
+            };
+            let choices = if idx == 0 && c != '0' && token.len() > 1 {
+                alphabet.iter().copied().filter(|d| *d != '0').collect_vec()
+            } else {
+                alphabet.clone()
+            };
+            replacement.push(if choices.is_empty() {
+                c
+            } else {
+                choices[self.rng.below(choices.len())]
+            });
+        }
+        replacement
+    }
+
+    /// Returns the replacement for a number or word `token`, which is the token itself
+    /// for keywords.
+    fn replace(&mut self, token: &str) -> anyhow::Result<String> {
+        if self.keywords.contains(token) {
+            return Ok(token.to_string());
+        }
+        if let Some(replacement) = self.replacements.get(token) {
+            return Ok(replacement.clone());
+        }
+
+        for _ in 0..MAX_TRIES {
+            let replacement = self.random_like(token);
+            if !self.used.contains(&replacement) && !self.keywords.contains(&replacement) {
+                self.used.insert(replacement.clone());
+                self.replacements
+                    .insert(token.to_string(), replacement.clone());
+                return Ok(replacement);
+            }
+        }
+        bail!("Could not find an unused replacement for '{token}'")
+    }
+}
+
+/// Returns a copy of an `input` in which every number and word other than the `keywords`
+/// is consistently replaced with a random one of the same form, using a random `seed`.
+///
+/// Numbers keep their number of digits, and words keep their length and the case of each
+/// letter.
+/// Replacements only use the digits and letters that appear in the input, so that, for
+/// example, binary numbers remain binary.
+pub fn scrub(input: &str, keywords: HashSet<String>, seed: u64) -> anyhow::Result<String> {
+    let alphabet = |f: fn(&char) -> bool| input.chars().filter(f).sorted().dedup().collect_vec();
+    let mut scrubber = Scrubber {
+        rng: SplitMix::new(seed),
+        keywords,
+        digits: alphabet(char::is_ascii_digit),
+        lowercase: alphabet(char::is_ascii_lowercase),
+        uppercase: alphabet(char::is_ascii_uppercase),
+        replacements: HashMap::new(),
+        used: HashSet::new(),
+    };
+
+    let mut scrubbed = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        let kind = Kind::of(c);
+        let mut token = String::from(c);
+        while let Some(next) = chars.next_if(|n| Kind::of(*n) == kind) {
+            token.push(next);
+        }
+
+        match kind {
+            Kind::Number | Kind::Word => scrubbed.push_str(&scrubber.replace(&token)?),
+            Kind::Other => scrubbed.push_str(&token),
+        }
+    }
+    Ok(scrubbed)
+}
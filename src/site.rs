@@ -0,0 +1,102 @@
+//! Access to the Advent of Code website to fetch problem inputs and submit answers.
+//!
+//! Both need the session cookie of a logged in account, which is read from the
+//! [`SESSION_VAR`] environment variable.
+
+use anyhow::Context;
+use aoc::solution::{Answer, Day, Part, Year};
+use itertools::Itertools;
+use std::{fs, path::Path};
+
+/// The environment variable containing the session cookie for the website.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The base URL of the website.
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the program to the website, as requested by its operators for
+/// automated access.
+const USER_AGENT: &str = "github.com/kyp44/advent-of-code";
+
+/// Returns the value of the cookie header for the session of the [`SESSION_VAR`].
+fn session_cookie() -> anyhow::Result<String> {
+    let session = std::env::var(SESSION_VAR).with_context(|| {
+        format!("The {SESSION_VAR} environment variable must be set to the website session cookie")
+    })?;
+    Ok(format!("session={}", session.trim()))
+}
+
+/// Downloads the input for the problem of a `day` in some `year` to a file at `path`.
+///
+/// An existing input file is only replaced if `overwrite` is set, so that the website
+/// is not accessed needlessly.
+/// Returns whether the input was downloaded.
+pub fn fetch_input(year: Year, day: Day, path: &Path, overwrite: bool) -> anyhow::Result<bool> {
+    if path.exists() && !overwrite {
+        return Ok(false);
+    }
+
+    let input = ureq::get(&format!("{BASE_URL}/{year}/day/{day}/input"))
+        .set("Cookie", &session_cookie()?)
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .with_context(|| format!("Could not fetch the input for year {year} day {day}"))?
+        .into_string()?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input)
+        .with_context(|| format!("Could not write input file {}", path.display()))?;
+    Ok(true)
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug)]
+pub struct SubmitResponse {
+    /// Whether the answer was accepted as correct.
+    pub correct: bool,
+    /// The message from the website, which explains why an answer was not accepted,
+    /// for example when answers are submitted too quickly.
+    pub message: String,
+}
+
+/// Submits an `answer` to a `part` of the problem of a `day` in some `year`.
+pub fn submit_answer(
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &Answer,
+) -> anyhow::Result<SubmitResponse> {
+    let page = ureq::post(&format!("{BASE_URL}/{year}/day/{day}/answer"))
+        .set("Cookie", &session_cookie()?)
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[
+            ("level", &part.to_string()),
+            ("answer", &answer.to_string()),
+        ])
+        .with_context(|| format!("Could not submit the answer for year {year} day {day}"))?
+        .into_string()?;
+
+    // The message is the text of the main article of the page
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .unwrap_or(&page);
+    let mut message = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => message.push(c),
+            _ => {}
+        }
+    }
+
+    Ok(SubmitResponse {
+        correct: message.contains("That's the right answer"),
+        message: message.split_whitespace().join(" "),
+    })
+}